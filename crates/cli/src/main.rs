@@ -4,10 +4,14 @@
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::{anyhow, bail, Result};
 use clap::Parser;
-use digests_feed::{apply_metadata_to_feed, enrich_items_with_metadata, parse_feed_bytes, pick_site_url};
+use digests_feed::{
+    apply_metadata_to_feed, enrich_items_parallel, parse_feed_bytes, pick_site_url,
+    probe_enclosures, EnclosureHead, FetchOutcome, ParallelEnrichmentOptions, UNLIMITED_FETCHES,
+};
 use digests_hermes::extract_metadata_only;
 use reqwest::blocking::Client;
 use serde_json::json;
@@ -28,6 +32,14 @@ struct Args {
     /// Output compact JSON instead of pretty.
     #[arg(long, default_value_t = false)]
     compact: bool,
+
+    /// Issue HEAD requests to fill in missing enclosure length/type.
+    #[arg(long, default_value_t = false)]
+    probe_enclosures: bool,
+
+    /// Maximum concurrent item page fetches during thumbnail enrichment.
+    #[arg(long, default_value_t = 8)]
+    enrich_concurrency: usize,
 }
 
 fn main() -> Result<()> {
@@ -37,7 +49,14 @@ fn main() -> Result<()> {
         bail!("--feed-url is only valid when parsing a single target");
     }
 
-    let http_client = Client::builder().user_agent("digests-cli/0.1").build()?;
+    let http_client = Client::builder()
+        .user_agent("digests-cli/0.1")
+        .timeout(Duration::from_secs(15))
+        .build()?;
+    let enrich_options = ParallelEnrichmentOptions {
+        concurrency: args.enrich_concurrency,
+        ..Default::default()
+    };
 
     let mut results = Vec::new();
 
@@ -57,12 +76,26 @@ fn main() -> Result<()> {
                 }
 
                 // Item-level metadata thumbnails (only missing ones)
-                enrich_items_with_metadata(&mut feed, |url| {
-                    fetch_url(&http_client, url)
-                        .ok()
-                        .and_then(|html| extract_metadata_only(&html, url).ok())
+                let item_client = http_client.clone();
+                enrich_items_parallel(&mut feed, &enrich_options, move |url| {
+                    match fetch_url(&item_client, url) {
+                        Ok(html) => extract_metadata_only(&html, url).ok().into(),
+                        Err(err) => match err
+                            .downcast_ref::<reqwest::Error>()
+                            .and_then(|e| e.status())
+                        {
+                            Some(status) => FetchOutcome::HttpStatus(status.as_u16()),
+                            None => FetchOutcome::Failed,
+                        },
+                    }
                 });
 
+                if args.probe_enclosures {
+                    probe_enclosures(&mut feed, UNLIMITED_FETCHES, |url| {
+                        head_url(&http_client, url).ok()
+                    });
+                }
+
                 results.push(json!({
                     "feed_url": feed_url,
                     "ok": true,
@@ -139,3 +172,17 @@ fn fetch_url(client: &Client, url: &str) -> Result<String> {
     let resp = client.get(url).send()?.error_for_status()?;
     Ok(resp.text()?)
 }
+
+fn head_url(client: &Client, url: &str) -> Result<EnclosureHead> {
+    let resp = client.head(url).send()?.error_for_status()?;
+    let header = |name: reqwest::header::HeaderName| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    Ok(EnclosureHead {
+        content_length: header(reqwest::header::CONTENT_LENGTH).and_then(|v| v.parse().ok()),
+        content_type: header(reqwest::header::CONTENT_TYPE),
+    })
+}
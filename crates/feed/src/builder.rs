@@ -0,0 +1,427 @@
+// ABOUTME: Builders for constructing Feed and FeedItem models programmatically.
+// ABOUTME: Validates required fields, URLs, and guid uniqueness so built feeds serialize cleanly.
+
+use std::collections::HashSet;
+
+use url::Url;
+
+use crate::error::FeedError;
+use crate::models::{normalize_category, Author, Category, Enclosure, Feed, FeedItem, FeedKind};
+
+/// Builder for a [`Feed`] that applications generate rather than parse.
+///
+/// Pair with [`crate::serialize_feed`] to emit RSS, Atom, or JSON Feed.
+#[derive(Debug, Clone)]
+pub struct FeedBuilder {
+    feed: Feed,
+}
+
+impl FeedBuilder {
+    /// Create a builder for a feed with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            feed: Feed {
+                title: title.into(),
+                feed_type: FeedKind::Article.as_str().to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the site (home page) URL.
+    pub fn home_url(mut self, url: impl Into<String>) -> Self {
+        self.feed.home_url = url.into();
+        self
+    }
+
+    /// Set the URL the feed is published at; used as the `rel="self"` link.
+    pub fn feed_url(mut self, url: impl Into<String>) -> Self {
+        self.feed.feed_url = url.into();
+        self
+    }
+
+    /// Set the plain-text channel description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.feed.description = description.into();
+        self
+    }
+
+    /// Set the language tag (e.g. `en` or `en-US`).
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.feed.language = Some(language.into());
+        self
+    }
+
+    /// Set the channel artwork URL.
+    pub fn image_url(mut self, url: impl Into<String>) -> Self {
+        self.feed.image_url = Some(url.into());
+        self
+    }
+
+    /// Set the feed-level author.
+    pub fn author(mut self, author: Author) -> Self {
+        self.feed.author = Some(author);
+        self
+    }
+
+    /// Set the publication time in Unix milliseconds.
+    pub fn published_ms(mut self, ms: u64) -> Self {
+        self.feed.published_ms = ms;
+        self
+    }
+
+    /// Set the last-updated time in Unix milliseconds.
+    pub fn updated_ms(mut self, ms: u64) -> Self {
+        self.feed.updated_ms = ms;
+        self
+    }
+
+    /// Set the copyright notice.
+    pub fn copyright(mut self, copyright: impl Into<String>) -> Self {
+        self.feed.copyright = Some(copyright.into());
+        self
+    }
+
+    /// Set the generator name.
+    pub fn generator(mut self, generator: impl Into<String>) -> Self {
+        self.feed.generator = Some(generator.into());
+        self
+    }
+
+    /// Set the content kind (also sets `feed_type`).
+    pub fn kind(mut self, kind: FeedKind) -> Self {
+        self.feed.kind = kind;
+        self.feed.feed_type = kind.as_str().to_string();
+        self
+    }
+
+    /// Set the RSS `<ttl>` in minutes.
+    pub fn ttl_minutes(mut self, minutes: u32) -> Self {
+        self.feed.ttl_minutes = Some(minutes);
+        self
+    }
+
+    /// Append an item (typically from [`FeedItemBuilder::build`]).
+    pub fn item(mut self, item: FeedItem) -> Self {
+        self.feed.items.push(item);
+        self
+    }
+
+    /// Append several items.
+    pub fn items(mut self, items: impl IntoIterator<Item = FeedItem>) -> Self {
+        self.feed.items.extend(items);
+        self
+    }
+
+    /// Validate and return the feed.
+    ///
+    /// Fails with [`FeedError::Invalid`] when the title is blank, a URL is not
+    /// an absolute http(s) URL, the language tag is malformed, an item is
+    /// invalid, or two items share a guid.
+    pub fn build(self) -> Result<Feed, FeedError> {
+        let feed = self.feed;
+        if feed.title.trim().is_empty() {
+            return Err(FeedError::invalid("feed title is required"));
+        }
+        check_url("home_url", &feed.home_url)?;
+        check_url("feed_url", &feed.feed_url)?;
+        check_url("image_url", feed.image_url.as_deref().unwrap_or(""))?;
+        if let Some(ref language) = feed.language {
+            let valid = !language.is_empty()
+                && language.split('-').all(|part| {
+                    !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric())
+                });
+            if !valid {
+                return Err(FeedError::invalid(format!(
+                    "invalid language tag: {:?}",
+                    language
+                )));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for item in &feed.items {
+            validate_item(item)?;
+            if !seen.insert(item.guid.as_str()) {
+                return Err(FeedError::invalid(format!(
+                    "duplicate item guid: {}",
+                    item.guid
+                )));
+            }
+        }
+        Ok(feed)
+    }
+}
+
+/// Builder for a [`FeedItem`].
+#[derive(Debug, Clone)]
+pub struct FeedItemBuilder {
+    item: FeedItem,
+}
+
+impl FeedItemBuilder {
+    /// Create a builder for an item with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            item: FeedItem {
+                title: title.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the item permalink.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.item.url = url.into();
+        self
+    }
+
+    /// Set the guid; defaults to the URL when not set.
+    pub fn guid(mut self, guid: impl Into<String>) -> Self {
+        self.item.guid = guid.into();
+        self
+    }
+
+    /// Set the plain-text summary.
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.item.summary = summary.into();
+        self
+    }
+
+    /// Set the plain-text content.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.item.content = content.into();
+        self
+    }
+
+    /// Set the content HTML.
+    pub fn content_html(mut self, html: impl Into<String>) -> Self {
+        self.item.content_html = Some(html.into());
+        self
+    }
+
+    /// Set the author (also recorded in `authors`).
+    pub fn author(mut self, author: Author) -> Self {
+        self.item.authors = vec![author.clone()];
+        self.item.author = Some(author);
+        self
+    }
+
+    /// Set the publication time in Unix milliseconds.
+    pub fn published_ms(mut self, ms: u64) -> Self {
+        self.item.published_ms = ms;
+        self
+    }
+
+    /// Set the last-updated time in Unix milliseconds.
+    pub fn updated_ms(mut self, ms: u64) -> Self {
+        self.item.updated_ms = ms;
+        self
+    }
+
+    /// Add a category term; duplicates (case-insensitive) are ignored.
+    pub fn category(mut self, term: impl Into<String>) -> Self {
+        let term = term.into();
+        let normalized = normalize_category(&term);
+        if !normalized.is_empty()
+            && !self
+                .item
+                .category_details
+                .iter()
+                .any(|c| c.normalized == normalized)
+        {
+            self.item.categories.push(term.clone());
+            self.item.category_details.push(Category {
+                term,
+                scheme: None,
+                label: None,
+                normalized,
+            });
+        }
+        self
+    }
+
+    /// Add a media enclosure; the first one becomes `primary_media_url`.
+    pub fn enclosure(
+        mut self,
+        url: impl Into<String>,
+        mime_type: impl Into<String>,
+        length: u64,
+    ) -> Self {
+        let mime_type = mime_type.into();
+        self.item.enclosures.push(Enclosure {
+            url: url.into(),
+            mime_type: (!mime_type.is_empty()).then_some(mime_type),
+            length,
+        });
+        self
+    }
+
+    /// Set the item image / thumbnail URL.
+    pub fn image_url(mut self, url: impl Into<String>) -> Self {
+        let url = url.into();
+        self.item.thumbnail_url = Some(url.clone());
+        self.item.image_url = Some(url);
+        self
+    }
+
+    /// Set the media duration in seconds.
+    pub fn duration_seconds(mut self, seconds: u32) -> Self {
+        self.item.duration_seconds = seconds;
+        self
+    }
+
+    /// Validate and return the item.
+    ///
+    /// Fails with [`FeedError::Invalid`] when the item has neither a title nor
+    /// a summary/content, has neither a guid nor a URL, or carries a URL that
+    /// is not an absolute http(s) URL.
+    pub fn build(self) -> Result<FeedItem, FeedError> {
+        let mut item = self.item;
+        if item.guid.is_empty() {
+            item.guid = item.url.clone();
+        }
+        if item.primary_media_url.is_none() && !item.enclosures.is_empty() {
+            item.primary_media_url = Some(item.enclosures[0].url.clone());
+            item.primary_enclosure_index = Some(0);
+        }
+        validate_item(&item)?;
+        Ok(item)
+    }
+}
+
+/// Checks an item against the rules [`FeedItemBuilder::build`] documents.
+fn validate_item(item: &FeedItem) -> Result<(), FeedError> {
+    if item.title.trim().is_empty()
+        && item.summary.trim().is_empty()
+        && item.content.trim().is_empty()
+    {
+        return Err(FeedError::invalid("item needs a title or summary"));
+    }
+    if item.guid.trim().is_empty() {
+        return Err(FeedError::invalid(format!(
+            "item {:?} needs a guid or url",
+            item.title
+        )));
+    }
+    check_url("item url", &item.url)?;
+    check_url("item image_url", item.image_url.as_deref().unwrap_or(""))?;
+    for enclosure in &item.enclosures {
+        if enclosure.url.is_empty() {
+            return Err(FeedError::invalid("enclosure url is required"));
+        }
+        check_url("enclosure url", &enclosure.url)?;
+    }
+    Ok(())
+}
+
+/// Accepts an empty value (unset) or an absolute http(s) URL.
+fn check_url(field: &str, value: &str) -> Result<(), FeedError> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    match Url::parse(value) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+        _ => Err(FeedError::invalid(format!(
+            "{} must be an absolute http(s) URL: {:?}",
+            field, value
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_feed_bytes;
+    use crate::serialize::{serialize_feed, FeedFormat};
+
+    fn episode(n: u32) -> FeedItem {
+        FeedItemBuilder::new(format!("Episode {}", n))
+            .url(format!("https://example.com/ep/{}", n))
+            .summary("Show notes & links")
+            .published_ms(1_700_000_000_000 + u64::from(n))
+            .category("News")
+            .category("news")
+            .enclosure(
+                format!("https://cdn.example.com/ep{}.mp3", n),
+                "audio/mpeg",
+                1234,
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_item_builder_defaults() {
+        let item = episode(1);
+        assert_eq!(item.guid, "https://example.com/ep/1");
+        assert_eq!(item.categories, vec!["News"]);
+        assert_eq!(
+            item.primary_media_url.as_deref(),
+            Some("https://cdn.example.com/ep1.mp3")
+        );
+        assert_eq!(item.primary_enclosure_index, Some(0));
+    }
+
+    #[test]
+    fn test_item_builder_validation() {
+        let err = FeedItemBuilder::new("").build().unwrap_err();
+        assert!(err.to_string().contains("title or summary"));
+        let err = FeedItemBuilder::new("No id").build().unwrap_err();
+        assert!(err.to_string().contains("guid or url"));
+        let err = FeedItemBuilder::new("Relative")
+            .url("/posts/1")
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("item url"));
+        let err = FeedItemBuilder::new("Bad media")
+            .guid("x")
+            .enclosure("ftp://example.com/a.mp3", "audio/mpeg", 0)
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("enclosure url"));
+        assert!(FeedItemBuilder::new("")
+            .summary("Note")
+            .guid("n1")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_feed_builder_validation() {
+        assert!(FeedBuilder::new(" ").build().is_err());
+        assert!(FeedBuilder::new("T")
+            .home_url("example.com")
+            .build()
+            .is_err());
+        assert!(FeedBuilder::new("T").language("en us").build().is_err());
+        let err = FeedBuilder::new("T")
+            .items([episode(1), episode(1)])
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("duplicate item guid"));
+    }
+
+    #[test]
+    fn test_built_feed_round_trips_through_serializer() {
+        let feed = FeedBuilder::new("My Show")
+            .home_url("https://example.com/")
+            .feed_url("https://example.com/feed.xml")
+            .description("A show")
+            .language("en-US")
+            .kind(FeedKind::Podcast)
+            .items([episode(1), episode(2)])
+            .build()
+            .unwrap();
+        assert_eq!(feed.feed_type, "podcast");
+
+        for format in [FeedFormat::Rss, FeedFormat::Atom, FeedFormat::JsonFeed] {
+            let out = serialize_feed(&feed, format);
+            let parsed = parse_feed_bytes(out.as_bytes(), "https://example.com/feed.xml").unwrap();
+            assert_eq!(parsed.title, "My Show");
+            assert_eq!(parsed.items.len(), 2);
+            assert_eq!(parsed.items[0].guid, "https://example.com/ep/1");
+            assert_eq!(parsed.items[1].summary, "Show notes & links");
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// ABOUTME: Podcast chapter parsing for Podcast 2.0 JSON chapters and Podlove Simple Chapters.
+// ABOUTME: Exposes chapter artwork so episodes can use it in place of the show's artwork.
+
+use serde::Deserialize;
+
+use crate::error::FeedError;
+use crate::image_utils::same_image_url;
+use crate::models::{Chapter, FeedItem};
+
+#[derive(Deserialize)]
+struct ChaptersDocument {
+    #[serde(default)]
+    chapters: Vec<JsonChapter>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonChapter {
+    #[serde(default)]
+    start_time: f64,
+    title: Option<String>,
+    img: Option<String>,
+    url: Option<String>,
+    /// `false` marks a chapter that only carries artwork/links, not a TOC entry.
+    #[serde(default = "default_toc")]
+    toc: bool,
+}
+
+fn default_toc() -> bool {
+    true
+}
+
+/// Parses a Podcast 2.0 chapters document (the JSON file a `podcast:chapters`
+/// element points at) into chapters ordered by start time.
+///
+/// Chapters marked `"toc": false` are kept, since they often exist only to
+/// change the displayed artwork.
+pub fn parse_chapters_json(data: &[u8]) -> Result<Vec<Chapter>, FeedError> {
+    let doc: ChaptersDocument = serde_json::from_slice(data).map_err(FeedError::parse)?;
+    let mut chapters: Vec<Chapter> = doc
+        .chapters
+        .into_iter()
+        .map(|c| Chapter {
+            start_seconds: c.start_time.max(0.0),
+            title: non_empty(c.title),
+            image_url: non_empty(c.img),
+            url: non_empty(c.url),
+            toc: c.toc,
+        })
+        .collect();
+    chapters.sort_by(|a, b| a.start_seconds.total_cmp(&b.start_seconds));
+    Ok(chapters)
+}
+
+/// Returns the image of the earliest chapter that has one.
+pub fn chapter_artwork(chapters: &[Chapter]) -> Option<&str> {
+    chapters.iter().find_map(|c| c.image_url.as_deref())
+}
+
+/// Stores fetched chapters on `item` and uses their artwork as the thumbnail
+/// when the item has none or only repeats the show's `feed_image_url`.
+///
+/// Returns true when the thumbnail changed.
+pub fn apply_chapters(
+    item: &mut FeedItem,
+    chapters: Vec<Chapter>,
+    feed_image_url: Option<&str>,
+) -> bool {
+    let artwork = chapter_artwork(&chapters).map(str::to_string);
+    item.chapters = chapters;
+
+    let Some(artwork) = artwork else {
+        return false;
+    };
+    let replaceable = match (item.thumbnail_url.as_deref(), feed_image_url) {
+        (None, _) => true,
+        (Some(thumb), Some(feed_image)) => same_image_url(thumb, feed_image),
+        (Some(_), None) => false,
+    };
+    if !replaceable || item.thumbnail_url.as_deref() == Some(artwork.as_str()) {
+        return false;
+    }
+    item.thumbnail_url = Some(artwork);
+    item.thumbnail_width = None;
+    item.thumbnail_height = None;
+    true
+}
+
+/// Parses a Normal Play Time offset (`62.5`, `01:02.5`, `00:01:02.500`) into
+/// seconds, as used by Podlove Simple Chapters `start` attributes.
+pub(crate) fn parse_npt_seconds(s: &str) -> Option<f64> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() > 3 {
+        return None;
+    }
+    let mut total = 0.0;
+    for part in parts {
+        let value: f64 = part.trim().parse().ok()?;
+        if !value.is_finite() || value < 0.0 {
+            return None;
+        }
+        total = total * 60.0 + value;
+    }
+    Some(total)
+}
+
+fn non_empty(s: Option<String>) -> Option<String> {
+    s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chapters_json() {
+        let json = br#"{
+            "version": "1.2.0",
+            "chapters": [
+                {"startTime": 120.5, "title": "Interview", "img": "https://example.com/c2.jpg"},
+                {"startTime": 0, "title": "Intro", "url": "https://example.com/show"},
+                {"startTime": 60, "img": "https://example.com/ad.jpg", "toc": false}
+            ]
+        }"#;
+        let chapters = parse_chapters_json(json).unwrap();
+        assert_eq!(chapters.len(), 3);
+        assert_eq!(chapters[0].title.as_deref(), Some("Intro"));
+        assert!(chapters[0].toc);
+        assert!(!chapters[1].toc);
+        assert_eq!(chapters[2].start_seconds, 120.5);
+        assert_eq!(
+            chapter_artwork(&chapters),
+            Some("https://example.com/ad.jpg")
+        );
+
+        assert!(parse_chapters_json(b"not json").is_err());
+        assert!(parse_chapters_json(b"{}").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_chapters_replaces_show_artwork_only() {
+        let chapters = vec![Chapter {
+            image_url: Some("https://example.com/ch1.jpg".to_string()),
+            toc: true,
+            ..Default::default()
+        }];
+        let show_art = "https://example.com/show.jpg";
+
+        let mut item = FeedItem {
+            thumbnail_url: Some("https://example.com/show.jpg?w=600".to_string()),
+            thumbnail_width: Some(600),
+            ..Default::default()
+        };
+        assert!(apply_chapters(&mut item, chapters.clone(), Some(show_art)));
+        assert_eq!(
+            item.thumbnail_url.as_deref(),
+            Some("https://example.com/ch1.jpg")
+        );
+        assert_eq!(item.thumbnail_width, None);
+        assert_eq!(item.chapters.len(), 1);
+
+        let mut item = FeedItem {
+            thumbnail_url: Some("https://example.com/episode.jpg".to_string()),
+            ..Default::default()
+        };
+        assert!(!apply_chapters(&mut item, chapters, Some(show_art)));
+        assert_eq!(
+            item.thumbnail_url.as_deref(),
+            Some("https://example.com/episode.jpg")
+        );
+    }
+
+    #[test]
+    fn test_parse_npt_seconds() {
+        assert_eq!(parse_npt_seconds("62.5"), Some(62.5));
+        assert_eq!(parse_npt_seconds("01:02.5"), Some(62.5));
+        assert_eq!(parse_npt_seconds("01:00:01.250"), Some(3601.25));
+        assert_eq!(parse_npt_seconds("1:2:3:4"), None);
+        assert_eq!(parse_npt_seconds("abc"), None);
+        assert_eq!(parse_npt_seconds(""), None);
+    }
+}
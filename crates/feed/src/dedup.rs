@@ -0,0 +1,221 @@
+// ABOUTME: Cross-feed duplicate detection for stories syndicated in several feeds.
+// ABOUTME: Matches canonicalized URLs (tracking params stripped) and near-identical titles, marking rather than removing.
+
+use std::collections::{HashMap, HashSet};
+
+use url::Url;
+
+use crate::models::Feed;
+
+pub use digests_hermes::resource::{is_tracking_param, normalize_url};
+
+/// Options for [`mark_duplicates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DedupOptions {
+    /// Minimum title similarity (0.0-1.0, Jaccard over words) for a title match.
+    pub title_threshold: f64,
+    /// Titles with fewer words than this never match on title alone.
+    pub min_title_words: usize,
+}
+
+impl Default for DedupOptions {
+    fn default() -> Self {
+        Self {
+            title_threshold: 0.8,
+            min_title_words: 4,
+        }
+    }
+}
+
+/// Normalizes a URL for duplicate matching: applies [`normalize_url`]
+/// (fragment and tracking parameters dropped, query sorted), then drops the
+/// scheme and a leading `www.` and trims a trailing slash. Unparseable input
+/// is returned trimmed.
+pub fn canonicalize_url(url: &str) -> String {
+    let trimmed = url.trim();
+    let Ok(parsed) = Url::parse(&normalize_url(trimmed)) else {
+        return trimmed.to_string();
+    };
+
+    let host = parsed
+        .host_str()
+        .map(|h| h.trim_start_matches("www.").to_string());
+    let path = parsed.path().trim_end_matches('/').to_string();
+    let query = parsed
+        .query()
+        .map(|q| format!("?{}", q))
+        .unwrap_or_default();
+    match host {
+        // Scheme is ignored so http/https copies match
+        Some(host) => format!("{}{}{}", host, path, query),
+        None => parsed.to_string(),
+    }
+}
+
+/// Word-set Jaccard similarity of two titles (case and punctuation ignored).
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (title_words(a), title_words(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    jaccard(&a, &b)
+}
+
+fn title_words(title: &str) -> HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Marks items that repeat a story already seen in `feeds`.
+///
+/// Items are compared oldest first, so the earliest published copy is the
+/// original; undated items come after dated ones, in input order. A later item
+/// whose canonical URL matches, or whose title is at least
+/// `title_threshold` similar, gets `duplicate_of` set to the original's guid.
+/// Nothing is removed. Returns the number of items marked.
+pub fn mark_duplicates(feeds: &mut [Feed], options: &DedupOptions) -> usize {
+    // (feed, item) positions in comparison order
+    let mut order: Vec<(usize, usize)> = feeds
+        .iter()
+        .enumerate()
+        .flat_map(|(f, feed)| (0..feed.items.len()).map(move |i| (f, i)))
+        .collect();
+    order.sort_by_key(|&(f, i)| {
+        let ms = feeds[f].items[i].published_ms;
+        (ms == 0, ms)
+    });
+
+    let mut by_url: HashMap<String, String> = HashMap::new();
+    let mut originals: Vec<(HashSet<String>, String)> = Vec::new();
+    let mut marked = 0;
+
+    for (f, i) in order {
+        let item = &feeds[f].items[i];
+        let url = (!item.url.trim().is_empty()).then(|| canonicalize_url(&item.url));
+        let words = title_words(&item.title);
+
+        let original = url
+            .as_ref()
+            .and_then(|u| by_url.get(u))
+            .cloned()
+            .or_else(|| {
+                if words.len() < options.min_title_words {
+                    return None;
+                }
+                originals
+                    .iter()
+                    .find(|(other, _)| jaccard(&words, other) >= options.title_threshold)
+                    .map(|(_, guid)| guid.clone())
+            });
+
+        match original {
+            Some(guid) => {
+                feeds[f].items[i].duplicate_of = Some(guid);
+                marked += 1;
+            }
+            None => {
+                let guid = item.guid.clone();
+                if let Some(url) = url {
+                    by_url.insert(url, guid.clone());
+                }
+                if words.len() >= options.min_title_words {
+                    originals.push((words, guid));
+                }
+            }
+        }
+    }
+
+    marked
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let shared = a.intersection(b).count();
+    shared as f64 / (a.len() + b.len() - shared) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FeedItem;
+
+    #[test]
+    fn test_canonicalize_url_strips_tracking() {
+        assert_eq!(
+            canonicalize_url("https://www.Example.com/story/?utm_source=rss&b=2&fbclid=x&a=1#top"),
+            "example.com/story?a=1&b=2"
+        );
+        assert_eq!(
+            canonicalize_url("http://example.com/story"),
+            canonicalize_url("https://www.example.com/story/?utm_medium=feed")
+        );
+        assert_eq!(canonicalize_url(" not a url "), "not a url");
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert_eq!(title_similarity("Hello, World!", "hello world"), 1.0);
+        assert!(
+            title_similarity(
+                "Apple releases new iPhone today",
+                "Apple releases new iPhone"
+            ) > 0.79
+        );
+        assert_eq!(title_similarity("", "x"), 0.0);
+    }
+
+    fn item(guid: &str, url: &str, title: &str, ms: u64) -> FeedItem {
+        FeedItem {
+            guid: guid.into(),
+            url: url.into(),
+            title: title.into(),
+            published_ms: ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mark_duplicates_across_feeds() {
+        let mut feeds = vec![
+            Feed {
+                items: vec![
+                    item(
+                        "a1",
+                        "https://news.com/s?utm_source=a",
+                        "Something else entirely here",
+                        20,
+                    ),
+                    item(
+                        "a2",
+                        "https://other.com/x",
+                        "Council approves new city budget plan",
+                        30,
+                    ),
+                ],
+                ..Default::default()
+            },
+            Feed {
+                items: vec![
+                    item("b1", "https://www.news.com/s", "Different title", 10),
+                    item(
+                        "b2",
+                        "https://wire.com/y",
+                        "Council approves new city budget plan!",
+                        25,
+                    ),
+                    item("b3", "https://wire.com/z", "Short", 5),
+                ],
+                ..Default::default()
+            },
+        ];
+
+        let marked = mark_duplicates(&mut feeds, &DedupOptions::default());
+        assert_eq!(marked, 2);
+        // b1 is older, so it is the original of a1
+        assert_eq!(feeds[0].items[0].duplicate_of.as_deref(), Some("b1"));
+        assert_eq!(feeds[0].items[1].duplicate_of.as_deref(), Some("b2"));
+        assert!(feeds[1].items.iter().all(|i| i.duplicate_of.is_none()));
+    }
+}
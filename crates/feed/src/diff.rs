@@ -0,0 +1,218 @@
+// ABOUTME: Computes item-level deltas between two snapshots of the same feed.
+// ABOUTME: Items are matched by guid, then URL, then a content hash, and reported as added/removed/updated.
+// ABOUTME: Also flags items edited in place (same guid, new content_hash) for "updated" badges.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::hash::fnv1a;
+use crate::models::{Feed, FeedItem};
+
+/// Item-level changes between two feed snapshots.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedDiff {
+    /// Items present in the new feed but not the old one, in new-feed order.
+    pub added: Vec<FeedItem>,
+    /// Items present in the old feed but not the new one, in old-feed order.
+    pub removed: Vec<FeedItem>,
+    /// Items present in both whose visible content changed (new version), in new-feed order.
+    pub updated: Vec<FeedItem>,
+}
+
+impl FeedDiff {
+    /// Returns true if no items were added, removed, or updated.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+/// Compares two snapshots of a feed and returns the item deltas.
+///
+/// Items are keyed on guid, falling back to the item URL, then to a hash of
+/// title/summary/content when both are empty. A matched item counts as updated
+/// when its title, URL, summary, content, updated time, or enclosures differ.
+pub fn diff_feeds(old: &Feed, new: &Feed) -> FeedDiff {
+    let old_by_key: HashMap<String, &FeedItem> = old
+        .items
+        .iter()
+        .map(|item| (item_key(item), item))
+        .collect();
+    let new_keys: HashSet<String> = new.items.iter().map(item_key).collect();
+
+    let mut diff = FeedDiff::default();
+    for item in &new.items {
+        match old_by_key.get(&item_key(item)) {
+            None => diff.added.push(item.clone()),
+            Some(previous) if content_fingerprint(previous) != content_fingerprint(item) => {
+                diff.updated.push(item.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = old
+        .items
+        .iter()
+        .filter(|item| !new_keys.contains(&item_key(item)))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Flags items in `current` that share a guid with an item in `previous` but
+/// whose `content_hash` differs, setting `FeedItem::edited`. Items that are new,
+/// lack a guid, or lack a hash on either side are left unflagged; a flag set by
+/// an earlier call is kept. Returns the number of items newly flagged.
+pub fn mark_edited_items(previous: &Feed, current: &mut Feed) -> usize {
+    let previous_hashes: HashMap<&str, &str> = previous
+        .items
+        .iter()
+        .filter(|item| !item.guid.is_empty() && !item.content_hash.is_empty())
+        .map(|item| (item.guid.as_str(), item.content_hash.as_str()))
+        .collect();
+
+    let mut flagged = 0;
+    for item in &mut current.items {
+        if item.edited || item.content_hash.is_empty() {
+            continue;
+        }
+        if let Some(old_hash) = previous_hashes.get(item.guid.as_str()) {
+            if *old_hash != item.content_hash {
+                item.edited = true;
+                flagged += 1;
+            }
+        }
+    }
+    flagged
+}
+
+/// Stable identity key for an item: guid, then URL, then a content hash.
+pub fn item_key(item: &FeedItem) -> String {
+    if !item.guid.is_empty() {
+        return format!("guid:{}", item.guid);
+    }
+    if !item.url.is_empty() {
+        return format!("url:{}", item.url);
+    }
+    // Persisted through FeedState, so the hash must not change between builds
+    let hash = fnv1a(&[&item.title, &item.summary, &item.content]);
+    format!("hash:{:016x}", hash)
+}
+
+fn content_fingerprint(item: &FeedItem) -> u64 {
+    let updated = item.updated_ms.to_string();
+    let enclosures: Vec<String> = item
+        .enclosures
+        .iter()
+        .map(|e| {
+            let mime = e.mime_type.as_deref().unwrap_or_default();
+            format!("{}\u{1f}{}\u{1f}{}", e.url, mime, e.length)
+        })
+        .collect();
+    let mut parts = vec![
+        item.title.as_str(),
+        item.url.as_str(),
+        item.summary.as_str(),
+        item.content.as_str(),
+        updated.as_str(),
+    ];
+    parts.extend(enclosures.iter().map(String::as_str));
+    fnv1a(&parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(guid: &str, url: &str, title: &str) -> FeedItem {
+        FeedItem {
+            guid: guid.into(),
+            url: url.into(),
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_updated() {
+        let old = Feed {
+            items: vec![
+                item("a", "https://e.com/a", "A"),
+                item("b", "https://e.com/b", "B"),
+                item("", "https://e.com/c", "C"),
+            ],
+            ..Default::default()
+        };
+        let new = Feed {
+            items: vec![
+                item("d", "https://e.com/d", "D"),
+                item("a", "https://e.com/a", "A (corrected)"),
+                item("", "https://e.com/c", "C"),
+            ],
+            ..Default::default()
+        };
+
+        let diff = diff_feeds(&old, &new);
+        let titles =
+            |items: &[FeedItem]| -> Vec<String> { items.iter().map(|i| i.title.clone()).collect() };
+        assert_eq!(titles(&diff.added), vec!["D"]);
+        assert_eq!(titles(&diff.removed), vec!["B"]);
+        assert_eq!(titles(&diff.updated), vec!["A (corrected)"]);
+    }
+
+    #[test]
+    fn test_mark_edited_items_flags_same_guid_new_hash() {
+        let with_hash = |guid: &str, hash: &str| FeedItem {
+            content_hash: hash.into(),
+            ..item(guid, "", guid)
+        };
+        let previous = Feed {
+            items: vec![
+                with_hash("a", "1111"),
+                with_hash("b", "2222"),
+                with_hash("c", ""),
+            ],
+            ..Default::default()
+        };
+        let mut current = Feed {
+            items: vec![
+                with_hash("a", "1111"),
+                with_hash("b", "3333"),
+                with_hash("c", "4444"),
+                with_hash("d", "5555"),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(mark_edited_items(&previous, &mut current), 1);
+        let edited: Vec<bool> = current.items.iter().map(|i| i.edited).collect();
+        assert_eq!(edited, vec![false, true, false, false]);
+
+        // Re-marking against the same snapshot flags nothing new
+        assert_eq!(mark_edited_items(&previous, &mut current), 0);
+        assert!(current.items[1].edited);
+    }
+
+    #[test]
+    fn test_items_without_guid_or_url_match_on_content() {
+        let old = Feed {
+            items: vec![item("", "", "Untitled note")],
+            ..Default::default()
+        };
+        let new = old.clone();
+        assert!(diff_feeds(&old, &new).is_empty());
+
+        let changed = Feed {
+            items: vec![item("", "", "Different note")],
+            ..Default::default()
+        };
+        let diff = diff_feeds(&old, &changed);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.removed.len(), 1);
+        assert!(diff.updated.is_empty());
+    }
+
+    #[test]
+    fn test_content_hash_key_is_stable_across_builds() {
+        // Persisted in FeedState; a change here turns stored items into "added"
+        assert_eq!(item_key(&item("", "", "Untitled")), "hash:67db362552abf72e");
+    }
+}
@@ -1,12 +1,14 @@
 // ABOUTME: Duration string parsing for podcast episode lengths.
-// ABOUTME: Supports integer seconds, HH:MM:SS, MM:SS, and Go-style duration strings.
+// ABOUTME: Supports seconds, HH:MM:SS, MM:SS, ISO 8601, and Go-style/human duration strings.
 
-/// Parses a duration string into seconds.
+/// Parses a duration string into seconds, truncating any fractional part.
 /// Supports:
-/// - Plain integers (seconds)
-/// - HH:MM:SS format
+/// - Plain integers or decimals (seconds), e.g. "123", "123.5"
+/// - HH:MM:SS format, with optional fractional seconds ("1:02:03.5")
 /// - MM:SS format
+/// - ISO 8601 durations like "PT1H2M3S", "PT45.5S", "P1DT2H"
 /// - Go-style durations like "1h30m", "45m", "2h"
+/// - Human strings like "90 min", "1.5 hours"
 /// Returns None if parsing fails or value doesn't fit in u32.
 pub fn parse_duration_seconds(s: &str) -> Option<u32> {
     let s = s.trim();
@@ -19,12 +21,22 @@ pub fn parse_duration_seconds(s: &str) -> Option<u32> {
         return u32::try_from(secs).ok();
     }
 
+    // Plain decimal seconds ("123.5")
+    if s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return seconds_from_f64(s.parse().ok()?);
+    }
+
     // Try HH:MM:SS or MM:SS
     if s.contains(':') {
         return parse_colon_format(s);
     }
 
-    // Try Go-style duration (1h30m, 45m, 2h, etc.)
+    // ISO 8601 (PT1H2M3S)
+    if s.starts_with(['P', 'p']) {
+        return parse_iso8601(s);
+    }
+
+    // Try Go-style or human duration (1h30m, 45m, "90 min", "1.5 hours", etc.)
     if let Ok(duration) = parse_duration::parse(s) {
         let secs = duration.as_secs();
         return u32::try_from(secs).ok();
@@ -40,22 +52,81 @@ fn parse_colon_format(s: &str) -> Option<u32> {
         2 => {
             // MM:SS
             let mins: u64 = parts[0].parse().ok()?;
-            let secs: u64 = parts[1].parse().ok()?;
-            let total = mins * 60 + secs;
-            u32::try_from(total).ok()
+            let secs = parse_seconds_part(parts[1])?;
+            seconds_from_f64((mins * 60) as f64 + secs)
         }
         3 => {
             // HH:MM:SS
             let hours: u64 = parts[0].parse().ok()?;
             let mins: u64 = parts[1].parse().ok()?;
-            let secs: u64 = parts[2].parse().ok()?;
-            let total = hours * 3600 + mins * 60 + secs;
-            u32::try_from(total).ok()
+            let secs = parse_seconds_part(parts[2])?;
+            seconds_from_f64((hours * 3600 + mins * 60) as f64 + secs)
         }
         _ => None,
     }
 }
 
+/// Parses the trailing seconds component, which may carry a fraction ("03.5").
+fn parse_seconds_part(s: &str) -> Option<f64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parses an ISO 8601 duration limited to weeks, days, hours, minutes, and seconds.
+/// Year and month designators are rejected since their length is ambiguous.
+fn parse_iso8601(s: &str) -> Option<u32> {
+    let upper = s.to_ascii_uppercase();
+    let rest = upper.strip_prefix('P')?;
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    let mut total = 0.0;
+    let mut seen_any = false;
+    for (part, units) in [
+        (date_part, &[('W', 604_800.0), ('D', 86_400.0)][..]),
+        (
+            time_part.unwrap_or(""),
+            &[('H', 3600.0), ('M', 60.0), ('S', 1.0)][..],
+        ),
+    ] {
+        let mut number = String::new();
+        let mut next_unit = 0;
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+            let offset = units[next_unit..].iter().position(|(u, _)| *u == c)?;
+            let (_, factor) = units[next_unit + offset];
+            let value: f64 = number.parse().ok()?;
+            total += value * factor;
+            number.clear();
+            next_unit += offset + 1;
+            seen_any = true;
+        }
+        if !number.is_empty() {
+            return None;
+        }
+    }
+
+    // "PT" with no components is not a duration
+    if !seen_any || time_part == Some("") {
+        return None;
+    }
+    seconds_from_f64(total)
+}
+
+fn seconds_from_f64(secs: f64) -> Option<u32> {
+    if !secs.is_finite() || secs < 0.0 || secs >= (u32::MAX as f64) + 1.0 {
+        return None;
+    }
+    Some(secs.trunc() as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +156,34 @@ mod tests {
         assert_eq!(parse_duration_seconds("2h"), Some(7200));
     }
 
+    #[test]
+    fn test_fractional_seconds() {
+        assert_eq!(parse_duration_seconds("1:02:03.5"), Some(3723));
+        assert_eq!(parse_duration_seconds("05:30.999"), Some(330));
+        assert_eq!(parse_duration_seconds("123.7"), Some(123));
+        assert!(parse_duration_seconds("1:02:x3").is_none());
+    }
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(parse_duration_seconds("PT1H2M3S"), Some(3723));
+        assert_eq!(parse_duration_seconds("PT45M"), Some(2700));
+        assert_eq!(parse_duration_seconds("PT90.5S"), Some(90));
+        assert_eq!(parse_duration_seconds("pt1h"), Some(3600));
+        assert_eq!(parse_duration_seconds("P1DT2H"), Some(93600));
+        assert!(parse_duration_seconds("P").is_none());
+        assert!(parse_duration_seconds("PT").is_none());
+        assert!(parse_duration_seconds("P1Y").is_none());
+        assert!(parse_duration_seconds("PT3S1H").is_none());
+    }
+
+    #[test]
+    fn test_human_units() {
+        assert_eq!(parse_duration_seconds("90 min"), Some(5400));
+        assert_eq!(parse_duration_seconds("1.5 hours"), Some(5400));
+        assert_eq!(parse_duration_seconds("45 minutes"), Some(2700));
+    }
+
     #[test]
     fn test_empty_returns_none() {
         assert!(parse_duration_seconds("").is_none());
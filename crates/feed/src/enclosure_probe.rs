@@ -0,0 +1,189 @@
+// ABOUTME: Optional enrichment that fills missing enclosure length/type from HEAD responses.
+// ABOUTME: The caller supplies the HEAD request; URLs are deduplicated and fetches can be capped.
+
+use std::collections::HashMap;
+
+use crate::models::{Enclosure, Feed};
+
+/// Headers of interest from a HEAD response for an enclosure URL.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnclosureHead {
+    /// `Content-Length`, when the server sent one.
+    pub content_length: Option<u64>,
+    /// `Content-Type` header value; parameters such as `charset` are ignored.
+    pub content_type: Option<String>,
+}
+
+/// Stats returned from enclosure probing.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EnclosureProbeStats {
+    /// Number of unique enclosure URLs that needed probing.
+    pub urls_queued: usize,
+    /// Number of enclosures that gained a length or type.
+    pub enclosures_updated: usize,
+    /// Number of enclosures skipped because the probe cap was reached.
+    pub skipped_fetch_cap: usize,
+}
+
+fn needs_probe(enclosure: &Enclosure) -> bool {
+    !enclosure.url.is_empty()
+        && (enclosure.length == 0
+            || enclosure
+                .mime_type
+                .as_deref()
+                .map(str::is_empty)
+                .unwrap_or(true))
+}
+
+/// Fills in missing enclosure `length` (0) and `mime_type` from HEAD responses.
+///
+/// Works like [`enrich_items_with_metadata`](crate::enrich_items_with_metadata):
+/// - Only enclosures missing a length or type are queued; values from the feed
+///   are never overwritten.
+/// - URLs are deduplicated and probed in feed order; once `max_fetches` calls to
+///   `head` have been made, the rest are counted in `skipped_fetch_cap`. Pass
+///   [`UNLIMITED_FETCHES`](crate::UNLIMITED_FETCHES) for no cap.
+///
+/// `head` should synchronously issue a HEAD request for the URL and return the
+/// response headers, or `None` on any failure.
+pub fn probe_enclosures<F>(feed: &mut Feed, max_fetches: usize, mut head: F) -> EnclosureProbeStats
+where
+    F: FnMut(&str) -> Option<EnclosureHead>,
+{
+    let mut stats = EnclosureProbeStats::default();
+
+    // Enclosure URLs in first-seen order, each with the (item, enclosure) positions to fill
+    let mut queue: Vec<(String, Vec<(usize, usize)>)> = Vec::new();
+    let mut url_positions: HashMap<String, usize> = HashMap::new();
+    for (item_idx, item) in feed.items.iter().enumerate() {
+        for (enc_idx, enclosure) in item.enclosures.iter().enumerate() {
+            if !needs_probe(enclosure) {
+                continue;
+            }
+            let pos = *url_positions
+                .entry(enclosure.url.clone())
+                .or_insert_with(|| {
+                    queue.push((enclosure.url.clone(), Vec::new()));
+                    queue.len() - 1
+                });
+            queue[pos].1.push((item_idx, enc_idx));
+        }
+    }
+    stats.urls_queued = queue.len();
+
+    let mut budget = max_fetches;
+    for (url, positions) in queue {
+        if budget == 0 {
+            stats.skipped_fetch_cap += positions.len();
+            continue;
+        }
+        budget -= 1;
+        let Some(response) = head(&url) else {
+            continue;
+        };
+        let content_type = response
+            .content_type
+            .as_deref()
+            .and_then(|ct| ct.split(';').next())
+            .map(|ct| ct.trim().to_ascii_lowercase())
+            .filter(|ct| !ct.is_empty());
+
+        for (item_idx, enc_idx) in positions {
+            let enclosure = &mut feed.items[item_idx].enclosures[enc_idx];
+            let mut updated = false;
+            if enclosure.length == 0 {
+                if let Some(len) = response.content_length.filter(|len| *len > 0) {
+                    enclosure.length = len;
+                    updated = true;
+                }
+            }
+            if enclosure
+                .mime_type
+                .as_deref()
+                .map(str::is_empty)
+                .unwrap_or(true)
+            {
+                if let Some(ct) = &content_type {
+                    enclosure.mime_type = Some(ct.clone());
+                    updated = true;
+                }
+            }
+            if updated {
+                stats.enclosures_updated += 1;
+            }
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FeedItem;
+
+    fn enclosure(url: &str, mime: Option<&str>, length: u64) -> Enclosure {
+        Enclosure {
+            url: url.into(),
+            mime_type: mime.map(Into::into),
+            length,
+        }
+    }
+
+    #[test]
+    fn test_probe_fills_missing_fields_and_dedupes_urls() {
+        let mut feed = Feed {
+            items: vec![
+                FeedItem {
+                    enclosures: vec![enclosure("https://e.com/a.mp3", None, 0)],
+                    ..Default::default()
+                },
+                FeedItem {
+                    enclosures: vec![
+                        enclosure("https://e.com/a.mp3", Some("audio/mpeg"), 0),
+                        enclosure("https://e.com/b.mp3", Some("audio/mpeg"), 42),
+                    ],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let mut probed = Vec::new();
+        let stats = probe_enclosures(&mut feed, usize::MAX, |url| {
+            probed.push(url.to_string());
+            Some(EnclosureHead {
+                content_length: Some(1234),
+                content_type: Some("Audio/MPEG; charset=binary".into()),
+            })
+        });
+
+        assert_eq!(probed, vec!["https://e.com/a.mp3"]);
+        assert_eq!(stats.urls_queued, 1);
+        assert_eq!(stats.enclosures_updated, 2);
+        assert_eq!(
+            feed.items[0].enclosures[0],
+            enclosure("https://e.com/a.mp3", Some("audio/mpeg"), 1234)
+        );
+        // Complete enclosures are left alone
+        assert_eq!(feed.items[1].enclosures[1].length, 42);
+    }
+
+    #[test]
+    fn test_probe_respects_fetch_cap() {
+        let mut feed = Feed {
+            items: vec![FeedItem {
+                enclosures: vec![
+                    enclosure("https://e.com/1.mp3", None, 0),
+                    enclosure("https://e.com/2.mp3", None, 0),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let stats = probe_enclosures(&mut feed, 1, |_| None);
+        assert_eq!(stats.urls_queued, 2);
+        assert_eq!(stats.skipped_fetch_cap, 1);
+        assert_eq!(stats.enclosures_updated, 0);
+    }
+}
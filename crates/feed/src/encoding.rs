@@ -0,0 +1,147 @@
+// ABOUTME: Converts non-UTF-8 feed bytes to UTF-8 before parsing.
+// ABOUTME: Honors BOMs and the XML declaration encoding, falling back to chardetng detection via hermes.
+
+use std::borrow::Cow;
+
+use digests_hermes::resource::decode_body;
+
+/// How far into the document to look for the XML declaration.
+const DECLARATION_SCAN_LIMIT: usize = 1024;
+
+/// Returns the feed as UTF-8 bytes, decoding it when needed.
+///
+/// Order of precedence: byte order mark, a BOM-less UTF-16 `<?xml` prolog, the
+/// `encoding` attribute of the XML declaration, then charset detection for input
+/// that is not valid UTF-8. When bytes are re-encoded the XML declaration is
+/// rewritten to say UTF-8 so downstream XML parsers do not decode a second time.
+pub(crate) fn to_utf8(data: &[u8]) -> Cow<'_, [u8]> {
+    if let Some(rest) = data.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return Cow::Borrowed(rest);
+    }
+
+    let charset = match utf16_variant(data) {
+        Some(label) => Some(label.to_string()),
+        // An ASCII-readable prolog cannot be UTF-16, whatever it claims
+        None => declared_encoding(data)
+            .filter(|label| !label.to_ascii_lowercase().starts_with("utf-16")),
+    };
+
+    match charset {
+        Some(label) if !is_utf8_label(&label) => {
+            let decoded = decode_body(data, Some(&format!("charset={}", label)));
+            Cow::Owned(rewrite_declaration(decoded).into_bytes())
+        }
+        _ if std::str::from_utf8(data).is_err() => {
+            let decoded = decode_body(data, None);
+            Cow::Owned(rewrite_declaration(decoded).into_bytes())
+        }
+        _ => Cow::Borrowed(data),
+    }
+}
+
+/// Detects UTF-16 from a byte order mark or the byte pattern of a leading `<?`.
+fn utf16_variant(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0xFF, 0xFE, ..] | [b'<', 0, b'?', 0, ..] => Some("utf-16le"),
+        [0xFE, 0xFF, ..] | [0, b'<', 0, b'?', ..] => Some("utf-16be"),
+        _ => None,
+    }
+}
+
+fn is_utf8_label(label: &str) -> bool {
+    matches!(label.to_ascii_lowercase().as_str(), "utf-8" | "utf8")
+}
+
+/// Reads the `encoding` pseudo-attribute from a leading `<?xml ...?>` declaration.
+fn declared_encoding(data: &[u8]) -> Option<String> {
+    let head = &data[..data.len().min(DECLARATION_SCAN_LIMIT)];
+    let head = String::from_utf8_lossy(head);
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    if !head.starts_with("<?xml") {
+        return None;
+    }
+    let decl = &head[..head.find("?>")?];
+    let rest = &decl[decl.find("encoding")? + "encoding".len()..];
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &rest[1..];
+    let label = value[..value.find(quote)?].trim();
+    (!label.is_empty()).then(|| label.to_string())
+}
+
+/// Replaces a leading XML declaration with one declaring UTF-8.
+fn rewrite_declaration(text: String) -> String {
+    let body = text.trim_start_matches('\u{feff}');
+    let trimmed = body.trim_start();
+    if !trimmed.starts_with("<?xml") {
+        return body.to_string();
+    }
+    match trimmed.find("?>") {
+        Some(end) => format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>{}",
+            &trimmed[end + 2..]
+        ),
+        None => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_encoding_is_read_from_declaration() {
+        assert_eq!(
+            declared_encoding(b"<?xml version=\"1.0\" encoding='windows-1251'?><rss/>").as_deref(),
+            Some("windows-1251")
+        );
+        assert_eq!(declared_encoding(b"<?xml version=\"1.0\"?><rss/>"), None);
+        assert_eq!(declared_encoding(b"<rss encoding=\"koi8-r\"/>"), None);
+    }
+
+    #[test]
+    fn test_to_utf8_decodes_declared_encoding() {
+        let mut data = b"<?xml version=\"1.0\" encoding=\"windows-1251\"?><t>".to_vec();
+        data.extend_from_slice(&[0xCF, 0xF0, 0xE8, 0xE2, 0xE5, 0xF2]);
+        data.extend_from_slice(b"</t>");
+        let out = to_utf8(&data);
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><t>Привет</t>"
+        );
+    }
+
+    fn utf16le(text: &str, bom: bool) -> Vec<u8> {
+        let mut out = if bom { vec![0xFF, 0xFE] } else { Vec::new() };
+        out.extend(text.encode_utf16().flat_map(|u| u.to_le_bytes()));
+        out
+    }
+
+    #[test]
+    fn test_to_utf8_decodes_utf16_with_and_without_bom() {
+        let xml = "<?xml version=\"1.0\" encoding=\"UTF-16\"?><t>Grüße</t>";
+        let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?><t>Grüße</t>";
+        for bom in [true, false] {
+            let data = utf16le(xml, bom);
+            assert_eq!(std::str::from_utf8(&to_utf8(&data)).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_to_utf8_ignores_utf16_label_on_8bit_bytes() {
+        let data = "<?xml version=\"1.0\" encoding=\"utf-16\"?><t>café</t>".as_bytes();
+        assert!(matches!(to_utf8(data), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_to_utf8_strips_utf8_bom() {
+        let data = b"\xEF\xBB\xBF<rss/>";
+        assert_eq!(to_utf8(data).as_ref(), b"<rss/>");
+    }
+
+    #[test]
+    fn test_to_utf8_leaves_utf8_untouched() {
+        let data = "<?xml version=\"1.0\" encoding=\"utf-8\"?><t>café</t>".as_bytes();
+        assert!(matches!(to_utf8(data), Cow::Borrowed(_)));
+    }
+}
@@ -1,24 +1,91 @@
 // ABOUTME: Feed-level enrichment helpers aligning with digests-api behavior.
-// ABOUTME: Applies Hermes metadata HTML to fill missing feed fields (title/description/image).
+// ABOUTME: Applies Hermes metadata to feed fields (title/description/image/icon/theme) per a field policy.
+
+use std::future::Future;
 
 use crate::error::FeedError;
 use crate::models::Feed;
+use crate::pick_site_url;
 use digests_hermes::{extract_metadata_only, Metadata};
+use serde::{Deserialize, Serialize};
+
+/// How site metadata may change a single feed field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldPolicy {
+    /// Leave the feed's value untouched.
+    Never,
+    /// Fill the field only when the feed left it empty.
+    #[default]
+    IfEmpty,
+    /// Replace the feed's value whenever the metadata has one.
+    Always,
+}
+
+impl FieldPolicy {
+    /// Applies `value` to `field` under this policy; an empty `value` never
+    /// clears the field.
+    fn apply(self, field: &mut String, value: &str) {
+        let take = match self {
+            FieldPolicy::Never => false,
+            FieldPolicy::IfEmpty => field.is_empty(),
+            FieldPolicy::Always => true,
+        };
+        if take && !value.is_empty() {
+            *field = value.to_string();
+        }
+    }
+
+    fn apply_opt(self, field: &mut Option<String>, value: &str) {
+        let mut current = field.take().unwrap_or_default();
+        self.apply(&mut current, value);
+        *field = Some(current).filter(|v| !v.is_empty());
+    }
+}
+
+/// Per-field policy for [`apply_metadata_to_feed_with_policy`].
+///
+/// The default fills every field only when the feed left it empty, which is
+/// what [`apply_metadata_to_feed`] does. Set e.g. `title: Never` with
+/// `icon`/`theme_color: Always` to keep the feed's own title but take the
+/// site's branding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetadataPolicy {
+    pub title: FieldPolicy,
+    pub description: FieldPolicy,
+    /// `Feed::image_url` from the site's og:image/twitter:image.
+    pub image: FieldPolicy,
+    /// `Feed::icon_url` from the site's favicon.
+    pub icon: FieldPolicy,
+    pub theme_color: FieldPolicy,
+}
 
 /// Apply metadata fallbacks to an already-parsed feed.
 /// Mirrors digests-api's UnifiedEnrichmentService.applyMetadataToFeeds logic.
 pub fn apply_metadata_to_feed(feed: &mut Feed, metadata: &Metadata) {
-    if feed.title.is_empty() && !metadata.title.is_empty() {
-        feed.title = metadata.title.clone();
-    }
-    if feed.description.is_empty() && !metadata.description.is_empty() {
-        feed.description = metadata.description.clone();
-    }
-    if feed.image_url.is_none() || feed.image_url.as_deref().unwrap_or("").is_empty() {
-        if !metadata.image_url.is_empty() {
-            feed.image_url = Some(metadata.image_url.clone());
-        }
-    }
+    apply_metadata_to_feed_with_policy(feed, metadata, &MetadataPolicy::default());
+}
+
+/// Apply metadata to an already-parsed feed, field by field, per `policy`.
+pub fn apply_metadata_to_feed_with_policy(
+    feed: &mut Feed,
+    metadata: &Metadata,
+    policy: &MetadataPolicy,
+) {
+    policy.title.apply(&mut feed.title, &metadata.title);
+    policy
+        .description
+        .apply(&mut feed.description, &metadata.description);
+    policy
+        .image
+        .apply_opt(&mut feed.image_url, &metadata.image_url);
+    policy
+        .icon
+        .apply_opt(&mut feed.icon_url, &metadata.icon_url);
+    policy
+        .theme_color
+        .apply_opt(&mut feed.theme_color, &metadata.theme_color);
 }
 
 /// Convenience: parse site HTML to metadata and apply to feed.
@@ -34,6 +101,25 @@ pub fn enrich_feed_with_site_html(
     Ok(())
 }
 
+/// Async feed-level enrichment: awaits `fetch_metadata` for the feed's site URL
+/// (see [`pick_site_url`]) and applies the result. Returns true if metadata was applied.
+pub async fn enrich_feed_with_metadata_async<F, Fut>(feed: &mut Feed, fetch_metadata: F) -> bool
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = Option<Metadata>>,
+{
+    let Some(site_url) = pick_site_url(feed) else {
+        return false;
+    };
+    match fetch_metadata(site_url).await {
+        Some(meta) => {
+            apply_metadata_to_feed(feed, &meta);
+            true
+        }
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,4 +162,71 @@ mod tests {
         assert_eq!(feed.description, "KeepDesc");
         assert_eq!(feed.image_url.as_deref(), Some("keep"));
     }
+
+    #[test]
+    fn policy_controls_each_field() {
+        let meta = Metadata {
+            title: "Site Title".into(),
+            description: "Site Description".into(),
+            icon_url: "https://example.com/favicon.ico".into(),
+            theme_color: "#112233".into(),
+            ..Default::default()
+        };
+        let mut feed = Feed {
+            title: "Feed Title".into(),
+            image_url: Some("https://example.com/feed.png".into()),
+            theme_color: Some("#000000".into()),
+            ..Default::default()
+        };
+        let policy = MetadataPolicy {
+            title: FieldPolicy::Never,
+            description: FieldPolicy::Never,
+            image: FieldPolicy::Always,
+            icon: FieldPolicy::IfEmpty,
+            theme_color: FieldPolicy::Always,
+        };
+        apply_metadata_to_feed_with_policy(&mut feed, &meta, &policy);
+
+        assert_eq!(feed.title, "Feed Title");
+        assert_eq!(feed.description, "");
+        // Always never clears a field when the metadata value is empty
+        assert_eq!(
+            feed.image_url.as_deref(),
+            Some("https://example.com/feed.png")
+        );
+        assert_eq!(
+            feed.icon_url.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        assert_eq!(feed.theme_color.as_deref(), Some("#112233"));
+
+        // Always replaces a non-empty title
+        let policy = MetadataPolicy {
+            title: FieldPolicy::Always,
+            ..Default::default()
+        };
+        apply_metadata_to_feed_with_policy(&mut feed, &meta, &policy);
+        assert_eq!(feed.title, "Site Title");
+        assert_eq!(feed.description, "Site Description");
+    }
+
+    #[test]
+    fn async_enrichment_fetches_site_url() {
+        let mut feed = Feed {
+            feed_url: "https://example.com/feed.xml".into(),
+            ..Default::default()
+        };
+        let applied = futures::executor::block_on(enrich_feed_with_metadata_async(
+            &mut feed,
+            |url| async move {
+                assert_eq!(url, "https://example.com");
+                Some(Metadata {
+                    title: "Site".into(),
+                    ..Default::default()
+                })
+            },
+        ));
+        assert!(applied);
+        assert_eq!(feed.title, "Site");
+    }
 }
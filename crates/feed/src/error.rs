@@ -1,5 +1,5 @@
 // ABOUTME: Error types for feed parsing operations.
-// ABOUTME: Provides FeedError enum with Parse, Invalid, and Empty variants.
+// ABOUTME: Provides FeedError enum with Parse, Invalid, Empty, and LimitExceeded variants.
 
 use std::fmt;
 use thiserror::Error;
@@ -18,6 +18,10 @@ pub enum FeedError {
     /// The feed contains no items.
     #[error("feed is empty: no items found")]
     Empty,
+
+    /// The input exceeded one of the configured `ParseLimits`.
+    #[error("parse limit exceeded: {0}")]
+    LimitExceeded(String),
 }
 
 impl FeedError {
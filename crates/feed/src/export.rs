@@ -0,0 +1,158 @@
+// ABOUTME: Export helpers that render parsed feeds into other interchange formats.
+// ABOUTME: Currently provides iCalendar (RFC 5545) output for event-oriented feeds.
+
+use chrono::{DateTime, Utc};
+
+use crate::models::{Feed, FeedItem};
+
+/// PRODID emitted on generated calendars.
+const ICAL_PRODID: &str = "-//digests-core//feed export//EN";
+
+/// Maximum content line length in octets before folding (RFC 5545 §3.1).
+const ICAL_LINE_LIMIT: usize = 75;
+
+/// Renders a feed as an iCalendar document.
+///
+/// Each item with a publish date becomes a `VEVENT` (UID from guid, falling back
+/// to the item URL; SUMMARY from title; DTSTART from `published_ms`; DESCRIPTION
+/// from summary; URL from the item link). Items without a date are skipped.
+pub fn to_ical(feed: &Feed) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", ICAL_PRODID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    if !feed.title.is_empty() {
+        push_line(
+            &mut out,
+            &format!("X-WR-CALNAME:{}", escape_text(&feed.title)),
+        );
+    }
+
+    for item in &feed.items {
+        write_event(&mut out, item);
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+fn write_event(out: &mut String, item: &FeedItem) {
+    let Some(start) = format_timestamp(item.published_ms) else {
+        return;
+    };
+    let uid = if item.guid.is_empty() {
+        &item.url
+    } else {
+        &item.guid
+    };
+    if uid.is_empty() {
+        return;
+    }
+    let stamp = format_timestamp(item.updated_ms).unwrap_or_else(|| start.clone());
+
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", escape_text(uid)));
+    push_line(out, &format!("DTSTAMP:{}", stamp));
+    push_line(out, &format!("DTSTART:{}", start));
+    push_line(out, &format!("SUMMARY:{}", escape_text(&item.title)));
+    if !item.summary.is_empty() {
+        push_line(out, &format!("DESCRIPTION:{}", escape_text(&item.summary)));
+    }
+    if !item.url.is_empty() {
+        push_line(out, &format!("URL:{}", item.url));
+    }
+    push_line(out, "END:VEVENT");
+}
+
+/// Formats a millisecond Unix timestamp as an iCalendar UTC date-time.
+fn format_timestamp(ms: u64) -> Option<String> {
+    if ms == 0 {
+        return None;
+    }
+    let dt = DateTime::<Utc>::from_timestamp_millis(ms as i64)?;
+    Some(dt.format("%Y%m%dT%H%M%SZ").to_string())
+}
+
+/// Escapes TEXT property values per RFC 5545 §3.3.11.
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Appends a content line, folding it at 75 octets and terminating with CRLF.
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > ICAL_LINE_LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(guid: &str, title: &str, published_ms: u64) -> FeedItem {
+        FeedItem {
+            guid: guid.into(),
+            title: title.into(),
+            url: format!("https://example.com/{}", guid),
+            summary: "Talks, workshops; and more".into(),
+            published_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_to_ical_emits_vevent_per_dated_item() {
+        let feed = Feed {
+            title: "Conf Calendar".into(),
+            items: vec![
+                item("evt-1", "Keynote", 1_705_312_800_000),
+                item("evt-2", "Closing", 1_705_399_200_000),
+                item("evt-3", "Undated", 0),
+            ],
+            ..Default::default()
+        };
+
+        let ical = to_ical(&feed);
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ical.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(ical.matches("END:VEVENT").count(), 2);
+        assert!(ical.contains("UID:evt-1\r\n"));
+        assert!(ical.contains("UID:evt-2\r\n"));
+        assert!(!ical.contains("evt-3"));
+        assert!(ical.contains("DTSTART:20240115T100000Z\r\n"));
+        assert!(ical.contains("SUMMARY:Keynote\r\n"));
+        assert!(ical.contains("DESCRIPTION:Talks\\, workshops\\; and more\r\n"));
+        assert!(ical.contains("URL:https://example.com/evt-1\r\n"));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "x".repeat(100)));
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert_eq!(lines[0].len(), ICAL_LINE_LIMIT);
+        assert!(lines[1].starts_with(' '));
+    }
+}
@@ -0,0 +1,257 @@
+// ABOUTME: Preserves elements from unmapped XML namespaces as generic ExtensionElement trees.
+// ABOUTME: Runs a namespace-aware pass so custom CMS tags survive parsing, keyed by namespace URI.
+
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use quick_xml::reader::NsReader;
+
+use crate::models::{ExtensionElement, Extensions};
+
+/// Namespaces the parser already maps into typed fields; their elements are not preserved.
+const KNOWN_NAMESPACES: &[&str] = &[
+    "http://www.w3.org/2005/Atom",
+    "http://purl.org/rss/1.0",
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+    "http://www.w3.org/XML/1998/namespace",
+    "http://www.w3.org/1999/xhtml",
+    "http://www.itunes.com/dtds/podcast-1.0.dtd",
+    "https://podcastindex.org/namespace/1.0",
+    "http://podcastindex.org/namespace/1.0",
+    "http://purl.org/dc/elements/1.1",
+    "http://purl.org/dc/terms",
+    "http://purl.org/rss/1.0/modules/content",
+    "http://search.yahoo.com/mrss",
+    "http://www.georss.org/georss",
+    "http://www.w3.org/2003/01/geo/wgs84_pos#",
+    "http://www.opengis.net/gml",
+    "http://purl.org/rss/1.0/modules/slash",
+    "http://purl.org/syndication/thread/1.0",
+    "http://purl.org/rss/1.0/modules/syndication",
+    "http://www.youtube.com/xml/schemas/2015",
+    "http://podlove.org/simple-chapters",
+];
+
+/// Extension elements found at the channel level and per item.
+#[derive(Debug, Default, Clone)]
+pub struct ParsedExtensions {
+    pub feed: Extensions,
+    /// One map per `<item>`/`<entry>`, in document order.
+    pub items: Vec<Extensions>,
+}
+
+/// Collects direct children of the channel and of each item whose namespace is
+/// not one the parser maps. Malformed XML stops the pass and keeps what was read.
+pub fn parse_extension_elements(data: &[u8]) -> ParsedExtensions {
+    let mut result = ParsedExtensions::default();
+    let mut reader = NsReader::from_reader(data);
+    let mut buf = Vec::new();
+
+    let mut depth = 0usize;
+    let mut channel_depth: Option<usize> = None;
+    let mut item_depth: Option<usize> = None;
+    let mut current_item = Extensions::new();
+    // Open extension elements (outermost first) and the namespace of the outermost
+    let mut capture: Vec<ExtensionElement> = Vec::new();
+    let mut capture_ns = String::new();
+
+    while let Ok((ns, event)) = reader.read_resolved_event_into(&mut buf) {
+        let ns = namespace_uri(ns);
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let local = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                let is_core = ns.as_deref().is_none_or(is_known_namespace);
+
+                if !capture.is_empty() {
+                    capture.push(read_element(e));
+                } else if !is_core && (item_depth == Some(depth) || channel_depth == Some(depth)) {
+                    capture_ns = ns.unwrap_or_default();
+                    capture.push(read_element(e));
+                } else if is_core && matches!(local.as_str(), "item" | "entry") {
+                    if is_empty {
+                        result.items.push(Extensions::new());
+                    } else {
+                        item_depth = Some(depth + 1);
+                        current_item = Extensions::new();
+                    }
+                } else if is_core && matches!(local.as_str(), "channel" | "feed") {
+                    channel_depth = Some(depth + 1);
+                }
+
+                if is_empty {
+                    let target = if item_depth.is_some() {
+                        &mut current_item
+                    } else {
+                        &mut result.feed
+                    };
+                    close_capture(&mut capture, &capture_ns, target);
+                } else {
+                    depth += 1;
+                }
+            }
+            Event::Text(ref t) if !capture.is_empty() => {
+                if let Ok(text) = t.decode() {
+                    push_text(&mut capture, &text);
+                }
+            }
+            Event::CData(ref t) if !capture.is_empty() => {
+                push_text(&mut capture, &String::from_utf8_lossy(t.as_ref()));
+            }
+            Event::GeneralRef(ref r) if !capture.is_empty() => {
+                let resolved = match r.resolve_char_ref() {
+                    Ok(Some(c)) => Some(c.to_string()),
+                    _ => r
+                        .decode()
+                        .ok()
+                        .and_then(|name| resolve_predefined_entity(&name).map(str::to_string)),
+                };
+                if let Some(text) = resolved {
+                    push_text(&mut capture, &text);
+                }
+            }
+            Event::End(_) => {
+                if item_depth == Some(depth) && capture.is_empty() {
+                    result.items.push(std::mem::take(&mut current_item));
+                    item_depth = None;
+                } else if channel_depth == Some(depth) && capture.is_empty() {
+                    channel_depth = None;
+                } else {
+                    let target = if item_depth.is_some() {
+                        &mut current_item
+                    } else {
+                        &mut result.feed
+                    };
+                    close_capture(&mut capture, &capture_ns, target);
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    result
+}
+
+fn namespace_uri(ns: ResolveResult) -> Option<String> {
+    match ns {
+        ResolveResult::Bound(ns) => Some(String::from_utf8_lossy(ns.as_ref()).to_string()),
+        _ => None,
+    }
+}
+
+fn is_known_namespace(uri: &str) -> bool {
+    let uri = uri.trim_end_matches('/');
+    KNOWN_NAMESPACES.contains(&uri)
+}
+
+fn read_element(e: &BytesStart) -> ExtensionElement {
+    let attrs = e
+        .attributes()
+        .flatten()
+        .filter(|a| {
+            let key = a.key.as_ref();
+            key != b"xmlns" && !key.starts_with(b"xmlns:")
+        })
+        .map(|a| {
+            let key = String::from_utf8_lossy(a.key.as_ref()).to_string();
+            let value = a
+                .unescape_value()
+                .map(|v| v.into_owned())
+                .unwrap_or_default();
+            (key, value)
+        })
+        .collect();
+    ExtensionElement {
+        name: String::from_utf8_lossy(e.name().as_ref()).to_string(),
+        attrs,
+        ..Default::default()
+    }
+}
+
+fn push_text(capture: &mut [ExtensionElement], text: &str) {
+    if let Some(open) = capture.last_mut() {
+        open.value.get_or_insert_with(String::new).push_str(text);
+    }
+}
+
+/// Closes the innermost open extension element, storing it once the outermost closes.
+fn close_capture(capture: &mut Vec<ExtensionElement>, ns: &str, target: &mut Extensions) {
+    let Some(mut done) = capture.pop() else {
+        return;
+    };
+    done.value = done
+        .value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    match capture.last_mut() {
+        Some(parent) => parent.children.push(done),
+        None => target.entry(ns.to_string()).or_default().push(done),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preserves_unknown_namespace_elements() {
+        let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:cms="https://cms.example/ns" xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel>
+  <title>T</title>
+  <cms:site id="42"/>
+  <item>
+    <title>One</title>
+    <dc:creator>Ann</dc:creator>
+    <cms:rating scale="5">4 &amp; up</cms:rating>
+    <cms:meta><cms:tag>a</cms:tag><cms:tag>b</cms:tag></cms:meta>
+  </item>
+  <item><title>Two</title></item>
+</channel>
+</rss>"#;
+        let parsed = parse_extension_elements(xml.as_bytes());
+
+        let site = &parsed.feed["https://cms.example/ns"][0];
+        assert_eq!(site.name, "cms:site");
+        assert_eq!(site.attrs.get("id").map(String::as_str), Some("42"));
+
+        assert_eq!(parsed.items.len(), 2);
+        let cms = &parsed.items[0]["https://cms.example/ns"];
+        assert_eq!(cms.len(), 2);
+        assert_eq!(cms[0].value.as_deref(), Some("4 & up"));
+        assert_eq!(cms[0].attrs.get("scale").map(String::as_str), Some("5"));
+        let tags: Vec<_> = cms[1]
+            .children
+            .iter()
+            .filter_map(|c| c.value.as_deref())
+            .collect();
+        assert_eq!(tags, vec!["a", "b"]);
+
+        // Mapped namespaces are not duplicated into extensions
+        assert!(!parsed.items[0].contains_key("http://purl.org/dc/elements/1.1/"));
+        assert!(parsed.items[1].is_empty());
+    }
+
+    #[test]
+    fn test_atom_default_namespace_is_not_an_extension() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom" xmlns:as="http://activitystrea.ms/spec/1.0/">
+  <title>A</title>
+  <entry><title>E</title><as:verb>post</as:verb>
+    <content type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml"><p>hi</p></div></content>
+  </entry>
+</feed>"#;
+        let parsed = parse_extension_elements(xml.as_bytes());
+        assert!(parsed.feed.is_empty());
+        assert_eq!(parsed.items.len(), 1);
+        assert_eq!(parsed.items[0].len(), 1);
+        assert_eq!(
+            parsed.items[0]["http://activitystrea.ms/spec/1.0/"][0]
+                .value
+                .as_deref(),
+            Some("post")
+        );
+    }
+}
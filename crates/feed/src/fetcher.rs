@@ -0,0 +1,352 @@
+// ABOUTME: Polite feed fetching with conditional GET (ETag / If-Modified-Since) and gzip handling.
+// ABOUTME: The caller supplies the HTTP transport; the fetcher owns validators and parsing.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::error::FeedError;
+use crate::limits::ParseLimits;
+use crate::models::Feed;
+use crate::parser::{parse_feed_bytes_with_options, ParseOptions};
+
+/// `Accept` header sent with every feed request.
+const FEED_ACCEPT: &str = "application/rss+xml, application/atom+xml, application/feed+json;q=0.9, application/xml;q=0.8, text/xml;q=0.8, */*;q=0.5";
+
+/// A GET request the transport should perform, headers included verbatim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+/// Raw HTTP response returned by the transport.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchResponse {
+    pub status: u16,
+    /// Response headers; names are matched case-insensitively.
+    pub headers: Vec<(String, String)>,
+    /// Body bytes as received. Gzip bodies are decoded by the fetcher.
+    pub body: Vec<u8>,
+}
+
+impl FetchResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+}
+
+/// Cache validators remembered for one feed URL. Serializable so callers can
+/// persist them between runs and restore them with [`FeedFetcher::set_validators`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl FeedValidators {
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// Result of a successful fetch.
+#[derive(Debug, Clone)]
+pub enum FeedFetchResult {
+    /// The server answered 304; the previously fetched feed is still current.
+    NotModified,
+    /// The feed changed (or had no validators) and was parsed.
+    Fetched(Box<Feed>),
+}
+
+/// Errors from [`FeedFetcher::fetch`].
+#[derive(Debug, Error)]
+pub enum FeedFetchError {
+    /// The transport failed before a response arrived.
+    #[error("feed request failed: {0}")]
+    Transport(String),
+
+    /// The server answered with a non-success status other than 304.
+    #[error("feed request returned HTTP {0}")]
+    Http(u16),
+
+    /// The body could not be decompressed.
+    #[error("failed to decode feed body: {0}")]
+    Decode(String),
+
+    /// The body was fetched but did not parse as a feed.
+    #[error(transparent)]
+    Feed(#[from] FeedError),
+}
+
+/// Fetches feeds through a caller-supplied transport, sending conditional
+/// request headers and remembering `ETag`/`Last-Modified` per feed URL.
+///
+/// `transport` performs a single GET and returns the raw response, or an error
+/// message on network failure. It should not follow conditional semantics
+/// itself; the fetcher adds `If-None-Match`/`If-Modified-Since` and treats 304
+/// as [`FeedFetchResult::NotModified`]. `Accept-Encoding: gzip` is requested and
+/// gzip bodies are decoded, including transports that skip `Content-Encoding`.
+pub struct FeedFetcher<T> {
+    transport: T,
+    validators: HashMap<String, FeedValidators>,
+    parse_options: ParseOptions,
+}
+
+impl<T> FeedFetcher<T>
+where
+    T: FnMut(&FetchRequest) -> Result<FetchResponse, String>,
+{
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            validators: HashMap::new(),
+            parse_options: ParseOptions::default(),
+        }
+    }
+
+    /// Sets the options used to parse fetched feeds.
+    pub fn with_parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
+    /// Validators currently remembered for `url`.
+    pub fn validators(&self, url: &str) -> Option<&FeedValidators> {
+        self.validators.get(url)
+    }
+
+    /// Restores persisted validators for `url`.
+    pub fn set_validators(&mut self, url: &str, validators: FeedValidators) {
+        if validators.is_empty() {
+            self.validators.remove(url);
+        } else {
+            self.validators.insert(url.to_string(), validators);
+        }
+    }
+
+    /// Drops the validators for `url`, forcing the next fetch to be unconditional.
+    pub fn forget(&mut self, url: &str) {
+        self.validators.remove(url);
+    }
+
+    /// Fetches and parses `url`, or reports that it has not changed.
+    ///
+    /// Validators are only updated after a successful parse (or a 304), so a
+    /// broken response never suppresses the next full fetch.
+    pub fn fetch(&mut self, url: &str) -> Result<FeedFetchResult, FeedFetchError> {
+        let request = self.build_request(url, true);
+        let response = (self.transport)(&request).map_err(FeedFetchError::Transport)?;
+        let fresh = FeedValidators {
+            etag: response.header("etag").map(str::to_string),
+            last_modified: response.header("last-modified").map(str::to_string),
+        };
+
+        match response.status {
+            304 => {
+                // A 304 may refresh the validators; otherwise keep what we sent
+                if !fresh.is_empty() {
+                    self.set_validators(url, fresh);
+                }
+                Ok(FeedFetchResult::NotModified)
+            }
+            200..=299 => {
+                let feed = self.parse_response(&response, url)?;
+                self.set_validators(url, fresh);
+                Ok(FeedFetchResult::Fetched(Box::new(feed)))
+            }
+            status => Err(FeedFetchError::Http(status)),
+        }
+    }
+
+    /// Fetches and parses `url` without sending or remembering validators, for
+    /// one-off reads (such as archive pages) that must not touch the cache
+    /// [`fetch`](Self::fetch) relies on. A 304 is reported as an HTTP error.
+    pub fn fetch_unconditional(&mut self, url: &str) -> Result<Feed, FeedFetchError> {
+        let request = self.build_request(url, false);
+        let response = (self.transport)(&request).map_err(FeedFetchError::Transport)?;
+        match response.status {
+            200..=299 => self.parse_response(&response, url),
+            status => Err(FeedFetchError::Http(status)),
+        }
+    }
+
+    fn parse_response(&self, response: &FetchResponse, url: &str) -> Result<Feed, FeedFetchError> {
+        let body = decode_body(response, &self.parse_options.limits)?;
+        Ok(parse_feed_bytes_with_options(
+            &body,
+            url,
+            &self.parse_options,
+        )?)
+    }
+
+    fn build_request(&self, url: &str, conditional: bool) -> FetchRequest {
+        let mut headers = vec![
+            ("Accept".to_string(), FEED_ACCEPT.to_string()),
+            ("Accept-Encoding".to_string(), "gzip".to_string()),
+        ];
+        if let Some(v) = self.validators.get(url).filter(|_| conditional) {
+            if let Some(etag) = &v.etag {
+                headers.push(("If-None-Match".to_string(), etag.clone()));
+            }
+            if let Some(last_modified) = &v.last_modified {
+                headers.push(("If-Modified-Since".to_string(), last_modified.clone()));
+            }
+        }
+        FetchRequest {
+            url: url.to_string(),
+            headers,
+        }
+    }
+}
+
+/// Returns the body, gunzipped when `Content-Encoding` says gzip or the bytes
+/// carry the gzip magic number (e.g. `.xml.gz` served as a plain file).
+///
+/// Decompression stops one byte past `limits.max_bytes`, so a gzip bomb fails
+/// with [`FeedError::LimitExceeded`] instead of being inflated in full.
+fn decode_body(response: &FetchResponse, limits: &ParseLimits) -> Result<Vec<u8>, FeedFetchError> {
+    let declared_gzip = response
+        .header("content-encoding")
+        .is_some_and(|enc| enc.eq_ignore_ascii_case("gzip") || enc.eq_ignore_ascii_case("x-gzip"));
+    if !declared_gzip && !response.body.starts_with(&[0x1f, 0x8b]) {
+        return Ok(response.body.clone());
+    }
+    let read_limit = limits
+        .max_bytes
+        .map_or(u64::MAX, |max| (max as u64).saturating_add(1));
+    let mut decoded = Vec::new();
+    GzDecoder::new(response.body.as_slice())
+        .take(read_limit)
+        .read_to_end(&mut decoded)
+        .map_err(|e| FeedFetchError::Decode(e.to_string()))?;
+    limits.check_size(decoded.len())?;
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    const RSS: &str = r#"<rss version="2.0"><channel><title>T</title><item><title>One</title><link>https://e.com/1</link></item></channel></rss>"#;
+
+    fn header<'a>(req: &'a FetchRequest, name: &str) -> Option<&'a str> {
+        req.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn test_sends_validators_and_reports_not_modified() {
+        let mut seen = Vec::new();
+        let mut fetcher = FeedFetcher::new(|req: &FetchRequest| {
+            seen.push(header(req, "If-None-Match").map(str::to_string));
+            if header(req, "If-None-Match") == Some("\"v1\"") {
+                return Ok(FetchResponse {
+                    status: 304,
+                    ..Default::default()
+                });
+            }
+            Ok(FetchResponse {
+                status: 200,
+                headers: vec![
+                    ("ETag".into(), "\"v1\"".into()),
+                    (
+                        "Last-Modified".into(),
+                        "Wed, 01 Jan 2025 00:00:00 GMT".into(),
+                    ),
+                ],
+                body: RSS.as_bytes().to_vec(),
+            })
+        });
+
+        let first = fetcher.fetch("https://e.com/feed").unwrap();
+        assert!(matches!(first, FeedFetchResult::Fetched(ref f) if f.items.len() == 1));
+        assert_eq!(
+            fetcher
+                .validators("https://e.com/feed")
+                .unwrap()
+                .etag
+                .as_deref(),
+            Some("\"v1\"")
+        );
+
+        let second = fetcher.fetch("https://e.com/feed").unwrap();
+        assert!(matches!(second, FeedFetchResult::NotModified));
+        drop(fetcher);
+        assert_eq!(seen, vec![None, Some("\"v1\"".to_string())]);
+    }
+
+    #[test]
+    fn test_decodes_gzip_and_keeps_validators_on_error() {
+        let mut gz = GzEncoder::new(Vec::new(), Compression::default());
+        gz.write_all(RSS.as_bytes()).unwrap();
+        let compressed = gz.finish().unwrap();
+
+        let mut status = 200;
+        let mut fetcher = FeedFetcher::new(move |_: &FetchRequest| {
+            let response = FetchResponse {
+                status,
+                headers: vec![("ETag".into(), format!("\"{}\"", status))],
+                body: compressed.clone(),
+            };
+            status = 500;
+            Ok(response)
+        });
+
+        assert!(matches!(
+            fetcher.fetch("https://e.com/feed.gz"),
+            Ok(FeedFetchResult::Fetched(_))
+        ));
+        assert!(matches!(
+            fetcher.fetch("https://e.com/feed.gz"),
+            Err(FeedFetchError::Http(500))
+        ));
+        assert_eq!(
+            fetcher
+                .validators("https://e.com/feed.gz")
+                .unwrap()
+                .etag
+                .as_deref(),
+            Some("\"200\"")
+        );
+    }
+
+    #[test]
+    fn test_gzip_output_is_bounded_by_max_bytes() {
+        let mut gz = GzEncoder::new(Vec::new(), Compression::best());
+        gz.write_all(&vec![b' '; 1024 * 1024]).unwrap();
+        let bomb = gz.finish().unwrap();
+
+        let mut fetcher = FeedFetcher::new(move |_: &FetchRequest| {
+            Ok(FetchResponse {
+                status: 200,
+                headers: vec![("Content-Encoding".into(), "gzip".into())],
+                body: bomb.clone(),
+            })
+        })
+        .with_parse_options(ParseOptions {
+            limits: ParseLimits {
+                max_bytes: Some(64 * 1024),
+                ..ParseLimits::default()
+            },
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            fetcher.fetch("https://e.com/bomb.xml"),
+            Err(FeedFetchError::Feed(FeedError::LimitExceeded(_)))
+        ));
+    }
+}
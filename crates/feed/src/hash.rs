@@ -0,0 +1,20 @@
+// ABOUTME: Stable FNV-1a hashing for values that are persisted or compared across runs.
+// ABOUTME: Used for synthetic guids, content hashes, and diff keys instead of DefaultHasher.
+
+/// FNV-1a over `parts` (stable across builds, unlike `DefaultHasher`).
+pub(crate) fn fnv1a(parts: &[&str]) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            // Unit separator keeps ("ab", "c") distinct from ("a", "bc")
+            hash = (hash ^ 0x1f).wrapping_mul(FNV_PRIME);
+        }
+        for byte in part.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
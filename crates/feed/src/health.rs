@@ -0,0 +1,215 @@
+// ABOUTME: Feed health report listing data-quality problems in a parsed feed.
+// ABOUTME: Flags missing/duplicate guids, bad dates, missing links, broken enclosures, and oversize descriptions.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use url::Url;
+
+use crate::models::Feed;
+
+/// Plain-text summary length (bytes) above which a description counts as oversize.
+pub const OVERSIZE_DESCRIPTION_BYTES: usize = 10_000;
+
+/// How far in the future a publish date may be before it is treated as invalid.
+const FUTURE_DATE_TOLERANCE_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Category of a feed quality problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedIssueKind {
+    /// The item had no guid/id; its identity was synthesized.
+    MissingGuid,
+    /// The item's guid is shared with an earlier item.
+    DuplicateGuid,
+    /// The publish date is missing, unparseable, or far in the future.
+    InvalidDate,
+    /// The item has no link.
+    MissingLink,
+    /// An enclosure URL is empty or not an absolute http(s) URL.
+    BrokenEnclosureUrl,
+    /// The description exceeds [`OVERSIZE_DESCRIPTION_BYTES`].
+    OversizeDescription,
+}
+
+/// One problem found in a feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedIssue {
+    pub kind: FeedIssueKind,
+    /// Index into `feed.items`.
+    pub item_index: usize,
+    /// Human-readable detail suitable for a "feed issues" panel.
+    pub message: String,
+}
+
+/// Quality report for a parsed feed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedHealth {
+    pub item_count: usize,
+    /// Issues in item order.
+    pub issues: Vec<FeedIssue>,
+}
+
+impl FeedHealth {
+    /// Returns true when no issues were found.
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Number of issues of `kind`.
+    pub fn count(&self, kind: FeedIssueKind) -> usize {
+        self.issues.iter().filter(|i| i.kind == kind).count()
+    }
+}
+
+/// Checks every item of `feed` for common data-quality problems.
+pub fn analyze_feed(feed: &Feed) -> FeedHealth {
+    analyze_feed_at(feed, Utc::now().timestamp_millis() as u64)
+}
+
+fn analyze_feed_at(feed: &Feed, now_ms: u64) -> FeedHealth {
+    let mut health = FeedHealth {
+        item_count: feed.items.len(),
+        issues: Vec::new(),
+    };
+    let mut first_with_guid: HashMap<&str, usize> = HashMap::new();
+
+    for (idx, item) in feed.items.iter().enumerate() {
+        let mut report = |kind: FeedIssueKind, message: String| {
+            health.issues.push(FeedIssue {
+                kind,
+                item_index: idx,
+                message,
+            })
+        };
+
+        if item.guid_generated || item.guid.trim().is_empty() {
+            report(
+                FeedIssueKind::MissingGuid,
+                "item has no guid; read state may not survive edits".to_string(),
+            );
+        } else if let Some(first) = first_with_guid.get(item.guid.as_str()) {
+            report(
+                FeedIssueKind::DuplicateGuid,
+                format!("guid \"{}\" already used by item {}", item.guid, first),
+            );
+        } else {
+            first_with_guid.insert(&item.guid, idx);
+        }
+
+        if item.published_ms == 0 {
+            report(
+                FeedIssueKind::InvalidDate,
+                "publish date is missing or unparseable".to_string(),
+            );
+        } else if item.published_ms > now_ms.saturating_add(FUTURE_DATE_TOLERANCE_MS) {
+            report(
+                FeedIssueKind::InvalidDate,
+                "publish date is in the future".to_string(),
+            );
+        }
+
+        if item.url.trim().is_empty() {
+            report(FeedIssueKind::MissingLink, "item has no link".to_string());
+        }
+
+        for enclosure in &item.enclosures {
+            if !is_fetchable_url(&enclosure.url) {
+                report(
+                    FeedIssueKind::BrokenEnclosureUrl,
+                    format!(
+                        "enclosure URL \"{}\" is not a valid http(s) URL",
+                        enclosure.url
+                    ),
+                );
+            }
+        }
+
+        if item.summary.len() > OVERSIZE_DESCRIPTION_BYTES {
+            report(
+                FeedIssueKind::OversizeDescription,
+                format!("description is {} bytes", item.summary.len()),
+            );
+        }
+    }
+
+    health
+}
+
+fn is_fetchable_url(url: &str) -> bool {
+    Url::parse(url.trim())
+        .map(|u| matches!(u.scheme(), "http" | "https") && u.host_str().is_some())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Enclosure, FeedItem};
+
+    const NOW_MS: u64 = 1_700_000_000_000;
+
+    fn good_item(guid: &str) -> FeedItem {
+        FeedItem {
+            guid: guid.into(),
+            url: format!("https://e.com/{}", guid),
+            published_ms: NOW_MS - 1000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_clean_feed_is_healthy() {
+        let feed = Feed {
+            items: vec![good_item("a"), good_item("b")],
+            ..Default::default()
+        };
+        let health = analyze_feed_at(&feed, NOW_MS);
+        assert!(health.is_healthy());
+        assert_eq!(health.item_count, 2);
+    }
+
+    #[test]
+    fn test_reports_each_issue_kind() {
+        let feed = Feed {
+            items: vec![
+                good_item("a"),
+                FeedItem {
+                    published_ms: 0,
+                    ..good_item("a")
+                },
+                FeedItem {
+                    guid_generated: true,
+                    url: String::new(),
+                    published_ms: NOW_MS + 7 * FUTURE_DATE_TOLERANCE_MS,
+                    enclosures: vec![Enclosure {
+                        url: "/relative.mp3".into(),
+                        ..Default::default()
+                    }],
+                    summary: "x".repeat(OVERSIZE_DESCRIPTION_BYTES + 1),
+                    ..good_item("c")
+                },
+            ],
+            ..Default::default()
+        };
+
+        let health = analyze_feed_at(&feed, NOW_MS);
+        let kinds: Vec<_> = health
+            .issues
+            .iter()
+            .map(|i| (i.item_index, i.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (1, FeedIssueKind::DuplicateGuid),
+                (1, FeedIssueKind::InvalidDate),
+                (2, FeedIssueKind::MissingGuid),
+                (2, FeedIssueKind::InvalidDate),
+                (2, FeedIssueKind::MissingLink),
+                (2, FeedIssueKind::BrokenEnclosureUrl),
+                (2, FeedIssueKind::OversizeDescription),
+            ]
+        );
+        assert_eq!(health.count(FeedIssueKind::InvalidDate), 2);
+    }
+}
@@ -1,27 +1,23 @@
 // ABOUTME: HTML utility functions for feed content processing.
-// ABOUTME: Provides tag stripping and HTML entity decoding matching Go behavior.
+// ABOUTME: Provides tag stripping and HTML entity decoding matching Go behavior, plus URL resolution.
 
-use aho_corasick::AhoCorasick;
-use once_cell::sync::Lazy;
+use digests_hermes::formats::insert_block_breaks;
+use markup5ever::data::NAMED_ENTITIES;
 
-/// Named HTML entities to decode (patterns for Aho-Corasick automaton).
-const ENTITY_PATTERNS: &[&str] = &[
-    "&amp;", "&lt;", "&gt;", "&quot;", "&apos;", "&#39;", "&nbsp;", "&ndash;", "&mdash;",
-    "&lsquo;", "&rsquo;", "&ldquo;", "&rdquo;", "&hellip;", "&copy;", "&reg;", "&trade;", "&bull;",
-    "&middot;", "&deg;", "&plusmn;", "&times;", "&divide;", "&frac12;", "&frac14;", "&frac34;",
-    "&euro;", "&pound;", "&yen;", "&cent;",
-];
+/// Rewrites relative URLs in content HTML; shared with hermes so feed and
+/// article content resolve `href`, `src`, `poster`, `cite` and `srcset` alike.
+pub use digests_hermes::formats::absolutize_urls as resolve_relative_urls;
 
-/// Replacement strings for each entity (must match order of ENTITY_PATTERNS).
-const ENTITY_REPLACEMENTS: &[&str] = &[
-    "&", "<", ">", "\"", "'", "'", " ", "–", "—", "'", "'", "\u{201C}", "\u{201D}", "…", "©", "®",
-    "™", "•", "·", "°", "±", "×", "÷", "½", "¼", "¾", "€", "£", "¥", "¢",
-];
+/// Longest HTML5 entity name (`&CounterClockwiseContourIntegral;`), without `&`/`;`.
+const MAX_ENTITY_NAME_LEN: usize = 32;
 
-/// Aho-Corasick automaton for single-pass HTML entity replacement.
-/// Compiled once at first use, then reused for all subsequent calls.
-static ENTITY_MATCHER: Lazy<AhoCorasick> =
-    Lazy::new(|| AhoCorasick::new(ENTITY_PATTERNS).expect("failed to build entity matcher"));
+/// Windows-1252 characters that HTML5 substitutes for numeric references in the
+/// C1 control range (`&#128;`..`&#159;`), as emitted by many CMS exports.
+const WINDOWS_1252_C1: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '•', '–', '—', '˜', '™', 'š', '›',
+    'œ', '\u{9D}', 'ž', 'Ÿ',
+];
 
 /// Strips HTML tags from a string, returning plain text.
 /// This is a naive implementation that removes angle-bracketed content.
@@ -45,79 +41,147 @@ pub fn strip_html(s: &str) -> String {
     collapse_whitespace(&decoded)
 }
 
-/// Decodes common HTML entities to their character equivalents.
-/// Uses Aho-Corasick for O(n) single-pass named entity replacement,
-/// then handles numeric entities in a second pass.
+/// Strips HTML tags like [`strip_html`], but keeps a line break at each tag
+/// named in `block_tags` (see `digests_hermes::formats::BLOCK_TAGS` for the default set).
+/// Whitespace is collapsed within each line and empty lines are dropped.
+pub fn strip_html_with_blocks(s: &str, block_tags: &[&str]) -> String {
+    let broken = insert_block_breaks(s, block_tags);
+    let mut result = String::with_capacity(broken.len());
+    let mut in_tag = false;
+
+    for c in broken.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+
+    let decoded = decode_entities(&result);
+    decoded
+        .lines()
+        .map(collapse_whitespace)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Decodes HTML entities to their character equivalents.
+///
+/// Supports the full HTML5 named entity set (including the legacy names that
+/// may omit the trailing `;`, e.g. `&copy`) and decimal/hex numeric references.
+/// Numeric references in the C1 range map to their Windows-1252 characters and
+/// invalid code points become U+FFFD, as browsers do. `&nbsp;` decodes to a
+/// plain space and `&lsquo;`/`&rsquo;` to an ASCII `'` (Go parity). Unknown
+/// entities are left as-is. This is a single pass; see
+/// [`decode_entities_double_encoded`] for `&amp;amp;`-style input.
 pub fn decode_entities(s: &str) -> String {
     // Fast path: no entity markers at all
     if !s.contains('&') {
         return s.to_string();
     }
 
-    // Single-pass named entity replacement using Aho-Corasick automaton
-    let named_decoded = ENTITY_MATCHER.replace_all(s, ENTITY_REPLACEMENTS);
-
-    // Handle numeric entities (decimal and hex) - already O(n)
-    decode_numeric_entities(&named_decoded)
-}
-
-/// Decodes numeric HTML entities like &#123; and &#x7B;
-fn decode_numeric_entities(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
-    let mut chars = s.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        if c == '&' && chars.peek() == Some(&'#') {
-            chars.next(); // consume '#'
-            let mut num_str = String::new();
-            let is_hex = chars.peek() == Some(&'x') || chars.peek() == Some(&'X');
-
-            if is_hex {
-                chars.next(); // consume 'x' or 'X'
+    let mut rest = s;
+    while let Some(pos) = rest.find('&') {
+        result.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        match decode_entity_at(rest) {
+            Some((decoded, consumed)) => {
+                result.push_str(&decoded);
+                rest = &rest[consumed..];
             }
-
-            while let Some(&nc) = chars.peek() {
-                if nc == ';' {
-                    chars.next(); // consume ';'
-                    break;
-                }
-                if is_hex && nc.is_ascii_hexdigit() {
-                    num_str.push(chars.next().unwrap());
-                } else if !is_hex && nc.is_ascii_digit() {
-                    num_str.push(chars.next().unwrap());
-                } else {
-                    break;
-                }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
             }
+        }
+    }
+    result.push_str(rest);
+    result
+}
 
-            if !num_str.is_empty() {
-                let code = if is_hex {
-                    u32::from_str_radix(&num_str, 16).ok()
-                } else {
-                    num_str.parse::<u32>().ok()
-                };
-
-                if let Some(code) = code {
-                    if let Some(decoded_char) = char::from_u32(code) {
-                        result.push(decoded_char);
-                        continue;
-                    }
-                }
-            }
+/// Decodes entities like [`decode_entities`], then once more when the input
+/// contained `&amp;`, so double-encoded feed text (`Tom &amp;amp; Jerry`,
+/// `&amp;#8217;`) comes out clean. Opt-in, since it also decodes text that
+/// deliberately shows an entity literally.
+pub fn decode_entities_double_encoded(s: &str) -> String {
+    let once = decode_entities(s);
+    if s.contains("&amp;") && once.contains('&') {
+        decode_entities(&once)
+    } else {
+        once
+    }
+}
 
-            // Failed to decode, push original
-            result.push('&');
-            result.push('#');
-            if is_hex {
-                result.push('x');
-            }
-            result.push_str(&num_str);
-        } else {
-            result.push(c);
+/// Decodes the entity at the start of `s` (which begins with `&`), returning
+/// the replacement text and the number of bytes consumed.
+fn decode_entity_at(s: &str) -> Option<(String, usize)> {
+    let body = &s[1..];
+    if let Some(numeric) = body.strip_prefix('#') {
+        let (digits, radix, prefix_len) = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 3),
+            None => (numeric, 10, 2),
+        };
+        let len = digits
+            .bytes()
+            .take_while(|b| (*b as char).is_digit(radix))
+            .count();
+        if len == 0 {
+            return None;
         }
+        let code = u32::from_str_radix(&digits[..len], radix).unwrap_or(u32::MAX);
+        let semicolon = usize::from(digits[len..].starts_with(';'));
+        return Some((
+            numeric_reference_char(code).to_string(),
+            prefix_len + len + semicolon,
+        ));
     }
 
-    result
+    let name_len = body
+        .bytes()
+        .take(MAX_ENTITY_NAME_LEN)
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+    if name_len == 0 {
+        return None;
+    }
+    if body[name_len..].starts_with(';') {
+        let name = &body[..=name_len];
+        if let Some(decoded) = named_entity(name) {
+            return Some((decoded, name.len() + 1));
+        }
+    }
+    // Legacy entities may appear without `;` (`&copy 2024`); take the longest match
+    (2..=name_len)
+        .rev()
+        .find_map(|len| named_entity(&body[..len]).map(|decoded| (decoded, len + 1)))
+}
+
+/// Looks up an HTML5 entity name (with its `;` when it has one).
+fn named_entity(name: &str) -> Option<String> {
+    match name {
+        "nbsp;" | "nbsp" => return Some(" ".to_string()),
+        "lsquo;" | "rsquo;" => return Some("'".to_string()),
+        _ => {}
+    }
+    // The table also holds name prefixes (used by html5ever's tokenizer) as (0, 0)
+    let &(first, second) = NAMED_ENTITIES.get(name).filter(|(first, _)| *first != 0)?;
+    let mut decoded: String = char::from_u32(first).into_iter().collect();
+    if second != 0 {
+        decoded.extend(char::from_u32(second));
+    }
+    Some(decoded)
+}
+
+/// Maps a numeric character reference to the character browsers display.
+fn numeric_reference_char(code: u32) -> char {
+    match code {
+        0x80..=0x9F => WINDOWS_1252_C1[(code - 0x80) as usize],
+        0 => char::REPLACEMENT_CHARACTER,
+        _ => char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+    }
 }
 
 /// Collapses multiple whitespace characters into single spaces.
@@ -187,6 +251,84 @@ mod tests {
         assert_eq!(decode_entities("&amp;&#38;&lt;"), "&&<");
     }
 
+    #[test]
+    fn test_decode_entities_html5_named() {
+        assert_eq!(decode_entities("Wait&hellip;"), "Wait…");
+        assert_eq!(decode_entities("It&rsquo;s &lsquo;x&rsquo;"), "It's 'x'");
+        assert_eq!(decode_entities("&laquo;&raquo;&eacute;&Omega;"), "«»éΩ");
+        assert_eq!(decode_entities("&NotEqualTilde;"), "\u{2242}\u{338}");
+        // Legacy names without a semicolon; modern ones require it
+        assert_eq!(decode_entities("&copy 2024"), "© 2024");
+        assert_eq!(decode_entities("&hellip"), "&hellip");
+        // Unknown names and bare ampersands are kept
+        assert_eq!(decode_entities("AT&T &bogus; a & b"), "AT&T &bogus; a & b");
+    }
+
+    #[test]
+    fn test_decode_entities_numeric_edge_cases() {
+        assert_eq!(
+            decode_entities("&#x2019;&#X2019;&#8217"),
+            "\u{2019}\u{2019}\u{2019}"
+        );
+        // C1 range maps to Windows-1252
+        assert_eq!(decode_entities("&#146;&#151;"), "\u{2019}—");
+        assert_eq!(
+            decode_entities("&#0;&#xD800;&#99999999;"),
+            "\u{FFFD}\u{FFFD}\u{FFFD}"
+        );
+        assert_eq!(decode_entities("&#;&#x;"), "&#;&#x;");
+    }
+
+    #[test]
+    fn test_decode_entities_is_single_pass() {
+        assert_eq!(decode_entities("&amp;amp;"), "&amp;");
+        assert_eq!(decode_entities("&amp;#38;"), "&#38;");
+    }
+
+    #[test]
+    fn test_decode_entities_double_encoded() {
+        assert_eq!(
+            decode_entities_double_encoded("Tom &amp;amp; Jerry&amp;#8217;s"),
+            "Tom & Jerry\u{2019}s"
+        );
+        assert_eq!(decode_entities_double_encoded("&lt;b&gt;"), "<b>");
+        assert_eq!(decode_entities_double_encoded("&amp;&amp;"), "&&");
+    }
+
+    #[test]
+    fn test_resolve_relative_urls() {
+        let base = "https://blog.example.com/posts/1/";
+        let html = r#"<p><a href="/about">About</a> <img src='img/a.png' alt="x"> <a href=../2>Next</a></p>"#;
+        assert_eq!(
+            resolve_relative_urls(html, base),
+            r#"<p><a href="https://blog.example.com/about">About</a> <img src='https://blog.example.com/posts/1/img/a.png' alt="x"> <a href=https://blog.example.com/posts/2>Next</a></p>"#
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_urls_keeps_absolute_and_special() {
+        let base = "https://example.com/a/";
+        let html = r##"<a href="https://other.org/x">x</a><a href="#top">t</a><a HREF="mailto:me@example.com">m</a><img src="data:image/png;base64,AA"><img src="//cdn.example.com/p.jpg"><span title="a/b">s</span>"##;
+        let out = resolve_relative_urls(html, base);
+        assert!(out.contains(r#"href="https://other.org/x""#));
+        assert!(out.contains(r##"href="#top""##));
+        assert!(out.contains(r#"HREF="mailto:me@example.com""#));
+        assert!(out.contains(r#"src="data:image/png;base64,AA""#));
+        assert!(out.contains(r#"src="https://cdn.example.com/p.jpg""#));
+        assert!(out.contains(r#"title="a/b""#));
+        assert_eq!(resolve_relative_urls(html, "not a url"), html);
+    }
+
+    #[test]
+    fn test_resolve_relative_urls_srcset_and_media() {
+        let base = "https://example.com/";
+        let html = r#"<img srcset="small.jpg 480w, /large.jpg 1080w"><video poster="p.jpg" src="v.mp4" data-x="y.mp4"></video> 1 < 2"#;
+        assert_eq!(
+            resolve_relative_urls(html, base),
+            r#"<img srcset="https://example.com/small.jpg 480w, https://example.com/large.jpg 1080w"><video poster="https://example.com/p.jpg" src="https://example.com/v.mp4" data-x="y.mp4"></video> 1 < 2"#
+        );
+    }
+
     #[test]
     fn test_empty_string() {
         assert_eq!(strip_html(""), "");
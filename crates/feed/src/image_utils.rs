@@ -1,6 +1,7 @@
 // ABOUTME: Image URL extraction and validation for feed content.
 // ABOUTME: Extracts first valid image from HTML and filters tracking pixels.
 
+use digests_hermes::formats::{parse_srcset, SrcsetCandidate};
 use scraper::{Html, Selector};
 use url::Url;
 
@@ -17,32 +18,98 @@ const INVALID_PATTERNS: &[&str] = &[
     "data:image/gif;base64,r0lgodlhaqabai",
 ];
 
+/// An image picked from HTML, with its declared `width`/`height` attributes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtractedImage {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
 /// Extracts the first valid image URL from HTML content.
-/// Resolves relative URLs using the provided base URL if available.
+///
+/// For each `<img>`, the largest `srcset`/`data-srcset` candidate is preferred over
+/// `src` (or lazy-loading `data-src`). Images whose width/height attributes mark
+/// them as tracking pixels (1px or smaller) are skipped, as are URLs matching
+/// [`is_valid_image_url`]'s pixel patterns. Relative URLs are resolved using the
+/// provided base URL if available.
 pub fn extract_first_image(html: &str, base_url: Option<&str>) -> Option<String> {
+    extract_first_image_details(html, base_url).map(|img| img.url)
+}
+
+/// Like [`extract_first_image`], but also returns the `<img>` width/height hints.
+pub fn extract_first_image_details(html: &str, base_url: Option<&str>) -> Option<ExtractedImage> {
     let document = Html::parse_fragment(html);
-    let selector = Selector::parse("img[src]").ok()?;
+    let selector = Selector::parse("img").ok()?;
 
     for element in document.select(&selector) {
-        if let Some(src) = element.value().attr("src") {
-            // Skip empty sources
-            if src.trim().is_empty() {
-                continue;
-            }
-
-            // Resolve URL
-            let resolved = resolve_image_url(src, base_url);
-            if let Some(ref url) = resolved {
-                if is_valid_image_url(url) {
-                    return resolved;
-                }
-            }
+        let img = element.value();
+        if is_pixel_sized(img.attr("width")) || is_pixel_sized(img.attr("height")) {
+            continue;
+        }
+
+        // srcset candidates first, then src; an unusable one falls through to the next
+        let srcset = ["srcset", "data-srcset"]
+            .iter()
+            .filter_map(|attr| img.attr(attr))
+            .filter_map(largest_srcset_candidate);
+        let src = ["src", "data-src"]
+            .iter()
+            .filter_map(|attr| img.attr(attr))
+            .map(str::trim)
+            .filter(|src| !src.is_empty())
+            .map(str::to_string);
+        let resolved = srcset
+            .chain(src)
+            .filter_map(|candidate| resolve_image_url(&candidate, base_url))
+            .find(|url| is_valid_image_url(url));
+        if let Some(url) = resolved {
+            return Some(ExtractedImage {
+                url,
+                width: parse_dimension(img.attr("width")),
+                height: parse_dimension(img.attr("height")),
+            });
         }
     }
 
     None
 }
 
+/// Returns true for a width/height attribute of 1px or less (e.g. `"1"`, `"0px"`).
+fn is_pixel_sized(value: Option<&str>) -> bool {
+    value
+        .map(|v| v.trim().trim_end_matches("px"))
+        .and_then(|v| v.parse::<f64>().ok())
+        .is_some_and(|v| v <= 1.0)
+}
+
+/// Parses a pixel width/height attribute (`"640"`, `"640px"`); percentages are ignored.
+fn parse_dimension(value: Option<&str>) -> Option<u32> {
+    value
+        .map(|v| v.trim().trim_end_matches("px"))
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|v| *v > 0)
+}
+
+/// Picks the largest candidate from a `srcset` value.
+///
+/// Width descriptors (`800w`) rank above density descriptors (`2x`); a candidate
+/// without a usable descriptor counts as `1x`.
+fn largest_srcset_candidate(srcset: &str) -> Option<String> {
+    let mut best: Option<((u8, f64), SrcsetCandidate)> = None;
+    for candidate in parse_srcset(srcset) {
+        let rank = match (candidate.width(), candidate.density()) {
+            (Some(width), _) => (2, f64::from(width)),
+            (None, Some(density)) => (1, density),
+            (None, None) => (1, 1.0),
+        };
+        if best.as_ref().is_none_or(|(best_rank, _)| rank > *best_rank) {
+            best = Some((rank, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate.url)
+}
+
 /// Resolves a potentially relative image URL against a base URL.
 /// Returns None if resolution fails or the input is invalid.
 pub fn resolve_image_url(src: &str, base_url: Option<&str>) -> Option<String> {
@@ -70,6 +137,19 @@ pub fn resolve_image_url(src: &str, base_url: Option<&str>) -> Option<String> {
     Some(resolved.to_string())
 }
 
+/// Returns true when two image URLs point at the same resource, ignoring the
+/// query string and fragment (e.g. CDN resize parameters).
+pub fn same_image_url(a: &str, b: &str) -> bool {
+    fn strip(u: &str) -> &str {
+        let end = u.find(['?', '#']).unwrap_or(u.len());
+        &u[..end]
+    }
+    match (Url::parse(a.trim()), Url::parse(b.trim())) {
+        (Ok(ua), Ok(ub)) => ua.host_str() == ub.host_str() && ua.path() == ub.path(),
+        _ => strip(a.trim()) == strip(b.trim()),
+    }
+}
+
 /// Checks if an image URL is valid (not a tracking pixel or similar).
 /// Returns false for URLs matching known tracking/pixel patterns.
 pub fn is_valid_image_url(url: &str) -> bool {
@@ -146,6 +226,18 @@ mod tests {
         assert!(!is_valid_image_url("data:image/gif;base64,R0lGODlhAQABAI"));
     }
 
+    #[test]
+    fn test_same_image_url_ignores_query() {
+        assert!(same_image_url(
+            "https://cdn.example.com/a.jpg?w=300",
+            "https://cdn.example.com/a.jpg#x"
+        ));
+        assert!(!same_image_url(
+            "https://cdn.example.com/a.jpg",
+            "https://cdn.example.com/b.jpg"
+        ));
+    }
+
     #[test]
     fn test_resolve_image_url_absolute() {
         let result = resolve_image_url("https://example.com/image.jpg", None);
@@ -211,6 +303,66 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_extract_first_image_prefers_largest_srcset() {
+        let html = r#"<img src="/small.jpg" srcset="/a-480.jpg 480w, /a-1200.jpg 1200w, /a-800.jpg 800w">"#;
+        let result = extract_first_image(html, Some("https://example.com/post"));
+        assert_eq!(result, Some("https://example.com/a-1200.jpg".to_string()));
+
+        let html = r#"<img data-srcset="https://cdn.example.com/c_fill,w_300/a.jpg 1x, https://cdn.example.com/c_fill,w_600/a.jpg 2x">"#;
+        let result = extract_first_image(html, None);
+        assert_eq!(
+            result,
+            Some("https://cdn.example.com/c_fill,w_600/a.jpg".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_first_image_details_reads_dimensions() {
+        let html = r#"<img src="https://example.com/a.jpg" width="640px" height="360"><img src="https://example.com/b.jpg" width="100%">"#;
+        let img = extract_first_image_details(html, None).unwrap();
+        assert_eq!(img.width, Some(640));
+        assert_eq!(img.height, Some(360));
+
+        let img = extract_first_image_details(r#"<img src="/b.jpg" width="100%">"#, None);
+        assert_eq!(img, None, "relative URL without base cannot resolve");
+    }
+
+    #[test]
+    fn test_extract_first_image_skips_pixel_sized() {
+        let html = r#"<img src="https://example.com/t.gif" width="1" height="1"><img data-src="https://example.com/lazy.jpg">"#;
+        let result = extract_first_image(html, None);
+        assert_eq!(result, Some("https://example.com/lazy.jpg".to_string()));
+    }
+
+    #[test]
+    fn test_largest_srcset_candidate_without_descriptors() {
+        assert_eq!(
+            largest_srcset_candidate("a.jpg, b.jpg 2x"),
+            Some("b.jpg".to_string())
+        );
+        assert_eq!(largest_srcset_candidate(" , "), None);
+    }
+
+    #[test]
+    fn test_srcset_with_non_ascii_descriptor_does_not_panic() {
+        let html = r#"<img srcset="https://e.com/a.jpg 2×">"#;
+        assert_eq!(
+            extract_first_image(html, None),
+            Some("https://e.com/a.jpg".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unusable_srcset_falls_back_to_src() {
+        let html =
+            r#"<img src="https://e.com/photo.jpg" srcset="https://e.com/tracking/p.gif 2x">"#;
+        assert_eq!(
+            extract_first_image(html, None),
+            Some("https://e.com/photo.jpg".to_string())
+        );
+    }
+
     #[test]
     fn test_extract_first_image_empty_src() {
         let html = r#"<img src=""><img src="https://example.com/real.jpg">"#;
@@ -0,0 +1,187 @@
+// ABOUTME: Incremental feed parsing that returns only items newer than a stored last-seen marker.
+// ABOUTME: The marker (FeedState) is a small serializable blob clients persist between polls.
+
+use serde::{Deserialize, Serialize};
+
+use crate::diff::item_key;
+use crate::error::FeedError;
+use crate::models::{Feed, FeedItem};
+use crate::parser::parse_feed_bytes;
+
+/// Last-seen marker for a feed, persisted by the caller between polls.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedState {
+    /// Identity key (see [`item_key`]) of the newest item seen so far.
+    pub latest_key: Option<String>,
+    /// Newest publish time seen so far; 0 if no dated item has been seen.
+    pub latest_published_ms: u64,
+    /// Keys of items published exactly at `latest_published_ms`, so ties are not re-emitted.
+    #[serde(default)]
+    pub keys_at_latest: Vec<String>,
+}
+
+/// Parses a feed and keeps only items newer than `state`.
+///
+/// Dated items are new when published after `state.latest_published_ms` (ties are
+/// resolved with `keys_at_latest`). Undated items are new when they appear before
+/// the previously newest item in document order (or when that item is no longer in
+/// the feed). An empty state treats every item as new. Returns the filtered feed and the state to store for the next poll.
+pub fn parse_feed_incremental(
+    data: &[u8],
+    feed_url: &str,
+    state: &FeedState,
+) -> Result<(Feed, FeedState), FeedError> {
+    let mut feed = parse_feed_bytes(data, feed_url)?;
+    let next_state = advance_state(state, &feed.items);
+
+    let keys: Vec<String> = feed.items.iter().map(item_key).collect();
+    let marker_idx = state
+        .latest_key
+        .as_ref()
+        .and_then(|latest| keys.iter().position(|k| k == latest));
+
+    let mut idx = 0;
+    feed.items.retain(|item| {
+        let key = &keys[idx];
+        let position = idx;
+        idx += 1;
+        is_new(item, key, position, marker_idx, state)
+    });
+
+    Ok((feed, next_state))
+}
+
+fn is_new(
+    item: &FeedItem,
+    key: &str,
+    position: usize,
+    marker_idx: Option<usize>,
+    state: &FeedState,
+) -> bool {
+    if state.latest_key.as_deref() == Some(key) {
+        return false;
+    }
+    if item.published_ms > 0 && state.latest_published_ms > 0 {
+        return item.published_ms > state.latest_published_ms
+            || (item.published_ms == state.latest_published_ms
+                && !state.keys_at_latest.iter().any(|k| k == key));
+    }
+    // Without a usable date, compare document order; a marker that scrolled out of
+    // the feed window means every remaining item is newer
+    match marker_idx {
+        Some(marker) => position < marker,
+        None => true,
+    }
+}
+
+/// Computes the state after seeing `items`, never moving the marker backwards.
+fn advance_state(state: &FeedState, items: &[FeedItem]) -> FeedState {
+    let newest_ms = items.iter().map(|i| i.published_ms).max().unwrap_or(0);
+    let mut next = state.clone();
+
+    if newest_ms > state.latest_published_ms {
+        next.latest_published_ms = newest_ms;
+        next.keys_at_latest.clear();
+    }
+    if next.latest_published_ms > 0 {
+        for item in items
+            .iter()
+            .filter(|i| i.published_ms == next.latest_published_ms)
+        {
+            let key = item_key(item);
+            if !next.keys_at_latest.contains(&key) {
+                next.keys_at_latest.push(key);
+            }
+        }
+    }
+
+    // Newest item: latest dated one, else first in document order
+    let newest = items
+        .iter()
+        .filter(|i| i.published_ms > 0)
+        .max_by_key(|i| i.published_ms)
+        .or_else(|| items.first());
+    if let Some(item) = newest {
+        if item.published_ms >= state.latest_published_ms || state.latest_key.is_none() {
+            next.latest_key = Some(item_key(item));
+        }
+    }
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rss(items: &[(&str, &str)]) -> String {
+        let body: String = items
+            .iter()
+            .map(|(guid, date)| {
+                format!(
+                    "<item><title>{0}</title><link>https://e.com/{0}</link><guid>{0}</guid>{1}</item>",
+                    guid,
+                    if date.is_empty() {
+                        String::new()
+                    } else {
+                        format!("<pubDate>{}</pubDate>", date)
+                    }
+                )
+            })
+            .collect();
+        format!(
+            "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>T</title><link>https://e.com</link><description>d</description>{}</channel></rss>",
+            body
+        )
+    }
+
+    fn guids(feed: &Feed) -> Vec<&str> {
+        feed.items.iter().map(|i| i.guid.as_str()).collect()
+    }
+
+    #[test]
+    fn test_incremental_returns_only_newer_dated_items() {
+        let first = rss(&[
+            ("b", "Tue, 02 Jan 2024 10:00:00 GMT"),
+            ("a", "Mon, 01 Jan 2024 10:00:00 GMT"),
+        ]);
+        let (feed, state) = parse_feed_incremental(
+            first.as_bytes(),
+            "https://e.com/feed",
+            &FeedState::default(),
+        )
+        .unwrap();
+        assert_eq!(guids(&feed), vec!["b", "a"]);
+        assert_eq!(state.latest_key.as_deref(), Some("guid:b"));
+
+        let second = rss(&[
+            ("c", "Wed, 03 Jan 2024 10:00:00 GMT"),
+            ("b", "Tue, 02 Jan 2024 10:00:00 GMT"),
+            ("a", "Mon, 01 Jan 2024 10:00:00 GMT"),
+        ]);
+        let (feed, state) =
+            parse_feed_incremental(second.as_bytes(), "https://e.com/feed", &state).unwrap();
+        assert_eq!(guids(&feed), vec!["c"]);
+
+        let (feed, _) =
+            parse_feed_incremental(second.as_bytes(), "https://e.com/feed", &state).unwrap();
+        assert!(feed.items.is_empty());
+    }
+
+    #[test]
+    fn test_incremental_undated_items_use_document_order() {
+        let first = rss(&[("b", ""), ("a", "")]);
+        let (_, state) = parse_feed_incremental(
+            first.as_bytes(),
+            "https://e.com/feed",
+            &FeedState::default(),
+        )
+        .unwrap();
+        assert_eq!(state.latest_key.as_deref(), Some("guid:b"));
+
+        let second = rss(&[("d", ""), ("c", ""), ("b", ""), ("a", "")]);
+        let (feed, state) =
+            parse_feed_incremental(second.as_bytes(), "https://e.com/feed", &state).unwrap();
+        assert_eq!(guids(&feed), vec!["d", "c"]);
+        assert_eq!(state.latest_key.as_deref(), Some("guid:d"));
+    }
+}
@@ -1,9 +1,18 @@
 // ABOUTME: Item-level enrichment using Hermes metadata.
-// ABOUTME: Fills missing thumbnails/images for feed items by fetching page metadata.
+// ABOUTME: Fills missing thumbnails/images for feed items by fetching page metadata (sync, parallel, or async).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use digests_hermes::Metadata;
+use futures::stream::{self, StreamExt};
+use url::Url;
 
 use crate::models::Feed;
 
@@ -16,6 +25,181 @@ pub struct ItemEnrichmentStats {
     pub skipped_with_thumbnails: usize,
     /// Number of items whose thumbnail/image was filled from metadata.
     pub items_updated: usize,
+    /// Number of extra fetch attempts made after retryable failures.
+    pub retries: usize,
+    /// Number of items skipped because the fetch cap was reached.
+    pub skipped_fetch_cap: usize,
+    /// One entry per feed item, in feed order.
+    pub details: Vec<ItemEnrichmentDetail>,
+}
+
+/// A field filled on an item from fetched metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrichedField {
+    Thumbnail,
+    Image,
+    Summary,
+}
+
+/// Why an item was left unenriched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrichmentSkipReason {
+    /// The item already had a thumbnail, so it was never queued.
+    HasThumbnail,
+    /// The item has no URL to fetch.
+    MissingUrl,
+    /// Not fetched because `max_fetches` was spent.
+    FetchCap,
+    /// Every attempt failed; see `http_status` for the last HTTP error.
+    FetchFailed,
+    /// The last attempt exceeded the configured timeout.
+    TimedOut,
+    /// Metadata was fetched but had nothing the item was missing.
+    NothingToFill,
+}
+
+/// Per-item enrichment record for logging and tuning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemEnrichmentDetail {
+    /// Index into `feed.items`.
+    pub item_index: usize,
+    pub url: String,
+    /// Wall time spent fetching the item's URL, including retries. Items that
+    /// share a URL report the same duration; zero when nothing was fetched.
+    pub duration: Duration,
+    /// Fields filled on this item, in the order they were applied.
+    pub filled: Vec<EnrichedField>,
+    /// HTTP status of the last failed attempt, when the fetcher reported one.
+    pub http_status: Option<u16>,
+    /// Set when nothing was filled.
+    pub skip_reason: Option<EnrichmentSkipReason>,
+}
+
+/// Pass as `max_fetches` to leave enrichment fetches uncapped.
+pub const UNLIMITED_FETCHES: usize = usize::MAX;
+
+/// Result of a single enrichment fetch attempt.
+#[derive(Debug, Clone)]
+pub enum FetchOutcome {
+    /// Metadata was fetched successfully.
+    Fetched(Box<Metadata>),
+    /// A transient failure (timeout, 5xx, connection reset); worth retrying.
+    Retryable,
+    /// A permanent failure; the URL is skipped without retrying.
+    Failed,
+    /// The server answered with a non-success status. 408, 429 and 5xx are
+    /// retried like [`FetchOutcome::Retryable`]; anything else is permanent.
+    HttpStatus(u16),
+}
+
+impl FetchOutcome {
+    fn is_retryable(&self) -> bool {
+        match self {
+            FetchOutcome::Retryable => true,
+            FetchOutcome::HttpStatus(status) => matches!(status, 408 | 429 | 500..=599),
+            FetchOutcome::Fetched(_) | FetchOutcome::Failed => false,
+        }
+    }
+}
+
+impl From<Option<Metadata>> for FetchOutcome {
+    fn from(meta: Option<Metadata>) -> Self {
+        match meta {
+            Some(m) => FetchOutcome::Fetched(Box::new(m)),
+            None => FetchOutcome::Failed,
+        }
+    }
+}
+
+/// Retry/backoff policy applied around each enrichment fetch.
+///
+/// The default is a single attempt (no retries).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts per URL, including the first. Values below 1 are treated as 1.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each subsequent retry.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            backoff: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Final result of fetching one queued URL, across all attempts.
+#[derive(Debug, Clone)]
+struct UrlFetch {
+    meta: Option<Metadata>,
+    duration: Duration,
+    http_status: Option<u16>,
+    failure: Option<EnrichmentSkipReason>,
+}
+
+impl UrlFetch {
+    fn skipped(reason: EnrichmentSkipReason) -> Self {
+        Self {
+            meta: None,
+            duration: Duration::ZERO,
+            http_status: None,
+            failure: Some(reason),
+        }
+    }
+
+    /// Records one attempt; `None` means the attempt timed out.
+    fn from_attempt(outcome: Option<FetchOutcome>) -> Self {
+        let mut fetch = Self::skipped(EnrichmentSkipReason::FetchFailed);
+        match outcome {
+            Some(FetchOutcome::Fetched(meta)) => {
+                fetch.meta = Some(*meta);
+                fetch.failure = None;
+            }
+            Some(FetchOutcome::HttpStatus(status)) => fetch.http_status = Some(status),
+            Some(FetchOutcome::Retryable | FetchOutcome::Failed) => {}
+            None => fetch.failure = Some(EnrichmentSkipReason::TimedOut),
+        }
+        fetch
+    }
+}
+
+/// Runs `attempt` for `url` under `policy`. `attempt` returns `None` on timeout,
+/// which is retried like a retryable failure.
+///
+/// Every attempt consumes one unit of `take_budget`; retries stop early once it
+/// is spent, and the URL is then reported as skipped for the fetch cap.
+fn fetch_with_retry(
+    policy: &RetryPolicy,
+    mut take_budget: impl FnMut() -> bool,
+    mut attempt: impl FnMut() -> Option<FetchOutcome>,
+    retries: &AtomicUsize,
+) -> UrlFetch {
+    let start = Instant::now();
+    let attempts = policy.max_attempts.max(1);
+    let mut delay = policy.backoff;
+    let mut result = UrlFetch::skipped(EnrichmentSkipReason::FetchCap);
+    for n in 1..=attempts {
+        if !take_budget() {
+            result.failure = Some(EnrichmentSkipReason::FetchCap);
+            break;
+        }
+        let outcome = attempt();
+        let retryable = outcome.as_ref().is_none_or(FetchOutcome::is_retryable);
+        result = UrlFetch::from_attempt(outcome);
+        if !retryable || n == attempts {
+            break;
+        }
+        retries.fetch_add(1, Ordering::SeqCst);
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+        delay = delay.saturating_mul(2);
+    }
+    result.duration = start.elapsed();
+    result
 }
 
 /// Enrich feed items with metadata-derived thumbnails/images.
@@ -25,18 +209,118 @@ pub struct ItemEnrichmentStats {
 /// - URLs are deduplicated; a single metadata fetch can update multiple items.
 /// - If metadata.image_url is present, it is applied to both `thumbnail_url` and
 ///   `image_url` (keeping them in sync, matching the parser's selection logic).
+/// - An empty item `summary` is filled from the metadata description.
+///
+/// - URLs are fetched in feed order; once `max_fetches` calls to `fetch_metadata`
+///   have been made, the remaining items are skipped and counted in
+///   `skipped_fetch_cap`. Pass [`UNLIMITED_FETCHES`] for no cap.
 ///
 /// `fetch_metadata` should synchronously fetch the page at the URL and return
 /// Hermes `Metadata` (or `None` on any failure). Errors are swallowed to avoid
-/// failing the whole parse.
-pub fn enrich_items_with_metadata<F>(feed: &mut Feed, mut fetch_metadata: F) -> ItemEnrichmentStats
+/// failing the whole parse; `stats.details` records what happened to each item.
+pub fn enrich_items_with_metadata<F>(
+    feed: &mut Feed,
+    max_fetches: usize,
+    mut fetch_metadata: F,
+) -> ItemEnrichmentStats
 where
     F: FnMut(&str) -> Option<Metadata>,
+{
+    enrich_items_with_retry(feed, &RetryPolicy::default(), max_fetches, |url| {
+        fetch_metadata(url).into()
+    })
+}
+
+/// Like [`enrich_items_with_metadata`], but retries fetches that report
+/// [`FetchOutcome::Retryable`] (or a retryable [`FetchOutcome::HttpStatus`])
+/// according to `policy`. Retries count toward `max_fetches`.
+pub fn enrich_items_with_retry<F>(
+    feed: &mut Feed,
+    policy: &RetryPolicy,
+    max_fetches: usize,
+    mut fetch_metadata: F,
+) -> ItemEnrichmentStats
+where
+    F: FnMut(&str) -> FetchOutcome,
 {
     let mut stats = ItemEnrichmentStats::default();
+    let queue = queue_missing_thumbnails(feed, &mut stats);
 
-    // Map of article URL -> indices of items needing enrichment
-    let mut url_to_indices: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut budget = max_fetches;
+    let retries = AtomicUsize::new(0);
+    let fetches: Vec<UrlFetch> = queue
+        .iter()
+        .map(|(url, _)| {
+            let take_budget = || {
+                let available = budget > 0;
+                budget = budget.saturating_sub(1);
+                available
+            };
+            fetch_with_retry(policy, take_budget, || Some(fetch_metadata(url)), &retries)
+        })
+        .collect();
+    stats.retries = retries.into_inner();
+
+    apply_fetches(feed, &queue, fetches, &mut stats);
+    stats
+}
+
+/// Async counterpart of [`enrich_items_with_metadata`].
+///
+/// `fetch_metadata` returns a future per URL; up to `concurrency` futures are
+/// polled at once (values below 1 are treated as 1) on the caller's executor,
+/// so no threads are spawned. The first `max_fetches` unique URLs are fetched
+/// and the rest are counted in `skipped_fetch_cap`. Metadata is applied in feed
+/// order regardless of completion order.
+pub async fn enrich_items_with_metadata_async<F, Fut>(
+    feed: &mut Feed,
+    max_fetches: usize,
+    concurrency: usize,
+    mut fetch_metadata: F,
+) -> ItemEnrichmentStats
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Option<Metadata>>,
+{
+    let mut stats = ItemEnrichmentStats::default();
+    let queue = queue_missing_thumbnails(feed, &mut stats);
+
+    let fetch_count = max_fetches.min(queue.len());
+    let urls: Vec<String> = queue[..fetch_count]
+        .iter()
+        .map(|(url, _)| url.clone())
+        .collect();
+    let mut fetches: Vec<UrlFetch> = stream::iter(urls)
+        .map(|url| {
+            let fetch = fetch_metadata(url);
+            async move {
+                let start = Instant::now();
+                let mut result = UrlFetch::from_attempt(Some(fetch.await.into()));
+                result.duration = start.elapsed();
+                result
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+    fetches.resize(
+        queue.len(),
+        UrlFetch::skipped(EnrichmentSkipReason::FetchCap),
+    );
+
+    apply_fetches(feed, &queue, fetches, &mut stats);
+    stats
+}
+
+/// Collects article URLs in first-seen order, each with the indices of items
+/// that still need a thumbnail. Items that already have one are counted in
+/// `stats`, and every item gets a placeholder entry in `stats.details`.
+fn queue_missing_thumbnails(
+    feed: &Feed,
+    stats: &mut ItemEnrichmentStats,
+) -> Vec<(String, Vec<usize>)> {
+    let mut queue: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut url_positions: HashMap<String, usize> = HashMap::new();
 
     for (idx, item) in feed.items.iter().enumerate() {
         let has_thumb = item
@@ -45,56 +329,415 @@ where
             .map(|s| !s.is_empty())
             .unwrap_or(false);
 
-        if has_thumb {
+        let skip_reason = if has_thumb {
             stats.skipped_with_thumbnails += 1;
+            Some(EnrichmentSkipReason::HasThumbnail)
+        } else if item.url.is_empty() {
+            Some(EnrichmentSkipReason::MissingUrl)
+        } else {
+            None
+        };
+        stats.details.push(ItemEnrichmentDetail {
+            item_index: idx,
+            url: item.url.clone(),
+            duration: Duration::ZERO,
+            filled: Vec::new(),
+            http_status: None,
+            skip_reason,
+        });
+        if skip_reason.is_some() {
             continue;
         }
 
-        if item.url.is_empty() {
-            continue;
+        let pos = *url_positions.entry(item.url.clone()).or_insert_with(|| {
+            queue.push((item.url.clone(), Vec::new()));
+            queue.len() - 1
+        });
+        queue[pos].1.push(idx);
+    }
+
+    stats.urls_queued = queue.len();
+    queue
+}
+
+/// Applies each URL's fetch result to its items and fills in `stats.details`.
+/// Returns, per queued URL, whether any thumbnail was filled.
+fn apply_fetches(
+    feed: &mut Feed,
+    queue: &[(String, Vec<usize>)],
+    fetches: Vec<UrlFetch>,
+    stats: &mut ItemEnrichmentStats,
+) -> Vec<bool> {
+    let mut thumbnails_filled = Vec::with_capacity(queue.len());
+    for ((_, indices), fetch) in queue.iter().zip(fetches) {
+        let mut any_thumbnail = false;
+        for &idx in indices {
+            let filled = match &fetch.meta {
+                Some(meta) => apply_item_metadata(feed, idx, meta),
+                None => Vec::new(),
+            };
+            if filled.contains(&EnrichedField::Thumbnail) {
+                stats.items_updated += 1;
+                any_thumbnail = true;
+            }
+            if fetch.failure == Some(EnrichmentSkipReason::FetchCap) {
+                stats.skipped_fetch_cap += 1;
+            }
+
+            let detail = &mut stats.details[idx];
+            detail.duration = fetch.duration;
+            detail.http_status = fetch.http_status;
+            detail.skip_reason = if filled.is_empty() {
+                Some(fetch.failure.unwrap_or(EnrichmentSkipReason::NothingToFill))
+            } else {
+                None
+            };
+            detail.filled = filled;
         }
+        thumbnails_filled.push(any_thumbnail);
+    }
+    thumbnails_filled
+}
 
-        url_to_indices
-            .entry(item.url.clone())
-            .or_default()
-            .push(idx);
+/// Fills missing fields on one item from metadata, returning what was filled.
+fn apply_item_metadata(feed: &mut Feed, idx: usize, meta: &Metadata) -> Vec<EnrichedField> {
+    let item = &mut feed.items[idx];
+    let mut filled = Vec::new();
+
+    // Only overwrite when still missing/empty to avoid clobbering feed data.
+    if !meta.image_url.is_empty() {
+        if item
+            .thumbnail_url
+            .as_ref()
+            .map(|s| s.is_empty())
+            .unwrap_or(true)
+        {
+            item.thumbnail_url = Some(meta.image_url.clone());
+            item.thumbnail_width = (meta.image_width > 0).then_some(meta.image_width);
+            item.thumbnail_height = (meta.image_height > 0).then_some(meta.image_height);
+            filled.push(EnrichedField::Thumbnail);
+        }
+
+        if item
+            .image_url
+            .as_ref()
+            .map(|s| s.is_empty())
+            .unwrap_or(true)
+        {
+            item.image_url = Some(meta.image_url.clone());
+            filled.push(EnrichedField::Image);
+        }
     }
 
-    stats.urls_queued = url_to_indices.len();
+    if item.summary.is_empty() && !meta.description.is_empty() {
+        item.summary = meta.description.clone();
+        filled.push(EnrichedField::Summary);
+    }
+
+    filled
+}
+
+// ----------------------------------------------------------------------------
+// Parallel enrichment
+// ----------------------------------------------------------------------------
+
+/// Settings for [`enrich_items_parallel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParallelEnrichmentOptions {
+    /// Maximum fetches in flight at once. Values below 1 are treated as 1.
+    pub concurrency: usize,
+    /// Maximum fetches in flight per host. Values below 1 are treated as 1.
+    pub per_host_limit: usize,
+    /// Per-attempt deadline; `None` waits for the callback indefinitely.
+    pub timeout: Option<Duration>,
+    /// Total fetch attempts, including retries. See [`UNLIMITED_FETCHES`].
+    pub max_fetches: usize,
+    /// Retry policy applied per URL; timeouts count as retryable.
+    pub retry: RetryPolicy,
+}
+
+impl Default for ParallelEnrichmentOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            per_host_limit: 2,
+            timeout: Some(Duration::from_secs(10)),
+            max_fetches: UNLIMITED_FETCHES,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+/// What happened to one queued URL during parallel enrichment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemFetchStatus {
+    /// Metadata supplied an image that was applied to the items.
+    Updated,
+    /// Metadata was fetched but had no image.
+    NoImage,
+    /// Every attempt failed.
+    Failed,
+    /// The last attempt exceeded the configured timeout.
+    TimedOut,
+    /// Not fetched because `max_fetches` was spent.
+    SkippedFetchCap,
+}
+
+/// Outcome for one unique item URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemEnrichmentResult {
+    pub url: String,
+    /// Indices into `feed.items` that share this URL.
+    pub item_indices: Vec<usize>,
+    pub status: ItemFetchStatus,
+}
+
+/// Aggregate stats plus one result per queued URL, in feed order.
+#[derive(Debug, Default)]
+pub struct ParallelEnrichmentReport {
+    pub stats: ItemEnrichmentStats,
+    pub results: Vec<ItemEnrichmentResult>,
+}
+
+/// Pending URLs and the number of in-flight fetches per host.
+struct Scheduler {
+    pending: VecDeque<(usize, String)>,
+    active_by_host: HashMap<String, usize>,
+}
+
+/// A host slot taken from the [`Scheduler`]; dropping it frees the slot and
+/// wakes waiting workers, even when the fetch callback panics.
+struct HostSlot<'a> {
+    scheduler: &'a Mutex<Scheduler>,
+    freed: &'a Condvar,
+    host: String,
+}
+
+impl Drop for HostSlot<'_> {
+    fn drop(&mut self) {
+        let mut sched = self
+            .scheduler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(active) = sched.active_by_host.get_mut(&self.host) {
+            *active -= 1;
+        }
+        drop(sched);
+        self.freed.notify_all();
+    }
+}
+
+/// Caps the threads running timed attempts, counting abandoned ones that are
+/// still inside the callback after their deadline.
+struct AttemptThreads {
+    running: Mutex<usize>,
+    freed: Condvar,
+    limit: usize,
+}
+
+/// An [`AttemptThreads`] slot, held by the attempt thread until it exits.
+struct AttemptSlot(Arc<AttemptThreads>);
+
+impl Drop for AttemptSlot {
+    fn drop(&mut self) {
+        *self
+            .0
+            .running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) -= 1;
+        self.0.freed.notify_one();
+    }
+}
+
+fn host_of(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_ascii_lowercase))
+        .unwrap_or_default()
+}
 
-    for (url, indices) in url_to_indices {
-        if let Some(meta) = fetch_metadata(&url) {
-            if meta.image_url.is_empty() {
-                continue;
+/// Runs one attempt, giving up after `timeout`. `None` means the attempt timed out.
+/// A panicking callback counts as [`FetchOutcome::Failed`].
+///
+/// A timed-out callback keeps running on its own thread; its result is discarded.
+/// Those threads count against `threads`, and an attempt that cannot get a
+/// thread before its deadline times out without calling `fetch`.
+fn fetch_with_timeout<F>(
+    fetch: &Arc<F>,
+    url: &str,
+    timeout: Option<Duration>,
+    threads: &Arc<AttemptThreads>,
+) -> Option<FetchOutcome>
+where
+    F: Fn(&str) -> FetchOutcome + Send + Sync + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(
+            panic::catch_unwind(AssertUnwindSafe(|| fetch(url))).unwrap_or(FetchOutcome::Failed),
+        );
+    };
+    let deadline = Instant::now() + timeout;
+    {
+        let mut running = threads.running.lock().unwrap();
+        while *running >= threads.limit {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return None;
             }
+            running = threads.freed.wait_timeout(running, left).unwrap().0;
+        }
+        *running += 1;
+    }
+    let slot = AttemptSlot(Arc::clone(threads));
+    let (tx, rx) = mpsc::channel();
+    let fetch = Arc::clone(fetch);
+    let url = url.to_string();
+    thread::spawn(move || {
+        let _slot = slot;
+        let _ = tx.send(fetch(&url));
+    });
+    match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        Ok(outcome) => Some(outcome),
+        Err(RecvTimeoutError::Timeout) => None,
+        // The callback panicked
+        Err(RecvTimeoutError::Disconnected) => Some(FetchOutcome::Failed),
+    }
+}
 
-            for idx in indices {
-                let item = &mut feed.items[idx];
-
-                // Only overwrite when still missing/empty to avoid clobbering feed data.
-                if item
-                    .thumbnail_url
-                    .as_ref()
-                    .map(|s| s.is_empty())
-                    .unwrap_or(true)
-                {
-                    item.thumbnail_url = Some(meta.image_url.clone());
-                    stats.items_updated += 1;
-                }
+/// Parallel counterpart of [`enrich_items_with_retry`].
+///
+/// Unique item URLs are fetched by up to `options.concurrency` worker threads,
+/// with at most `options.per_host_limit` requests to the same host in flight.
+/// Each attempt is bounded by `options.timeout`; a timed-out attempt is retried
+/// under `options.retry` like [`FetchOutcome::Retryable`]. Metadata is applied
+/// to the feed after all fetches finish, so results do not depend on completion order.
+///
+/// `fetch_metadata` is shared across threads and may be called concurrently.
+/// Callers should still configure a client-level timeout: an attempt that
+/// exceeds `options.timeout` is abandoned, not cancelled. At most
+/// `options.concurrency` abandoned attempts run at once; further timed
+/// attempts wait for one of them to return. A panicking callback counts as a
+/// failed attempt.
+pub fn enrich_items_parallel<F>(
+    feed: &mut Feed,
+    options: &ParallelEnrichmentOptions,
+    fetch_metadata: F,
+) -> ParallelEnrichmentReport
+where
+    F: Fn(&str) -> FetchOutcome + Send + Sync + 'static,
+{
+    let mut stats = ItemEnrichmentStats::default();
+    let queue = queue_missing_thumbnails(feed, &mut stats);
+
+    let fetch = Arc::new(fetch_metadata);
+    let budget = AtomicUsize::new(options.max_fetches);
+    let retries = AtomicUsize::new(0);
+    let per_host_limit = options.per_host_limit.max(1);
+    let scheduler = Mutex::new(Scheduler {
+        pending: queue
+            .iter()
+            .enumerate()
+            .map(|(pos, (url, _))| (pos, url.clone()))
+            .collect(),
+        active_by_host: HashMap::new(),
+    });
+    let host_freed = Condvar::new();
+    let concurrency = options.concurrency.max(1);
+    let attempt_threads = Arc::new(AttemptThreads {
+        running: Mutex::new(0),
+        freed: Condvar::new(),
+        limit: concurrency * 2,
+    });
+    let outcomes: Mutex<Vec<Option<UrlFetch>>> = Mutex::new(vec![None; queue.len()]);
 
-                if item
-                    .image_url
-                    .as_ref()
-                    .map(|s| s.is_empty())
-                    .unwrap_or(true)
-                {
-                    item.image_url = Some(meta.image_url.clone());
+    let take_budget = || {
+        budget
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |b| b.checked_sub(1))
+            .is_ok()
+    };
+
+    let worker = || loop {
+        // Take the first pending URL whose host has a free slot
+        let (pos, url, _slot) = {
+            let mut sched = scheduler.lock().unwrap();
+            loop {
+                if sched.pending.is_empty() {
+                    return;
+                }
+                let ready = sched.pending.iter().position(|(_, url)| {
+                    sched
+                        .active_by_host
+                        .get(&host_of(url))
+                        .copied()
+                        .unwrap_or(0)
+                        < per_host_limit
+                });
+                if let Some(i) = ready {
+                    let (pos, url) = sched.pending.remove(i).unwrap();
+                    let host = host_of(&url);
+                    *sched.active_by_host.entry(host.clone()).or_default() += 1;
+                    let slot = HostSlot {
+                        scheduler: &scheduler,
+                        freed: &host_freed,
+                        host,
+                    };
+                    break (pos, url, slot);
                 }
+                sched = host_freed.wait(sched).unwrap();
             }
+        };
+
+        let outcome = fetch_with_retry(
+            &options.retry,
+            take_budget,
+            || fetch_with_timeout(&fetch, &url, options.timeout, &attempt_threads),
+            &retries,
+        );
+        outcomes.lock().unwrap()[pos] = Some(outcome);
+    };
+
+    let workers = concurrency.min(queue.len());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(worker);
         }
-    }
+    });
 
-    stats
+    stats.retries = retries.into_inner();
+    let fetches: Vec<UrlFetch> = outcomes
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|f| f.unwrap_or_else(|| UrlFetch::skipped(EnrichmentSkipReason::FetchCap)))
+        .collect();
+    let statuses: Vec<ItemFetchStatus> = fetches
+        .iter()
+        .map(|f| match f.failure {
+            None => ItemFetchStatus::Updated,
+            Some(EnrichmentSkipReason::FetchCap) => ItemFetchStatus::SkippedFetchCap,
+            Some(EnrichmentSkipReason::TimedOut) => ItemFetchStatus::TimedOut,
+            Some(_) => ItemFetchStatus::Failed,
+        })
+        .collect();
+    let thumbnails_filled = apply_fetches(feed, &queue, fetches, &mut stats);
+
+    let results = queue
+        .into_iter()
+        .zip(statuses)
+        .zip(thumbnails_filled)
+        .map(
+            |(((url, item_indices), status), filled)| ItemEnrichmentResult {
+                url,
+                item_indices,
+                status: match status {
+                    ItemFetchStatus::Updated if !filled => ItemFetchStatus::NoImage,
+                    other => other,
+                },
+            },
+        )
+        .collect();
+
+    ParallelEnrichmentReport { stats, results }
 }
 
 #[cfg(test)]
@@ -122,10 +765,12 @@ mod tests {
 
         let meta = Metadata {
             image_url: "https://example.com/og.jpg".into(),
+            image_width: 1200,
             ..Default::default()
         };
 
-        let stats = enrich_items_with_metadata(&mut feed, |_| Some(meta.clone()));
+        let stats =
+            enrich_items_with_metadata(&mut feed, UNLIMITED_FETCHES, |_| Some(meta.clone()));
 
         assert_eq!(stats.urls_queued, 1);
         assert_eq!(stats.items_updated, 1);
@@ -134,6 +779,8 @@ mod tests {
             feed.items[0].thumbnail_url.as_deref(),
             Some("https://example.com/og.jpg")
         );
+        assert_eq!(feed.items[0].thumbnail_width, Some(1200));
+        assert_eq!(feed.items[0].thumbnail_height, None);
         assert_eq!(
             feed.items[0].image_url.as_deref(),
             Some("https://example.com/og.jpg")
@@ -164,12 +811,13 @@ mod tests {
             ..Default::default()
         };
 
-        let stats = enrich_items_with_metadata(&mut feed, |_| {
+        let stats = enrich_items_with_metadata(&mut feed, UNLIMITED_FETCHES, |_| {
             call_count += 1;
             Some(meta.clone())
         });
 
         assert_eq!(call_count, 1, "should dedupe identical item URLs");
+        assert_eq!(stats.retries, 0);
         assert_eq!(stats.urls_queued, 1);
         assert_eq!(stats.items_updated, 2);
         assert_eq!(
@@ -181,4 +829,426 @@ mod tests {
             Some("https://example.com/og.jpg")
         );
     }
+
+    #[test]
+    fn retries_transient_failure_then_enriches() {
+        let mut feed = Feed {
+            items: vec![crate::models::FeedItem {
+                url: "https://example.com/a".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let meta = Metadata {
+            image_url: "https://example.com/og.jpg".into(),
+            ..Default::default()
+        };
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            backoff: Duration::ZERO,
+        };
+
+        let mut call_count = 0usize;
+        let stats = enrich_items_with_retry(&mut feed, &policy, UNLIMITED_FETCHES, |_| {
+            call_count += 1;
+            if call_count == 1 {
+                FetchOutcome::Retryable
+            } else {
+                FetchOutcome::Fetched(Box::new(meta.clone()))
+            }
+        });
+
+        assert_eq!(call_count, 2);
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.items_updated, 1);
+        assert_eq!(
+            feed.items[0].thumbnail_url.as_deref(),
+            Some("https://example.com/og.jpg")
+        );
+    }
+
+    #[test]
+    fn default_policy_does_not_retry() {
+        let mut feed = Feed {
+            items: vec![crate::models::FeedItem {
+                url: "https://example.com/a".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let mut call_count = 0usize;
+        let stats = enrich_items_with_retry(
+            &mut feed,
+            &RetryPolicy::default(),
+            UNLIMITED_FETCHES,
+            |_| {
+                call_count += 1;
+                FetchOutcome::Retryable
+            },
+        );
+
+        assert_eq!(call_count, 1);
+        assert_eq!(stats.retries, 0);
+        assert!(feed.items[0].thumbnail_url.is_none());
+    }
+
+    #[test]
+    fn stops_fetching_at_cap_and_counts_skipped() {
+        let mut feed = Feed {
+            items: (0..10)
+                .map(|i| crate::models::FeedItem {
+                    url: format!("https://example.com/{}", i),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let meta = Metadata {
+            image_url: "https://example.com/og.jpg".into(),
+            ..Default::default()
+        };
+
+        let mut call_count = 0usize;
+        let stats = enrich_items_with_metadata(&mut feed, 3, |_| {
+            call_count += 1;
+            Some(meta.clone())
+        });
+
+        assert_eq!(call_count, 3);
+        assert_eq!(stats.urls_queued, 10);
+        assert_eq!(stats.items_updated, 3);
+        assert_eq!(stats.skipped_fetch_cap, 7);
+        assert_eq!(
+            stats.items_updated + stats.skipped_fetch_cap,
+            feed.items.len()
+        );
+        assert!(feed.items[..3].iter().all(|i| i.thumbnail_url.is_some()));
+        assert!(feed.items[3..].iter().all(|i| i.thumbnail_url.is_none()));
+
+        // The cap running out between retries of an item skips that item too
+        for item in &mut feed.items {
+            item.thumbnail_url = None;
+        }
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            backoff: Duration::ZERO,
+        };
+        let stats = enrich_items_with_retry(&mut feed, &policy, 3, |url| {
+            if url.ends_with("/1") {
+                FetchOutcome::Retryable
+            } else {
+                FetchOutcome::Fetched(Box::new(meta.clone()))
+            }
+        });
+        assert_eq!(stats.items_updated, 1);
+        assert_eq!(stats.skipped_fetch_cap, 9);
+        assert_eq!(
+            stats.items_updated + stats.skipped_fetch_cap,
+            feed.items.len()
+        );
+        assert_eq!(
+            stats.details[1].skip_reason,
+            Some(EnrichmentSkipReason::FetchCap)
+        );
+    }
+
+    fn feed_with_urls(urls: &[&str]) -> Feed {
+        Feed {
+            items: urls
+                .iter()
+                .map(|url| crate::models::FeedItem {
+                    url: url.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn og_image(url: &str) -> FetchOutcome {
+        FetchOutcome::Fetched(Box::new(Metadata {
+            image_url: format!("{}/og.jpg", url),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn parallel_enrichment_reports_per_url_results_in_feed_order() {
+        let mut feed = feed_with_urls(&[
+            "https://a.com/1",
+            "https://b.com/1",
+            "https://a.com/1",
+            "https://c.com/none",
+        ]);
+
+        let report =
+            enrich_items_parallel(&mut feed, &ParallelEnrichmentOptions::default(), |url| {
+                if url.ends_with("none") {
+                    FetchOutcome::Fetched(Box::default())
+                } else {
+                    og_image(url)
+                }
+            });
+
+        assert_eq!(report.stats.urls_queued, 3);
+        assert_eq!(report.stats.items_updated, 3);
+        let statuses: Vec<_> = report.results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ItemFetchStatus::Updated,
+                ItemFetchStatus::Updated,
+                ItemFetchStatus::NoImage
+            ]
+        );
+        assert_eq!(report.results[0].item_indices, vec![0, 2]);
+        assert_eq!(
+            feed.items[2].thumbnail_url.as_deref(),
+            Some("https://a.com/1/og.jpg")
+        );
+        assert!(feed.items[3].thumbnail_url.is_none());
+    }
+
+    #[test]
+    fn parallel_enrichment_respects_per_host_limit() {
+        let urls: Vec<String> = (0..6).map(|i| format!("https://same.com/{}", i)).collect();
+        let url_refs: Vec<&str> = urls.iter().map(String::as_str).collect();
+        let mut feed = feed_with_urls(&url_refs);
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (in_flight_cb, peak_cb) = (Arc::clone(&in_flight), Arc::clone(&peak));
+        let options = ParallelEnrichmentOptions {
+            concurrency: 4,
+            per_host_limit: 2,
+            ..Default::default()
+        };
+
+        let report = enrich_items_parallel(&mut feed, &options, move |url| {
+            let now = in_flight_cb.fetch_add(1, Ordering::SeqCst) + 1;
+            peak_cb.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            in_flight_cb.fetch_sub(1, Ordering::SeqCst);
+            og_image(url)
+        });
+
+        assert_eq!(report.stats.items_updated, 6);
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn parallel_enrichment_times_out_slow_fetches() {
+        let mut feed = feed_with_urls(&["https://slow.com/a", "https://fast.com/b"]);
+        let options = ParallelEnrichmentOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+
+        let report = enrich_items_parallel(&mut feed, &options, |url| {
+            if url.contains("slow") {
+                thread::sleep(Duration::from_millis(500));
+            }
+            og_image(url)
+        });
+
+        assert_eq!(report.results[0].status, ItemFetchStatus::TimedOut);
+        assert_eq!(report.results[1].status, ItemFetchStatus::Updated);
+        assert!(feed.items[0].thumbnail_url.is_none());
+    }
+
+    #[test]
+    fn parallel_enrichment_survives_panicking_callback() {
+        let mut feed = feed_with_urls(&[
+            "https://same.com/boom",
+            "https://same.com/a",
+            "https://same.com/b",
+        ]);
+        let options = ParallelEnrichmentOptions {
+            concurrency: 2,
+            per_host_limit: 1,
+            timeout: None,
+            ..Default::default()
+        };
+
+        let report = enrich_items_parallel(&mut feed, &options, |url| {
+            if url.ends_with("boom") {
+                panic!("callback failure");
+            }
+            og_image(url)
+        });
+
+        let statuses: Vec<_> = report.results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ItemFetchStatus::Failed,
+                ItemFetchStatus::Updated,
+                ItemFetchStatus::Updated
+            ]
+        );
+    }
+
+    #[test]
+    fn parallel_enrichment_bounds_abandoned_attempts() {
+        let urls: Vec<String> = (0..12)
+            .map(|i| format!("https://host{}.com/a", i))
+            .collect();
+        let url_refs: Vec<&str> = urls.iter().map(String::as_str).collect();
+        let mut feed = feed_with_urls(&url_refs);
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (in_flight_cb, peak_cb) = (Arc::clone(&in_flight), Arc::clone(&peak));
+        let options = ParallelEnrichmentOptions {
+            concurrency: 2,
+            timeout: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+
+        let report = enrich_items_parallel(&mut feed, &options, move |url| {
+            let now = in_flight_cb.fetch_add(1, Ordering::SeqCst) + 1;
+            peak_cb.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(200));
+            in_flight_cb.fetch_sub(1, Ordering::SeqCst);
+            og_image(url)
+        });
+
+        assert!(report
+            .results
+            .iter()
+            .all(|r| r.status == ItemFetchStatus::TimedOut));
+        assert!(peak.load(Ordering::SeqCst) <= 4);
+    }
+
+    #[test]
+    fn parallel_enrichment_counts_fetch_cap_and_retries() {
+        let mut feed = feed_with_urls(&["https://a.com/1", "https://b.com/1", "https://c.com/1"]);
+        let options = ParallelEnrichmentOptions {
+            concurrency: 1,
+            max_fetches: 2,
+            retry: RetryPolicy {
+                max_attempts: 2,
+                backoff: Duration::ZERO,
+            },
+            ..Default::default()
+        };
+
+        let report = enrich_items_parallel(&mut feed, &options, |_| FetchOutcome::Retryable);
+
+        assert_eq!(report.stats.retries, 1);
+        assert_eq!(report.stats.skipped_fetch_cap, 2);
+        let statuses: Vec<_> = report.results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ItemFetchStatus::Failed,
+                ItemFetchStatus::SkippedFetchCap,
+                ItemFetchStatus::SkippedFetchCap
+            ]
+        );
+    }
+
+    #[test]
+    fn async_enrichment_applies_in_feed_order_and_caps_fetches() {
+        let mut feed = feed_with_urls(&[
+            "https://a.com/1",
+            "https://b.com/1",
+            "https://a.com/1",
+            "https://c.com/1",
+        ]);
+
+        let mut fetched = Vec::new();
+        let stats =
+            futures::executor::block_on(enrich_items_with_metadata_async(&mut feed, 2, 4, |url| {
+                fetched.push(url.clone());
+                async move {
+                    Some(Metadata {
+                        image_url: format!("{}/og.jpg", url),
+                        ..Default::default()
+                    })
+                }
+            }));
+
+        assert_eq!(fetched, vec!["https://a.com/1", "https://b.com/1"]);
+        assert_eq!(stats.urls_queued, 3);
+        assert_eq!(stats.items_updated, 3);
+        assert_eq!(stats.skipped_fetch_cap, 1);
+        assert_eq!(
+            feed.items[2].thumbnail_url.as_deref(),
+            Some("https://a.com/1/og.jpg")
+        );
+        assert!(feed.items[3].thumbnail_url.is_none());
+    }
+
+    #[test]
+    fn details_record_filled_fields_statuses_and_skip_reasons() {
+        let mut feed = feed_with_urls(&[
+            "https://a.com/1",
+            "https://b.com/404",
+            "",
+            "https://c.com/1",
+        ]);
+        feed.items[3].thumbnail_url = Some("keep".into());
+
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            backoff: Duration::ZERO,
+        };
+        let stats = enrich_items_with_retry(&mut feed, &policy, UNLIMITED_FETCHES, |url| {
+            if url.ends_with("404") {
+                FetchOutcome::HttpStatus(404)
+            } else {
+                FetchOutcome::Fetched(Box::new(Metadata {
+                    image_url: "https://a.com/og.jpg".into(),
+                    description: "From the page".into(),
+                    ..Default::default()
+                }))
+            }
+        });
+
+        assert_eq!(stats.details.len(), 4);
+        assert_eq!(
+            stats.details[0].filled,
+            vec![
+                EnrichedField::Thumbnail,
+                EnrichedField::Image,
+                EnrichedField::Summary
+            ]
+        );
+        assert_eq!(stats.details[0].skip_reason, None);
+        assert_eq!(feed.items[0].summary, "From the page");
+
+        // 404 is permanent: no retries, status recorded
+        assert_eq!(stats.retries, 0);
+        assert_eq!(stats.details[1].http_status, Some(404));
+        assert_eq!(
+            stats.details[1].skip_reason,
+            Some(EnrichmentSkipReason::FetchFailed)
+        );
+        assert_eq!(
+            stats.details[2].skip_reason,
+            Some(EnrichmentSkipReason::MissingUrl)
+        );
+        assert_eq!(
+            stats.details[3].skip_reason,
+            Some(EnrichmentSkipReason::HasThumbnail)
+        );
+    }
+
+    #[test]
+    fn retryable_http_status_is_retried() {
+        let mut feed = feed_with_urls(&["https://a.com/1"]);
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            backoff: Duration::ZERO,
+        };
+        let stats = enrich_items_with_retry(&mut feed, &policy, UNLIMITED_FETCHES, |_| {
+            FetchOutcome::HttpStatus(503)
+        });
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.details[0].http_status, Some(503));
+    }
 }
@@ -1,28 +1,72 @@
-// ABOUTME: Raw XML parsing for iTunes podcast extensions not exposed by feed-rs.
-// ABOUTME: Extracts duration, explicit, image, and author from itunes namespace elements.
+// ABOUTME: Raw XML parsing for iTunes, Podcast 2.0, Dublin Core, and GeoRSS extensions not exposed by feed-rs.
+// ABOUTME: Extracts itunes duration/explicit/image/author, podcast: transcripts/chapters, dc:* fields, and geo points.
 
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::reader::Reader;
 use std::collections::HashMap;
 
+use crate::chapters::parse_npt_seconds;
 use crate::duration_parse::parse_duration_seconds;
+use crate::models::{
+    Chapter, Funding, MediaRendition, PodcastPerson, PodcastValue, Soundbite, Transcript,
+    ValueRecipient,
+};
+
+/// Dublin Core (`dc:*`) values used as fallbacks for missing core elements.
+#[derive(Debug, Default, Clone)]
+pub struct DublinCoreExt {
+    /// First dc:creator text content.
+    pub creator: Option<String>,
+    /// All dc:creator text contents, in document order.
+    pub creators: Vec<String>,
+    /// First dc:date text content (raw string).
+    pub date: Option<String>,
+    /// All dc:subject text contents.
+    pub subjects: Vec<String>,
+    /// First dc:rights text content.
+    pub rights: Option<String>,
+}
 
 /// iTunes metadata extracted from raw XML at the feed (channel) level.
 #[derive(Debug, Default, Clone)]
 pub struct FeedITunesExt {
     /// True if feed has itunes namespace declaration.
     pub has_itunes_namespace: bool,
+    /// True if feed declares the YouTube (`yt:`) namespace.
+    pub has_youtube_namespace: bool,
     /// Feed-level itunes:image href attribute.
     pub image_href: Option<String>,
     /// Feed-level itunes:author text content.
     pub author: Option<String>,
     /// Feed-level itunes:explicit text content.
     pub explicit: Option<String>,
+    /// Feed-level podcast:funding links.
+    pub funding: Vec<Funding>,
+    /// Feed-level podcast:person credits.
+    pub persons: Vec<PodcastPerson>,
+    /// Feed-level podcast:value block.
+    pub value: Option<PodcastValue>,
+    /// Feed-level Dublin Core values.
+    pub dc: DublinCoreExt,
+    /// RSS `<skipHours>` hours (0-23).
+    pub skip_hours: Vec<u8>,
+    /// RSS `<skipDays>` day names.
+    pub skip_days: Vec<String>,
+    /// `sy:updatePeriod`, lowercased.
+    pub update_period: Option<String>,
+    /// `sy:updateFrequency`.
+    pub update_frequency: Option<u32>,
 }
 
 /// iTunes metadata extracted from raw XML at the item level.
 #[derive(Debug, Default, Clone)]
 pub struct ItemITunesExt {
+    /// YouTube `yt:videoId`.
+    pub video_id: Option<String>,
+    /// YouTube `yt:channelId`.
+    pub channel_id: Option<String>,
+    /// True if the item had a `<guid>`/`<id>` element.
+    pub has_guid: bool,
     /// Item-level itunes:image href attribute.
     pub image_href: Option<String>,
     /// Item-level itunes:author text content.
@@ -31,6 +75,65 @@ pub struct ItemITunesExt {
     pub duration: Option<String>,
     /// Item-level itunes:explicit text content.
     pub explicit: Option<String>,
+    /// Item-level itunes:season number.
+    pub season: Option<u32>,
+    /// Item-level itunes:episode number.
+    pub episode: Option<u32>,
+    /// Item-level itunes:episodeType text content ("full", "trailer", "bonus").
+    pub episode_type: Option<String>,
+    /// Item-level itunes:block text content.
+    pub block: Option<String>,
+    /// Item-level podcast:transcript links.
+    pub transcripts: Vec<Transcript>,
+    /// Item-level podcast:chapters url attribute.
+    pub chapters_url: Option<String>,
+    /// Item-level Podlove Simple Chapters (`psc:chapter`), in document order.
+    pub chapters: Vec<Chapter>,
+    /// Item-level podcast:person credits.
+    pub persons: Vec<PodcastPerson>,
+    /// Item-level podcast:soundbite clips.
+    pub soundbites: Vec<Soundbite>,
+    /// Item-level podcast:value block.
+    pub value: Option<PodcastValue>,
+    /// Item-level media:content renditions with their full attribute set.
+    pub media_renditions: Vec<MediaRendition>,
+    /// Item-level Dublin Core values.
+    pub dc: DublinCoreExt,
+    /// Item-level georss:point or georss:where/gml:pos (lat, long).
+    pub geo_point: Option<(f64, f64)>,
+    /// Item-level W3C geo:lat text content.
+    pub geo_lat: Option<f64>,
+    /// Item-level W3C geo:long text content.
+    pub geo_long: Option<f64>,
+    /// Item-level georss:featurename text content.
+    pub geo_name: Option<String>,
+    /// Item-level RSS <comments> URL.
+    pub comments_url: Option<String>,
+    /// Item-level slash:comments count.
+    pub comments_count: Option<u32>,
+    /// Item-level thr:in-reply-to ref attribute (RFC 4685).
+    pub in_reply_to: Option<String>,
+    /// Item-level thr:total text content (RFC 4685).
+    pub replies_total: Option<u32>,
+    /// RSS `<source>` text or Atom `<source><title>`.
+    pub source_title: Option<String>,
+    /// RSS `<source url>` attribute or Atom `<source><link href>`.
+    pub source_url: Option<String>,
+}
+
+impl ItemITunesExt {
+    /// Returns the item's coordinates, preferring GeoRSS over W3C geo:lat/geo:long.
+    pub fn location(&self) -> Option<(f64, f64)> {
+        self.geo_point
+            .or_else(|| valid_coordinates(self.geo_lat?, self.geo_long?))
+    }
+}
+
+/// A Podcast 2.0 element whose text content is still being read.
+enum PendingPodcast {
+    Funding(Funding),
+    Person(PodcastPerson),
+    Soundbite(Soundbite),
 }
 
 /// Parsed iTunes extensions for a complete feed.
@@ -43,7 +146,7 @@ pub struct ParsedITunesExtensions {
     pub items_by_index: Vec<ItemITunesExt>,
 }
 
-/// Parses iTunes extensions from raw RSS/Atom XML bytes.
+/// Parses iTunes and Podcast 2.0 extensions from raw RSS/Atom XML bytes.
 /// This extracts data that feed-rs doesn't properly expose.
 pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
     let mut result = ParsedITunesExtensions::default();
@@ -59,9 +162,16 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
     let mut current_item_ext = ItemITunesExt::default();
     let mut current_element: Option<String> = None;
     let mut item_index = 0;
+    let mut pending_podcast: Option<PendingPodcast> = None;
+    let mut current_value: Option<PodcastValue> = None;
+    let mut media_group: Option<usize> = None;
+    let mut media_groups_in_item = 0;
+    let mut in_source = false;
 
     loop {
-        match reader.read_event_into(&mut buf) {
+        let event = reader.read_event_into(&mut buf);
+        let is_empty = matches!(event, Ok(Event::Empty(_)));
+        match event {
             Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
                 let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 let local_name = name.split(':').last().unwrap_or(&name);
@@ -69,16 +179,41 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
                 // Check for itunes namespace declaration at root
                 if name == "rss" || name == "feed" {
                     result.feed.has_itunes_namespace = has_itunes_namespace(e);
+                    result.feed.has_youtube_namespace =
+                        has_namespace_containing(e, "youtube.com/xml/schemas");
                 }
 
                 match local_name {
                     "channel" => in_channel = true,
                     "item" | "entry" => {
                         in_item = true;
+                        in_source = false;
                         current_item_guid = None;
                         current_item_ext = ItemITunesExt::default();
+                        media_groups_in_item = 0;
                     }
+                    // RSS <source url="...">Title</source>; Atom <source> wraps
+                    // the origin feed's own title/link/id elements
+                    "source" if in_item => {
+                        current_item_ext.source_url = get_attribute(e, "url");
+                        if !is_empty {
+                            in_source = true;
+                            current_element = Some("source".to_string());
+                        }
+                    }
+                    "title" if in_source => current_element = Some("source".to_string()),
+                    "link" if in_source => {
+                        let rel = get_attribute(e, "rel");
+                        if current_item_ext.source_url.is_none()
+                            && matches!(rel.as_deref(), None | Some("alternate"))
+                        {
+                            current_item_ext.source_url = get_attribute(e, "href");
+                        }
+                        current_element = None;
+                    }
+                    _ if in_source => current_element = None,
                     "guid" | "id" if in_item => {
+                        current_item_ext.has_guid = true;
                         current_element = Some("guid".to_string());
                     }
                     _ => {}
@@ -98,12 +233,174 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
                                 }
                             }
                         }
-                        "author" | "duration" | "explicit" => {
+                        "author" | "duration" | "explicit" | "season" | "episode"
+                        | "episodeType" | "block" => {
                             current_element = Some(itunes_name.to_string());
                         }
                         _ => {}
                     }
                 }
+
+                // Handle dc:* (Dublin Core) text elements
+                if matches!(
+                    name.as_str(),
+                    "dc:creator" | "dc:date" | "dc:subject" | "dc:rights"
+                ) && !is_empty
+                {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle Atom threading (RFC 4685): thr:in-reply-to ref and thr:total
+                if in_item && name == "thr:in-reply-to" && current_item_ext.in_reply_to.is_none() {
+                    current_item_ext.in_reply_to = get_attribute(e, "ref");
+                } else if in_item && name == "thr:total" && !is_empty {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle YouTube yt:videoId / yt:channelId
+                if in_item && !is_empty && (name == "yt:videoId" || name == "yt:channelId") {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle RSS <skipHours><hour> / <skipDays><day>
+                if in_channel && !in_item && !is_empty && (name == "hour" || name == "day") {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle syndication module sy:updatePeriod / sy:updateFrequency
+                // (channel-level in RSS, feed-level in Atom)
+                if !in_item
+                    && !is_empty
+                    && (name == "sy:updatePeriod" || name == "sy:updateFrequency")
+                {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle RSS <comments> and slash:comments
+                if in_item && !is_empty && (name == "comments" || name == "slash:comments") {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle GeoRSS / W3C geo text elements
+                if in_item
+                    && !is_empty
+                    && matches!(
+                        name.as_str(),
+                        "georss:point" | "gml:pos" | "geo:lat" | "geo:long" | "georss:featurename"
+                    )
+                {
+                    current_element = Some(name.clone());
+                }
+
+                // Handle media:group / media:content (Media RSS) renditions
+                if in_item && name == "media:group" && !is_empty {
+                    media_group = Some(media_groups_in_item);
+                    media_groups_in_item += 1;
+                } else if in_item && name == "media:content" {
+                    if let Some(rendition) = read_media_rendition(e, media_group) {
+                        current_item_ext.media_renditions.push(rendition);
+                    }
+                }
+
+                // Handle Podlove Simple Chapters: <psc:chapter start title href image/>
+                if in_item && name == "psc:chapter" {
+                    current_item_ext.chapters.push(Chapter {
+                        start_seconds: get_attribute(e, "start")
+                            .and_then(|s| parse_npt_seconds(&s))
+                            .unwrap_or(0.0),
+                        title: get_attribute(e, "title").filter(|s| !s.trim().is_empty()),
+                        image_url: get_attribute(e, "image").filter(|s| !s.trim().is_empty()),
+                        url: get_attribute(e, "href").filter(|s| !s.trim().is_empty()),
+                        toc: true,
+                    });
+                }
+
+                // Handle podcast:* (Podcast 2.0) elements
+                if let Some(podcast_name) = name.strip_prefix("podcast:") {
+                    match podcast_name {
+                        "transcript" if in_item => {
+                            if let Some(url) = get_attribute(e, "url") {
+                                current_item_ext.transcripts.push(Transcript {
+                                    url,
+                                    mime_type: get_attribute(e, "type"),
+                                    language: get_attribute(e, "language"),
+                                    rel: get_attribute(e, "rel"),
+                                });
+                            }
+                        }
+                        "chapters" if in_item => {
+                            current_item_ext.chapters_url = get_attribute(e, "url");
+                        }
+                        "funding" => {
+                            if let Some(url) = get_attribute(e, "url") {
+                                pending_podcast = Some(PendingPodcast::Funding(Funding {
+                                    url,
+                                    text: String::new(),
+                                }));
+                            }
+                        }
+                        "person" => {
+                            pending_podcast = Some(PendingPodcast::Person(PodcastPerson {
+                                name: String::new(),
+                                role: get_attribute(e, "role"),
+                                group: get_attribute(e, "group"),
+                                img: get_attribute(e, "img"),
+                                href: get_attribute(e, "href"),
+                            }));
+                        }
+                        "soundbite" if in_item => {
+                            pending_podcast = Some(PendingPodcast::Soundbite(Soundbite {
+                                start_seconds: parse_seconds_attr(e, "startTime"),
+                                duration_seconds: parse_seconds_attr(e, "duration"),
+                                title: None,
+                            }));
+                        }
+                        "value" => {
+                            current_value = Some(PodcastValue {
+                                value_type: get_attribute(e, "type").unwrap_or_default(),
+                                method: get_attribute(e, "method").unwrap_or_default(),
+                                suggested: get_attribute(e, "suggested"),
+                                recipients: Vec::new(),
+                            });
+                        }
+                        "valueRecipient" => {
+                            if let Some(ref mut value) = current_value {
+                                value.recipients.push(ValueRecipient {
+                                    name: get_attribute(e, "name"),
+                                    recipient_type: get_attribute(e, "type").unwrap_or_default(),
+                                    address: get_attribute(e, "address").unwrap_or_default(),
+                                    split: get_attribute(e, "split")
+                                        .and_then(|s| s.trim().parse().ok())
+                                        .unwrap_or(0),
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // Self-closing elements have no text or End event; finish them now
+                    if is_empty {
+                        if let Some(pending) = pending_podcast.take() {
+                            finish_pending(pending, in_item, &mut current_item_ext, &mut result);
+                        }
+                        if podcast_name == "value" {
+                            if let Some(value) = current_value.take() {
+                                assign_value(value, in_item, &mut current_item_ext, &mut result);
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(Event::Text(ref e)) if pending_podcast.is_some() => {
+                let text = e.decode().map(|s| s.into_owned()).unwrap_or_default();
+                match pending_podcast {
+                    Some(PendingPodcast::Funding(ref mut f)) => f.text.push_str(&text),
+                    Some(PendingPodcast::Person(ref mut p)) => p.name.push_str(&text),
+                    Some(PendingPodcast::Soundbite(ref mut s)) => {
+                        s.title.get_or_insert_with(String::new).push_str(&text)
+                    }
+                    None => {}
+                }
             }
             Ok(Event::Text(ref e)) => {
                 if let Some(ref elem) = current_element {
@@ -123,6 +420,18 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
                             "duration" if in_item => {
                                 current_item_ext.duration = Some(text);
                             }
+                            "season" if in_item => {
+                                current_item_ext.season = text.trim().parse().ok();
+                            }
+                            "episode" if in_item => {
+                                current_item_ext.episode = text.trim().parse().ok();
+                            }
+                            "episodeType" if in_item => {
+                                current_item_ext.episode_type = Some(text.trim().to_lowercase());
+                            }
+                            "block" if in_item => {
+                                current_item_ext.block = Some(text);
+                            }
                             "explicit" => {
                                 if in_item {
                                     current_item_ext.explicit = Some(text);
@@ -130,6 +439,76 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
                                     result.feed.explicit = Some(text);
                                 }
                             }
+                            "dc:creator" | "dc:date" | "dc:subject" | "dc:rights" => {
+                                let dc = if in_item {
+                                    &mut current_item_ext.dc
+                                } else {
+                                    &mut result.feed.dc
+                                };
+                                let text = text.trim().to_string();
+                                match elem.as_str() {
+                                    "dc:creator" => {
+                                        dc.creator.get_or_insert(text.clone());
+                                        dc.creators.push(text);
+                                    }
+                                    "dc:date" => {
+                                        dc.date.get_or_insert(text);
+                                    }
+                                    "dc:rights" => {
+                                        dc.rights.get_or_insert(text);
+                                    }
+                                    _ => dc.subjects.push(text),
+                                }
+                            }
+                            "georss:point" | "gml:pos"
+                                if in_item && current_item_ext.geo_point.is_none() =>
+                            {
+                                current_item_ext.geo_point = parse_point(&text);
+                            }
+                            "comments" if in_item => {
+                                current_item_ext.comments_url = Some(text.trim().to_string());
+                            }
+                            "thr:total" if in_item => {
+                                current_item_ext.replies_total = text.trim().parse().ok();
+                            }
+                            "slash:comments" if in_item => {
+                                current_item_ext.comments_count = text.trim().parse().ok();
+                            }
+                            "geo:lat" if in_item => {
+                                current_item_ext.geo_lat = text.trim().parse().ok();
+                            }
+                            "geo:long" if in_item => {
+                                current_item_ext.geo_long = text.trim().parse().ok();
+                            }
+                            "yt:videoId" if in_item => {
+                                current_item_ext.video_id = Some(text.trim().to_string());
+                            }
+                            "yt:channelId" if in_item => {
+                                current_item_ext.channel_id = Some(text.trim().to_string());
+                            }
+                            "hour" if !in_item => {
+                                if let Ok(hour) = text.trim().parse::<u8>() {
+                                    if hour < 24 && !result.feed.skip_hours.contains(&hour) {
+                                        result.feed.skip_hours.push(hour);
+                                    }
+                                }
+                            }
+                            "day" if !in_item => {
+                                result.feed.skip_days.push(text.trim().to_string());
+                            }
+                            "sy:updatePeriod" if !in_item => {
+                                result.feed.update_period = Some(text.trim().to_lowercase());
+                            }
+                            "sy:updateFrequency" if !in_item => {
+                                result.feed.update_frequency =
+                                    text.trim().parse().ok().filter(|f| *f > 0);
+                            }
+                            "georss:featurename" if in_item => {
+                                current_item_ext.geo_name = Some(text.trim().to_string());
+                            }
+                            "source" if in_item && !text.trim().is_empty() => {
+                                current_item_ext.source_title = Some(text.trim().to_string());
+                            }
                             _ => {}
                         }
                     }
@@ -141,6 +520,11 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
 
                 match local_name {
                     "channel" => in_channel = false,
+                    "source" if in_source => {
+                        in_source = false;
+                        current_element = None;
+                    }
+                    "title" if in_source => current_element = None,
                     "item" | "entry" => {
                         // Store item extensions
                         let key = current_item_guid
@@ -155,9 +539,37 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
                 }
 
                 // Clear current element after processing
-                if name.starts_with("itunes:") || local_name == "guid" || local_name == "id" {
+                if name.starts_with("itunes:")
+                    || name.starts_with("dc:")
+                    || name.starts_with("georss:")
+                    || local_name == "comments"
+                    || name == "hour"
+                    || name.starts_with("yt:")
+                    || name == "day"
+                    || name.starts_with("sy:")
+                    || name == "thr:total"
+                    || name.starts_with("geo:")
+                    || name == "gml:pos"
+                    || local_name == "guid"
+                    || local_name == "id"
+                {
                     current_element = None;
                 }
+
+                match name.as_str() {
+                    "media:group" => media_group = None,
+                    "podcast:funding" | "podcast:person" | "podcast:soundbite" => {
+                        if let Some(pending) = pending_podcast.take() {
+                            finish_pending(pending, in_item, &mut current_item_ext, &mut result);
+                        }
+                    }
+                    "podcast:value" => {
+                        if let Some(value) = current_value.take() {
+                            assign_value(value, in_item, &mut current_item_ext, &mut result);
+                        }
+                    }
+                    _ => {}
+                }
             }
             Ok(Event::Eof) => break,
             Err(_) => break,
@@ -169,12 +581,110 @@ pub fn parse_itunes_extensions(data: &[u8]) -> ParsedITunesExtensions {
     result
 }
 
+/// Stores a completed text-bearing Podcast 2.0 element on the item or channel.
+fn finish_pending(
+    pending: PendingPodcast,
+    in_item: bool,
+    item: &mut ItemITunesExt,
+    result: &mut ParsedITunesExtensions,
+) {
+    match pending {
+        PendingPodcast::Funding(mut funding) => {
+            funding.text = funding.text.trim().to_string();
+            result.feed.funding.push(funding);
+        }
+        PendingPodcast::Person(mut person) => {
+            person.name = person.name.trim().to_string();
+            if person.name.is_empty() {
+                return;
+            }
+            if in_item {
+                item.persons.push(person);
+            } else {
+                result.feed.persons.push(person);
+            }
+        }
+        PendingPodcast::Soundbite(mut soundbite) => {
+            soundbite.title = soundbite
+                .title
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty());
+            item.soundbites.push(soundbite);
+        }
+    }
+}
+
+fn assign_value(
+    value: PodcastValue,
+    in_item: bool,
+    item: &mut ItemITunesExt,
+    result: &mut ParsedITunesExtensions,
+) {
+    if in_item {
+        item.value = Some(value);
+    } else {
+        result.feed.value = Some(value);
+    }
+}
+
+/// Reads a media:content element's attributes; returns None without a url.
+fn read_media_rendition(e: &BytesStart, group: Option<usize>) -> Option<MediaRendition> {
+    let url = get_attribute(e, "url").filter(|u| !u.trim().is_empty())?;
+    let parse_u32 = |name: &str| {
+        get_attribute(e, name)
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|v| *v >= 0.0)
+            .map(|v| v.round() as u32)
+    };
+    Some(MediaRendition {
+        url,
+        mime_type: get_attribute(e, "type"),
+        medium: get_attribute(e, "medium").map(|m| m.to_ascii_lowercase()),
+        bitrate: parse_u32("bitrate"),
+        width: parse_u32("width"),
+        height: parse_u32("height"),
+        file_size: get_attribute(e, "fileSize")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(0),
+        duration_seconds: parse_u32("duration").unwrap_or(0),
+        is_default: get_attribute(e, "isDefault").is_some_and(|v| v.eq_ignore_ascii_case("true")),
+        group,
+    })
+}
+
+/// Parses a GeoRSS "lat long" pair (whitespace or comma separated).
+fn parse_point(text: &str) -> Option<(f64, f64)> {
+    let mut parts = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|p| !p.is_empty());
+    let lat = parts.next()?.parse().ok()?;
+    let long = parts.next()?.parse().ok()?;
+    valid_coordinates(lat, long)
+}
+
+/// Returns the pair if it is a valid latitude/longitude.
+fn valid_coordinates(lat: f64, long: f64) -> Option<(f64, f64)> {
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&long)).then_some((lat, long))
+}
+
+/// Parses a seconds attribute (e.g. soundbite `startTime="73.0"`), defaulting to 0.
+fn parse_seconds_attr(e: &BytesStart, name: &str) -> f64 {
+    get_attribute(e, name)
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0.0)
+}
+
 /// Checks if an RSS/feed element has the iTunes namespace declared.
 fn has_itunes_namespace(e: &BytesStart) -> bool {
+    has_namespace_containing(e, "itunes.com")
+}
+
+/// Checks whether any xmlns declaration on the element has a URI containing `needle`.
+fn has_namespace_containing(e: &BytesStart, needle: &str) -> bool {
     for attr in e.attributes().flatten() {
         let key = String::from_utf8_lossy(attr.key.as_ref());
         let value = String::from_utf8_lossy(&attr.value);
-        if key.starts_with("xmlns") && value.contains("itunes.com") {
+        if key.starts_with("xmlns") && value.contains(needle) {
             return true;
         }
     }
@@ -182,7 +692,7 @@ fn has_itunes_namespace(e: &BytesStart) -> bool {
 }
 
 /// Gets an attribute value from an XML element.
-fn get_attribute(e: &BytesStart, name: &str) -> Option<String> {
+pub(crate) fn get_attribute(e: &BytesStart, name: &str) -> Option<String> {
     for attr in e.attributes().flatten() {
         let key = String::from_utf8_lossy(attr.key.as_ref());
         if key == name {
@@ -326,4 +836,156 @@ mod tests {
         assert_eq!(ext.items_by_index[0].duration, Some("10:00".to_string()));
         assert_eq!(ext.items_by_index[1].duration, Some("20:00".to_string()));
     }
+
+    #[test]
+    fn test_parse_podcast_namespace() {
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    <channel>
+        <title>Pod</title>
+        <podcast:funding url="https://example.com/donate">Support the show!</podcast:funding>
+        <podcast:person role="host" img="https://example.com/host.jpg">Jane Host</podcast:person>
+        <podcast:value type="lightning" method="keysend" suggested="0.00000005000">
+            <podcast:valueRecipient name="Host" type="node" address="02abc" split="90"/>
+            <podcast:valueRecipient name="App" type="node" address="03def" split="10"/>
+        </podcast:value>
+        <item>
+            <guid>ep-1</guid>
+            <podcast:transcript url="https://example.com/ep1.vtt" type="text/vtt" language="en" rel="captions"/>
+            <podcast:transcript url="https://example.com/ep1.srt" type="application/srt"/>
+            <podcast:chapters url="https://example.com/ep1.json" type="application/json+chapters"/>
+            <podcast:person role="guest" href="https://example.com/guest">Sam Guest</podcast:person>
+            <podcast:soundbite startTime="73.5" duration="60.0">The best bit</podcast:soundbite>
+        </item>
+    </channel>
+</rss>"#;
+
+        let ext = parse_itunes_extensions(rss.as_bytes());
+
+        assert_eq!(
+            ext.feed.funding,
+            vec![Funding {
+                url: "https://example.com/donate".into(),
+                text: "Support the show!".into()
+            }]
+        );
+        assert_eq!(ext.feed.persons.len(), 1);
+        assert_eq!(ext.feed.persons[0].name, "Jane Host");
+        assert_eq!(ext.feed.persons[0].role.as_deref(), Some("host"));
+        let value = ext.feed.value.as_ref().unwrap();
+        assert_eq!(value.value_type, "lightning");
+        assert_eq!(value.recipients.len(), 2);
+        assert_eq!(value.recipients[0].split, 90);
+
+        let item = ext.items.get("ep-1").unwrap();
+        assert_eq!(item.transcripts.len(), 2);
+        assert_eq!(item.transcripts[0].url, "https://example.com/ep1.vtt");
+        assert_eq!(item.transcripts[0].rel.as_deref(), Some("captions"));
+        assert_eq!(
+            item.chapters_url.as_deref(),
+            Some("https://example.com/ep1.json")
+        );
+        assert_eq!(item.persons[0].name, "Sam Guest");
+        assert_eq!(
+            item.soundbites,
+            vec![Soundbite {
+                start_seconds: 73.5,
+                duration_seconds: 60.0,
+                title: Some("The best bit".into())
+            }]
+        );
+        assert!(item.value.is_none());
+    }
+
+    #[test]
+    fn test_parse_dublin_core_fields() {
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <channel>
+        <dc:creator>Records Office</dc:creator>
+        <dc:rights>Public domain</dc:rights>
+        <item>
+            <guid>doc-1</guid>
+            <dc:subject>Budget</dc:subject>
+            <dc:subject>Transport</dc:subject>
+            <dc:date>2024-01-15T10:00:00Z</dc:date>
+        </item>
+    </channel>
+</rss>"#;
+
+        let ext = parse_itunes_extensions(rss.as_bytes());
+        assert_eq!(ext.feed.dc.creator.as_deref(), Some("Records Office"));
+        assert_eq!(ext.feed.dc.rights.as_deref(), Some("Public domain"));
+
+        let item = ext.items.get("doc-1").unwrap();
+        assert_eq!(item.dc.subjects, vec!["Budget", "Transport"]);
+        assert_eq!(item.dc.date.as_deref(), Some("2024-01-15T10:00:00Z"));
+        assert!(item.dc.creator.is_none());
+    }
+
+    #[test]
+    fn test_parse_geo_locations() {
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:georss="http://www.georss.org/georss"
+     xmlns:gml="http://www.opengis.net/gml"
+     xmlns:geo="http://www.w3.org/2003/01/geo/wgs84_pos#">
+    <channel>
+        <item>
+            <guid>a</guid>
+            <georss:point>45.256 -71.92</georss:point>
+            <georss:featurename>Sherbrooke</georss:featurename>
+        </item>
+        <item>
+            <guid>b</guid>
+            <georss:where><gml:Point><gml:pos>51.5 -0.12</gml:pos></gml:Point></georss:where>
+        </item>
+        <item>
+            <guid>c</guid>
+            <geo:lat>48.85</geo:lat>
+            <geo:long>2.35</geo:long>
+        </item>
+        <item>
+            <guid>d</guid>
+            <georss:point>123 456</georss:point>
+        </item>
+    </channel>
+</rss>"#;
+
+        let ext = parse_itunes_extensions(rss.as_bytes());
+        let a = ext.items.get("a").unwrap();
+        assert_eq!(a.location(), Some((45.256, -71.92)));
+        assert_eq!(a.geo_name.as_deref(), Some("Sherbrooke"));
+        assert_eq!(ext.items.get("b").unwrap().location(), Some((51.5, -0.12)));
+        assert_eq!(ext.items.get("c").unwrap().location(), Some((48.85, 2.35)));
+        assert_eq!(ext.items.get("d").unwrap().location(), None);
+    }
+
+    #[test]
+    fn test_parse_comments_url_and_count() {
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:slash="http://purl.org/rss/1.0/modules/slash/">
+    <channel>
+        <item>
+            <guid>p-1</guid>
+            <comments>https://example.com/p-1#comments</comments>
+            <slash:comments>17</slash:comments>
+        </item>
+        <item>
+            <guid>p-2</guid>
+            <slash:comments>n/a</slash:comments>
+        </item>
+    </channel>
+</rss>"#;
+
+        let ext = parse_itunes_extensions(rss.as_bytes());
+        let first = ext.items.get("p-1").unwrap();
+        assert_eq!(
+            first.comments_url.as_deref(),
+            Some("https://example.com/p-1#comments")
+        );
+        assert_eq!(first.comments_count, Some(17));
+        let second = ext.items.get("p-2").unwrap();
+        assert!(second.comments_url.is_none());
+        assert!(second.comments_count.is_none());
+    }
 }
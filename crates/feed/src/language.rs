@@ -0,0 +1,4 @@
+// ABOUTME: Natural-language detection for feed items, shared with hermes article parsing.
+// ABOUTME: Re-exports the whatlang-based detector; enabled by the `language-detection` feature.
+
+pub use digests_hermes::language::detect_language;
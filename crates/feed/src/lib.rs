@@ -1,26 +1,96 @@
 // ABOUTME: Core feed parsing library for digests-core.
 // ABOUTME: Provides feed parsing, time/duration parsing, HTML utilities, and image extraction.
 
+pub mod builder;
+pub mod chapters;
+pub mod dedup;
+pub mod diff;
 pub mod duration_parse;
+pub mod enclosure_probe;
+mod encoding;
 pub mod enrichment;
 pub mod error;
+pub mod export;
+pub mod extensions;
+pub mod fetcher;
+mod hash;
+pub mod health;
 pub mod html_utils;
 pub mod image_utils;
+pub mod incremental;
 pub mod item_enrichment;
 pub mod itunes_ext;
+#[cfg(feature = "language-detection")]
+pub mod language;
+pub mod limits;
+pub mod media_utils;
+pub mod merge;
 pub mod models;
+pub mod opml;
+pub mod paging;
 pub mod parser;
+pub mod poll;
+pub mod repair;
+pub mod serialize;
+pub mod streaming;
 pub mod time_parse;
+pub mod url_kind;
+pub mod validate;
 
+pub use builder::{FeedBuilder, FeedItemBuilder};
+pub use chapters::{apply_chapters, chapter_artwork, parse_chapters_json};
+pub use dedup::{
+    canonicalize_url, is_tracking_param, mark_duplicates, normalize_url, title_similarity,
+    DedupOptions,
+};
+pub use diff::{diff_feeds, mark_edited_items, FeedDiff};
 pub use duration_parse::parse_duration_seconds;
-pub use enrichment::{apply_metadata_to_feed, enrich_feed_with_site_html};
+pub use enclosure_probe::{probe_enclosures, EnclosureHead, EnclosureProbeStats};
+pub use enrichment::{
+    apply_metadata_to_feed, apply_metadata_to_feed_with_policy, enrich_feed_with_metadata_async,
+    enrich_feed_with_site_html, FieldPolicy, MetadataPolicy,
+};
 pub use error::FeedError;
-pub use html_utils::{decode_entities, strip_html};
-pub use image_utils::{extract_first_image, is_valid_image_url, resolve_image_url};
-pub use item_enrichment::{enrich_items_with_metadata, ItemEnrichmentStats};
-pub use models::{Author, Enclosure, Feed, FeedItem};
-pub use parser::parse_feed_bytes;
+pub use export::to_ical;
+pub use fetcher::{
+    FeedFetchError, FeedFetchResult, FeedFetcher, FeedValidators, FetchRequest, FetchResponse,
+};
+pub use health::{analyze_feed, FeedHealth, FeedIssue, FeedIssueKind};
+pub use html_utils::{
+    decode_entities, decode_entities_double_encoded, resolve_relative_urls, strip_html,
+    strip_html_with_blocks,
+};
+pub use image_utils::{
+    extract_first_image, extract_first_image_details, is_valid_image_url, resolve_image_url,
+    ExtractedImage,
+};
+pub use incremental::{parse_feed_incremental, FeedState};
+pub use item_enrichment::{
+    enrich_items_parallel, enrich_items_with_metadata, enrich_items_with_metadata_async,
+    enrich_items_with_retry, EnrichedField, EnrichmentSkipReason, FetchOutcome,
+    ItemEnrichmentDetail, ItemEnrichmentResult, ItemEnrichmentStats, ItemFetchStatus,
+    ParallelEnrichmentOptions, ParallelEnrichmentReport, RetryPolicy, UNLIMITED_FETCHES,
+};
+#[cfg(feature = "language-detection")]
+pub use language::detect_language;
+pub use limits::ParseLimits;
+pub use media_utils::extract_inline_media;
+pub use merge::{merge_feeds, MergeOptions};
+pub use models::{
+    normalize_category, Author, Category, Chapter, Enclosure, ExtensionElement, Extensions, Feed,
+    FeedItem, FeedKind, Funding, ItemSource, MediaRendition, PodcastPerson, PodcastValue,
+    Soundbite, Transcript, ValueRecipient,
+};
+pub use opml::{parse_opml, to_opml, Opml, Outline};
+pub use paging::fetch_paged_feed;
+pub use parser::{parse_feed_bytes, parse_feed_bytes_with_options, ParseOptions};
+pub use poll::{suggest_poll_interval, FeedCadence, PollPolicy, PollScheduler};
+pub use repair::repair_xml;
+pub use serialize::{serialize_feed, to_atom, to_json_feed, to_rss, FeedFormat};
+pub use streaming::{stream_feed_items, FeedItemStream};
 pub use time_parse::parse_flexible_time;
+pub use url_kind::{classify_url, UrlKind};
+pub use validate::{validate_feed_bytes, Diagnostic, DiagnosticKind, Severity};
 
 // ----------------------------------------------------------------------------
 // URL utilities
@@ -0,0 +1,191 @@
+// ABOUTME: Resource limits for feed parsing (input size, item count, nesting depth, text size).
+// ABOUTME: A cheap streaming pre-scan rejects oversized XML before feed-rs builds its tree.
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use crate::error::FeedError;
+
+/// Bounds enforced by `parse_feed_bytes_with_options`; `None` disables a limit.
+///
+/// The defaults are far above any real-world feed and exist to keep a hostile
+/// or broken document from exhausting memory in FFI hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum input size in bytes.
+    pub max_bytes: Option<usize>,
+    /// Maximum number of items/entries.
+    pub max_items: Option<usize>,
+    /// Maximum element nesting depth.
+    pub max_depth: Option<usize>,
+    /// Maximum size in bytes of a single text node (CDATA included).
+    pub max_text_bytes: Option<usize>,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: Some(64 * 1024 * 1024),
+            max_items: Some(50_000),
+            max_depth: Some(256),
+            max_text_bytes: Some(16 * 1024 * 1024),
+        }
+    }
+}
+
+impl ParseLimits {
+    /// No limits at all.
+    pub fn unlimited() -> Self {
+        Self {
+            max_bytes: None,
+            max_items: None,
+            max_depth: None,
+            max_text_bytes: None,
+        }
+    }
+
+    /// Rejects input larger than `max_bytes`.
+    pub(crate) fn check_size(&self, len: usize) -> Result<(), FeedError> {
+        exceeds("max_bytes", len, self.max_bytes)
+    }
+
+    /// Rejects a parsed feed with more than `max_items` items.
+    pub(crate) fn check_items(&self, count: usize) -> Result<(), FeedError> {
+        exceeds("max_items", count, self.max_items)
+    }
+
+    /// Streams through XML input checking depth, text node size and item
+    /// count. Malformed XML is left for the real parser to report.
+    pub(crate) fn check_xml(&self, data: &[u8]) -> Result<(), FeedError> {
+        if self.max_items.is_none() && self.max_depth.is_none() && self.max_text_bytes.is_none() {
+            return Ok(());
+        }
+
+        let mut reader = Reader::from_reader(data);
+        let mut buf = Vec::new();
+        let mut depth = 0usize;
+        let mut items = 0usize;
+        let mut text_len = 0usize;
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) => {
+                    depth += 1;
+                    text_len = 0;
+                    exceeds("max_depth", depth, self.max_depth)?;
+                    if is_item(e.local_name().as_ref()) {
+                        items += 1;
+                        self.check_items(items)?;
+                    }
+                }
+                Ok(Event::Empty(e)) => {
+                    text_len = 0;
+                    exceeds("max_depth", depth + 1, self.max_depth)?;
+                    if is_item(e.local_name().as_ref()) {
+                        items += 1;
+                        self.check_items(items)?;
+                    }
+                }
+                Ok(Event::End(_)) => {
+                    depth = depth.saturating_sub(1);
+                    text_len = 0;
+                }
+                // Entity references split a text node into several events
+                Ok(Event::Text(e)) => {
+                    text_len += e.len();
+                    exceeds("max_text_bytes", text_len, self.max_text_bytes)?;
+                }
+                Ok(Event::CData(e)) => {
+                    text_len += e.len();
+                    exceeds("max_text_bytes", text_len, self.max_text_bytes)?;
+                }
+                Ok(Event::GeneralRef(e)) => {
+                    text_len += e.len() + 2;
+                    exceeds("max_text_bytes", text_len, self.max_text_bytes)?;
+                }
+                Ok(Event::Eof) | Err(_) => return Ok(()),
+                Ok(_) => {}
+            }
+            buf.clear();
+        }
+    }
+}
+
+fn is_item(local_name: &[u8]) -> bool {
+    local_name == b"item" || local_name == b"entry"
+}
+
+fn exceeds(limit: &str, value: usize, max: Option<usize>) -> Result<(), FeedError> {
+    match max {
+        Some(max) if value > max => Err(FeedError::LimitExceeded(format!(
+            "{} of {} exceeded",
+            limit, max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> ParseLimits {
+        ParseLimits::unlimited()
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let xml = b"<rss><channel><item><a><b>x</b></a></item></channel></rss>";
+        let strict = ParseLimits {
+            max_depth: Some(4),
+            ..limits()
+        };
+        let err = strict.check_xml(xml).unwrap_err();
+        assert!(matches!(err, FeedError::LimitExceeded(_)));
+        assert_eq!(err.to_string(), "parse limit exceeded: max_depth of 4 exceeded");
+
+        let ok = ParseLimits {
+            max_depth: Some(5),
+            ..limits()
+        };
+        assert!(ok.check_xml(xml).is_ok());
+    }
+
+    #[test]
+    fn test_item_limit_counts_items_and_entries() {
+        let rss = b"<rss><channel><item/><item></item><item/></channel></rss>";
+        let atom = b"<feed xmlns='http://www.w3.org/2005/Atom'><entry/><entry/></feed>";
+        let two = ParseLimits {
+            max_items: Some(2),
+            ..limits()
+        };
+        assert!(two.check_xml(rss).is_err());
+        assert!(two.check_xml(atom).is_ok());
+        assert!(two.check_items(3).is_err());
+    }
+
+    #[test]
+    fn test_text_limit_includes_cdata_and_entities() {
+        let small = ParseLimits {
+            max_text_bytes: Some(10),
+            ..limits()
+        };
+        assert!(small.check_xml(b"<a><b>0123456789</b></a>").is_ok());
+        assert!(small.check_xml(b"<a><b>01234&amp;56789</b></a>").is_err());
+        assert!(small.check_xml(b"<a><![CDATA[0123456789A]]></a>").is_err());
+        // Separate text nodes are measured individually
+        assert!(small.check_xml(b"<a>0123456789<b/>0123456789</a>").is_ok());
+    }
+
+    #[test]
+    fn test_size_limit_and_unlimited() {
+        let tiny = ParseLimits {
+            max_bytes: Some(4),
+            ..limits()
+        };
+        assert!(tiny.check_size(5).is_err());
+        assert!(tiny.check_size(4).is_ok());
+        assert!(limits().check_xml(b"<a><b><c/></b></a>").is_ok());
+        assert!(limits().check_size(usize::MAX).is_ok());
+    }
+}
@@ -0,0 +1,143 @@
+// ABOUTME: Inline audio/video extraction from item HTML content.
+// ABOUTME: Turns embedded <audio>/<video> players into Enclosure entries for feeds that declare none.
+
+use scraper::{ElementRef, Html, Selector};
+
+use crate::image_utils::resolve_image_url;
+use crate::models::Enclosure;
+
+/// File extensions of common audio/video formats and their MIME types.
+const MEDIA_EXTENSIONS: &[(&str, &str)] = &[
+    ("mp3", "audio/mpeg"),
+    ("m4a", "audio/mp4"),
+    ("aac", "audio/aac"),
+    ("oga", "audio/ogg"),
+    ("ogg", "audio/ogg"),
+    ("opus", "audio/opus"),
+    ("wav", "audio/wav"),
+    ("flac", "audio/flac"),
+    ("mp4", "video/mp4"),
+    ("m4v", "video/mp4"),
+    ("webm", "video/webm"),
+    ("ogv", "video/ogg"),
+    ("mov", "video/quicktime"),
+];
+
+/// Extracts `<audio>`/`<video>` sources from HTML content as enclosures.
+///
+/// Each element contributes its `src` attribute, or failing that its first
+/// `<source src>` child. The MIME type comes from the `type` attribute, then
+/// the file extension; it stays `None` when neither identifies the format.
+/// Relative URLs are resolved against `base_url`; duplicates are dropped.
+pub fn extract_inline_media(html: &str, base_url: Option<&str>) -> Vec<Enclosure> {
+    let mut enclosures: Vec<Enclosure> = Vec::new();
+    let lower = html.to_ascii_lowercase();
+    if !lower.contains("<audio") && !lower.contains("<video") {
+        return enclosures;
+    }
+
+    let document = Html::parse_fragment(html);
+    let (Ok(players), Ok(sources)) = (Selector::parse("audio, video"), Selector::parse("source"))
+    else {
+        return enclosures;
+    };
+
+    for player in document.select(&players) {
+        let candidate = media_source(player).or_else(|| {
+            player
+                .select(&sources)
+                .find_map(|source| media_source(source))
+        });
+        let Some((src, declared_type)) = candidate else {
+            continue;
+        };
+        let Some(url) = resolve_image_url(&src, base_url).filter(|u| !u.starts_with("data:"))
+        else {
+            continue;
+        };
+        if enclosures.iter().any(|e| e.url == url) {
+            continue;
+        }
+
+        let mime_type = declared_type
+            .filter(|t| t.contains('/'))
+            .or_else(|| mime_from_extension(&url).map(str::to_string));
+        enclosures.push(Enclosure {
+            url,
+            mime_type,
+            length: 0,
+        });
+    }
+
+    enclosures
+}
+
+/// Returns an element's non-empty `src` and its lowercased `type` attribute.
+fn media_source(element: ElementRef) -> Option<(String, Option<String>)> {
+    let el = element.value();
+    let src = el.attr("src").map(str::trim).filter(|s| !s.is_empty())?;
+    let mime_type = el.attr("type").map(|t| {
+        // Drop codec parameters: `audio/ogg; codecs=opus` -> `audio/ogg`
+        t.split(';').next().unwrap_or(t).trim().to_ascii_lowercase()
+    });
+    Some((src.to_string(), mime_type))
+}
+
+/// Guesses a MIME type from the URL path's file extension.
+fn mime_from_extension(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let file = path.rsplit('/').next().unwrap_or(path);
+    let (_, ext) = file.rsplit_once('.')?;
+    let ext = ext.to_ascii_lowercase();
+    MEDIA_EXTENSIONS
+        .iter()
+        .find(|(e, _)| *e == ext)
+        .map(|(_, mime)| *mime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_src_with_extension_mime() {
+        let html = r#"<p>Listen:</p><audio controls src="/media/ep1.mp3?dl=1"></audio>"#;
+        let media = extract_inline_media(html, Some("https://blog.example.com/posts/1"));
+        assert_eq!(
+            media,
+            vec![Enclosure {
+                url: "https://blog.example.com/media/ep1.mp3?dl=1".to_string(),
+                mime_type: Some("audio/mpeg".to_string()),
+                length: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_video_source_child_with_declared_type() {
+        let html = r#"<video poster="p.jpg">
+            <source src="https://cdn.example.com/clip" type="video/webm; codecs=vp9">
+            <source src="https://cdn.example.com/clip.mp4" type="video/mp4">
+        </video>"#;
+        let media = extract_inline_media(html, None);
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].url, "https://cdn.example.com/clip");
+        assert_eq!(media[0].mime_type.as_deref(), Some("video/webm"));
+    }
+
+    #[test]
+    fn test_unknown_format_and_duplicates() {
+        let html = r#"<audio src="https://e.com/stream"></audio>
+            <audio src="https://e.com/stream"></audio>
+            <audio></audio>"#;
+        let media = extract_inline_media(html, None);
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].mime_type, None);
+    }
+
+    #[test]
+    fn test_no_players() {
+        assert!(extract_inline_media("<p>No media <img src='a.png'></p>", None).is_empty());
+        assert!(extract_inline_media("", None).is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+// ABOUTME: Merges several parsed feeds into a single "river of news" timeline.
+// ABOUTME: Interleaves items by publish time, tags them with their source feed, and drops duplicate URLs.
+
+use std::collections::HashSet;
+
+use crate::models::{Feed, FeedKind, ItemSource};
+
+/// Options for [`merge_feeds`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeOptions {
+    /// Title of the merged feed.
+    pub title: String,
+    /// Keep at most this many items (newest first); `None` keeps all.
+    pub max_items: Option<usize>,
+    /// Drop later items whose URL matches an item already kept.
+    pub dedupe_urls: bool,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            title: "All items".to_string(),
+            max_items: None,
+            dedupe_urls: true,
+        }
+    }
+}
+
+/// Combines `feeds` into one Feed whose items are sorted newest first.
+///
+/// - Every item gets `source` set to its originating feed (items that already
+///   carry a source, e.g. from an earlier merge, keep it).
+/// - Undated items sort after dated ones, keeping their input order.
+/// - With `dedupe_urls`, the newest copy of a URL wins; items without a URL are
+///   never treated as duplicates.
+/// - `kind`/`feed_type` are shared when every input feed agrees, else article.
+pub fn merge_feeds(feeds: &[Feed], options: &MergeOptions) -> Feed {
+    let mut items = Vec::with_capacity(feeds.iter().map(|f| f.items.len()).sum());
+    for feed in feeds {
+        let source = ItemSource {
+            feed_url: feed.feed_url.clone(),
+            title: feed.title.clone(),
+            home_url: feed.home_url.clone(),
+            image_url: feed.image_url.clone(),
+        };
+        for item in &feed.items {
+            let mut item = item.clone();
+            item.source.get_or_insert_with(|| source.clone());
+            items.push(item);
+        }
+    }
+
+    // Stable sort: newest first, undated (0) last
+    items.sort_by_key(|item| (item.published_ms == 0, std::cmp::Reverse(item.published_ms)));
+
+    if options.dedupe_urls {
+        let mut seen: HashSet<String> = HashSet::new();
+        items.retain(|item| {
+            let url = item.url.trim();
+            url.is_empty() || seen.insert(url.to_string())
+        });
+    }
+    if let Some(max) = options.max_items {
+        items.truncate(max);
+    }
+
+    let kind = match feeds.split_first() {
+        Some((first, rest)) if rest.iter().all(|f| f.kind == first.kind) => first.kind,
+        _ => FeedKind::Article,
+    };
+
+    Feed {
+        title: options.title.clone(),
+        published_ms: items.iter().map(|i| i.published_ms).max().unwrap_or(0),
+        updated_ms: feeds.iter().map(|f| f.updated_ms).max().unwrap_or(0),
+        feed_type: kind.as_str().to_string(),
+        kind,
+        items,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FeedItem;
+
+    fn feed(url: &str, items: &[(&str, u64)]) -> Feed {
+        Feed {
+            feed_url: url.into(),
+            title: url.into(),
+            items: items
+                .iter()
+                .map(|(item_url, ms)| FeedItem {
+                    url: item_url.to_string(),
+                    published_ms: *ms,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_merge_interleaves_tags_and_dedupes() {
+        let a = feed(
+            "https://a.com/feed",
+            &[("https://x.com/1", 30), ("https://x.com/u", 0)],
+        );
+        let b = feed(
+            "https://b.com/feed",
+            &[
+                ("https://x.com/2", 40),
+                ("https://x.com/1", 10),
+                ("https://x.com/3", 20),
+            ],
+        );
+
+        let merged = merge_feeds(&[a, b], &MergeOptions::default());
+        let urls: Vec<&str> = merged.items.iter().map(|i| i.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://x.com/2",
+                "https://x.com/1",
+                "https://x.com/3",
+                "https://x.com/u"
+            ]
+        );
+        let sources: Vec<&str> = merged
+            .items
+            .iter()
+            .map(|i| i.source.as_ref().unwrap().feed_url.as_str())
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                "https://b.com/feed",
+                "https://a.com/feed",
+                "https://b.com/feed",
+                "https://a.com/feed"
+            ]
+        );
+        assert_eq!(merged.published_ms, 40);
+    }
+
+    #[test]
+    fn test_merge_respects_max_items_and_keeps_existing_source() {
+        let mut inner = feed("https://a.com/feed", &[("https://x.com/1", 5)]);
+        inner.items[0].source = Some(ItemSource {
+            feed_url: "https://orig.com/feed".into(),
+            ..Default::default()
+        });
+        let other = feed("https://b.com/feed", &[("https://x.com/2", 1)]);
+
+        let merged = merge_feeds(
+            &[inner, other],
+            &MergeOptions {
+                max_items: Some(1),
+                ..Default::default()
+            },
+        );
+        assert_eq!(merged.items.len(), 1);
+        assert_eq!(
+            merged.items[0].source.as_ref().unwrap().feed_url,
+            "https://orig.com/feed"
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // ABOUTME: Internal Rust models for parsed feed data.
 // ABOUTME: Mirrors the ABI structs in .ai_agents/structs.md using native Rust types.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Represents a media enclosure (audio, video, or image attachment).
@@ -19,6 +21,144 @@ pub struct Author {
     pub uri: Option<String>,
 }
 
+/// A single `media:content` rendition (optionally from a `media:group`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MediaRendition {
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// `medium` attribute: `image`, `audio`, `video`, `document`, or `executable`.
+    pub medium: Option<String>,
+    /// Kilobits per second.
+    pub bitrate: Option<u32>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// `fileSize` in bytes; 0 if unknown.
+    pub file_size: u64,
+    pub duration_seconds: u32,
+    /// `isDefault="true"`: the publisher's preferred rendition within its group.
+    pub is_default: bool,
+    /// Index of the enclosing `media:group` within the item, if any.
+    pub group: Option<usize>,
+}
+
+/// A `podcast:transcript` link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    pub url: String,
+    pub mime_type: Option<String>,
+    pub language: Option<String>,
+    /// `rel` attribute; `"captions"` marks a captions file.
+    pub rel: Option<String>,
+}
+
+/// A `podcast:funding` link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Funding {
+    pub url: String,
+    pub text: String,
+}
+
+/// A `podcast:person` credit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PodcastPerson {
+    pub name: String,
+    pub role: Option<String>,
+    pub group: Option<String>,
+    pub img: Option<String>,
+    pub href: Option<String>,
+}
+
+/// A `podcast:soundbite` clip within an episode.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Soundbite {
+    pub start_seconds: f64,
+    pub duration_seconds: f64,
+    pub title: Option<String>,
+}
+
+/// A chapter marker from Podlove Simple Chapters (`psc:chapter`) or a
+/// Podcast 2.0 chapters JSON document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    pub start_seconds: f64,
+    pub title: Option<String>,
+    /// Chapter artwork URL.
+    pub image_url: Option<String>,
+    /// Link shown alongside the chapter.
+    pub url: Option<String>,
+    /// False for chapters that only change artwork/links and are hidden from a table of contents.
+    pub toc: bool,
+}
+
+/// A `podcast:valueRecipient` payment split.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValueRecipient {
+    pub name: Option<String>,
+    pub recipient_type: String,
+    pub address: String,
+    pub split: u32,
+}
+
+/// A `podcast:value` block (value-for-value payment routing).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PodcastValue {
+    pub value_type: String,
+    pub method: String,
+    pub suggested: Option<String>,
+    pub recipients: Vec<ValueRecipient>,
+}
+
+/// A category with its Atom `scheme`/`label` (or RSS `domain`) preserved.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Category {
+    /// Term as written in the feed.
+    pub term: String,
+    /// Atom `scheme` or RSS `domain` attribute.
+    pub scheme: Option<String>,
+    /// Atom `label` attribute.
+    pub label: Option<String>,
+    /// Case-folded, whitespace-collapsed term used for matching across feeds.
+    pub normalized: String,
+}
+
+impl Category {
+    /// Creates a category, deriving `normalized` from `term`.
+    pub fn new(term: &str, scheme: Option<String>, label: Option<String>) -> Self {
+        Category {
+            term: term.trim().to_string(),
+            scheme,
+            label,
+            normalized: normalize_category(term),
+        }
+    }
+}
+
+/// Normalizes a category term for matching: trims, collapses internal
+/// whitespace, and lowercases ("  Rust  Lang " -> "rust lang").
+pub fn normalize_category(term: &str) -> String {
+    term.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// An element from a namespace the parser does not map, preserved as a small tree.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionElement {
+    /// Qualified name as written in the document (e.g. `"cms:rating"`).
+    pub name: String,
+    /// Trimmed text content, when the element has any.
+    pub value: Option<String>,
+    /// Attributes keyed by their qualified name (namespace declarations excluded).
+    #[serde(default)]
+    pub attrs: HashMap<String, String>,
+    #[serde(default)]
+    pub children: Vec<ExtensionElement>,
+}
+
+/// Unmapped namespaced elements keyed by namespace URI, in document order.
+pub type Extensions = HashMap<String, Vec<ExtensionElement>>;
+
 /// Represents a single item/entry within a feed.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FeedItem {
@@ -27,18 +167,146 @@ pub struct FeedItem {
     pub image_url: Option<String>,
     pub summary: String,
     pub content: String,
+    /// Sanitized summary HTML; only set when parsed with `ParseOptions::sanitize_content`.
+    pub summary_html: Option<String>,
+    /// Sanitized content HTML; only set when parsed with `ParseOptions::sanitize_content`.
+    pub content_html: Option<String>,
     pub guid: String,
+    /// True when the source item had no guid/id, so `guid` is a synthetic
+    /// `urn:digests:item:<hash>` of link, title, and publish time, stable across polls.
+    #[serde(default)]
+    pub guid_generated: bool,
     pub language: Option<String>,
     pub feed_type: String,
     pub published_ms: u64,
     pub updated_ms: u64,
+    /// Primary author; always the first entry of `authors` when present.
     pub author: Option<Author>,
+    /// Every credited person: authors first, then contributors, deduplicated by name.
+    #[serde(default)]
+    pub authors: Vec<Author>,
+    /// Category terms as written, deduplicated case-insensitively (first spelling kept).
     pub categories: Vec<String>,
+    /// Structured categories matching `categories` one-to-one.
+    #[serde(default)]
+    pub category_details: Vec<Category>,
     pub enclosures: Vec<Enclosure>,
     pub primary_media_url: Option<String>,
+    /// Index into `enclosures` of the entry chosen as `primary_media_url`;
+    /// `None` when the primary media came from elsewhere or there is none.
+    #[serde(default)]
+    pub primary_enclosure_index: Option<usize>,
     pub thumbnail_url: Option<String>,
+    /// Declared thumbnail width in pixels, when the source gave one.
+    pub thumbnail_width: Option<u32>,
+    /// Declared thumbnail height in pixels, when the source gave one.
+    pub thumbnail_height: Option<u32>,
+    /// Stable hash (16 hex digits) of the title and text content; changes when
+    /// the publisher edits the item. Empty for items not produced by the parser.
+    #[serde(default)]
+    pub content_hash: String,
+    /// True when the item's content changed since a previous snapshot under
+    /// the same guid; set by `mark_edited_items`.
+    #[serde(default)]
+    pub edited: bool,
+    /// Guid of the earlier copy of this story, set by `mark_duplicates`.
+    #[serde(default)]
+    pub duplicate_of: Option<String>,
+    /// Originating feed; only set on items produced by `merge_feeds`.
+    #[serde(default)]
+    pub source: Option<ItemSource>,
+    /// Title of the feed this item was republished from (RSS `<source>` text
+    /// or Atom `<source><title>`), as declared by aggregator feeds.
+    #[serde(default)]
+    pub source_title: Option<String>,
+    /// URL of the feed this item was republished from (RSS `<source url>` or
+    /// Atom `<source><link>`).
+    #[serde(default)]
+    pub source_url: Option<String>,
+    /// YouTube `yt:videoId`.
+    #[serde(default)]
+    pub video_id: Option<String>,
+    /// YouTube `yt:channelId` of the uploading channel.
+    #[serde(default)]
+    pub channel_id: Option<String>,
     pub explicit_flag: bool,
     pub duration_seconds: u32,
+    /// `itunes:season` number.
+    pub season: Option<u32>,
+    /// `itunes:episode` number within the season.
+    pub episode: Option<u32>,
+    /// `itunes:episodeType`, lowercased: "full", "trailer", or "bonus".
+    pub episode_type: Option<String>,
+    /// True when `itunes:block` is "Yes": the episode should be hidden from directories.
+    #[serde(default)]
+    pub itunes_block: bool,
+    /// RSS `<comments>` page URL.
+    pub comments_url: Option<String>,
+    /// `slash:comments` count.
+    pub comments_count: Option<u32>,
+    /// Atom threading `thr:in-reply-to` ref: the guid of the entry this replies to.
+    pub in_reply_to_guid: Option<String>,
+    /// Atom threading `thr:total` reply count.
+    pub replies_count: Option<u32>,
+    /// GeoRSS / W3C geo coordinates as (latitude, longitude).
+    pub location: Option<(f64, f64)>,
+    /// GeoRSS `featurename` for the location.
+    pub location_name: Option<String>,
+    /// Every Media RSS `media:content` rendition, in document order.
+    #[serde(default)]
+    pub media_renditions: Vec<MediaRendition>,
+    /// Podcast 2.0 `podcast:transcript` links.
+    #[serde(default)]
+    pub transcripts: Vec<Transcript>,
+    /// Podcast 2.0 `podcast:chapters` JSON URL.
+    pub chapters_url: Option<String>,
+    /// Inline `psc:chapters`, or chapters fetched from `chapters_url` via `apply_chapters`.
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+    /// Podcast 2.0 `podcast:person` credits for this episode.
+    #[serde(default)]
+    pub persons: Vec<PodcastPerson>,
+    /// Podcast 2.0 `podcast:soundbite` clips.
+    #[serde(default)]
+    pub soundbites: Vec<Soundbite>,
+    /// Podcast 2.0 `podcast:value` block for this episode (overrides the feed's).
+    pub value: Option<PodcastValue>,
+    /// Elements from namespaces the parser does not map (custom CMS tags, etc.).
+    #[serde(default)]
+    pub extensions: Extensions,
+}
+
+/// The feed an item came from, set when feeds are merged into one timeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemSource {
+    pub feed_url: String,
+    pub title: String,
+    pub home_url: String,
+    pub image_url: Option<String>,
+}
+
+/// Content kind of a feed, used to route it to a suitable reader UI.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedKind {
+    /// Text posts (blogs, news).
+    #[default]
+    Article,
+    /// Audio episodes (iTunes tags or mostly audio enclosures).
+    Podcast,
+    /// Video uploads (YouTube channel feeds or mostly video enclosures).
+    Video,
+}
+
+impl FeedKind {
+    /// Lowercase name, as used in `feed_type`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedKind::Article => "article",
+            FeedKind::Podcast => "podcast",
+            FeedKind::Video => "video",
+        }
+    }
 }
 
 /// Represents a parsed feed with metadata and items.
@@ -47,14 +315,97 @@ pub struct Feed {
     pub title: String,
     pub home_url: String,
     pub feed_url: String,
+    /// Plain-text channel description (tags stripped, entities decoded).
     pub description: String,
+    /// Sanitized HTML form of the channel description, when one was present.
+    pub description_html: Option<String>,
     pub language: Option<String>,
     pub image_url: Option<String>,
+    /// Site favicon; feeds don't declare one, so this comes from site metadata.
+    #[serde(default)]
+    pub icon_url: Option<String>,
+    /// Site `theme-color`, from site metadata.
+    #[serde(default)]
+    pub theme_color: Option<String>,
     pub author: Option<Author>,
     pub published_ms: u64,
     pub updated_ms: u64,
     pub items: Vec<FeedItem>,
     pub generator: Option<String>,
     pub copyright: Option<String>,
+    /// `kind` as a string: "article", "podcast", or "video".
     pub feed_type: String,
+    /// Typed form of `feed_type`.
+    #[serde(default)]
+    pub kind: FeedKind,
+    /// WebSub hub endpoint from a `rel="hub"` link.
+    pub hub_url: Option<String>,
+    /// Canonical feed URL from a `rel="self"` link.
+    pub self_url: Option<String>,
+    /// RFC 5005 `rel="next"` link to the next page of a paged feed.
+    #[serde(default)]
+    pub next_page_url: Option<String>,
+    /// RFC 5005 `rel="prev-archive"` link to the previous archive document.
+    #[serde(default)]
+    pub prev_archive_url: Option<String>,
+    /// True when both `hub_url` and `self_url` are declared, the minimum needed to
+    /// subscribe via WebSub. Derived from the feed document; no network check is made.
+    #[serde(default)]
+    pub supports_push: bool,
+    /// Podcast 2.0 `podcast:funding` links.
+    #[serde(default)]
+    pub funding: Vec<Funding>,
+    /// Podcast 2.0 channel-level `podcast:person` credits.
+    #[serde(default)]
+    pub persons: Vec<PodcastPerson>,
+    /// Podcast 2.0 channel-level `podcast:value` block.
+    pub value: Option<PodcastValue>,
+    /// Channel-level elements from namespaces the parser does not map.
+    #[serde(default)]
+    pub extensions: Extensions,
+    /// RSS `<ttl>`: minutes the feed may be cached before refreshing.
+    /// See [`Feed::refresh_hint_minutes`] for the hint across all formats.
+    #[serde(default)]
+    pub ttl_minutes: Option<u32>,
+    /// RSS `<skipHours>`: UTC hours (0-23) in which aggregators should not poll.
+    #[serde(default)]
+    pub skip_hours: Vec<u8>,
+    /// RSS `<skipDays>`: day names (e.g. "Saturday") on which aggregators should not poll.
+    #[serde(default)]
+    pub skip_days: Vec<String>,
+    /// Syndication module `sy:updatePeriod` ("hourly", "daily", "weekly",
+    /// "monthly", "yearly"), the cache hint used by RSS 1.0 and Atom feeds.
+    #[serde(default)]
+    pub update_period: Option<String>,
+    /// Syndication module `sy:updateFrequency`: updates per `update_period`.
+    #[serde(default)]
+    pub update_frequency: Option<u32>,
+    /// True when the feed only parsed after `repair_xml` fixed malformed XML
+    /// (see `ParseOptions::recover_malformed`).
+    #[serde(default)]
+    pub recovered: bool,
+}
+
+impl Feed {
+    /// Publisher guidance on how long the feed may be cached, in minutes:
+    /// RSS `<ttl>` when present, else `sy:updatePeriod` divided by
+    /// `sy:updateFrequency` (each defaulting to "daily" and 1 per the module).
+    pub fn refresh_hint_minutes(&self) -> Option<u32> {
+        if self.ttl_minutes.is_some() {
+            return self.ttl_minutes;
+        }
+        if self.update_period.is_none() && self.update_frequency.is_none() {
+            return None;
+        }
+        let period_minutes = match self.update_period.as_deref().unwrap_or("daily") {
+            "hourly" => 60,
+            "daily" => 24 * 60,
+            "weekly" => 7 * 24 * 60,
+            "monthly" => 30 * 24 * 60,
+            "yearly" => 365 * 24 * 60,
+            _ => return None,
+        };
+        let frequency = self.update_frequency.unwrap_or(1).max(1);
+        Some((period_minutes / frequency).max(1))
+    }
 }
@@ -0,0 +1,333 @@
+// ABOUTME: OPML subscription list parsing into a typed outline tree, and OPML export.
+// ABOUTME: Supports nested folders on import and serializes feeds or bare feed URLs on export.
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::error::FeedError;
+use crate::models::Feed;
+
+/// A parsed OPML document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Opml {
+    /// `<head><title>`, when present.
+    pub title: Option<String>,
+    /// Top-level outlines from `<body>`.
+    pub outlines: Vec<Outline>,
+}
+
+/// A single `<outline>` element: a feed subscription, a folder, or both.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Outline {
+    /// `text` attribute (falls back to `title` when absent).
+    pub text: String,
+    pub title: Option<String>,
+    /// `type` attribute, usually `"rss"` for subscriptions.
+    pub outline_type: Option<String>,
+    /// `xmlUrl` attribute: the feed URL.
+    pub xml_url: Option<String>,
+    /// `htmlUrl` attribute: the site URL.
+    pub html_url: Option<String>,
+    pub children: Vec<Outline>,
+}
+
+impl Outline {
+    /// Creates a subscription outline for a feed URL.
+    pub fn subscription(text: &str, xml_url: &str) -> Self {
+        Outline {
+            text: text.to_string(),
+            outline_type: Some("rss".to_string()),
+            xml_url: Some(xml_url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if this outline points at a feed.
+    pub fn is_subscription(&self) -> bool {
+        self.xml_url.as_deref().is_some_and(|u| !u.is_empty())
+    }
+}
+
+impl From<&Feed> for Outline {
+    fn from(feed: &Feed) -> Self {
+        let xml_url = feed.self_url.as_deref().unwrap_or(&feed.feed_url);
+        let text = if feed.title.is_empty() {
+            xml_url
+        } else {
+            &feed.title
+        };
+        let mut outline = Outline::subscription(text, xml_url);
+        outline.title = (!feed.title.is_empty()).then(|| feed.title.clone());
+        outline.html_url = (!feed.home_url.is_empty()).then(|| feed.home_url.clone());
+        outline
+    }
+}
+
+impl Opml {
+    /// Builds a flat OPML document from parsed feeds.
+    pub fn from_feeds(title: &str, feeds: &[Feed]) -> Self {
+        Opml {
+            title: Some(title.to_string()),
+            outlines: feeds.iter().map(Outline::from).collect(),
+        }
+    }
+
+    /// Builds a flat OPML document from bare feed URLs.
+    pub fn from_urls(title: &str, urls: &[&str]) -> Self {
+        Opml {
+            title: Some(title.to_string()),
+            outlines: urls
+                .iter()
+                .map(|url| Outline::subscription(url, url))
+                .collect(),
+        }
+    }
+
+    /// Returns every subscription outline in document order, flattening folders.
+    pub fn subscriptions(&self) -> Vec<&Outline> {
+        fn walk<'a>(outlines: &'a [Outline], acc: &mut Vec<&'a Outline>) {
+            for outline in outlines {
+                if outline.is_subscription() {
+                    acc.push(outline);
+                }
+                walk(&outline.children, acc);
+            }
+        }
+        let mut acc = Vec::new();
+        walk(&self.outlines, &mut acc);
+        acc
+    }
+}
+
+/// Parses OPML bytes into an outline tree.
+///
+/// # Returns
+/// * `Ok(Opml)` - Parsed document (outlines may be empty)
+/// * `Err(FeedError)` - Malformed XML, or the root element is not `<opml>`
+pub fn parse_opml(data: &[u8]) -> Result<Opml, FeedError> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    let mut opml = Opml::default();
+    let mut buf = Vec::new();
+    let mut seen_root = false;
+    let mut in_head_title = false;
+    // Open <outline> elements awaiting their children
+    let mut stack: Vec<Outline> = Vec::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buf);
+        if let Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) = event {
+            if !seen_root {
+                if local_name(e) != "opml" {
+                    return Err(FeedError::invalid("root element is not <opml>"));
+                }
+                seen_root = true;
+            }
+        }
+        match event {
+            Ok(Event::Start(ref e)) => match local_name(e).as_str() {
+                "title" if stack.is_empty() => in_head_title = true,
+                "outline" => stack.push(read_outline(e)),
+                _ => {}
+            },
+            Ok(Event::Empty(ref e)) if local_name(e) == "outline" => {
+                attach(read_outline(e), &mut stack, &mut opml.outlines);
+            }
+            Ok(Event::Text(ref e)) if in_head_title => {
+                let text = e.decode().map(|s| s.into_owned()).unwrap_or_default();
+                if !text.is_empty() {
+                    opml.title = Some(text);
+                }
+            }
+            Ok(Event::End(ref e)) => match e.local_name().as_ref() {
+                b"title" => in_head_title = false,
+                b"outline" => {
+                    if let Some(outline) = stack.pop() {
+                        attach(outline, &mut stack, &mut opml.outlines);
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(FeedError::parse(e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    if !seen_root {
+        return Err(FeedError::invalid("root element is not <opml>"));
+    }
+
+    Ok(opml)
+}
+
+/// Serializes an OPML document (version 2.0).
+pub fn to_opml(opml: &Opml) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<opml version=\"2.0\">\n  <head>\n");
+    if let Some(title) = &opml.title {
+        out.push_str(&format!("    <title>{}</title>\n", escape(title.as_str())));
+    }
+    out.push_str("  </head>\n  <body>\n");
+    for outline in &opml.outlines {
+        write_outline(&mut out, outline, 2);
+    }
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
+
+fn write_outline(out: &mut String, outline: &Outline, depth: usize) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push_str("<outline");
+    push_attr(out, "text", Some(&outline.text));
+    push_attr(out, "title", outline.title.as_ref());
+    push_attr(out, "type", outline.outline_type.as_ref());
+    push_attr(out, "xmlUrl", outline.xml_url.as_ref());
+    push_attr(out, "htmlUrl", outline.html_url.as_ref());
+    if outline.children.is_empty() {
+        out.push_str("/>\n");
+        return;
+    }
+    out.push_str(">\n");
+    for child in &outline.children {
+        write_outline(out, child, depth + 1);
+    }
+    out.push_str(&indent);
+    out.push_str("</outline>\n");
+}
+
+fn push_attr(out: &mut String, name: &str, value: Option<&String>) {
+    if let Some(value) = value {
+        out.push_str(&format!(" {}=\"{}\"", name, escape(value.as_str())));
+    }
+}
+
+fn local_name(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.local_name().as_ref()).to_ascii_lowercase()
+}
+
+/// Reads outline attributes; attribute names are matched case-insensitively
+/// since exporters disagree on `xmlUrl` vs `xmlurl`.
+fn read_outline(e: &BytesStart) -> Outline {
+    let mut outline = Outline::default();
+    for attr in e.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_ascii_lowercase();
+        let value = attr
+            .unescape_value()
+            .map(|v| v.trim().to_string())
+            .unwrap_or_default();
+        if value.is_empty() {
+            continue;
+        }
+        match key.as_str() {
+            "text" => outline.text = value,
+            "title" => outline.title = Some(value),
+            "type" => outline.outline_type = Some(value),
+            "xmlurl" => outline.xml_url = Some(value),
+            "htmlurl" => outline.html_url = Some(value),
+            _ => {}
+        }
+    }
+    if outline.text.is_empty() {
+        outline.text = outline.title.clone().unwrap_or_default();
+    }
+    outline
+}
+
+fn attach(outline: Outline, stack: &mut [Outline], top: &mut Vec<Outline>) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(outline),
+        None => top.push(outline),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="1.0">
+  <head><title>My Subscriptions</title></head>
+  <body>
+    <outline text="Tech" title="Tech">
+      <outline type="rss" text="Rust Blog" xmlUrl="https://blog.rust-lang.org/feed.xml" htmlUrl="https://blog.rust-lang.org/"/>
+      <outline type="rss" text="Q &amp; A" xmlurl="https://example.com/qa.xml"/>
+    </outline>
+    <outline type="rss" title="Loose Feed" xmlUrl="https://example.org/rss"/>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn test_parse_opml_builds_nested_tree() {
+        let opml = parse_opml(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(opml.title.as_deref(), Some("My Subscriptions"));
+        assert_eq!(opml.outlines.len(), 2);
+
+        let folder = &opml.outlines[0];
+        assert_eq!(folder.text, "Tech");
+        assert!(!folder.is_subscription());
+        assert_eq!(folder.children.len(), 2);
+        assert_eq!(folder.children[1].text, "Q & A");
+        assert_eq!(
+            folder.children[1].xml_url.as_deref(),
+            Some("https://example.com/qa.xml")
+        );
+
+        // text falls back to title
+        assert_eq!(opml.outlines[1].text, "Loose Feed");
+
+        let urls: Vec<&str> = opml
+            .subscriptions()
+            .iter()
+            .filter_map(|o| o.xml_url.as_deref())
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://blog.rust-lang.org/feed.xml",
+                "https://example.com/qa.xml",
+                "https://example.org/rss",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_opml_rejects_non_opml_root() {
+        let err = parse_opml(b"<rss version=\"2.0\"><channel/></rss>").unwrap_err();
+        assert!(matches!(err, FeedError::Invalid(_)));
+    }
+
+    #[test]
+    fn test_to_opml_round_trips() {
+        let feeds = vec![Feed {
+            title: "News & Notes".into(),
+            home_url: "https://example.com/".into(),
+            feed_url: "https://example.com/feed.xml".into(),
+            ..Default::default()
+        }];
+        let xml = to_opml(&Opml::from_feeds("Export", &feeds));
+        assert!(xml.contains("text=\"News &amp; Notes\""));
+
+        let parsed = parse_opml(xml.as_bytes()).unwrap();
+        assert_eq!(parsed.title.as_deref(), Some("Export"));
+        assert_eq!(parsed.outlines.len(), 1);
+        assert_eq!(parsed.outlines[0].text, "News & Notes");
+        assert_eq!(
+            parsed.outlines[0].xml_url.as_deref(),
+            Some("https://example.com/feed.xml")
+        );
+        assert_eq!(
+            parsed.outlines[0].html_url.as_deref(),
+            Some("https://example.com/")
+        );
+
+        let from_urls = to_opml(&Opml::from_urls("Bare", &["https://a.test/rss"]));
+        let parsed = parse_opml(from_urls.as_bytes()).unwrap();
+        assert_eq!(parsed.subscriptions().len(), 1);
+    }
+}
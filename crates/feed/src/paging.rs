@@ -0,0 +1,171 @@
+// ABOUTME: RFC 5005 paged/archived feed traversal for backfilling history.
+// ABOUTME: Follows prev-archive (or next) links through a FeedFetcher and merges the pages into one Feed.
+
+use std::collections::HashSet;
+
+use crate::diff::item_key;
+use crate::fetcher::{FeedFetchError, FeedFetcher, FetchRequest, FetchResponse};
+use crate::models::Feed;
+
+/// Fetches `url` and up to `max_pages - 1` further pages, returning one Feed
+/// with every page's items in traversal order.
+///
+/// Each page links onward via `prev_archive_url` (RFC 5005 archived feeds),
+/// falling back to `next_page_url` (paged feeds). The walk stops at `max_pages`,
+/// at a page with no onward link, or when a link revisits a page. Items already
+/// seen on an earlier page (by [`item_key`]) are dropped.
+///
+/// Pages are always fetched unconditionally, since a backfill needs content
+/// rather than a 304, and the validators the fetcher holds are left as they were. An error on the first page is returned; an error on a
+/// later page ends the walk and keeps the pages fetched so far.
+pub fn fetch_paged_feed<T>(
+    fetcher: &mut FeedFetcher<T>,
+    url: &str,
+    max_pages: usize,
+) -> Result<Feed, FeedFetchError>
+where
+    T: FnMut(&FetchRequest) -> Result<FetchResponse, String>,
+{
+    let mut combined = fetch_page(fetcher, url)?;
+    let mut visited: HashSet<String> = HashSet::from([url.to_string()]);
+    let mut seen: HashSet<String> = combined.items.iter().map(item_key).collect();
+    let mut next = onward_link(&combined);
+
+    for _ in 1..max_pages.max(1) {
+        let Some(page_url) = next.take().filter(|u| visited.insert(u.clone())) else {
+            break;
+        };
+        let Ok(page) = fetch_page(fetcher, &page_url) else {
+            break;
+        };
+        next = onward_link(&page);
+        combined.items.extend(
+            page.items
+                .into_iter()
+                .filter(|item| seen.insert(item_key(item))),
+        );
+    }
+
+    Ok(combined)
+}
+
+fn fetch_page<T>(fetcher: &mut FeedFetcher<T>, url: &str) -> Result<Feed, FeedFetchError>
+where
+    T: FnMut(&FetchRequest) -> Result<FetchResponse, String>,
+{
+    // Validator-free, so the caller's conditional-GET state is left untouched
+    fetcher.fetch_unconditional(url)
+}
+
+fn onward_link(feed: &Feed) -> Option<String> {
+    feed.prev_archive_url
+        .clone()
+        .or_else(|| feed.next_page_url.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetcher::FeedValidators;
+
+    fn page(id: &str, items: &[&str], prev: Option<&str>) -> String {
+        let prev = prev
+            .map(|p| format!(r#"<link rel="prev-archive" href="{}"/>"#, p))
+            .unwrap_or_default();
+        let entries: String = items
+            .iter()
+            .map(|i| format!("<entry><id>{}</id><title>{}</title></entry>", i, i))
+            .collect();
+        format!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><id>{}</id><title>T</title>{}{}</feed>"#,
+            id, prev, entries
+        )
+    }
+
+    fn fetcher_for(
+        pages: Vec<(&'static str, String)>,
+    ) -> FeedFetcher<impl FnMut(&FetchRequest) -> Result<FetchResponse, String>> {
+        FeedFetcher::new(move |req: &FetchRequest| {
+            pages
+                .iter()
+                .find(|(url, _)| *url == req.url)
+                .map(|(_, body)| FetchResponse {
+                    status: 200,
+                    headers: Vec::new(),
+                    body: body.clone().into_bytes(),
+                })
+                .ok_or_else(|| "not found".to_string())
+        })
+    }
+
+    #[test]
+    fn test_walks_archives_and_dedupes_items() {
+        let mut fetcher = fetcher_for(vec![
+            (
+                "https://e.com/feed",
+                page("f", &["c", "b"], Some("/archive/2")),
+            ),
+            (
+                "https://e.com/archive/2",
+                page("a2", &["b", "a"], Some("https://e.com/archive/1")),
+            ),
+            ("https://e.com/archive/1", page("a1", &["z"], None)),
+        ]);
+
+        let feed = fetch_paged_feed(&mut fetcher, "https://e.com/feed", 10).unwrap();
+        let ids: Vec<&str> = feed.items.iter().map(|i| i.guid.as_str()).collect();
+        assert_eq!(ids, vec!["c", "b", "a", "z"]);
+    }
+
+    #[test]
+    fn test_stops_at_page_limit_cycles_and_errors() {
+        let mut fetcher = fetcher_for(vec![
+            (
+                "https://e.com/1",
+                page("1", &["a"], Some("https://e.com/2")),
+            ),
+            (
+                "https://e.com/2",
+                page("2", &["b"], Some("https://e.com/1")),
+            ),
+        ]);
+        let limited = fetch_paged_feed(&mut fetcher, "https://e.com/1", 1).unwrap();
+        assert_eq!(limited.items.len(), 1);
+        let cyclic = fetch_paged_feed(&mut fetcher, "https://e.com/1", 10).unwrap();
+        assert_eq!(cyclic.items.len(), 2);
+
+        let mut broken = fetcher_for(vec![(
+            "https://e.com/1",
+            page("1", &["a"], Some("https://e.com/missing")),
+        )]);
+        let partial = fetch_paged_feed(&mut broken, "https://e.com/1", 10).unwrap();
+        assert_eq!(partial.items.len(), 1);
+        assert!(fetch_paged_feed(&mut broken, "https://e.com/missing", 10).is_err());
+    }
+
+    #[test]
+    fn test_paging_keeps_caller_validators() {
+        let mut fetcher = FeedFetcher::new(|req: &FetchRequest| {
+            assert!(
+                !req.headers
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("if-none-match")),
+                "page fetches must be unconditional"
+            );
+            Ok(FetchResponse {
+                status: 200,
+                headers: vec![("ETag".to_string(), "\"v2\"".to_string())],
+                body: page("f", &["a"], None).into_bytes(),
+            })
+        });
+        let cached = FeedValidators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+        };
+        fetcher.set_validators("https://e.com/feed", cached.clone());
+
+        let feed = fetch_paged_feed(&mut fetcher, "https://e.com/feed", 3).unwrap();
+        assert_eq!(feed.items.len(), 1);
+        assert_eq!(fetcher.validators("https://e.com/feed"), Some(&cached));
+    }
+}
@@ -1,34 +1,98 @@
 // ABOUTME: Feed parsing implementation using feed-rs.
 // ABOUTME: Maps feed-rs types to internal models with iTunes metadata extraction.
 
+use crate::chapters::chapter_artwork;
+use crate::encoding::to_utf8;
 use crate::error::FeedError;
-use crate::html_utils::strip_html;
-use crate::image_utils::extract_first_image;
+use crate::extensions::parse_extension_elements;
+use crate::hash::fnv1a;
+use crate::html_utils::{resolve_relative_urls, strip_html, strip_html_with_blocks};
+use crate::image_utils::{
+    extract_first_image_details, resolve_image_url, same_image_url, ExtractedImage,
+};
 use crate::itunes_ext::{
     is_explicit, parse_item_duration, parse_itunes_extensions, ItemITunesExt,
     ParsedITunesExtensions,
 };
-use crate::models::{Author, Enclosure, Feed, FeedItem};
+use crate::limits::ParseLimits;
+use crate::media_utils::extract_inline_media;
+use crate::models::{Author, Category, Enclosure, Feed, FeedItem, FeedKind, MediaRendition};
+use crate::repair::repair_xml;
+use crate::time_parse::parse_flexible_time;
 use chrono::Utc;
+use digests_hermes::formats::{sanitize_html, BLOCK_TAGS};
 use feed_rs::model::{Entry, Feed as FeedRsFeed, Link, Person};
 use std::collections::HashSet;
+use url::Url;
+
+/// Options for [`parse_feed_bytes_with_options`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Populate `FeedItem::summary_html`/`content_html` with item HTML passed
+    /// through the Hermes sanitizer (`digests_hermes::formats::sanitize_html`).
+    /// When false those fields stay `None` and only plain text is produced.
+    pub sanitize_content: bool,
+    /// Keep a line break at block boundaries (`p`, `div`, `li`, `br`, ...) in
+    /// `FeedItem::summary`/`content` instead of flattening them to one line.
+    pub preserve_paragraphs: bool,
+    /// Input size, item count, nesting depth and text node bounds.
+    pub limits: ParseLimits,
+    /// When XML fails to parse, repair common breakage (bare `&`, invalid
+    /// characters, truncated tails) with [`repair_xml`] and retry once,
+    /// marking the result `Feed::recovered`.
+    pub recover_malformed: bool,
+}
 
 /// Parses feed bytes into a Feed struct.
 ///
 /// # Arguments
-/// * `data` - Raw feed bytes (RSS, Atom, or JSON Feed)
+/// * `data` - Raw feed bytes (RSS 2.0, RSS 1.0/RDF, Atom, or JSON Feed).
+///   RDF feeds map `dc:date`/`dc:creator` into `published_ms`/author.
+///   Non-UTF-8 feeds are decoded using the XML declaration encoding, or
+///   charset detection when none is declared.
 /// * `feed_url` - The URL the feed was fetched from (stored as-is)
 ///
 /// # Returns
 /// * `Ok(Feed)` - Successfully parsed feed with items
-/// * `Err(FeedError)` - Parse failed, invalid feed, or empty feed
+/// * `Err(FeedError)` - Parse failed, invalid feed, empty feed, or the default
+///   [`ParseLimits`] were exceeded
 pub fn parse_feed_bytes(data: &[u8], feed_url: &str) -> Result<Feed, FeedError> {
-    let parsed = feed_rs::parser::parse(data).map_err(FeedError::parse)?;
+    parse_feed_bytes_with_options(data, feed_url, &ParseOptions::default())
+}
+
+/// Parses feed bytes like [`parse_feed_bytes`], applying `options`.
+pub fn parse_feed_bytes_with_options(
+    data: &[u8],
+    feed_url: &str,
+    options: &ParseOptions,
+) -> Result<Feed, FeedError> {
+    options.limits.check_size(data.len())?;
+    let utf8 = to_utf8(data);
+    let data = utf8.as_ref();
+    options.limits.check_xml(data)?;
+    let mut repaired = None;
+    let parsed = match feed_rs::parser::parse(data) {
+        Ok(parsed) => parsed,
+        Err(err) if options.recover_malformed && !is_json(data) => {
+            let fixed = repair_xml(data).ok_or_else(|| FeedError::parse(&err))?;
+            let parsed =
+                feed_rs::parser::parse(fixed.as_slice()).map_err(|_| FeedError::parse(err))?;
+            repaired = Some(fixed);
+            parsed
+        }
+        Err(err) => return Err(FeedError::parse(err)),
+    };
+    let recovered = repaired.is_some();
+    let data = repaired.as_deref().unwrap_or(data);
+    // JSON Feed input is not covered by the XML pre-scan
+    options.limits.check_items(parsed.entries.len())?;
 
     // Parse iTunes extensions from raw XML (feed-rs doesn't expose all iTunes metadata)
     let itunes_ext = parse_itunes_extensions(data);
+    let mut extensions = parse_extension_elements(data);
 
-    let feed_type = detect_feed_type(&parsed, &itunes_ext);
+    let kind = detect_feed_kind(&parsed, &itunes_ext);
+    let feed_type = kind.as_str().to_string();
     let feed_language = parsed.language.clone();
 
     // Extract feed-level author (iTunes author overrides if no standard author)
@@ -37,6 +101,12 @@ pub fn parse_feed_bytes(data: &[u8], feed_url: &str) -> Result<Feed, FeedError>
     // Extract feed-level image (iTunes image has priority)
     let feed_image_url = extract_feed_image(&parsed, &itunes_ext);
 
+    // Relative URLs in item HTML resolve against the item link, else the site/feed URL
+    let home_url = extract_home_url(&parsed.links);
+    let feed_base = [home_url.as_str(), feed_url]
+        .into_iter()
+        .find(|u| is_http_url(u));
+
     // Map items
     let items: Vec<FeedItem> = parsed
         .entries
@@ -49,92 +119,183 @@ pub fn parse_feed_bytes(data: &[u8], feed_url: &str) -> Result<Feed, FeedError>
                 .or_else(|| itunes_ext.items_by_index.get(idx))
                 .cloned()
                 .unwrap_or_default();
-            map_entry(entry, &feed_type, feed_language.as_deref(), &item_ext)
+            let mut item = map_entry(
+                entry,
+                &feed_type,
+                feed_language.as_deref(),
+                &item_ext,
+                feed_base,
+                itunes_ext.feed.image_href.as_deref(),
+                options,
+            );
+            if let Some(ext) = extensions.items.get_mut(idx) {
+                item.extensions = std::mem::take(ext);
+            }
+            // The raw XML pass sees whether a guid/id element existed; JSON feeds skip it
+            item.guid_generated = if itunes_ext.items_by_index.is_empty() {
+                entry.id.is_empty()
+            } else {
+                !item_ext.has_guid
+            };
+            if item.guid_generated {
+                // A URL that fell back to entry.id is feed-rs's generated id, not a link
+                if item.url == entry.id {
+                    item.url.clear();
+                }
+                item.guid = synthetic_guid(&item);
+            }
+            item
         })
         .collect();
 
+    // Channel <description>/<subtitle>: sanitize once, then derive plain text from the safe HTML
+    let description_html = parsed
+        .description
+        .map(|d| sanitize_html(&d.content))
+        .filter(|d| !d.trim().is_empty());
+    let description = description_html
+        .as_deref()
+        .map(strip_html)
+        .unwrap_or_default();
+
+    // Dublin Core dc:date stands in for a missing channel pubDate
+    let feed_published = parsed.published.or_else(|| {
+        itunes_ext
+            .feed
+            .dc
+            .date
+            .as_deref()
+            .and_then(parse_flexible_time)
+    });
+
+    // WebSub discovery links (atom:link rel="hub" / rel="self")
+    let hub_url = find_link_by_rel(&parsed.links, "hub");
+    let self_url = find_link_by_rel(&parsed.links, "self");
+    let supports_push = hub_url.is_some() && self_url.is_some();
+
+    // RFC 5005 paging/archive links, resolved against the feed URL
+    let resolve = |href: String| {
+        Url::parse(feed_url)
+            .and_then(|base| base.join(&href))
+            .map(|u| u.to_string())
+            .unwrap_or(href)
+    };
+    let next_page_url = find_link_by_rel(&parsed.links, "next").map(resolve);
+    let prev_archive_url = find_link_by_rel(&parsed.links, "prev-archive").map(resolve);
+
     // Build feed
     let feed = Feed {
         title: parsed.title.map(|t| t.content).unwrap_or_default(),
-        home_url: extract_home_url(&parsed.links),
+        home_url,
         feed_url: feed_url.to_string(),
-        description: parsed.description.map(|d| d.content).unwrap_or_default(),
+        description,
+        description_html,
         language: feed_language,
         image_url: feed_image_url,
         author: feed_author,
-        published_ms: parsed
-            .published
+        published_ms: feed_published
             .map(|dt| dt.timestamp_millis() as u64)
             .unwrap_or(0),
         updated_ms: parsed
             .updated
+            .or(feed_published)
             .map(|dt| dt.timestamp_millis() as u64)
-            .or_else(|| parsed.published.map(|dt| dt.timestamp_millis() as u64))
             .unwrap_or_else(|| Utc::now().timestamp_millis() as u64),
         items,
         generator: parsed.generator.map(|g| g.content),
-        copyright: parsed.rights.map(|r| r.content),
+        copyright: parsed
+            .rights
+            .map(|r| r.content)
+            .or_else(|| itunes_ext.feed.dc.rights.clone()),
         feed_type,
+        kind,
+        hub_url,
+        self_url,
+        next_page_url,
+        prev_archive_url,
+        supports_push,
+        funding: itunes_ext.feed.funding,
+        persons: itunes_ext.feed.persons,
+        value: itunes_ext.feed.value,
+        extensions: extensions.feed,
+        ttl_minutes: parsed.ttl,
+        skip_hours: itunes_ext.feed.skip_hours,
+        skip_days: itunes_ext.feed.skip_days,
+        update_period: itunes_ext.feed.update_period,
+        update_frequency: itunes_ext.feed.update_frequency,
+        icon_url: None,
+        theme_color: None,
+        recovered,
     };
 
     Ok(feed)
 }
 
-/// Detects whether the feed is a podcast or article feed.
+/// Detects the feed kind.
 ///
-/// Rules per requirements:
-/// 1. If feed.extensions contains namespace "itunes" (any key) OR feed.rating.urn == "itunes" => "podcast"
-/// 2. Else sample first up to 5 entries; for each entry count as podcast indicator if:
-///    (a) entry.extensions has namespace "itunes", OR
-///    (b) any link rel=="enclosure" with media_type starting with audio/ or video/, OR
-///    (c) any media.content content_type starts with audio/ or video/
-///    Majority -> podcast else article
-fn detect_feed_type(feed: &FeedRsFeed, itunes_ext: &ParsedITunesExtensions) -> String {
+/// Rules:
+/// 1. A `yt:` (YouTube) namespace declaration => Video
+/// 2. An iTunes namespace OR feed.rating.urn == "itunes" => Podcast
+/// 3. Else sample first up to 5 entries and classify each by its media:
+///    iTunes item data or audio/* enclosures/media count as audio, video/* as video.
+///    If a majority of sampled entries carry media => Video when video entries
+///    outnumber audio ones, otherwise Podcast; else Article
+fn detect_feed_kind(feed: &FeedRsFeed, itunes_ext: &ParsedITunesExtensions) -> FeedKind {
+    if itunes_ext.feed.has_youtube_namespace {
+        return FeedKind::Video;
+    }
+
     // Check for iTunes namespace at feed level
     if itunes_ext.feed.has_itunes_namespace {
-        return "podcast".to_string();
+        return FeedKind::Podcast;
     }
 
     // Check for iTunes rating at feed level (feed-rs stores iTunes explicit as rating)
     if let Some(ref rating) = feed.rating {
         if rating.urn == "itunes" {
-            return "podcast".to_string();
+            return FeedKind::Podcast;
         }
     }
 
-    // Check first 5 entries for podcast indicators
+    // Check first 5 entries for media indicators
     let check_count = feed.entries.len().min(5);
     if check_count == 0 {
-        return "article".to_string();
+        return FeedKind::Article;
     }
 
-    let mut podcast_count = 0;
+    let (mut audio_count, mut video_count) = (0, 0);
     for (idx, entry) in feed.entries.iter().take(check_count).enumerate() {
         let item_ext = itunes_ext
             .items
             .get(&entry.id)
             .or_else(|| itunes_ext.items_by_index.get(idx));
 
-        if has_podcast_indicators(entry, item_ext) {
-            podcast_count += 1;
+        match entry_media_kind(entry, item_ext) {
+            Some(FeedKind::Video) => video_count += 1,
+            Some(_) => audio_count += 1,
+            None => {}
         }
     }
 
-    // Majority rule: podcast_count * 2 > check_count
-    if podcast_count * 2 > check_count {
-        "podcast".to_string()
+    // Majority rule: media entries * 2 > check_count
+    if (audio_count + video_count) * 2 <= check_count {
+        FeedKind::Article
+    } else if video_count > audio_count {
+        FeedKind::Video
     } else {
-        "article".to_string()
+        FeedKind::Podcast
     }
 }
 
-/// Checks if an entry has podcast indicators.
+/// Classifies an entry's media: Podcast for audio (or iTunes item data), Video
+/// for video, None when it carries neither.
 ///
-/// Per requirements:
-/// (a) entry.extensions has namespace "itunes" (we check via ItemITunesExt having any data), OR
-/// (b) any link rel=="enclosure" with media_type starting with audio/ or video/, OR
-/// (c) any media.content content_type starts with audio/ or video/
-fn has_podcast_indicators(entry: &Entry, item_ext: Option<&ItemITunesExt>) -> bool {
+/// Checks, in order:
+/// (a) ItemITunesExt having any data => audio, then
+/// (b) link rel=="enclosure" media_type audio/ or video/, then
+/// (c) media.content content_type audio/ or video/, then
+/// (d) inline `<audio>`/`<video>` players in the entry's content or summary
+fn entry_media_kind(entry: &Entry, item_ext: Option<&ItemITunesExt>) -> Option<FeedKind> {
     // (a) Check if entry has iTunes extension data
     if let Some(ext) = item_ext {
         if ext.duration.is_some()
@@ -142,35 +303,46 @@ fn has_podcast_indicators(entry: &Entry, item_ext: Option<&ItemITunesExt>) -> bo
             || ext.image_href.is_some()
             || ext.author.is_some()
         {
-            return true;
+            return Some(FeedKind::Podcast);
         }
     }
 
-    // (b) Check for link rel="enclosure" with audio/video type
-    for link in &entry.links {
-        if is_enclosure_link(link) {
-            if let Some(ref media_type) = link.media_type {
-                let mime = media_type.to_string();
-                if mime.starts_with("audio/") || mime.starts_with("video/") {
-                    return true;
-                }
-            }
-        }
-    }
+    let enclosure_types = entry
+        .links
+        .iter()
+        .filter(|link| is_enclosure_link(link))
+        .filter_map(|link| link.media_type.clone());
+    // (b) enclosures, then (c) media.content
+    let media_types = entry
+        .media
+        .iter()
+        .flat_map(|m| &m.content)
+        .filter_map(|c| c.content_type.as_ref().map(|t| t.to_string()));
+
+    enclosure_types
+        .chain(media_types)
+        .find_map(|mime| media_kind_for_mime(&mime))
+        .or_else(|| {
+            let html = entry
+                .content
+                .as_ref()
+                .and_then(|c| c.body.as_deref())
+                .or_else(|| entry.summary.as_ref().map(|s| s.content.as_str()))?;
+            extract_inline_media(html, None)
+                .iter()
+                .filter_map(|enc| enc.mime_type.as_deref())
+                .find_map(media_kind_for_mime)
+        })
+}
 
-    // (c) Check media.content for audio/video types
-    for media in &entry.media {
-        for content in &media.content {
-            if let Some(ref content_type) = content.content_type {
-                let mime = content_type.to_string();
-                if mime.starts_with("audio/") || mime.starts_with("video/") {
-                    return true;
-                }
-            }
-        }
+fn media_kind_for_mime(mime: &str) -> Option<FeedKind> {
+    if mime.starts_with("audio/") {
+        Some(FeedKind::Podcast)
+    } else if mime.starts_with("video/") {
+        Some(FeedKind::Video)
+    } else {
+        None
     }
-
-    false
 }
 
 /// Checks if a link is an enclosure link (rel == "enclosure" or LinkRel::Enclosure).
@@ -195,6 +367,26 @@ fn extract_home_url(links: &[Link]) -> String {
     links.first().map(|l| l.href.clone()).unwrap_or_default()
 }
 
+/// Returns true when the document looks like JSON Feed rather than XML.
+fn is_json(data: &[u8]) -> bool {
+    data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{')
+}
+
+/// Returns true for absolute http(s) URLs usable as a base for relative links.
+fn is_http_url(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|u| matches!(u.scheme(), "http" | "https"))
+}
+
+/// Returns the first non-empty href among links with the given rel.
+fn find_link_by_rel(links: &[Link], rel: &str) -> Option<String> {
+    links
+        .iter()
+        .filter(|l| l.rel.as_deref() == Some(rel))
+        .map(|l| l.href.trim())
+        .find(|href| !href.is_empty())
+        .map(str::to_string)
+}
+
 /// Extracts the item URL from entry links.
 /// Prefers link with rel="alternate", otherwise first non-enclosure link, then entry.id.
 fn extract_item_url(entry: &Entry) -> String {
@@ -224,8 +416,13 @@ fn extract_feed_author(feed: &FeedRsFeed, itunes_ext: &ParsedITunesExtensions) -
         return Some(person_to_author(person));
     }
 
-    // Fall back to iTunes author
-    if let Some(ref author_name) = itunes_ext.feed.author {
+    // Fall back to iTunes author, then Dublin Core creator
+    if let Some(author_name) = itunes_ext.feed.author.as_ref().or(itunes_ext
+        .feed
+        .dc
+        .creator
+        .as_ref())
+    {
         return Some(Author {
             name: Some(author_name.clone()),
             email: None,
@@ -263,16 +460,45 @@ fn map_entry(
     feed_type: &str,
     feed_language: Option<&str>,
     item_ext: &ItemITunesExt,
+    feed_base: Option<&str>,
+    feed_artwork: Option<&str>,
+    options: &ParseOptions,
 ) -> FeedItem {
     let item_url = extract_item_url(entry);
+    let content_base = Some(item_url.as_str())
+        .filter(|u| is_http_url(u))
+        .or(feed_base);
+    let absolutize = |html: String| match content_base {
+        Some(base) => resolve_relative_urls(&html, base),
+        None => html,
+    };
 
-    // Extract summary (raw HTML then stripped plain text)
+    // Extract summary (raw HTML then stripped plain text); media:description
+    // stands in when there is none (YouTube puts the description only there)
     let summary_html = entry
         .summary
         .as_ref()
         .map(|t| t.content.clone())
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| {
+            entry
+                .media
+                .iter()
+                .find_map(|m| m.description.as_ref())
+                .map(|t| t.content.clone())
+        })
+        .map(absolutize)
         .unwrap_or_default();
-    let summary = strip_html(&summary_html);
+    let plain_text = |html: &str| {
+        if options.preserve_paragraphs {
+            let mut tags = BLOCK_TAGS.to_vec();
+            tags.push("br");
+            strip_html_with_blocks(html, &tags)
+        } else {
+            strip_html(html)
+        }
+    };
+    let summary = plain_text(&summary_html);
 
     // Extract content (prefer body/src); keep raw for image extraction, store plain text per Go parity
     let content_raw = entry
@@ -283,14 +509,34 @@ fn map_entry(
                 .clone()
                 .or_else(|| c.src.as_ref().map(|l| l.href.clone()))
         })
+        .map(absolutize)
         .unwrap_or_else(|| summary_html.clone());
-    let content = strip_html(&content_raw);
-
-    // Extract enclosures from links (rel=enclosure) and media.content, deduplicated
-    let enclosures = extract_enclosures(entry);
+    let content = plain_text(&content_raw);
+
+    // Sanitized HTML for clients that render markup (opt-in)
+    let sanitized = |html: &str| {
+        options
+            .sanitize_content
+            .then(|| sanitize_html(html))
+            .filter(|s| !s.trim().is_empty())
+    };
+    let safe_summary_html = sanitized(&summary_html);
+    let safe_content_html = sanitized(&content_raw);
+
+    // Extract enclosures from links (rel=enclosure) and media.content, deduplicated;
+    // when there are none, fall back to <audio>/<video> players embedded in the content
+    let mut enclosures = extract_enclosures(entry);
+    if enclosures.is_empty() && item_ext.media_renditions.is_empty() {
+        let base_url = Some(item_url.as_str()).filter(|u| !u.is_empty());
+        enclosures = extract_inline_media(&content_raw, base_url);
+    }
 
-    // Select primary media URL (audio priority)
-    let primary_media_url = select_primary_media(&enclosures);
+    // Select primary media URL (audio priority, then best Media RSS rendition)
+    let best_rendition = select_best_rendition(&item_ext.media_renditions);
+    let primary_media_url = select_primary_media(&enclosures, best_rendition);
+    let primary_enclosure_index = primary_media_url
+        .as_ref()
+        .and_then(|url| enclosures.iter().position(|e| &e.url == url));
 
     // Get duration: prefer our parsed iTunes duration (correct for MM:SS), fall back to feed-rs
     let duration_seconds = extract_duration(entry, item_ext);
@@ -299,66 +545,156 @@ fn map_entry(
     let explicit_flag = extract_explicit_flag(entry, item_ext);
 
     // Select image/thumbnail with priority cascade
-    let (image_url, thumbnail_url) = select_image_thumbnail(
+    let selection = select_image_thumbnail(
         entry,
         &enclosures,
         &content_raw,
         &summary_html,
-        &item_url,
+        content_base,
         item_ext,
+        feed_artwork,
     );
+    // A media:thumbnail is only a preview; an inline image in the item body is
+    // a separate lead image candidate, deduplicated against the thumbnail below
+    let media_thumbnail =
+        matches!(selection, Some((_, ImageSource::MediaThumbnail))) && item_ext.video_id.is_none();
+    let inline_image = if media_thumbnail {
+        extract_first_image_details(&content_raw, content_base)
+            .or_else(|| extract_first_image_details(&summary_html, content_base))
+            .map(|img| img.url)
+    } else {
+        None
+    };
+    let mut thumbnail = selection.map(|(img, _)| img);
+    let mut lead_image = thumbnail.as_ref().map(|t| t.url.clone());
+    // YouTube: the feed's media:thumbnail is the high-res still; synthesize the
+    // same hqdefault URL (always generated) only when the entry has none
+    let video_id = item_ext
+        .video_id
+        .as_deref()
+        .filter(|id| is_youtube_video_id(id));
+    if let (None, Some(video_id)) = (&thumbnail, video_id) {
+        let still = ExtractedImage {
+            url: format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", video_id),
+            width: Some(480),
+            height: Some(360),
+        };
+        lead_image = Some(still.url.clone());
+        thumbnail = Some(still);
+    }
+    let thumbnail_url = thumbnail.as_ref().map(|t| t.url.clone());
+    let image_url = match inline_image {
+        Some(inline) => dedupe_lead_image(Some(inline), thumbnail_url.as_deref()),
+        // A lone media:thumbnail would fill both fields with the same URL
+        None if media_thumbnail => dedupe_lead_image(lead_image, thumbnail_url.as_deref()),
+        None => lead_image,
+    };
+    let thumbnail_width = thumbnail.as_ref().and_then(|t| t.width);
+    let thumbnail_height = thumbnail.as_ref().and_then(|t| t.height);
 
     // Extract author (iTunes author if no standard author)
     let author = extract_entry_author(entry, item_ext);
+    let authors = extract_entry_authors(entry, item_ext, author.as_ref());
 
-    // Extract categories
-    let categories: Vec<String> = entry.categories.iter().map(|c| c.term.clone()).collect();
+    // Extract categories (dc:subject when no core categories)
+    let category_details = extract_categories(entry, item_ext);
+    let categories: Vec<String> = category_details.iter().map(|c| c.term.clone()).collect();
 
-    // Parse timestamps
-    let published_ms = entry
+    // Parse timestamps (dc:date when no core publish date)
+    let published = entry
         .published
+        .or_else(|| item_ext.dc.date.as_deref().and_then(parse_flexible_time));
+    let published_ms = published
         .map(|dt| dt.timestamp_millis() as u64)
         .unwrap_or(0);
 
     let updated_ms = entry
         .updated
+        .or(published)
         .map(|dt| dt.timestamp_millis() as u64)
-        .or_else(|| entry.published.map(|dt| dt.timestamp_millis() as u64))
         .unwrap_or(0);
 
-    // Language: entry language or feed language
+    let title = entry
+        .title
+        .as_ref()
+        .map(|t| t.content.clone())
+        .unwrap_or_default();
+
+    // Language: entry language or feed language, else detected from the text
     let language = entry
         .language
         .clone()
         .or_else(|| feed_language.map(String::from));
+    #[cfg(feature = "language-detection")]
+    let language =
+        language.or_else(|| crate::language::detect_language(&format!("{} {}", title, summary)));
+
+    // Hashed from the raw content so parse options that change `content`
+    // (e.g. preserve_paragraphs) do not make every item look edited
+    let text_hash = content_hash(&title, &strip_html(&content_raw));
 
     FeedItem {
-        title: entry
-            .title
-            .as_ref()
-            .map(|t| t.content.clone())
-            .unwrap_or_default(),
+        title,
         url: item_url.clone(),
         image_url,
         summary,
         content,
-        // Go parity: fall back to item URL when GUID is missing/empty
+        summary_html: safe_summary_html,
+        content_html: safe_content_html,
+        // Go parity: fall back to item URL when GUID is missing/empty (the caller
+        // replaces generated guids with `synthetic_guid`)
         guid: if entry.id.is_empty() {
             item_url.clone()
         } else {
             entry.id.clone()
         },
+        // Set by the caller, which can see the raw XML pass
+        guid_generated: false,
+        content_hash: text_hash,
+        edited: false,
+        source: None,
+        duplicate_of: None,
+        source_title: item_ext.source_title.clone(),
+        source_url: item_ext.source_url.clone(),
+        video_id: item_ext.video_id.clone(),
+        channel_id: item_ext.channel_id.clone(),
         language,
         feed_type: feed_type.to_string(),
         published_ms,
         updated_ms,
         author,
+        authors,
         categories,
+        category_details,
         enclosures,
         primary_media_url,
+        primary_enclosure_index,
         thumbnail_url,
+        thumbnail_width,
+        thumbnail_height,
         explicit_flag,
         duration_seconds,
+        season: item_ext.season,
+        episode: item_ext.episode,
+        episode_type: item_ext.episode_type.clone(),
+        itunes_block: item_ext
+            .block
+            .as_deref()
+            .is_some_and(|b| b.trim().eq_ignore_ascii_case("yes")),
+        transcripts: item_ext.transcripts.clone(),
+        chapters_url: item_ext.chapters_url.clone(),
+        chapters: item_ext.chapters.clone(),
+        persons: item_ext.persons.clone(),
+        soundbites: item_ext.soundbites.clone(),
+        value: item_ext.value.clone(),
+        media_renditions: item_ext.media_renditions.clone(),
+        comments_url: item_ext.comments_url.clone(),
+        comments_count: item_ext.comments_count,
+        in_reply_to_guid: item_ext.in_reply_to.clone(),
+        replies_count: item_ext.replies_total,
+        location: item_ext.location(),
+        location_name: item_ext.geo_name.clone(),
+        extensions: Default::default(),
     }
 }
 
@@ -366,23 +702,39 @@ fn map_entry(
 /// Per requirements:
 /// - Include entry.links where rel=="enclosure"; map url=href, mime_type=media_type, length=length.unwrap_or(0)
 /// - Also include media.content entries with url
-/// - Deduplicate identical URL/mime pairs (keep first)
+/// - Deduplicate by URL (keep first), filling its missing mime type/length from later copies
 fn extract_enclosures(entry: &Entry) -> Vec<Enclosure> {
-    let mut enclosures = Vec::new();
-    let mut seen: HashSet<(String, Option<String>)> = HashSet::new();
+    let mut enclosures: Vec<Enclosure> = Vec::new();
+    let mut add = |url: &str, mime_type: Option<String>, length: u64| {
+        let url = url.trim();
+        if url.is_empty() {
+            return;
+        }
+        match enclosures.iter_mut().find(|e| e.url == url) {
+            Some(existing) => {
+                if existing.mime_type.is_none() {
+                    existing.mime_type = mime_type;
+                }
+                if existing.length == 0 {
+                    existing.length = length;
+                }
+            }
+            None => enclosures.push(Enclosure {
+                url: url.to_string(),
+                mime_type,
+                length,
+            }),
+        }
+    };
 
     // First, extract from links with rel="enclosure"
     for link in &entry.links {
         if is_enclosure_link(link) {
-            let mime_type = link.media_type.clone();
-            let key = (link.href.clone(), mime_type.clone());
-            if seen.insert(key) {
-                enclosures.push(Enclosure {
-                    url: link.href.clone(),
-                    mime_type,
-                    length: link.length.unwrap_or(0),
-                });
-            }
+            add(
+                &link.href,
+                link.media_type.clone(),
+                link.length.unwrap_or(0),
+            );
         }
     }
 
@@ -391,14 +743,7 @@ fn extract_enclosures(entry: &Entry) -> Vec<Enclosure> {
         for content in &media.content {
             if let Some(ref url) = content.url {
                 let mime_type = content.content_type.as_ref().map(|m| m.to_string());
-                let key = (url.to_string(), mime_type.clone());
-                if seen.insert(key) {
-                    enclosures.push(Enclosure {
-                        url: url.to_string(),
-                        mime_type,
-                        length: content.size.unwrap_or(0),
-                    });
-                }
+                add(url.as_str(), mime_type, content.size.unwrap_or(0));
             }
         }
     }
@@ -406,11 +751,20 @@ fn extract_enclosures(entry: &Entry) -> Vec<Enclosure> {
     enclosures
 }
 
-/// Selects the primary media URL based on audio priority.
-/// Priority: audio/mpeg > audio/mp3 > audio/mp4 > audio/aac > first enclosure
-fn select_primary_media(enclosures: &[Enclosure]) -> Option<String> {
+/// Largest video enclosure (bytes) preferred as primary media; bigger files
+/// are only chosen when no video fits.
+const MAX_PRIMARY_VIDEO_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Selects the primary media URL deterministically.
+/// Priority: audio/mpeg > audio/mp3 > audio/mp4 > audio/aac > best rendition >
+/// largest video under [`MAX_PRIMARY_VIDEO_BYTES`] (else the smallest video) >
+/// any other audio > first enclosure. Ties keep document order.
+fn select_primary_media(
+    enclosures: &[Enclosure],
+    best_rendition: Option<&MediaRendition>,
+) -> Option<String> {
     if enclosures.is_empty() {
-        return None;
+        return best_rendition.map(|r| r.url.clone());
     }
 
     let priority_order = ["audio/mpeg", "audio/mp3", "audio/mp4", "audio/aac"];
@@ -425,10 +779,79 @@ fn select_primary_media(enclosures: &[Enclosure]) -> Option<String> {
         }
     }
 
+    if let Some(best) = best_rendition {
+        return Some(best.url.clone());
+    }
+
+    if let Some(video) = select_primary_video(enclosures) {
+        return Some(video.url.clone());
+    }
+
+    let has_mime_prefix = |enc: &&Enclosure, prefix: &str| {
+        enc.mime_type
+            .as_deref()
+            .is_some_and(|m| m.starts_with(prefix))
+    };
+    if let Some(audio) = enclosures.iter().find(|e| has_mime_prefix(e, "audio/")) {
+        return Some(audio.url.clone());
+    }
+
     // Fall back to first enclosure
     Some(enclosures[0].url.clone())
 }
 
+/// Picks the largest video enclosure within [`MAX_PRIMARY_VIDEO_BYTES`];
+/// unknown sizes rank below known ones. When every video is over the cap,
+/// the smallest one wins.
+fn select_primary_video(enclosures: &[Enclosure]) -> Option<&Enclosure> {
+    let videos: Vec<&Enclosure> = enclosures
+        .iter()
+        .filter(|e| {
+            e.mime_type
+                .as_deref()
+                .is_some_and(|m| m.starts_with("video/"))
+        })
+        .collect();
+    // max_by_key keeps the last maximum; reverse so the first one wins ties
+    videos
+        .iter()
+        .rev()
+        .filter(|e| e.length <= MAX_PRIMARY_VIDEO_BYTES)
+        .max_by_key(|e| e.length)
+        .or_else(|| videos.iter().min_by_key(|e| e.length))
+        .copied()
+}
+
+/// Returns true if a rendition is playable audio or video.
+fn is_playable_rendition(r: &MediaRendition) -> bool {
+    match r.medium.as_deref() {
+        Some("audio") | Some("video") => true,
+        Some(_) => false,
+        None => r
+            .mime_type
+            .as_deref()
+            .is_some_and(|m| m.starts_with("audio/") || m.starts_with("video/")),
+    }
+}
+
+/// Picks the best playable Media RSS rendition.
+///
+/// Prefers the publisher's `isDefault` rendition, then the highest bitrate,
+/// then the largest frame (width × height), then the largest file.
+fn select_best_rendition(renditions: &[MediaRendition]) -> Option<&MediaRendition> {
+    renditions
+        .iter()
+        .filter(|r| is_playable_rendition(r))
+        .max_by_key(|r| {
+            (
+                r.is_default,
+                r.bitrate.unwrap_or(0),
+                u64::from(r.width.unwrap_or(0)) * u64::from(r.height.unwrap_or(0)),
+                r.file_size,
+            )
+        })
+}
+
 /// Extracts duration in seconds.
 /// Per requirements: use iTunes extension duration parsed with parse_duration_seconds when media.duration missing.
 /// Our iTunes parser correctly handles MM:SS format unlike feed-rs.
@@ -477,30 +900,51 @@ fn extract_explicit_flag(entry: &Entry, item_ext: &ItemITunesExt) -> bool {
 
 /// Selects image and thumbnail URLs with priority cascade.
 /// Per requirements, order:
-/// (1) iTunes image (entry extension image href) - highest priority
+/// (1) iTunes image (entry extension image href) - highest priority, unless it
+///     only repeats the feed's `feed_artwork` and (1b) a `psc:chapter` image exists
 /// (2) First image enclosure (mime starts with image/)
 /// (3) media thumbnail (entry.media[].thumbnails.first())
-/// (4) first <img> in content HTML (extract_first_image with base=item URL)
+/// (4) first <img> in content HTML (extract_first_image with base=item URL, else site URL)
 /// (5) first <img> in summary HTML
-/// Both image_url and thumbnail_url set to the same selected value.
+/// The selection becomes both image_url and thumbnail_url. When it is a media
+/// thumbnail, the item's first inline image is used as image_url instead and
+/// `dedupe_lead_image` clears it if it is the same image. Width/height hints
+/// come from media thumbnails and `<img>` attributes when present.
 fn select_image_thumbnail(
     entry: &Entry,
     enclosures: &[Enclosure],
     content_html: &str,
     summary_html: &str,
-    item_url: &str,
+    base_url: Option<&str>,
     item_ext: &ItemITunesExt,
-) -> (Option<String>, Option<String>) {
-    // (1) iTunes image from extension - highest priority
+    feed_artwork: Option<&str>,
+) -> Option<(ExtractedImage, ImageSource)> {
+    let url_only = |url: &str| ExtractedImage {
+        url: url.to_string(),
+        width: None,
+        height: None,
+    };
+
+    // (1) iTunes image from extension - highest priority; episode-specific
+    // chapter art wins over an item image that is just the show artwork
+    let chapter_art = chapter_artwork(&item_ext.chapters);
     if let Some(ref href) = item_ext.image_href {
-        return (Some(href.clone()), Some(href.clone()));
+        let is_show_art = feed_artwork.is_some_and(|art| same_image_url(href, art));
+        if !is_show_art || chapter_art.is_none() {
+            return Some((url_only(href), ImageSource::Declared));
+        }
+    }
+    if let Some(art) = chapter_art {
+        if let Some(resolved) = resolve_image_url(art, base_url) {
+            return Some((url_only(&resolved), ImageSource::Declared));
+        }
     }
 
     // (2) First image enclosure
     for enc in enclosures {
         if let Some(ref mime) = enc.mime_type {
             if mime.starts_with("image/") {
-                return (Some(enc.url.clone()), Some(enc.url.clone()));
+                return Some((url_only(&enc.url), ImageSource::Declared));
             }
         }
     }
@@ -508,27 +952,71 @@ fn select_image_thumbnail(
     // (3) Media thumbnails
     for media in &entry.media {
         if let Some(thumb) = media.thumbnails.first() {
-            let url = thumb.image.uri.clone();
-            return (Some(url.clone()), Some(url));
+            let image = ExtractedImage {
+                url: thumb.image.uri.clone(),
+                width: thumb.image.width.filter(|w| *w > 0),
+                height: thumb.image.height.filter(|h| *h > 0),
+            };
+            return Some((image, ImageSource::MediaThumbnail));
         }
     }
 
-    // (4) First <img> from content HTML
-    let base_url = if item_url.is_empty() {
-        None
-    } else {
-        Some(item_url)
-    };
-    if let Some(img_url) = extract_first_image(content_html, base_url) {
-        return (Some(img_url.clone()), Some(img_url));
-    }
+    // (4) First <img> from content HTML, then (5) from summary HTML
+    extract_first_image_details(content_html, base_url)
+        .or_else(|| extract_first_image_details(summary_html, base_url))
+        .map(|image| (image, ImageSource::Inline))
+}
 
-    // (5) First <img> from summary HTML
-    if let Some(img_url) = extract_first_image(summary_html, base_url) {
-        return (Some(img_url.clone()), Some(img_url));
+/// Where [`select_image_thumbnail`] found its image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageSource {
+    /// iTunes image, chapter art, or an image enclosure
+    Declared,
+    /// `media:thumbnail`
+    MediaThumbnail,
+    /// First `<img>` in the content or summary HTML
+    Inline,
+}
+
+/// Deterministic guid for an item whose source has none, so dedup and diffing
+/// work across polls. Hashes link, title, and publish time (FNV-1a, stable across
+/// builds); the summary is mixed in only when link and title are both empty.
+fn synthetic_guid(item: &FeedItem) -> String {
+    let published = item.published_ms.to_string();
+    let mut parts = vec![item.url.trim(), item.title.trim(), published.as_str()];
+    if parts[0].is_empty() && parts[1].is_empty() {
+        parts.push(item.summary.trim());
     }
+    format!("urn:digests:item:{:016x}", fnv1a(&parts))
+}
 
-    (None, None)
+/// Stable hash of an item's title and text content, used to spot edits to an
+/// item whose guid is unchanged (see [`crate::diff::mark_edited_items`]).
+fn content_hash(title: &str, content: &str) -> String {
+    format!("{:016x}", fnv1a(&[title.trim(), content.trim()]))
+}
+
+/// True for an 11-character YouTube video id (`[A-Za-z0-9_-]`), safe to put in a URL.
+fn is_youtube_video_id(id: &str) -> bool {
+    id.len() == 11
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+/// Drops `image_url` when it names the same image as `thumbnail_url`. Applied
+/// to items whose thumbnail is a `media:thumbnail`, whether or not an inline
+/// image repeats it; a declared or inline lead image still fills both fields,
+/// and so does a YouTube still, which is the video's lead image.
+///
+/// Rule: when both resolve to the same host and path (query and fragment ignored),
+/// `thumbnail_url` is kept and `image_url` is cleared. The thumbnail is the one
+/// kept because item enrichment treats a missing thumbnail as "needs fetching".
+fn dedupe_lead_image(image_url: Option<String>, thumbnail_url: Option<&str>) -> Option<String> {
+    match (image_url, thumbnail_url) {
+        (Some(image), Some(thumb)) if same_image_url(&image, thumb) => None,
+        (image, _) => image,
+    }
 }
 
 /// Extracts entry-level author.
@@ -550,8 +1038,8 @@ fn extract_entry_author(entry: &Entry, item_ext: &ItemITunesExt) -> Option<Autho
         }
     }
 
-    // Fall back to iTunes author extension
-    if let Some(ref author_name) = item_ext.author {
+    // Fall back to iTunes author extension, then Dublin Core creator
+    if let Some(author_name) = item_ext.author.as_ref().or(item_ext.dc.creator.as_ref()) {
         return Some(Author {
             name: Some(author_name.clone()),
             email: None,
@@ -562,6 +1050,56 @@ fn extract_entry_author(entry: &Entry, item_ext: &ItemITunesExt) -> Option<Autho
     None
 }
 
+/// Builds structured categories, falling back to dc:subject when the entry has none.
+/// Empty terms are dropped and duplicates (by normalized term) keep the first occurrence.
+fn extract_categories(entry: &Entry, item_ext: &ItemITunesExt) -> Vec<Category> {
+    let mut categories: Vec<Category> = entry
+        .categories
+        .iter()
+        .map(|c| Category::new(&c.term, c.scheme.clone(), c.label.clone()))
+        .collect();
+    if categories.is_empty() {
+        categories = item_ext
+            .dc
+            .subjects
+            .iter()
+            .map(|s| Category::new(s, None, None))
+            .collect();
+    }
+
+    let mut seen = HashSet::new();
+    categories.retain(|c| !c.normalized.is_empty() && seen.insert(c.normalized.clone()));
+    categories
+}
+
+/// Collects every credited person for an entry, starting with the primary author.
+/// Order: primary, remaining entry authors, dc:creator values, then contributors.
+/// Entries are deduplicated by case-insensitive name.
+fn extract_entry_authors(
+    entry: &Entry,
+    item_ext: &ItemITunesExt,
+    primary: Option<&Author>,
+) -> Vec<Author> {
+    let candidates = primary
+        .cloned()
+        .into_iter()
+        .chain(entry.authors.iter().map(person_to_author))
+        .chain(item_ext.dc.creators.iter().map(|name| Author {
+            name: Some(name.clone()),
+            email: None,
+            uri: None,
+        }))
+        .chain(entry.contributors.iter().map(person_to_author));
+
+    let mut seen = HashSet::new();
+    candidates
+        .filter(|a| {
+            let key = a.name.as_deref().unwrap_or("").trim().to_lowercase();
+            !key.is_empty() && seen.insert(key)
+        })
+        .collect()
+}
+
 /// Converts a feed-rs Person to our Author model.
 fn person_to_author(person: &Person) -> Author {
     Author {
@@ -589,7 +1127,7 @@ mod tests {
 
         let parsed = feed_rs::parser::parse(rss.as_bytes()).unwrap();
         let itunes_ext = parse_itunes_extensions(rss.as_bytes());
-        let feed_type = detect_feed_type(&parsed, &itunes_ext);
+        let feed_type = detect_feed_kind(&parsed, &itunes_ext).as_str();
         assert_eq!(feed_type, "article");
     }
 
@@ -606,10 +1144,34 @@ mod tests {
 
         let parsed = feed_rs::parser::parse(rss.as_bytes()).unwrap();
         let itunes_ext = parse_itunes_extensions(rss.as_bytes());
-        let feed_type = detect_feed_type(&parsed, &itunes_ext);
+        let feed_type = detect_feed_kind(&parsed, &itunes_ext).as_str();
         assert_eq!(feed_type, "podcast");
     }
 
+    #[test]
+    fn test_detect_feed_kind_video() {
+        // YouTube channel feed: yt namespace wins
+        let atom = r#"<?xml version="1.0"?>
+        <feed xmlns="http://www.w3.org/2005/Atom" xmlns:yt="http://www.youtube.com/xml/schemas/2015">
+            <title>Channel</title>
+            <entry><id>yt:video:abc</id><title>Clip</title></entry>
+        </feed>"#;
+        let parsed = feed_rs::parser::parse(atom.as_bytes()).unwrap();
+        let itunes_ext = parse_itunes_extensions(atom.as_bytes());
+        assert_eq!(detect_feed_kind(&parsed, &itunes_ext), FeedKind::Video);
+
+        // Mostly video enclosures
+        let rss = r#"<?xml version="1.0"?>
+        <rss version="2.0"><channel><title>V</title>
+            <item><title>1</title><enclosure url="https://e.com/1.mp4" type="video/mp4" length="1"/></item>
+            <item><title>2</title><enclosure url="https://e.com/2.mp4" type="video/mp4" length="1"/></item>
+            <item><title>3</title><enclosure url="https://e.com/3.mp3" type="audio/mpeg" length="1"/></item>
+        </channel></rss>"#;
+        let parsed = feed_rs::parser::parse(rss.as_bytes()).unwrap();
+        let itunes_ext = parse_itunes_extensions(rss.as_bytes());
+        assert_eq!(detect_feed_kind(&parsed, &itunes_ext), FeedKind::Video);
+    }
+
     #[test]
     fn test_extract_home_url() {
         let rss = r#"<?xml version="1.0"?>
@@ -640,10 +1202,76 @@ mod tests {
             },
         ];
 
-        let primary = select_primary_media(&enclosures);
+        let primary = select_primary_media(&enclosures, None);
         assert_eq!(primary, Some("https://example.com/audio.mp3".to_string()));
     }
 
+    #[test]
+    fn test_select_primary_media_video_size_cap() {
+        let video = |url: &str, length: u64| Enclosure {
+            url: url.to_string(),
+            mime_type: Some("video/mp4".to_string()),
+            length,
+        };
+        let image = Enclosure {
+            url: "https://example.com/cover.jpg".to_string(),
+            mime_type: Some("image/jpeg".to_string()),
+            length: 10,
+        };
+
+        let enclosures = vec![
+            image.clone(),
+            video("https://example.com/480p.mp4", 80_000_000),
+            video("https://example.com/4k.mp4", 2_000_000_000),
+            video("https://example.com/1080p.mp4", 300_000_000),
+            video("https://example.com/1080p-copy.mp4", 300_000_000),
+        ];
+        let primary = select_primary_media(&enclosures, None);
+        assert_eq!(primary.as_deref(), Some("https://example.com/1080p.mp4"));
+
+        let enclosures = vec![
+            image,
+            video("https://example.com/8k.mp4", 4_000_000_000),
+            video("https://example.com/4k.mp4", 2_000_000_000),
+        ];
+        let primary = select_primary_media(&enclosures, None);
+        assert_eq!(primary.as_deref(), Some("https://example.com/4k.mp4"));
+    }
+
+    #[test]
+    fn test_select_best_rendition_prefers_default_then_bitrate() {
+        let rendition = |url: &str, bitrate: u32, is_default: bool| MediaRendition {
+            url: url.to_string(),
+            mime_type: Some("video/mp4".to_string()),
+            bitrate: Some(bitrate),
+            is_default,
+            ..Default::default()
+        };
+        let thumb = MediaRendition {
+            url: "https://example.com/poster.jpg".to_string(),
+            medium: Some("image".to_string()),
+            bitrate: Some(9000),
+            is_default: true,
+            ..Default::default()
+        };
+
+        let renditions = vec![
+            thumb.clone(),
+            rendition("https://example.com/low.mp4", 400, false),
+            rendition("https://example.com/high.mp4", 2500, false),
+        ];
+        let best = select_best_rendition(&renditions).unwrap();
+        assert_eq!(best.url, "https://example.com/high.mp4");
+
+        let renditions = vec![
+            thumb,
+            rendition("https://example.com/mid.mp4", 1200, true),
+            rendition("https://example.com/high.mp4", 2500, false),
+        ];
+        let best = select_best_rendition(&renditions).unwrap();
+        assert_eq!(best.url, "https://example.com/mid.mp4");
+    }
+
     #[test]
     fn test_duration_parsing_mmss() {
         // Test that our duration parsing correctly handles MM:SS format
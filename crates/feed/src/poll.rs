@@ -0,0 +1,399 @@
+// ABOUTME: Adaptive polling scheduler that learns each feed's update cadence.
+// ABOUTME: Combines ttl/skipHours/skipDays with observed item frequency to compute next poll times.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::fetcher::{FeedFetchError, FeedFetchResult, FeedFetcher, FetchRequest, FetchResponse};
+use crate::models::Feed;
+
+/// Number of most recent dated items used to estimate posting frequency.
+const CADENCE_SAMPLE: usize = 20;
+
+const HOUR_MS: u64 = 60 * 60 * 1000;
+
+/// Bounds and defaults applied to every computed interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollPolicy {
+    /// Shortest interval ever suggested.
+    pub min_interval: Duration,
+    /// Longest interval ever suggested, including backoff.
+    pub max_interval: Duration,
+    /// Interval for feeds with no ttl and too few dated items to estimate cadence.
+    pub default_interval: Duration,
+}
+
+impl Default for PollPolicy {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(15 * 60),
+            max_interval: Duration::from_secs(24 * 60 * 60),
+            default_interval: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// Per-feed scheduling state. Serializable so callers can persist it between runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedCadence {
+    /// When the feed was last polled (ms since epoch); 0 if never.
+    pub last_polled_ms: u64,
+    /// Publisher refresh hint in minutes (RSS `<ttl>` or `sy:updatePeriod`/
+    /// `sy:updateFrequency`) from the last fetched copy.
+    pub ttl_minutes: Option<u32>,
+    /// UTC hours in which the feed asked not to be polled.
+    #[serde(default)]
+    pub skip_hours: Vec<u8>,
+    /// Day names on which the feed asked not to be polled.
+    #[serde(default)]
+    pub skip_days: Vec<String>,
+    /// Median gap between recent items, when at least two were dated.
+    pub median_item_gap_ms: Option<u64>,
+    /// Consecutive polls that found nothing new (304 or no new items).
+    pub unchanged_streak: u32,
+    /// Consecutive failed polls.
+    pub error_streak: u32,
+}
+
+/// Tracks polling cadence for a set of feeds and computes when each is due.
+///
+/// The base interval is half the median gap between recent items (so a feed
+/// posting every 6 hours is polled every 3), never shorter than the feed's ttl,
+/// clamped to the [`PollPolicy`] bounds. Unchanged polls stretch it by 50% each
+/// up to `max_interval`, and errors back off exponentially from `min_interval`.
+/// The resulting time is then moved past any skipHours/skipDays window.
+#[derive(Debug, Clone, Default)]
+pub struct PollScheduler {
+    policy: PollPolicy,
+    feeds: HashMap<String, FeedCadence>,
+}
+
+impl PollScheduler {
+    pub fn new(policy: PollPolicy) -> Self {
+        Self {
+            policy,
+            feeds: HashMap::new(),
+        }
+    }
+
+    /// Starts tracking `url`; a feed that was never polled is due immediately.
+    pub fn add_feed(&mut self, url: &str) {
+        self.feeds.entry(url.to_string()).or_default();
+    }
+
+    /// Stops tracking `url`.
+    pub fn remove_feed(&mut self, url: &str) {
+        self.feeds.remove(url);
+    }
+
+    /// Current state for `url`, for persistence.
+    pub fn cadence(&self, url: &str) -> Option<&FeedCadence> {
+        self.feeds.get(url)
+    }
+
+    /// Restores persisted state for `url`.
+    pub fn set_cadence(&mut self, url: &str, cadence: FeedCadence) {
+        self.feeds.insert(url.to_string(), cadence);
+    }
+
+    /// Records a successful fetch of `feed` at `now_ms`. `has_new_items` should be
+    /// false when nothing changed since the previous poll (e.g. per [`crate::diff_feeds`]).
+    pub fn record_fetched(&mut self, url: &str, feed: &Feed, has_new_items: bool, now_ms: u64) {
+        let cadence = self.feeds.entry(url.to_string()).or_default();
+        cadence.last_polled_ms = now_ms;
+        cadence.ttl_minutes = feed.refresh_hint_minutes();
+        cadence.skip_hours = feed.skip_hours.clone();
+        cadence.skip_days = feed.skip_days.clone();
+        cadence.median_item_gap_ms = median_item_gap_ms(feed).or(cadence.median_item_gap_ms);
+        cadence.error_streak = 0;
+        if has_new_items {
+            cadence.unchanged_streak = 0;
+        } else {
+            cadence.unchanged_streak = cadence.unchanged_streak.saturating_add(1);
+        }
+    }
+
+    /// Records a 304 Not Modified response at `now_ms`.
+    pub fn record_not_modified(&mut self, url: &str, now_ms: u64) {
+        let cadence = self.feeds.entry(url.to_string()).or_default();
+        cadence.last_polled_ms = now_ms;
+        cadence.error_streak = 0;
+        cadence.unchanged_streak = cadence.unchanged_streak.saturating_add(1);
+    }
+
+    /// Records a failed poll at `now_ms`.
+    pub fn record_error(&mut self, url: &str, now_ms: u64) {
+        let cadence = self.feeds.entry(url.to_string()).or_default();
+        cadence.last_polled_ms = now_ms;
+        cadence.error_streak = cadence.error_streak.saturating_add(1);
+    }
+
+    /// Interval to wait after the last poll of `url`, before skip windows are applied.
+    pub fn interval(&self, url: &str) -> Option<Duration> {
+        self.feeds
+            .get(url)
+            .map(|cadence| compute_interval(cadence, &self.policy))
+    }
+
+    /// When `url` should next be polled (ms since epoch). Feeds never polled are due at 0.
+    pub fn next_poll_ms(&self, url: &str) -> Option<u64> {
+        let cadence = self.feeds.get(url)?;
+        if cadence.last_polled_ms == 0 {
+            return Some(0);
+        }
+        let interval = compute_interval(cadence, &self.policy).as_millis() as u64;
+        Some(skip_blocked_hours(
+            cadence.last_polled_ms.saturating_add(interval),
+            &cadence.skip_hours,
+            &cadence.skip_days,
+        ))
+    }
+
+    /// Feeds due at `now_ms`, most overdue first.
+    pub fn due_feeds(&self, now_ms: u64) -> Vec<String> {
+        let mut due: Vec<(u64, &String)> = self
+            .feeds
+            .keys()
+            .filter_map(|url| self.next_poll_ms(url).map(|at| (at, url)))
+            .filter(|(at, _)| *at <= now_ms)
+            .collect();
+        due.sort();
+        due.into_iter().map(|(_, url)| url.clone()).collect()
+    }
+
+    /// Fetches every feed due at `now_ms` through `fetcher` and records the outcome.
+    ///
+    /// A fetched feed counts as changed when its newest item is newer than the
+    /// previous poll; callers that need exact item diffs should use
+    /// [`record_fetched`](Self::record_fetched) directly.
+    pub fn poll_due<T>(
+        &mut self,
+        fetcher: &mut FeedFetcher<T>,
+        now_ms: u64,
+    ) -> Vec<(String, Result<FeedFetchResult, FeedFetchError>)>
+    where
+        T: FnMut(&FetchRequest) -> Result<FetchResponse, String>,
+    {
+        let due = self.due_feeds(now_ms);
+        let mut results = Vec::with_capacity(due.len());
+        for url in due {
+            let previous_poll = self.feeds.get(&url).map_or(0, |c| c.last_polled_ms);
+            let result = fetcher.fetch(&url);
+            match &result {
+                Ok(FeedFetchResult::NotModified) => self.record_not_modified(&url, now_ms),
+                Ok(FeedFetchResult::Fetched(feed)) => {
+                    let newest = feed.items.iter().map(|i| i.published_ms).max();
+                    let has_new = previous_poll == 0 || newest.is_none_or(|ms| ms > previous_poll);
+                    self.record_fetched(&url, feed, has_new, now_ms);
+                }
+                Err(_) => self.record_error(&url, now_ms),
+            }
+            results.push((url, result));
+        }
+        results
+    }
+}
+
+/// Stateless interval suggestion for a freshly parsed feed.
+pub fn suggest_poll_interval(feed: &Feed, policy: &PollPolicy) -> Duration {
+    let cadence = FeedCadence {
+        ttl_minutes: feed.refresh_hint_minutes(),
+        median_item_gap_ms: median_item_gap_ms(feed),
+        ..Default::default()
+    };
+    compute_interval(&cadence, policy)
+}
+
+fn compute_interval(cadence: &FeedCadence, policy: &PollPolicy) -> Duration {
+    let min = policy.min_interval;
+    let max = policy.max_interval.max(min);
+
+    if cadence.error_streak > 0 {
+        let factor = 1u32 << cadence.error_streak.min(16);
+        return min.saturating_mul(factor).min(max);
+    }
+
+    let mut base = cadence
+        .median_item_gap_ms
+        .map(|gap| Duration::from_millis(gap / 2))
+        .unwrap_or(policy.default_interval);
+    if let Some(ttl) = cadence.ttl_minutes {
+        base = base.max(Duration::from_secs(u64::from(ttl) * 60));
+    }
+    let mut interval = base.clamp(min, max);
+
+    for _ in 0..cadence.unchanged_streak.min(16) {
+        interval = interval.saturating_add(interval / 2);
+        if interval >= max {
+            break;
+        }
+    }
+    interval.min(max)
+}
+
+/// Median gap between the most recent dated items, newest first.
+fn median_item_gap_ms(feed: &Feed) -> Option<u64> {
+    let mut times: Vec<u64> = feed
+        .items
+        .iter()
+        .map(|i| i.published_ms)
+        .filter(|ms| *ms > 0)
+        .collect();
+    times.sort_unstable_by(|a, b| b.cmp(a));
+    times.dedup();
+    times.truncate(CADENCE_SAMPLE);
+    let mut gaps: Vec<u64> = times.windows(2).map(|w| w[0] - w[1]).collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    gaps.sort_unstable();
+    Some(gaps[gaps.len() / 2])
+}
+
+/// Moves `at_ms` forward to the next hour not excluded by skipHours/skipDays.
+fn skip_blocked_hours(at_ms: u64, skip_hours: &[u8], skip_days: &[String]) -> u64 {
+    let blocked = |ms: u64| {
+        let Some(dt) = DateTime::<Utc>::from_timestamp_millis(ms as i64) else {
+            return false;
+        };
+        let day = dt.weekday().to_string();
+        skip_hours.contains(&(dt.hour() as u8))
+            || skip_days.iter().any(|d| {
+                d.trim()
+                    .get(..3)
+                    .is_some_and(|d| d.eq_ignore_ascii_case(&day))
+            })
+    };
+
+    let mut at = at_ms;
+    // A week of hours covers every skipHours/skipDays combination
+    for _ in 0..24 * 7 {
+        if !blocked(at) {
+            return at;
+        }
+        at = (at / HOUR_MS + 1) * HOUR_MS;
+    }
+    at_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FeedItem;
+
+    fn feed_with_gaps(newest_ms: u64, gap_ms: u64, count: u64) -> Feed {
+        Feed {
+            items: (0..count)
+                .map(|i| FeedItem {
+                    published_ms: newest_ms - i * gap_ms,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_interval_follows_item_frequency_and_ttl() {
+        let policy = PollPolicy::default();
+        let feed = feed_with_gaps(100 * HOUR_MS, 6 * HOUR_MS, 5);
+        assert_eq!(
+            suggest_poll_interval(&feed, &policy),
+            Duration::from_secs(3 * 3600)
+        );
+
+        let feed = Feed {
+            ttl_minutes: Some(300),
+            ..feed
+        };
+        assert_eq!(
+            suggest_poll_interval(&feed, &policy),
+            Duration::from_secs(5 * 3600)
+        );
+
+        // sy:updatePeriod stands in for a missing ttl
+        let feed = Feed {
+            ttl_minutes: None,
+            update_period: Some("daily".into()),
+            ..feed
+        };
+        assert_eq!(
+            suggest_poll_interval(&feed, &policy),
+            Duration::from_secs(24 * 3600)
+        );
+
+        // No dated items: default interval
+        assert_eq!(
+            suggest_poll_interval(&Feed::default(), &policy),
+            policy.default_interval
+        );
+    }
+
+    #[test]
+    fn test_unchanged_polls_and_errors_back_off() {
+        let mut scheduler = PollScheduler::default();
+        let url = "https://e.com/feed";
+        scheduler.add_feed(url);
+        assert_eq!(scheduler.next_poll_ms(url), Some(0));
+
+        scheduler.record_fetched(url, &Feed::default(), true, 10 * HOUR_MS);
+        assert_eq!(scheduler.next_poll_ms(url), Some(11 * HOUR_MS));
+
+        scheduler.record_not_modified(url, 11 * HOUR_MS);
+        assert_eq!(scheduler.interval(url), Some(Duration::from_secs(5400)));
+
+        scheduler.record_error(url, 12 * HOUR_MS);
+        scheduler.record_error(url, 12 * HOUR_MS);
+        assert_eq!(scheduler.interval(url), Some(Duration::from_secs(60 * 60)));
+        assert_eq!(scheduler.due_feeds(12 * HOUR_MS), Vec::<String>::new());
+        assert_eq!(scheduler.due_feeds(13 * HOUR_MS), vec![url.to_string()]);
+    }
+
+    #[test]
+    fn test_next_poll_skips_hours_and_days() {
+        // 2025-01-04 is a Saturday
+        let saturday_10h = DateTime::parse_from_rfc3339("2025-01-04T10:00:00Z")
+            .unwrap()
+            .timestamp_millis() as u64;
+        let mut scheduler = PollScheduler::default();
+        let feed = Feed {
+            skip_days: vec!["Saturday".into(), "Sunday".into()],
+            skip_hours: vec![0, 1],
+            ..Default::default()
+        };
+        scheduler.record_fetched("f", &feed, true, saturday_10h);
+
+        let next = scheduler.next_poll_ms("f").unwrap();
+        let monday_2h = DateTime::parse_from_rfc3339("2025-01-06T02:00:00Z")
+            .unwrap()
+            .timestamp_millis() as u64;
+        assert_eq!(next, monday_2h);
+    }
+
+    #[test]
+    fn test_poll_due_fetches_and_records() {
+        let rss = r#"<rss version="2.0"><channel><title>T</title><ttl>120</ttl><item><title>One</title></item></channel></rss>"#;
+        let mut fetcher = FeedFetcher::new(|_: &FetchRequest| {
+            Ok(FetchResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: rss.as_bytes().to_vec(),
+            })
+        });
+        let mut scheduler = PollScheduler::default();
+        scheduler.add_feed("https://e.com/feed");
+
+        let results = scheduler.poll_due(&mut fetcher, HOUR_MS);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1.is_ok());
+        let cadence = scheduler.cadence("https://e.com/feed").unwrap();
+        assert_eq!(cadence.ttl_minutes, Some(120));
+        assert_eq!(
+            scheduler.next_poll_ms("https://e.com/feed"),
+            Some(3 * HOUR_MS)
+        );
+    }
+}
@@ -0,0 +1,242 @@
+// ABOUTME: Best-effort repair of malformed feed XML before a retry parse.
+// ABOUTME: Escapes bare ampersands, maps HTML entities, strips invalid chars, and closes truncated elements.
+
+use markup5ever::data::NAMED_ENTITIES;
+
+/// The five entities XML defines itself; every other name is an HTML-ism.
+const XML_ENTITIES: &[&str] = &["amp", "lt", "gt", "quot", "apos"];
+
+/// Rewrites common feed breakage into well-formed XML.
+///
+/// - Characters XML forbids (C0 controls other than tab/newline/CR, U+FFFE,
+///   U+FFFF) are dropped and invalid UTF-8 is replaced.
+/// - A bare `&` becomes `&amp;`; HTML named entities (`&nbsp;`, `&mdash;`)
+///   become numeric references. CDATA sections and comments are untouched.
+/// - A truncated tail is cut back to the last complete tag, an open CDATA
+///   section or comment is terminated, and still-open elements are closed.
+///   Unmatched end tags are dropped.
+///
+/// Returns `None` when nothing needed repairing.
+pub fn repair_xml(data: &[u8]) -> Option<Vec<u8>> {
+    let text = String::from_utf8_lossy(data);
+    let cleaned: String = text.chars().filter(|&c| is_xml_char(c)).collect();
+    let mut out = String::with_capacity(cleaned.len() + 64);
+    let mut open: Vec<String> = Vec::new();
+    let mut rest = cleaned.as_str();
+
+    loop {
+        let Some(pos) = rest.find(['<', '&']) else {
+            out.push_str(rest);
+            break;
+        };
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if rest.starts_with('&') {
+            let (replacement, consumed) = repair_reference(rest);
+            out.push_str(&replacement);
+            rest = &rest[consumed..];
+            continue;
+        }
+
+        // Markup that must be copied verbatim up to its terminator
+        if let Some((start, end)) = [("<![CDATA[", "]]>"), ("<!--", "-->"), ("<?", "?>")]
+            .into_iter()
+            .find(|(start, _)| rest.starts_with(start))
+        {
+            match rest[start.len()..].find(end) {
+                Some(n) => {
+                    let len = start.len() + n + end.len();
+                    out.push_str(&rest[..len]);
+                    rest = &rest[len..];
+                }
+                None => {
+                    // Truncated inside the section: keep what is there and terminate it
+                    out.push_str(rest);
+                    out.push_str(end);
+                    rest = "";
+                }
+            }
+            continue;
+        }
+
+        let name_start = rest[1..].starts_with(|c: char| c.is_alphabetic() || c == '_' || c == ':');
+        if !name_start && !rest.starts_with("</") && !rest.starts_with("<!") {
+            // A `<` that does not start markup (`a < b`)
+            out.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        }
+
+        let Some(tag_len) = find_tag_end(rest) else {
+            // Truncated tag: drop the partial tag
+            break;
+        };
+        let tag = &rest[..tag_len];
+        if name_start {
+            out.push_str(&escape_tag_ampersands(tag));
+            if !tag.ends_with("/>") {
+                open.push(tag_name(&tag[1..]).to_string());
+            }
+        } else if let Some(name) = rest.strip_prefix("</") {
+            // Close elements left open inside this one; drop closers with no opener
+            let name = tag_name(name);
+            if let Some(idx) = open.iter().rposition(|n| n == name) {
+                for inner in open.drain(idx..).skip(1).rev() {
+                    out.push_str("</");
+                    out.push_str(&inner);
+                    out.push('>');
+                }
+                out.push_str(tag);
+            }
+        } else {
+            out.push_str(tag);
+        }
+        rest = &rest[tag_len..];
+    }
+
+    for name in open.iter().rev() {
+        out.push_str("</");
+        out.push_str(name);
+        out.push('>');
+    }
+
+    (out.as_bytes() != data).then(|| out.into_bytes())
+}
+
+/// Returns true for characters allowed in an XML 1.0 document.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r') || (c >= ' ' && c != '\u{FFFE}' && c != '\u{FFFF}')
+}
+
+/// Returns the byte length of the tag starting at `s` (through its `>`),
+/// skipping quoted attribute values, or `None` when it is unterminated.
+fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices().skip(1) {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return Some(i + 1),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Element name at the start of a tag body (after `<` or `</`).
+fn tag_name(s: &str) -> &str {
+    let end = s
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Escapes bare ampersands inside attribute values of a start tag.
+fn escape_tag_ampersands(tag: &str) -> String {
+    let mut out = String::with_capacity(tag.len());
+    let mut rest = tag;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        let (replacement, consumed) = repair_reference(&rest[pos..]);
+        out.push_str(&replacement);
+        rest = &rest[pos + consumed..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Repairs the reference at the start of `s` (which begins with `&`),
+/// returning its replacement and the number of bytes consumed.
+fn repair_reference(s: &str) -> (String, usize) {
+    let body = &s[1..];
+    let len = body
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '#'))
+        .unwrap_or(body.len());
+    let name = &body[..len];
+    if len == 0 || !body[len..].starts_with(';') {
+        return ("&amp;".to_string(), 1);
+    }
+
+    if let Some(numeric) = name.strip_prefix('#') {
+        let valid = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            None => !numeric.is_empty() && numeric.chars().all(|c| c.is_ascii_digit()),
+        };
+        return if valid {
+            (s[..len + 2].to_string(), len + 2)
+        } else {
+            ("&amp;".to_string(), 1)
+        };
+    }
+    if XML_ENTITIES.contains(&name) {
+        return (s[..len + 2].to_string(), len + 2);
+    }
+    match NAMED_ENTITIES.get(format!("{};", name).as_str()) {
+        Some(&(first, second)) if first != 0 => {
+            let mut refs = format!("&#{};", first);
+            if second != 0 {
+                refs.push_str(&format!("&#{};", second));
+            }
+            (refs, len + 2)
+        }
+        _ => ("&amp;".to_string(), 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repair(s: &str) -> String {
+        String::from_utf8(repair_xml(s.as_bytes()).unwrap_or_else(|| s.as_bytes().to_vec()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_well_formed_input_is_unchanged() {
+        let xml = r#"<?xml version="1.0"?><rss><channel><title>A &amp; B &#8217; &lt;</title><!-- & --><description><![CDATA[x & y]]></description></channel></rss>"#;
+        assert!(repair_xml(xml.as_bytes()).is_none());
+    }
+
+    #[test]
+    fn test_escapes_bare_ampersands_and_html_entities() {
+        assert_eq!(
+            repair("<t>Tom & Jerry&nbsp;&mdash; R&D &amp; co &bogus; &#xZZ;</t>"),
+            "<t>Tom &amp; Jerry&#160;&#8212; R&amp;D &amp; co &amp;bogus; &amp;#xZZ;</t>"
+        );
+        assert_eq!(
+            repair(r#"<link href="https://e.com/?a=1&b=2"/>"#),
+            r#"<link href="https://e.com/?a=1&amp;b=2"/>"#
+        );
+        assert_eq!(repair("<t>1 < 2</t>"), "<t>1 &lt; 2</t>");
+        // A quote after a stray `<` must not swallow the rest of the document
+        assert_eq!(
+            repair("<c><i><p>1 < 2 isn't it</p></i><i><p>two</p></i></c>"),
+            "<c><i><p>1 &lt; 2 isn't it</p></i><i><p>two</p></i></c>"
+        );
+    }
+
+    #[test]
+    fn test_strips_invalid_characters() {
+        assert_eq!(repair("<t>a\u{0}b\u{1b}c\td</t>"), "<t>abc\td</t>");
+        let bytes = b"<t>caf\xe9</t>";
+        let repaired = String::from_utf8(repair_xml(bytes).unwrap()).unwrap();
+        assert_eq!(repaired, "<t>caf\u{FFFD}</t>");
+    }
+
+    #[test]
+    fn test_closes_truncated_document() {
+        assert_eq!(
+            repair("<rss><channel><item><title>One</title><desc"),
+            "<rss><channel><item><title>One</title></item></channel></rss>"
+        );
+        assert_eq!(
+            repair("<rss><channel><item><description><![CDATA[cut off"),
+            "<rss><channel><item><description><![CDATA[cut off]]></description></item></channel></rss>"
+        );
+        // Mismatched closers close the elements left open inside them
+        assert_eq!(repair("<a><b><c></b></x>"), "<a><b><c></c></b></a>");
+    }
+}
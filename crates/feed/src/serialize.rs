@@ -0,0 +1,508 @@
+// ABOUTME: Writes a Feed model back out as RSS 2.0, Atom 1.0, or JSON Feed 1.1.
+// ABOUTME: Enables parse → filter/merge → republish pipelines without another feed library.
+
+use chrono::{DateTime, Utc};
+use quick_xml::escape::escape;
+use serde_json::{json, Map, Value};
+
+use crate::models::{Author, Feed, FeedItem};
+
+/// Output format for [`serialize_feed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    JsonFeed,
+}
+
+/// Serializes a feed in the requested format.
+pub fn serialize_feed(feed: &Feed, format: FeedFormat) -> String {
+    match format {
+        FeedFormat::Rss => to_rss(feed),
+        FeedFormat::Atom => to_atom(feed),
+        FeedFormat::JsonFeed => to_json_feed(feed),
+    }
+}
+
+/// Renders a feed as an RSS 2.0 document.
+pub fn to_rss(feed: &Feed) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+    out.push_str("  <channel>\n");
+    push_element(&mut out, 4, "title", &feed.title);
+    push_element(&mut out, 4, "link", &feed.home_url);
+    push_element(&mut out, 4, "description", &channel_description(feed));
+    let self_url = self_url(feed);
+    if !self_url.is_empty() {
+        push_xml(
+            &mut out,
+            4,
+            &format!(
+                "<atom:link rel=\"self\" type=\"application/rss+xml\" href=\"{}\"/>",
+                escape(self_url)
+            ),
+        );
+    }
+    if let Some(hub) = non_empty(&feed.hub_url) {
+        push_xml(
+            &mut out,
+            4,
+            &format!("<atom:link rel=\"hub\" href=\"{}\"/>", escape(hub)),
+        );
+    }
+    push_opt_element(&mut out, 4, "language", feed.language.as_deref());
+    push_opt_element(&mut out, 4, "copyright", feed.copyright.as_deref());
+    push_opt_element(&mut out, 4, "generator", feed.generator.as_deref());
+    push_opt_element(
+        &mut out,
+        4,
+        "pubDate",
+        rfc2822(feed.published_ms).as_deref(),
+    );
+    push_opt_element(
+        &mut out,
+        4,
+        "lastBuildDate",
+        rfc2822(feed.updated_ms).as_deref(),
+    );
+    if let Some(image) = non_empty(&feed.image_url) {
+        push_xml(&mut out, 4, "<image>");
+        push_element(&mut out, 6, "url", image);
+        push_element(&mut out, 6, "title", &feed.title);
+        push_element(&mut out, 6, "link", &feed.home_url);
+        push_xml(&mut out, 4, "</image>");
+    }
+
+    for item in &feed.items {
+        push_xml(&mut out, 4, "<item>");
+        push_element(&mut out, 6, "title", &item.title);
+        push_element(&mut out, 6, "link", &item.url);
+        let guid = item_id(item);
+        if !guid.is_empty() {
+            let permalink = if guid == item.url { "true" } else { "false" };
+            push_xml(
+                &mut out,
+                6,
+                &format!(
+                    "<guid isPermaLink=\"{}\">{}</guid>",
+                    permalink,
+                    escape(guid)
+                ),
+            );
+        }
+        push_element(&mut out, 6, "description", &item_summary(item));
+        push_opt_element(
+            &mut out,
+            6,
+            "pubDate",
+            rfc2822(item.published_ms).as_deref(),
+        );
+        if let Some(name) = author_name(item.author.as_ref()) {
+            push_element(&mut out, 6, "dc:creator", name);
+        }
+        for category in &item.categories {
+            push_element(&mut out, 6, "category", category);
+        }
+        for enclosure in &item.enclosures {
+            push_xml(
+                &mut out,
+                6,
+                &format!(
+                    "<enclosure url=\"{}\" length=\"{}\" type=\"{}\"/>",
+                    escape(enclosure.url.as_str()),
+                    enclosure.length,
+                    escape(enclosure.mime_type.as_deref().unwrap_or(""))
+                ),
+            );
+        }
+        push_xml(&mut out, 4, "</item>");
+    }
+
+    out.push_str("  </channel>\n</rss>\n");
+    out
+}
+
+/// Renders a feed as an Atom 1.0 document.
+pub fn to_atom(feed: &Feed) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match feed.language.as_deref().and_then(non_empty_str) {
+        Some(lang) => out.push_str(&format!(
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">\n",
+            escape(lang)
+        )),
+        None => out.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n"),
+    }
+    let self_url = self_url(feed);
+    let feed_id = if self_url.is_empty() {
+        &feed.home_url
+    } else {
+        self_url
+    };
+    push_element(&mut out, 2, "id", feed_id);
+    push_element(&mut out, 2, "title", &feed.title);
+    push_opt_element(&mut out, 2, "subtitle", non_empty_str(&feed.description));
+    push_element(
+        &mut out,
+        2,
+        "updated",
+        &rfc3339(feed.updated_ms).unwrap_or_else(rfc3339_now),
+    );
+    push_link(&mut out, 2, "alternate", &feed.home_url);
+    push_link(&mut out, 2, "self", self_url);
+    if let Some(hub) = non_empty(&feed.hub_url) {
+        push_link(&mut out, 2, "hub", hub);
+    }
+    push_atom_author(&mut out, 2, feed.author.as_ref());
+    push_opt_element(&mut out, 2, "rights", feed.copyright.as_deref());
+    push_opt_element(&mut out, 2, "generator", feed.generator.as_deref());
+    push_opt_element(&mut out, 2, "logo", non_empty(&feed.image_url));
+
+    for item in &feed.items {
+        push_xml(&mut out, 2, "<entry>");
+        push_element(&mut out, 4, "id", item_id(item));
+        push_element(&mut out, 4, "title", &item.title);
+        push_link(&mut out, 4, "alternate", &item.url);
+        let updated = if item.updated_ms != 0 {
+            item.updated_ms
+        } else {
+            item.published_ms
+        };
+        push_element(
+            &mut out,
+            4,
+            "updated",
+            &rfc3339(updated).unwrap_or_else(rfc3339_now),
+        );
+        push_opt_element(
+            &mut out,
+            4,
+            "published",
+            rfc3339(item.published_ms).as_deref(),
+        );
+        push_atom_author(&mut out, 4, item.author.as_ref());
+        push_opt_element(&mut out, 4, "summary", non_empty_str(&item.summary));
+        push_opt_element(&mut out, 4, "content", non_empty_str(&item.content));
+        for category in &item.categories {
+            push_xml(
+                &mut out,
+                4,
+                &format!("<category term=\"{}\"/>", escape(category.as_str())),
+            );
+        }
+        for enclosure in &item.enclosures {
+            let mut link = format!(
+                "<link rel=\"enclosure\" href=\"{}\"",
+                escape(enclosure.url.as_str())
+            );
+            if let Some(mime) = enclosure.mime_type.as_deref() {
+                link.push_str(&format!(" type=\"{}\"", escape(mime)));
+            }
+            if enclosure.length > 0 {
+                link.push_str(&format!(" length=\"{}\"", enclosure.length));
+            }
+            link.push_str("/>");
+            push_xml(&mut out, 4, &link);
+        }
+        push_xml(&mut out, 2, "</entry>");
+    }
+
+    out.push_str("</feed>\n");
+    out
+}
+
+/// Renders a feed as a JSON Feed 1.1 document.
+pub fn to_json_feed(feed: &Feed) -> String {
+    let mut root = Map::new();
+    root.insert("version".into(), json!("https://jsonfeed.org/version/1.1"));
+    root.insert("title".into(), json!(feed.title));
+    insert_str(&mut root, "home_page_url", &feed.home_url);
+    insert_str(&mut root, "feed_url", self_url(feed));
+    insert_str(&mut root, "description", &feed.description);
+    insert_str(&mut root, "icon", feed.image_url.as_deref().unwrap_or(""));
+    insert_str(
+        &mut root,
+        "language",
+        feed.language.as_deref().unwrap_or(""),
+    );
+    if let Some(author) = json_author(feed.author.as_ref()) {
+        root.insert("authors".into(), json!([author]));
+    }
+    if let Some(hub) = non_empty(&feed.hub_url) {
+        root.insert("hubs".into(), json!([{ "type": "WebSub", "url": hub }]));
+    }
+
+    let items: Vec<Value> = feed.items.iter().map(json_item).collect();
+    root.insert("items".into(), Value::Array(items));
+
+    serde_json::to_string_pretty(&Value::Object(root)).unwrap_or_default()
+}
+
+fn json_item(item: &FeedItem) -> Value {
+    let mut obj = Map::new();
+    obj.insert("id".into(), json!(item_id(item)));
+    insert_str(&mut obj, "url", &item.url);
+    insert_str(&mut obj, "title", &item.title);
+    // JSON Feed requires content_html or content_text
+    let text = if item.content.is_empty() {
+        &item.summary
+    } else {
+        &item.content
+    };
+    obj.insert("content_text".into(), json!(text));
+    insert_str(&mut obj, "summary", &item.summary);
+    insert_str(&mut obj, "image", item.image_url.as_deref().unwrap_or(""));
+    if let Some(published) = rfc3339(item.published_ms) {
+        obj.insert("date_published".into(), json!(published));
+    }
+    if let Some(modified) = rfc3339(item.updated_ms) {
+        obj.insert("date_modified".into(), json!(modified));
+    }
+    if let Some(author) = json_author(item.author.as_ref()) {
+        obj.insert("authors".into(), json!([author]));
+    }
+    if !item.categories.is_empty() {
+        obj.insert("tags".into(), json!(item.categories));
+    }
+    if !item.enclosures.is_empty() {
+        let attachments: Vec<Value> = item
+            .enclosures
+            .iter()
+            .map(|e| {
+                let mut a = Map::new();
+                a.insert("url".into(), json!(e.url));
+                a.insert(
+                    "mime_type".into(),
+                    json!(e.mime_type.as_deref().unwrap_or("application/octet-stream")),
+                );
+                if e.length > 0 {
+                    a.insert("size_in_bytes".into(), json!(e.length));
+                }
+                Value::Object(a)
+            })
+            .collect();
+        obj.insert("attachments".into(), Value::Array(attachments));
+    }
+    Value::Object(obj)
+}
+
+fn json_author(author: Option<&Author>) -> Option<Value> {
+    let author = author?;
+    let mut obj = Map::new();
+    insert_str(&mut obj, "name", author.name.as_deref().unwrap_or(""));
+    insert_str(&mut obj, "url", author.uri.as_deref().unwrap_or(""));
+    (!obj.is_empty()).then_some(Value::Object(obj))
+}
+
+fn insert_str(obj: &mut Map<String, Value>, key: &str, value: &str) {
+    if !value.is_empty() {
+        obj.insert(key.into(), json!(value));
+    }
+}
+
+/// The item's stable id: guid, falling back to the item URL.
+fn item_id(item: &FeedItem) -> &str {
+    if item.guid.is_empty() {
+        &item.url
+    } else {
+        &item.guid
+    }
+}
+
+/// The canonical feed URL: declared rel="self", falling back to the fetch URL.
+fn self_url(feed: &Feed) -> &str {
+    non_empty(&feed.self_url).unwrap_or(&feed.feed_url)
+}
+
+/// RSS requires a channel description; prefer the sanitized HTML form when present.
+fn channel_description(feed: &Feed) -> String {
+    feed.description_html
+        .clone()
+        .unwrap_or_else(|| feed.description.clone())
+}
+
+/// Items store plain-text summaries; fall back to content when the summary is empty.
+fn item_summary(item: &FeedItem) -> String {
+    if item.summary.is_empty() {
+        item.content.clone()
+    } else {
+        item.summary.clone()
+    }
+}
+
+fn author_name(author: Option<&Author>) -> Option<&str> {
+    author?.name.as_deref().and_then(non_empty_str)
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().and_then(non_empty_str)
+}
+
+fn non_empty_str(value: &str) -> Option<&str> {
+    (!value.is_empty()).then_some(value)
+}
+
+fn timestamp(ms: u64) -> Option<DateTime<Utc>> {
+    if ms == 0 {
+        return None;
+    }
+    DateTime::<Utc>::from_timestamp_millis(ms as i64)
+}
+
+fn rfc2822(ms: u64) -> Option<String> {
+    timestamp(ms).map(|dt| dt.to_rfc2822())
+}
+
+fn rfc3339(ms: u64) -> Option<String> {
+    timestamp(ms).map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+}
+
+fn rfc3339_now() -> String {
+    Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn push_xml(out: &mut String, indent: usize, xml: &str) {
+    out.push_str(&" ".repeat(indent));
+    out.push_str(xml);
+    out.push('\n');
+}
+
+fn push_element(out: &mut String, indent: usize, name: &str, text: &str) {
+    push_xml(
+        out,
+        indent,
+        &format!("<{}>{}</{}>", name, escape(text), name),
+    );
+}
+
+fn push_opt_element(out: &mut String, indent: usize, name: &str, text: Option<&str>) {
+    if let Some(text) = text {
+        push_element(out, indent, name, text);
+    }
+}
+
+fn push_link(out: &mut String, indent: usize, rel: &str, href: &str) {
+    if !href.is_empty() {
+        push_xml(
+            out,
+            indent,
+            &format!("<link rel=\"{}\" href=\"{}\"/>", rel, escape(href)),
+        );
+    }
+}
+
+fn push_atom_author(out: &mut String, indent: usize, author: Option<&Author>) {
+    let Some(name) = author_name(author) else {
+        return;
+    };
+    push_xml(out, indent, "<author>");
+    push_element(out, indent + 2, "name", name);
+    if let Some(email) = author
+        .and_then(|a| a.email.as_deref())
+        .and_then(non_empty_str)
+    {
+        push_element(out, indent + 2, "email", email);
+    }
+    if let Some(uri) = author
+        .and_then(|a| a.uri.as_deref())
+        .and_then(non_empty_str)
+    {
+        push_element(out, indent + 2, "uri", uri);
+    }
+    push_xml(out, indent, "</author>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Enclosure;
+    use crate::parser::parse_feed_bytes;
+
+    fn sample_feed() -> Feed {
+        Feed {
+            title: "Tom & Jerry's Blog".into(),
+            home_url: "https://example.com/".into(),
+            feed_url: "https://example.com/feed.xml".into(),
+            description: "Cats <and> mice".into(),
+            language: Some("en".into()),
+            author: Some(Author {
+                name: Some("Tom".into()),
+                ..Default::default()
+            }),
+            updated_ms: 1_705_312_800_000,
+            items: vec![FeedItem {
+                title: "Chase scene".into(),
+                url: "https://example.com/chase".into(),
+                guid: "chase-1".into(),
+                summary: "A chase & a crash".into(),
+                published_ms: 1_705_312_800_000,
+                categories: vec!["cartoons".into()],
+                author: Some(Author {
+                    name: Some("Jerry".into()),
+                    ..Default::default()
+                }),
+                enclosures: vec![Enclosure {
+                    url: "https://cdn.example.com/chase.mp3".into(),
+                    mime_type: Some("audio/mpeg".into()),
+                    length: 1234,
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn assert_round_trip(format: FeedFormat) {
+        let original = sample_feed();
+        let out = serialize_feed(&original, format);
+        let parsed = parse_feed_bytes(out.as_bytes(), "https://example.com/feed.xml")
+            .unwrap_or_else(|e| panic!("{:?} output did not parse: {}\n{}", format, e, out));
+
+        assert_eq!(parsed.title, original.title, "{:?}", format);
+        assert_eq!(parsed.items.len(), 1, "{:?}", format);
+        let item = &parsed.items[0];
+        assert_eq!(item.title, "Chase scene", "{:?}", format);
+        assert_eq!(item.url, "https://example.com/chase", "{:?}", format);
+        assert_eq!(item.guid, "chase-1", "{:?}", format);
+        assert_eq!(item.published_ms, 1_705_312_800_000, "{:?}", format);
+        assert_eq!(item.categories, vec!["cartoons"], "{:?}", format);
+        assert_eq!(
+            item.author.as_ref().and_then(|a| a.name.as_deref()),
+            Some("Jerry"),
+            "{:?}",
+            format
+        );
+        // feed-rs maps JSON Feed attachments to plain links, so only XML enclosures round-trip
+        if format == FeedFormat::JsonFeed {
+            assert!(out.contains("\"size_in_bytes\": 1234"));
+        } else {
+            assert_eq!(
+                item.primary_media_url.as_deref(),
+                Some("https://cdn.example.com/chase.mp3"),
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_rss_round_trip() {
+        assert_round_trip(FeedFormat::Rss);
+    }
+
+    #[test]
+    fn test_atom_round_trip() {
+        assert_round_trip(FeedFormat::Atom);
+    }
+
+    #[test]
+    fn test_json_feed_round_trip() {
+        assert_round_trip(FeedFormat::JsonFeed);
+    }
+
+    #[test]
+    fn test_rss_escapes_text() {
+        let rss = to_rss(&sample_feed());
+        assert!(rss.contains("<title>Tom &amp; Jerry&apos;s Blog</title>"));
+        assert!(rss.contains("<description>Cats &lt;and&gt; mice</description>"));
+    }
+}
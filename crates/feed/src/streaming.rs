@@ -0,0 +1,425 @@
+// ABOUTME: Streaming feed item parser for very large RSS/Atom/RDF documents.
+// ABOUTME: Pulls one <item>/<entry> at a time with quick-xml so memory stays bounded by item size.
+
+use std::io::BufRead;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use quick_xml::writer::Writer;
+
+use crate::error::FeedError;
+use crate::models::FeedItem;
+use crate::parser::parse_feed_bytes;
+
+/// Channel-level text element whose content the stream remembers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChannelText {
+    Language,
+    Link,
+}
+
+/// Root element family of the document being streamed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RootKind {
+    Rss,
+    Atom,
+    Rdf,
+}
+
+/// Iterator over the items of a feed read incrementally from `R`.
+///
+/// Each `<item>` (RSS/RDF) or `<entry>` (Atom) is captured on its own and mapped
+/// with the same rules as [`parse_feed_bytes`], so only one item's XML is held in
+/// memory at a time. Iteration ends at end of input, after `max_items` items, or
+/// after the first error.
+pub struct FeedItemStream<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    feed_url: String,
+    encoding: Option<String>,
+    root: Option<(RootKind, Vec<(String, String)>)>,
+    channel_language: Option<String>,
+    channel_link: Option<String>,
+    in_text: Option<ChannelText>,
+    max_items: Option<usize>,
+    emitted: usize,
+    done: bool,
+}
+
+/// Streams feed items from a reader, stopping after `max_items` when set.
+///
+/// `feed_url` plays the same role as in [`parse_feed_bytes`]: relative URLs in
+/// item content resolve against the item link, else the channel link, else it.
+pub fn stream_feed_items<R: BufRead>(
+    reader: R,
+    feed_url: &str,
+    max_items: Option<usize>,
+) -> FeedItemStream<R> {
+    FeedItemStream {
+        reader: Reader::from_reader(reader),
+        buf: Vec::new(),
+        feed_url: feed_url.to_string(),
+        encoding: None,
+        root: None,
+        channel_language: None,
+        channel_link: None,
+        in_text: None,
+        max_items,
+        emitted: 0,
+        done: false,
+    }
+}
+
+impl<R: BufRead> FeedItemStream<R> {
+    fn fail(&mut self, err: FeedError) -> Option<Result<FeedItem, FeedError>> {
+        self.done = true;
+        Some(Err(err))
+    }
+
+    /// Remembers an Atom feed's `alternate` link, the base for relative content URLs.
+    fn note_atom_link(&mut self, e: &BytesStart) {
+        if self.root.as_ref().map(|(kind, _)| *kind) != Some(RootKind::Atom) {
+            return;
+        }
+        let attrs = element_attributes(e);
+        let attr = |name: &str| {
+            attrs
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.as_str())
+        };
+        if matches!(attr("rel"), None | Some("alternate")) {
+            self.channel_link = attr("href").map(str::to_string);
+        }
+    }
+
+    /// Copies events from the current item start to its matching end.
+    fn capture_item(&mut self, start: BytesStart<'static>) -> Result<Vec<u8>, FeedError> {
+        let mut writer = Writer::new(Vec::new());
+        let end_name = start.name().as_ref().to_vec();
+        writer
+            .write_event(Event::Start(start))
+            .map_err(FeedError::parse)?;
+        let mut depth = 1usize;
+        let mut buf = Vec::new();
+        loop {
+            let event = self
+                .reader
+                .read_event_into(&mut buf)
+                .map_err(FeedError::parse)?;
+            match &event {
+                Event::Start(_) => depth += 1,
+                Event::End(e) => {
+                    depth -= 1;
+                    if depth == 0 && e.name().as_ref() == end_name.as_slice() {
+                        writer.write_event(event).map_err(FeedError::parse)?;
+                        return Ok(writer.into_inner());
+                    }
+                }
+                Event::Eof => return Err(FeedError::invalid("unexpected end of feed inside item")),
+                _ => {}
+            }
+            writer.write_event(event).map_err(FeedError::parse)?;
+            buf.clear();
+        }
+    }
+
+    /// Wraps a captured item in a minimal document of the same flavor and maps it.
+    ///
+    /// The item keeps its original bytes; the wrapper repeats the document's
+    /// declared encoding so [`parse_feed_bytes`] decodes it like the full feed.
+    fn map_item(&self, item_xml: &[u8]) -> Result<FeedItem, FeedError> {
+        let (kind, attrs) = self
+            .root
+            .as_ref()
+            .ok_or_else(|| FeedError::invalid("item found before feed root element"))?;
+        let attrs: String = attrs
+            .iter()
+            .map(|(k, v)| format!(" {}=\"{}\"", k, quick_xml::escape::escape(v.as_str())))
+            .collect();
+        let language = self
+            .channel_language
+            .as_deref()
+            .map(|l| format!("<language>{}</language>", quick_xml::escape::escape(l)))
+            .unwrap_or_default();
+        let link = self.channel_link.as_deref().map(quick_xml::escape::escape);
+        let (head, tail) = match kind {
+            RootKind::Rss => (
+                format!(
+                    "<rss{}><channel><title>stream</title>{}{}",
+                    attrs,
+                    link.map(|l| format!("<link>{}</link>", l))
+                        .unwrap_or_default(),
+                    language
+                ),
+                "</channel></rss>",
+            ),
+            RootKind::Atom => (
+                format!(
+                    "<feed{}><title>stream</title>{}",
+                    attrs,
+                    link.map(|l| format!("<link rel=\"alternate\" href=\"{}\"/>", l))
+                        .unwrap_or_default()
+                ),
+                "</feed>",
+            ),
+            RootKind::Rdf => (
+                format!(
+                    "<rdf:RDF{}><channel rdf:about=\"\"><title>stream</title>{}</channel>",
+                    attrs,
+                    link.map(|l| format!("<link>{}</link>", l))
+                        .unwrap_or_default()
+                ),
+                "</rdf:RDF>",
+            ),
+        };
+        let declaration = self
+            .encoding
+            .as_deref()
+            .map(|e| format!("<?xml version=\"1.0\" encoding=\"{}\"?>", e))
+            .unwrap_or_default();
+
+        let mut doc =
+            Vec::with_capacity(declaration.len() + head.len() + item_xml.len() + tail.len());
+        doc.extend_from_slice(declaration.as_bytes());
+        doc.extend_from_slice(head.as_bytes());
+        doc.extend_from_slice(item_xml);
+        doc.extend_from_slice(tail.as_bytes());
+        let mut feed = parse_feed_bytes(&doc, &self.feed_url)?;
+        feed.items
+            .pop()
+            .ok_or_else(|| FeedError::invalid("item could not be mapped"))
+    }
+
+    /// Captures (or, when self-closing, takes) an item and maps it.
+    fn emit_item(
+        &mut self,
+        start: BytesStart<'static>,
+        empty: bool,
+    ) -> Option<Result<FeedItem, FeedError>> {
+        let captured = if empty {
+            let mut writer = Writer::new(Vec::new());
+            match writer.write_event(Event::Empty(start)) {
+                Ok(()) => writer.into_inner(),
+                Err(err) => return self.fail(FeedError::parse(err)),
+            }
+        } else {
+            match self.capture_item(start) {
+                Ok(xml) => xml,
+                Err(err) => return self.fail(err),
+            }
+        };
+        match self.map_item(&captured) {
+            Ok(item) => {
+                self.emitted += 1;
+                Some(Ok(item))
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for FeedItemStream<R> {
+    type Item = Result<FeedItem, FeedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.max_items.is_some_and(|max| self.emitted >= max) {
+            return None;
+        }
+        loop {
+            self.buf.clear();
+            let event = match self.reader.read_event_into(&mut self.buf) {
+                Ok(event) => event.into_owned(),
+                Err(e) => return self.fail(FeedError::parse(e)),
+            };
+            match event {
+                Event::Decl(d) => {
+                    self.encoding = d
+                        .encoding()
+                        .and_then(Result::ok)
+                        .map(|e| String::from_utf8_lossy(&e).into_owned());
+                }
+                Event::Start(e) | Event::Empty(e) if self.root.is_none() => {
+                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                    match root_kind(&name) {
+                        Some(kind) => self.root = Some((kind, element_attributes(&e))),
+                        None => {
+                            return self.fail(FeedError::invalid(format!(
+                                "unsupported feed root <{}>",
+                                name
+                            )))
+                        }
+                    }
+                }
+                Event::Start(e) => match e.name().as_ref() {
+                    b"item" | b"entry" => return self.emit_item(e, false),
+                    b"language" | b"dc:language" => self.in_text = Some(ChannelText::Language),
+                    b"link" => {
+                        self.note_atom_link(&e);
+                        if self.channel_link.is_none() {
+                            self.in_text = Some(ChannelText::Link);
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Empty(e) => match e.name().as_ref() {
+                    b"item" | b"entry" => return self.emit_item(e, true),
+                    b"link" => self.note_atom_link(&e),
+                    _ => {}
+                },
+                Event::Text(t) => {
+                    let Some(field) = self.in_text else {
+                        continue;
+                    };
+                    let text = t.decode().map(|s| s.trim().to_string()).unwrap_or_default();
+                    if !text.is_empty() {
+                        match field {
+                            ChannelText::Language => self.channel_language = Some(text),
+                            ChannelText::Link => self.channel_link = Some(text),
+                        }
+                    }
+                }
+                Event::End(_) => self.in_text = None,
+                Event::Eof => {
+                    self.done = true;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn root_kind(name: &str) -> Option<RootKind> {
+    match name {
+        "rss" => Some(RootKind::Rss),
+        "feed" => Some(RootKind::Atom),
+        "rdf:RDF" | "RDF" => Some(RootKind::Rdf),
+        _ => None,
+    }
+}
+
+/// Unescaped attributes of an element; for the root these are the namespace
+/// declarations and version copied onto each wrapper document.
+fn element_attributes(e: &BytesStart) -> Vec<(String, String)> {
+    e.attributes()
+        .flatten()
+        .map(|a| {
+            let key = String::from_utf8_lossy(a.key.as_ref()).to_string();
+            let value = a
+                .unescape_value()
+                .map(|v| v.into_owned())
+                .unwrap_or_default();
+            (key, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big_rss(count: usize) -> String {
+        let items: String = (0..count)
+            .map(|i| {
+                format!(
+                    "<item><title>Episode {i}</title><link>https://e.com/{i}</link><guid>ep-{i}</guid>\
+                     <itunes:duration>10:00</itunes:duration>\
+                     <description><![CDATA[<p>Show &amp; tell {i}</p>]]></description></item>"
+                )
+            })
+            .collect();
+        format!(
+            "<?xml version=\"1.0\"?><rss version=\"2.0\" xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\">\
+             <channel><title>Big</title><link>https://e.com</link><language>en-gb</language>{}</channel></rss>",
+            items
+        )
+    }
+
+    #[test]
+    fn test_stream_yields_items_with_full_mapping() {
+        let xml = big_rss(3);
+        let items: Vec<FeedItem> = stream_feed_items(xml.as_bytes(), "", None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].title, "Episode 0");
+        assert_eq!(items[2].guid, "ep-2");
+        assert_eq!(items[1].summary, "Show & tell 1");
+        assert_eq!(items[1].duration_seconds, 600);
+        assert_eq!(items[0].language.as_deref(), Some("en-gb"));
+    }
+
+    #[test]
+    fn test_stream_respects_item_limit() {
+        let xml = big_rss(50);
+        let items: Vec<FeedItem> = stream_feed_items(xml.as_bytes(), "", Some(5))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[4].guid, "ep-4");
+    }
+
+    #[test]
+    fn test_stream_atom_entries() {
+        let xml = r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>A</title><id>urn:a</id><updated>2024-01-15T10:00:00Z</updated>
+  <entry><title>One</title><id>urn:a:1</id><link href="https://e.com/1"/><updated>2024-01-15T10:00:00Z</updated></entry>
+  <entry><title>Two</title><id>urn:a:2</id><link href="https://e.com/2"/><updated>2024-01-14T10:00:00Z</updated></entry>
+</feed>"#;
+        let items: Vec<FeedItem> = stream_feed_items(xml.as_bytes(), "", None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].url, "https://e.com/2");
+        assert_eq!(items[0].updated_ms, 1_705_312_800_000);
+    }
+
+    #[test]
+    fn test_stream_resolves_relative_urls_against_channel_link() {
+        let xml = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><link>https://e.com/blog/</link>
+<item><title>One</title><guid>1</guid><description><![CDATA[<img src="img/a.png">]]></description></item>
+</channel></rss>"#;
+        let items: Vec<FeedItem> =
+            stream_feed_items(xml.as_bytes(), "https://feeds.e.com/rss", None)
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(
+            items[0].image_url.as_deref(),
+            Some("https://e.com/blog/img/a.png")
+        );
+
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>A</title><id>urn:a</id>
+<link rel="self" href="https://feeds.e.com/atom"/><link rel="alternate" href="https://e.com/"/>
+<entry><title>One</title><id>urn:a:1</id><updated>2024-01-15T10:00:00Z</updated>
+<summary type="html">&lt;img src="img/b.png"&gt;</summary></entry>
+</feed>"#;
+        let items: Vec<FeedItem> = stream_feed_items(atom.as_bytes(), "", None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            items[0].image_url.as_deref(),
+            Some("https://e.com/img/b.png")
+        );
+    }
+
+    #[test]
+    fn test_stream_decodes_declared_encoding_and_empty_items() {
+        let xml = b"<?xml version=\"1.0\" encoding=\"windows-1252\"?><rss version=\"2.0\"><channel><title>T</title>\
+<item><title>Caf\xe9</title><guid>1</guid></item><item/></channel></rss>"
+            .to_vec();
+        let items: Vec<FeedItem> = stream_feed_items(xml.as_slice(), "", None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Café");
+    }
+
+    #[test]
+    fn test_stream_rejects_non_feed_root() {
+        let mut stream = stream_feed_items("<html><body/></html>".as_bytes(), "", None);
+        assert!(matches!(stream.next(), Some(Err(FeedError::Invalid(_)))));
+        assert!(stream.next().is_none());
+    }
+}
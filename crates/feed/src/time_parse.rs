@@ -1,13 +1,15 @@
 // ABOUTME: Flexible time parsing for RSS/Atom feed dates.
 // ABOUTME: Tries multiple date formats matching Go's ParseFlexibleTime behavior.
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
 
 /// Parses a datetime string using multiple common RSS/Atom formats.
 /// Returns UTC datetime if successful, None if no format matches.
 ///
 /// Covers Go time formats: RFC3339, RFC3339Nano, RFC1123, RFC1123Z, RFC822, RFC822Z,
-/// and common variants with single-digit days and named timezones.
+/// RFC850, ANSIC, and common variants with single-digit days, missing seconds,
+/// slash-separated dates ("2024/01/05 10:00"), and named timezones ("EST", "CEST").
+/// A weekday that disagrees with the date is ignored rather than rejected.
 pub fn parse_flexible_time(s: &str) -> Option<DateTime<Utc>> {
     let s = s.trim();
     if s.is_empty() {
@@ -24,25 +26,47 @@ pub fn parse_flexible_time(s: &str) -> Option<DateTime<Utc>> {
         return Some(dt.with_timezone(&Utc));
     }
 
-    // Try named timezone parsing (MST, PST, EST, etc.)
-    if let Some(dt) = parse_with_named_timezone(s) {
+    // Named timezones (MST, PST, CEST, etc.) are swapped for their numeric offset
+    let normalized = replace_named_timezone(s);
+    let s = normalized.as_deref().unwrap_or(s);
+
+    if let Some(dt) = parse_known_formats(s) {
         return Some(dt);
     }
 
+    // Feeds often carry a weekday that doesn't match the date; drop it and retry
+    parse_known_formats(strip_weekday(s)?)
+}
+
+fn parse_known_formats(s: &str) -> Option<DateTime<Utc>> {
     // Formats with numeric timezone offset
     let formats_with_tz = [
         // RFC1123Z with numeric offset: "Mon, 02 Jan 2006 15:04:05 -0700"
         "%a, %d %b %Y %H:%M:%S %z",
         // Single digit day: "Mon, 2 Jan 2006 15:04:05 -0700"
         "%a, %e %b %Y %H:%M:%S %z",
+        // Without seconds: "Mon, 02 Jan 2006 15:04 -0700"
+        "%a, %d %b %Y %H:%M %z",
+        // RFC850: "Monday, 02-Jan-06 15:04:05 -0700"
+        "%A, %d-%b-%y %H:%M:%S %z",
         // Without weekday: "02 Jan 2006 15:04:05 -0700"
         "%d %b %Y %H:%M:%S %z",
         // Single digit day without weekday: "2 Jan 2006 15:04:05 -0700"
         "%e %b %Y %H:%M:%S %z",
+        // Without weekday or seconds: "02 Jan 2006 15:04 -0700"
+        "%d %b %Y %H:%M %z",
+        // RFC850 without weekday: "02-Jan-06 15:04:05 -0700"
+        "%d-%b-%y %H:%M:%S %z",
         // ISO-like with offset: "2006-01-02T15:04:05-07:00"
         "%Y-%m-%dT%H:%M:%S%:z",
         // ISO-like compact offset: "2006-01-02T15:04:05-0700"
         "%Y-%m-%dT%H:%M:%S%z",
+        // Space-separated with offset: "2006-01-02 15:04:05 -0700"
+        "%Y-%m-%d %H:%M:%S %z",
+        // Space-separated without seconds: "2006-01-02 15:04 -0700"
+        "%Y-%m-%d %H:%M %z",
+        // Slash-separated with offset: "2006/01/02 15:04:05 -0700"
+        "%Y/%m/%d %H:%M:%S %z",
     ];
 
     for fmt in &formats_with_tz {
@@ -55,14 +79,24 @@ pub fn parse_flexible_time(s: &str) -> Option<DateTime<Utc>> {
     let formats_naive = [
         // ISO datetime: "2006-01-02T15:04:05"
         "%Y-%m-%dT%H:%M:%S",
+        // ISO datetime without seconds: "2006-01-02T15:04"
+        "%Y-%m-%dT%H:%M",
         // Space-separated: "2006-01-02 15:04:05"
         "%Y-%m-%d %H:%M:%S",
+        // Space-separated without seconds: "2006-01-02 15:04"
+        "%Y-%m-%d %H:%M",
+        // Slash-separated: "2006/01/02 15:04:05"
+        "%Y/%m/%d %H:%M:%S",
+        // Slash-separated without seconds: "2006/01/02 15:04"
+        "%Y/%m/%d %H:%M",
         // Without weekday: "02 Jan 2006 15:04:05"
         "%d %b %Y %H:%M:%S",
         // Single digit day: "2 Jan 2006 15:04:05"
         "%e %b %Y %H:%M:%S",
-        // Date only: "02 Jan 2006"
-        "%d %b %Y",
+        // ANSIC: "Mon Jan  2 15:04:05 2006"
+        "%a %b %e %H:%M:%S %Y",
+        // Long month name: "January 2, 2006 15:04:05"
+        "%B %e, %Y %H:%M:%S",
     ];
 
     for fmt in &formats_naive {
@@ -71,84 +105,122 @@ pub fn parse_flexible_time(s: &str) -> Option<DateTime<Utc>> {
         }
     }
 
-    // Try date-only format: "2006-01-02"
-    if let Ok(naive_date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-        let naive_dt = naive_date.and_hms_opt(0, 0, 0)?;
-        return Some(Utc.from_utc_datetime(&naive_dt));
+    // Date-only formats, taken as midnight UTC
+    let formats_date = [
+        // "2006-01-02"
+        "%Y-%m-%d",
+        // "2006/01/02"
+        "%Y/%m/%d",
+        // "02 Jan 2006"
+        "%d %b %Y",
+        // "January 2, 2006" / "Jan 2, 2006"
+        "%B %e, %Y",
+    ];
+
+    for fmt in &formats_date {
+        if let Ok(naive_date) = NaiveDate::parse_from_str(s, fmt) {
+            let naive_dt = naive_date.and_hms_opt(0, 0, 0)?;
+            return Some(Utc.from_utc_datetime(&naive_dt));
+        }
     }
 
     None
 }
 
-/// Parses datetime strings with named timezones (MST, PST, EST, etc.).
+/// Common named timezone offsets (in seconds from UTC).
+/// Ambiguous abbreviations resolve to the first entry (CST is US Central, IST is Irish).
+const TZ_OFFSETS: &[(&str, i32)] = &[
+    ("GMT", 0),
+    ("UTC", 0),
+    ("UT", 0),
+    ("Z", 0),
+    ("EST", -5 * 3600),
+    ("EDT", -4 * 3600),
+    ("CST", -6 * 3600),
+    ("CDT", -5 * 3600),
+    ("MST", -7 * 3600),
+    ("MDT", -6 * 3600),
+    ("PST", -8 * 3600),
+    ("PDT", -7 * 3600),
+    ("AKST", -9 * 3600),
+    ("AKDT", -8 * 3600),
+    ("HST", -10 * 3600),
+    ("HAST", -10 * 3600),
+    ("HADT", -9 * 3600),
+    ("AST", -4 * 3600),
+    ("ADT", -3 * 3600),
+    ("NST", -(3 * 3600 + 30 * 60)),
+    ("NDT", -(2 * 3600 + 30 * 60)),
+    ("BRT", -3 * 3600),
+    ("ART", -3 * 3600),
+    // European
+    ("WET", 0),
+    ("WEST", 3600),
+    ("CET", 3600),
+    ("CEST", 2 * 3600),
+    ("MEZ", 3600),
+    ("MESZ", 2 * 3600),
+    ("EET", 2 * 3600),
+    ("EEST", 3 * 3600),
+    ("BST", 3600), // British Summer Time
+    ("IST", 3600), // Irish Standard Time (summer)
+    ("MSK", 3 * 3600),
+    ("SAST", 2 * 3600),
+    // Asia/Pacific
+    ("JST", 9 * 3600),
+    ("KST", 9 * 3600),
+    ("HKT", 8 * 3600),
+    ("SGT", 8 * 3600),
+    ("AWST", 8 * 3600),
+    ("ACST", 9 * 3600 + 30 * 60),
+    ("ACDT", 10 * 3600 + 30 * 60),
+    ("AEST", 10 * 3600),
+    ("AEDT", 11 * 3600),
+    ("NZST", 12 * 3600),
+    ("NZDT", 13 * 3600),
+];
+
+/// Replaces a trailing named timezone (MST, PST, EST, etc.) with a numeric offset.
 /// chrono's %Z doesn't parse these reliably, so we handle them manually.
-fn parse_with_named_timezone(s: &str) -> Option<DateTime<Utc>> {
-    // Common named timezone offsets (in seconds from UTC)
-    let tz_offsets: &[(&str, i32)] = &[
-        ("GMT", 0),
-        ("UTC", 0),
-        ("EST", -5 * 3600),
-        ("EDT", -4 * 3600),
-        ("CST", -6 * 3600),
-        ("CDT", -5 * 3600),
-        ("MST", -7 * 3600),
-        ("MDT", -6 * 3600),
-        ("PST", -8 * 3600),
-        ("PDT", -7 * 3600),
-        ("AKST", -9 * 3600),
-        ("AKDT", -8 * 3600),
-        ("HST", -10 * 3600),
-        ("HAST", -10 * 3600),
-        ("HADT", -9 * 3600),
-        ("AST", -4 * 3600),
-        ("ADT", -3 * 3600),
-        ("NST", -(3 * 3600 + 30 * 60)),
-        ("NDT", -(2 * 3600 + 30 * 60)),
-        // European
-        ("WET", 0),
-        ("WEST", 1 * 3600),
-        ("CET", 1 * 3600),
-        ("CEST", 2 * 3600),
-        ("EET", 2 * 3600),
-        ("EEST", 3 * 3600),
-        ("BST", 1 * 3600), // British Summer Time
-        ("IST", 1 * 3600), // Irish Standard Time (summer)
-        // Asia/Pacific
-        ("JST", 9 * 3600),
-        ("KST", 9 * 3600),
-        ("CST", 8 * 3600), // China Standard Time (note: conflicts with US Central)
-        ("IST", 5 * 3600 + 30 * 60), // India Standard Time
-        ("AEST", 10 * 3600),
-        ("AEDT", 11 * 3600),
-        ("AWST", 8 * 3600),
-        ("NZST", 12 * 3600),
-        ("NZDT", 13 * 3600),
-    ];
+/// The whole trailing token is matched, so "AEST" never resolves as "EST".
+fn replace_named_timezone(s: &str) -> Option<String> {
+    let (base, token) = s.rsplit_once(char::is_whitespace)?;
+    let token = token.to_ascii_uppercase();
+    let (_, offset_secs) = TZ_OFFSETS.iter().find(|(name, _)| *name == token)?;
+    let base = base.trim_end();
 
-    // Try to find and replace timezone abbreviation with offset
-    for (tz_name, offset_secs) in tz_offsets {
-        if s.ends_with(tz_name) {
-            let base = s.trim_end_matches(tz_name).trim_end();
-
-            // Try parsing the base datetime
-            let formats = [
-                "%a, %d %b %Y %H:%M:%S",
-                "%a, %e %b %Y %H:%M:%S",
-                "%d %b %Y %H:%M:%S",
-                "%e %b %Y %H:%M:%S",
-            ];
-
-            for fmt in &formats {
-                if let Ok(naive) = NaiveDateTime::parse_from_str(base, fmt) {
-                    let offset = FixedOffset::east_opt(*offset_secs)?;
-                    let dt = offset.from_local_datetime(&naive).single()?;
-                    return Some(dt.with_timezone(&Utc));
-                }
-            }
-        }
+    // "+0000 GMT": the numeric offset is already present, just drop the name
+    let has_offset = base
+        .rsplit_once(char::is_whitespace)
+        .map(|(_, last)| {
+            last.len() == 5
+                && last.starts_with(['+', '-'])
+                && last[1..].bytes().all(|b| b.is_ascii_digit())
+        })
+        .unwrap_or(false);
+    if has_offset {
+        return Some(base.to_string());
     }
 
-    None
+    let sign = if *offset_secs < 0 { '-' } else { '+' };
+    let abs = offset_secs.abs();
+    Some(format!(
+        "{} {}{:02}{:02}",
+        base,
+        sign,
+        abs / 3600,
+        (abs % 3600) / 60
+    ))
+}
+
+/// Strips a leading weekday name ("Mon, ", "Monday ") if present.
+fn strip_weekday(s: &str) -> Option<&str> {
+    let (first, rest) = s.split_once(char::is_whitespace)?;
+    let name = first.trim_end_matches(',');
+    if name.parse::<Weekday>().is_err() {
+        return None;
+    }
+    Some(rest.trim_start())
 }
 
 #[cfg(test)]
@@ -210,6 +282,20 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_abbreviation_matches_whole_token() {
+        // AEST must not be read as EST (which it ends with)
+        let dt = parse_flexible_time("Thu, 06 Jun 2024 08:00:00 AEST").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2024-06-05T22:00:00+00:00");
+        assert!(parse_flexible_time("Thu, 06 Jun 2024 08:00:00 XYZ").is_none());
+    }
+
+    #[test]
+    fn test_mismatched_weekday_is_ignored() {
+        let dt = parse_flexible_time("Mon, 06 Jun 2024 08:00:00 GMT").unwrap();
+        assert_eq!(dt.day(), 6);
+    }
+
     #[test]
     fn test_without_weekday_offset() {
         // "02 Jan 2006 15:04:05 -0700"
@@ -0,0 +1,104 @@
+// ABOUTME: Cheap URL-only classification of pasted links as feed, article, or unknown.
+// ABOUTME: Uses path and extension heuristics; never performs a network fetch.
+
+use url::Url;
+
+/// Best-guess kind of resource a URL points at, judged from the URL alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlKind {
+    FeedLikely,
+    ArticleLikely,
+    Unknown,
+}
+
+/// File extensions that almost always denote a feed document.
+const FEED_EXTENSIONS: &[&str] = &[".rss", ".xml", ".atom", ".rdf"];
+
+/// Path segments commonly used for feed endpoints.
+const FEED_SEGMENTS: &[&str] = &["feed", "feeds", "rss", "atom"];
+
+/// Query values (e.g. `?format=rss`) that request a feed rendering.
+const FEED_QUERY_VALUES: &[&str] = &["rss", "rss2", "atom", "feed"];
+
+/// Minimum number of hyphen-separated words for a segment to count as an article slug.
+const MIN_SLUG_WORDS: usize = 3;
+
+/// Classifies a URL as a likely feed, likely article, or unknown.
+///
+/// This is a heuristic first guess for routing pasted URLs; it does not fetch.
+/// URLs without a scheme are treated as `https://`.
+pub fn classify_url(url: &str) -> UrlKind {
+    let trimmed = url.trim();
+    let parsed = Url::parse(trimmed).or_else(|_| Url::parse(&format!("https://{}", trimmed)));
+    let Ok(parsed) = parsed else {
+        return UrlKind::Unknown;
+    };
+
+    let path = parsed.path().to_ascii_lowercase();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    if FEED_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+        || segments.iter().any(|s| FEED_SEGMENTS.contains(s))
+        || parsed
+            .query_pairs()
+            .any(|(_, v)| FEED_QUERY_VALUES.contains(&v.to_ascii_lowercase().as_str()))
+    {
+        return UrlKind::FeedLikely;
+    }
+
+    let Some(last) = segments.last() else {
+        return UrlKind::Unknown;
+    };
+    let stem = last
+        .trim_end_matches(".html")
+        .trim_end_matches(".htm")
+        .trim_end_matches(".php");
+    let slug_words = stem.split(['-', '_']).filter(|w| !w.is_empty()).count();
+    let has_date_path = segments
+        .iter()
+        .take(segments.len() - 1)
+        .any(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()));
+
+    if slug_words >= MIN_SLUG_WORDS || (has_date_path && slug_words >= 2) {
+        return UrlKind::ArticleLikely;
+    }
+
+    UrlKind::Unknown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_extension_is_feed_likely() {
+        assert_eq!(classify_url("example.com/feed.xml"), UrlKind::FeedLikely);
+        assert_eq!(
+            classify_url("https://example.com/blog/atom"),
+            UrlKind::FeedLikely
+        );
+        assert_eq!(
+            classify_url("https://example.com/?format=rss"),
+            UrlKind::FeedLikely
+        );
+    }
+
+    #[test]
+    fn test_dated_slug_is_article_likely() {
+        assert_eq!(
+            classify_url("example.com/2023/11/05/some-story"),
+            UrlKind::ArticleLikely
+        );
+        assert_eq!(
+            classify_url("https://example.com/posts/how-we-ship-rust-code"),
+            UrlKind::ArticleLikely
+        );
+    }
+
+    #[test]
+    fn test_bare_domain_is_unknown() {
+        assert_eq!(classify_url("example.com"), UrlKind::Unknown);
+        assert_eq!(classify_url("https://example.com/"), UrlKind::Unknown);
+        assert_eq!(classify_url("https://example.com/about"), UrlKind::Unknown);
+    }
+}
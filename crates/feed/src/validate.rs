@@ -0,0 +1,534 @@
+// ABOUTME: Lint mode that reports feed spec violations with line/column positions.
+// ABOUTME: Scans raw RSS/RDF/Atom XML with quick-xml; never fails, only collects diagnostics.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, NaiveDate};
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use url::Url;
+
+use crate::encoding::to_utf8;
+use crate::itunes_ext::get_attribute;
+use crate::time_parse::parse_flexible_time;
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Violates the format's spec; readers may drop or misread the data.
+    Error,
+    /// Tolerated by this parser but likely to trip up others.
+    Warning,
+}
+
+/// Category of a spec violation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// The document is not well-formed XML; scanning stopped here.
+    MalformedXml,
+    /// The root element is not `rss`, `rdf:RDF`, or `feed`.
+    UnknownFormat,
+    /// The channel (RSS/RDF) or feed (Atom) has no non-empty `<title>`.
+    MissingTitle,
+    /// A date element that no known format can parse.
+    InvalidDate,
+    /// A date that parses, but not in the format the spec requires
+    /// (RFC 822 for RSS, RFC 3339 for Atom, W3CDTF for `dc:date`).
+    NonStandardDate,
+    /// An enclosure without a URL.
+    MissingEnclosureUrl,
+    /// An enclosure whose URL is relative.
+    RelativeEnclosureUrl,
+    /// An item guid/id already used by an earlier item.
+    DuplicateGuid,
+}
+
+/// One problem found by [`validate_feed_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: Severity,
+    pub message: String,
+    /// 1-based line of the offending element's start tag.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending element's start tag.
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Rss,
+    Atom,
+}
+
+/// Which spec a date element must follow.
+#[derive(Debug, Clone, Copy)]
+enum DateSpec {
+    Rfc822,
+    Rfc3339,
+    W3cdtf,
+}
+
+/// Text-bearing element whose content is checked at its end tag.
+enum Capture {
+    ContainerTitle,
+    Date(DateSpec),
+    Guid,
+}
+
+/// Reports spec violations in a feed document without failing on them.
+///
+/// Checks for a missing channel/feed title, unparseable or non-standard dates,
+/// missing or relative enclosure URLs, and duplicate item guids. Positions refer
+/// to the start tag of the offending element in the UTF-8 form of the document.
+/// Malformed XML yields a single [`DiagnosticKind::MalformedXml`] and ends the
+/// scan. JSON Feed documents are not checked and return no diagnostics.
+pub fn validate_feed_bytes(bytes: &[u8]) -> Vec<Diagnostic> {
+    let data = to_utf8(bytes);
+    let data: &[u8] = &data;
+    if data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{') {
+        return Vec::new();
+    }
+
+    let positions = LineIndex::new(data);
+    let mut diagnostics = Vec::new();
+    let mut report = |kind: DiagnosticKind, severity: Severity, offset: usize, message: String| {
+        let (line, column) = positions.locate(offset);
+        diagnostics.push(Diagnostic {
+            kind,
+            severity,
+            message,
+            line,
+            column,
+        });
+    };
+
+    let mut reader = Reader::from_reader(data);
+    let mut buf = Vec::new();
+    let mut format: Option<Format> = None;
+    // (qualified name, start tag offset) for each open element
+    let mut stack: Vec<(String, usize)> = Vec::new();
+    // Offset of the open channel/feed element and whether it has a title
+    let mut container: Option<(usize, bool)> = None;
+    // Element being captured, its start tag offset, and its depth in `stack`;
+    // text of nested markup (e.g. an xhtml title's <div>) is collected too
+    let mut capture: Option<(Capture, usize, usize)> = None;
+    let mut text = String::new();
+    let mut guids: HashSet<String> = HashSet::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buf);
+        let end = reader.buffer_position() as usize;
+        match event {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                let is_empty = matches!(event, Ok(Event::Empty(_)));
+                let offset = end.saturating_sub(e.len() + if is_empty { 3 } else { 2 });
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                let parent = stack.last().map(|(n, _)| n.as_str());
+
+                if stack.is_empty() {
+                    format = match name.as_str() {
+                        "rss" | "rdf:RDF" | "RDF" => Some(Format::Rss),
+                        "feed" => Some(Format::Atom),
+                        _ => None,
+                    };
+                    if format.is_none() {
+                        report(
+                            DiagnosticKind::UnknownFormat,
+                            Severity::Error,
+                            offset,
+                            format!("root element <{}> is not rss, rdf:RDF or feed", name),
+                        );
+                        break;
+                    }
+                }
+
+                let is_container = match format {
+                    Some(Format::Rss) => name == "channel",
+                    Some(Format::Atom) => stack.is_empty(),
+                    None => false,
+                };
+                if is_container {
+                    container = Some((offset, false));
+                }
+
+                let in_item = stack
+                    .iter()
+                    .any(|(n, _)| matches!(n.as_str(), "item" | "entry"));
+                let container_name = if format == Some(Format::Atom) {
+                    "feed"
+                } else {
+                    "channel"
+                };
+
+                let capturing = capture.is_some();
+                let started = match name.as_str() {
+                    _ if capturing => None,
+                    "title" if parent == Some(container_name) => Some(Capture::ContainerTitle),
+                    "pubDate" | "lastBuildDate" => Some(Capture::Date(DateSpec::Rfc822)),
+                    "published" | "updated" | "atom:published" | "atom:updated" => {
+                        Some(Capture::Date(DateSpec::Rfc3339))
+                    }
+                    "dc:date" => Some(Capture::Date(DateSpec::W3cdtf)),
+                    "guid" if parent == Some("item") => Some(Capture::Guid),
+                    "id" if parent == Some("entry") => Some(Capture::Guid),
+                    _ => None,
+                };
+                if let Some(c) = started {
+                    capture = Some((c, offset, stack.len()));
+                    text.clear();
+                }
+
+                if in_item {
+                    if let Some((kind, severity, message)) = check_enclosure(e, &name, format) {
+                        report(kind, severity, offset, message);
+                    }
+                }
+
+                if is_empty {
+                    // No text and no End event: finish the element now
+                    if capture
+                        .as_ref()
+                        .is_some_and(|(_, _, depth)| *depth == stack.len())
+                    {
+                        if let Some((c, at, _)) = capture.take() {
+                            finish_capture(c, at, "", &mut container, &mut guids, &mut report);
+                        }
+                    }
+                    if is_container {
+                        finish_container(&mut container, &mut report);
+                    }
+                } else {
+                    stack.push((name, offset));
+                }
+            }
+            Ok(Event::Text(ref e)) if capture.is_some() => {
+                text.push_str(&e.decode().map(|s| s.into_owned()).unwrap_or_default());
+            }
+            Ok(Event::CData(ref e)) if capture.is_some() => {
+                text.push_str(&String::from_utf8_lossy(e));
+            }
+            Ok(Event::GeneralRef(ref e)) if capture.is_some() => {
+                if let Ok(Some(ch)) = e.resolve_char_ref() {
+                    text.push(ch);
+                } else if let Ok(entity) = e.decode() {
+                    text.push_str(resolve_predefined_entity(&entity).unwrap_or_default());
+                }
+            }
+            Ok(Event::End(_)) => {
+                let Some((name, _)) = stack.pop() else {
+                    continue;
+                };
+                if capture
+                    .as_ref()
+                    .is_some_and(|(_, _, depth)| *depth == stack.len())
+                {
+                    if let Some((c, at, _)) = capture.take() {
+                        finish_capture(c, at, &text, &mut container, &mut guids, &mut report);
+                    }
+                }
+                let closes_container = match format {
+                    Some(Format::Rss) => name == "channel",
+                    Some(Format::Atom) => stack.is_empty(),
+                    None => false,
+                };
+                if closes_container {
+                    finish_container(&mut container, &mut report);
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                report(
+                    DiagnosticKind::MalformedXml,
+                    Severity::Error,
+                    reader.error_position() as usize,
+                    format!("malformed XML: {}", e),
+                );
+                break;
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    diagnostics
+}
+
+fn finish_capture(
+    capture: Capture,
+    offset: usize,
+    text: &str,
+    container: &mut Option<(usize, bool)>,
+    guids: &mut HashSet<String>,
+    report: &mut impl FnMut(DiagnosticKind, Severity, usize, String),
+) {
+    let text = text.trim();
+    match capture {
+        Capture::ContainerTitle => {
+            if let Some((_, has_title)) = container.as_mut() {
+                *has_title |= !text.is_empty();
+            }
+        }
+        Capture::Date(spec) => {
+            if let Some((kind, severity, message)) = check_date(text, spec) {
+                report(kind, severity, offset, message);
+            }
+        }
+        Capture::Guid => {
+            if text.is_empty() {
+                return;
+            }
+            if !guids.insert(text.to_string()) {
+                report(
+                    DiagnosticKind::DuplicateGuid,
+                    Severity::Error,
+                    offset,
+                    format!("guid \"{}\" is already used by an earlier item", text),
+                );
+            }
+        }
+    }
+}
+
+fn finish_container(
+    container: &mut Option<(usize, bool)>,
+    report: &mut impl FnMut(DiagnosticKind, Severity, usize, String),
+) {
+    if let Some((offset, false)) = container.take() {
+        report(
+            DiagnosticKind::MissingTitle,
+            Severity::Error,
+            offset,
+            "feed has no title".to_string(),
+        );
+    }
+}
+
+fn check_date(text: &str, spec: DateSpec) -> Option<(DiagnosticKind, Severity, String)> {
+    if parse_flexible_time(text).is_none() && !is_w3cdtf(text) {
+        return Some((
+            DiagnosticKind::InvalidDate,
+            Severity::Error,
+            format!("unparseable date \"{}\"", text),
+        ));
+    }
+    let (conforms, expected) = match spec {
+        DateSpec::Rfc822 => (DateTime::parse_from_rfc2822(text).is_ok(), "RFC 822"),
+        DateSpec::Rfc3339 => (DateTime::parse_from_rfc3339(text).is_ok(), "RFC 3339"),
+        DateSpec::W3cdtf => (is_w3cdtf(text), "W3CDTF"),
+    };
+    (!conforms).then(|| {
+        (
+            DiagnosticKind::NonStandardDate,
+            Severity::Warning,
+            format!("date \"{}\" is not in {} format", text, expected),
+        )
+    })
+}
+
+/// W3CDTF: RFC 3339 date-times plus the reduced-precision forms YYYY, YYYY-MM, YYYY-MM-DD.
+fn is_w3cdtf(text: &str) -> bool {
+    if DateTime::parse_from_rfc3339(text).is_ok() {
+        return true;
+    }
+    let bytes = text.as_bytes();
+    match bytes.len() {
+        4 => bytes.iter().all(u8::is_ascii_digit),
+        7 => NaiveDate::parse_from_str(&format!("{}-01", text), "%Y-%m-%d").is_ok(),
+        10 => NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok(),
+        _ => false,
+    }
+}
+
+/// Checks an RSS `<enclosure>` or Atom `<link rel="enclosure">` URL.
+fn check_enclosure(
+    e: &BytesStart,
+    name: &str,
+    format: Option<Format>,
+) -> Option<(DiagnosticKind, Severity, String)> {
+    let url = match name {
+        "enclosure" => get_attribute(e, "url"),
+        "link" if format == Some(Format::Atom) => {
+            if get_attribute(e, "rel").as_deref() != Some("enclosure") {
+                return None;
+            }
+            get_attribute(e, "href")
+        }
+        _ => return None,
+    };
+    let url = url.map(|u| u.trim().to_string()).unwrap_or_default();
+    if url.is_empty() {
+        return Some((
+            DiagnosticKind::MissingEnclosureUrl,
+            Severity::Error,
+            "missing enclosure URL".to_string(),
+        ));
+    }
+    if Url::parse(&url).is_ok() {
+        return None;
+    }
+    // Atom resolves relative IRIs against xml:base; RSS requires absolute URLs
+    let severity = if format == Some(Format::Atom) {
+        Severity::Warning
+    } else {
+        Severity::Error
+    };
+    Some((
+        DiagnosticKind::RelativeEnclosureUrl,
+        severity,
+        format!("relative enclosure URL \"{}\"", url),
+    ))
+}
+
+/// Maps byte offsets to 1-based line/column positions.
+struct LineIndex<'a> {
+    data: &'a [u8],
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                data.iter()
+                    .enumerate()
+                    .filter(|(_, b)| **b == b'\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        LineIndex { data, line_starts }
+    }
+
+    fn locate(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.data.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let start = self.line_starts[line - 1];
+        let column = String::from_utf8_lossy(&self.data[start..offset])
+            .chars()
+            .count();
+        (line, column + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(diagnostics: &[Diagnostic]) -> Vec<DiagnosticKind> {
+        diagnostics.iter().map(|d| d.kind).collect()
+    }
+
+    #[test]
+    fn test_clean_rss_has_no_diagnostics() {
+        let xml = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>T</title>
+  <item><guid>1</guid><pubDate>Mon, 02 Jan 2006 15:04:05 -0700</pubDate>
+    <enclosure url="https://e.com/a.mp3" type="audio/mpeg" length="1"/></item>
+</channel></rss>"#;
+        assert!(validate_feed_bytes(xml.as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn test_reports_rss_violations_with_positions() {
+        let xml = r#"<?xml version="1.0"?>
+<rss version="2.0">
+ <channel>
+  <item>
+   <guid>dup</guid>
+   <pubDate>yesterday</pubDate>
+   <enclosure url="/media/a.mp3" type="audio/mpeg"/>
+  </item>
+  <item>
+   <guid>dup</guid>
+   <pubDate>2024-01-05T10:00:00Z</pubDate>
+   <enclosure type="audio/mpeg"/>
+  </item>
+ </channel>
+</rss>"#;
+        let diagnostics = validate_feed_bytes(xml.as_bytes());
+        assert_eq!(
+            kinds(&diagnostics),
+            vec![
+                DiagnosticKind::InvalidDate,
+                DiagnosticKind::RelativeEnclosureUrl,
+                DiagnosticKind::DuplicateGuid,
+                DiagnosticKind::NonStandardDate,
+                DiagnosticKind::MissingEnclosureUrl,
+                DiagnosticKind::MissingTitle,
+            ]
+        );
+
+        let at = |kind: DiagnosticKind| {
+            let d = diagnostics.iter().find(|d| d.kind == kind).unwrap();
+            (d.line, d.column, d.severity)
+        };
+        assert_eq!(at(DiagnosticKind::InvalidDate), (6, 4, Severity::Error));
+        assert_eq!(
+            at(DiagnosticKind::RelativeEnclosureUrl),
+            (7, 4, Severity::Error)
+        );
+        assert_eq!(at(DiagnosticKind::DuplicateGuid), (10, 4, Severity::Error));
+        assert_eq!(
+            at(DiagnosticKind::NonStandardDate),
+            (11, 4, Severity::Warning)
+        );
+        assert_eq!(at(DiagnosticKind::MissingTitle), (3, 2, Severity::Error));
+    }
+
+    #[test]
+    fn test_atom_checks() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+<title></title>
+<updated>Mon, 02 Jan 2006 15:04:05 GMT</updated>
+<entry><id>a</id><link rel="enclosure" href="a.mp3"/>
+  <source><id>a</id></source></entry>
+</feed>"#;
+        let diagnostics = validate_feed_bytes(xml.as_bytes());
+        assert_eq!(
+            kinds(&diagnostics),
+            vec![
+                DiagnosticKind::NonStandardDate,
+                DiagnosticKind::RelativeEnclosureUrl,
+                DiagnosticKind::MissingTitle,
+            ]
+        );
+        assert_eq!(diagnostics[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_xhtml_title_text_is_captured() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+<title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml">Head<b>line</b></div></title>
+<updated>2024-01-15T10:00:00Z</updated>
+<entry><id>a</id><title type="xhtml"><div>One</div></title><updated>2024-01-15T10:00:00Z</updated></entry>
+<entry><id>a</id><updated>2024-01-15T10:00:00Z</updated></entry>
+</feed>"#;
+        let diagnostics = validate_feed_bytes(xml.as_bytes());
+        assert_eq!(kinds(&diagnostics), vec![DiagnosticKind::DuplicateGuid]);
+    }
+
+    #[test]
+    fn test_malformed_xml_and_unknown_root() {
+        let diagnostics = validate_feed_bytes(b"<rss><channel><title>T</channel></rss>");
+        assert_eq!(kinds(&diagnostics), vec![DiagnosticKind::MalformedXml]);
+        assert_eq!(diagnostics[0].line, 1);
+
+        let diagnostics = validate_feed_bytes(b"<html><body/></html>");
+        assert_eq!(kinds(&diagnostics), vec![DiagnosticKind::UnknownFormat]);
+
+        assert!(
+            validate_feed_bytes(br#"{"version": "https://jsonfeed.org/version/1.1"}"#).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_w3cdtf() {
+        assert!(is_w3cdtf("2024"));
+        assert!(is_w3cdtf("2024-05"));
+        assert!(is_w3cdtf("2024-05-01"));
+        assert!(is_w3cdtf("2024-05-01T10:00:00+02:00"));
+        assert!(!is_w3cdtf("2024-13"));
+        assert!(!is_w3cdtf("May 2024"));
+    }
+}
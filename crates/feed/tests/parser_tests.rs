@@ -1,7 +1,9 @@
 // ABOUTME: Integration tests for feed parsing functionality.
 // ABOUTME: Tests article/podcast detection, iTunes metadata extraction, and time format coverage.
 
-use digests_feed::parse_feed_bytes;
+use digests_feed::{
+    parse_feed_bytes, parse_feed_bytes_with_options, FeedError, ParseLimits, ParseOptions,
+};
 
 /// Tests basic article feed parsing (RSS without iTunes/audio).
 /// Per requirements:
@@ -181,3 +183,1202 @@ fn test_time_format_mst() {
         "published_ms should be nonzero when parsing 'Mon, 02 Jan 2006 15:04:05 MST'"
     );
 }
+
+/// Tests that an HTML channel description is exposed as plain text and sanitized HTML.
+#[test]
+fn test_feed_description_html_and_text() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+    <channel>
+        <title>Rich Blog</title>
+        <link>https://example.com</link>
+        <description><![CDATA[<p>Notes on <strong>Rust</strong> &amp; <a href="https://example.com/about" onclick="steal()">more</a></p><script>alert(1)</script>]]></description>
+        <item>
+            <title>Post</title>
+            <link>https://example.com/post</link>
+            <guid>post-1</guid>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/feed.xml").unwrap();
+
+    assert_eq!(feed.description, "Notes on Rust & more");
+
+    let html = feed
+        .description_html
+        .expect("description_html should be set");
+    assert!(html.contains("<strong>Rust</strong>"), "got: {}", html);
+    assert!(
+        html.contains(r#"href="https://example.com/about""#),
+        "got: {}",
+        html
+    );
+    assert!(!html.contains("<script"), "got: {}", html);
+    assert!(!html.contains("onclick"), "got: {}", html);
+}
+
+/// Tests that a media:thumbnail duplicating the inline lead image is deduplicated:
+/// thumbnail_url is kept and image_url is cleared. A different inline image is
+/// kept as image_url, and a lone media:thumbnail fills only thumbnail_url.
+#[test]
+fn test_duplicate_lead_image_deduped() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    <channel>
+        <title>Photo Blog</title>
+        <link>https://example.com</link>
+        <description>Pictures</description>
+        <item>
+            <title>Sunset</title>
+            <link>https://example.com/sunset</link>
+            <guid>sunset-1</guid>
+            <media:thumbnail url="https://cdn.example.com/img/sunset.jpg?w=300"/>
+            <description><![CDATA[<p><img src="https://cdn.example.com/img/sunset.jpg"></p>]]></description>
+        </item>
+        <item>
+            <title>Harbor</title>
+            <link>https://example.com/harbor</link>
+            <guid>harbor-1</guid>
+            <media:thumbnail url="https://cdn.example.com/thumbs/harbor-small.jpg"/>
+            <description><![CDATA[<p><img src="https://cdn.example.com/img/harbor.jpg"></p>]]></description>
+        </item>
+        <item>
+            <title>Pier</title>
+            <link>https://example.com/pier</link>
+            <guid>pier-1</guid>
+            <media:thumbnail url="https://cdn.example.com/img/pier.jpg"/>
+            <description>No pictures in the body.</description>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/feed.xml").unwrap();
+
+    let duplicate = &feed.items[0];
+    assert_eq!(
+        duplicate.thumbnail_url.as_deref(),
+        Some("https://cdn.example.com/img/sunset.jpg?w=300")
+    );
+    assert_eq!(
+        duplicate.image_url, None,
+        "duplicate image_url should be cleared"
+    );
+
+    let distinct = &feed.items[1];
+    assert_eq!(
+        distinct.thumbnail_url.as_deref(),
+        Some("https://cdn.example.com/thumbs/harbor-small.jpg")
+    );
+    assert_eq!(
+        distinct.image_url.as_deref(),
+        Some("https://cdn.example.com/img/harbor.jpg")
+    );
+
+    let thumbnail_only = &feed.items[2];
+    assert_eq!(
+        thumbnail_only.thumbnail_url.as_deref(),
+        Some("https://cdn.example.com/img/pier.jpg")
+    );
+    assert_eq!(thumbnail_only.image_url, None);
+}
+
+/// Tests that an item whose only image is its inline lead image keeps it as
+/// both image_url and thumbnail_url.
+#[test]
+fn test_single_source_lead_image_kept() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+    <channel>
+        <title>Photo Blog</title>
+        <link>https://example.com</link>
+        <description>Pictures</description>
+        <item>
+            <title>Dawn</title>
+            <link>https://example.com/dawn</link>
+            <guid>dawn-1</guid>
+            <description><![CDATA[<p><img src="/img/dawn.jpg"></p>]]></description>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/feed.xml").unwrap();
+    let item = &feed.items[0];
+
+    assert_eq!(
+        item.image_url.as_deref(),
+        Some("https://example.com/img/dawn.jpg")
+    );
+    assert_eq!(item.thumbnail_url, item.image_url);
+}
+
+/// Tests that a srcset descriptor ending in a multi-byte character does not
+/// panic the parser.
+#[test]
+fn test_non_ascii_srcset_descriptor() {
+    let rss = r#"<rss version="2.0"><channel><title>T</title><link>https://e.com</link>
+<item><title>One</title><guid>1</guid><description><![CDATA[<img srcset="https://e.com/a.jpg 2×">]]></description></item>
+</channel></rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "").unwrap();
+    assert_eq!(
+        feed.items[0].thumbnail_url.as_deref(),
+        Some("https://e.com/a.jpg")
+    );
+}
+
+/// Tests that WebSub hub and self links are exposed and drive `supports_push`.
+#[test]
+fn test_supports_push_requires_hub_and_self() {
+    let with_hub = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+    <channel>
+        <title>Push Blog</title>
+        <link>https://example.com</link>
+        <atom:link rel="hub" href="https://pubsubhubbub.appspot.com/"/>
+        <atom:link rel="self" type="application/rss+xml" href="https://example.com/feed.xml"/>
+        <description>Pushed</description>
+        <item>
+            <title>Post</title>
+            <link>https://example.com/post</link>
+            <guid>post-1</guid>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(with_hub.as_bytes(), "https://example.com/feed.xml").unwrap();
+    assert_eq!(
+        feed.hub_url.as_deref(),
+        Some("https://pubsubhubbub.appspot.com/")
+    );
+    assert_eq!(
+        feed.self_url.as_deref(),
+        Some("https://example.com/feed.xml")
+    );
+    assert!(feed.supports_push);
+    assert_eq!(feed.home_url, "https://example.com/");
+
+    let without_hub = with_hub.replace(
+        r#"<atom:link rel="hub" href="https://pubsubhubbub.appspot.com/"/>"#,
+        "",
+    );
+    let feed = parse_feed_bytes(without_hub.as_bytes(), "https://example.com/feed.xml").unwrap();
+    assert!(feed.hub_url.is_none());
+    assert!(feed.self_url.is_some());
+    assert!(!feed.supports_push);
+}
+
+/// Tests that RSS 1.0 (RDF) feeds map channel/items and Dublin Core date/creator.
+#[test]
+fn test_rss1_rdf_feed() {
+    let rdf = r#"<?xml version="1.0" encoding="ISO-8859-1"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns="http://purl.org/rss/1.0/"
+         xmlns:dc="http://purl.org/dc/elements/1.1/"
+         xmlns:slash="http://purl.org/rss/1.0/modules/slash/">
+    <channel rdf:about="https://slashdot.org/">
+        <title>Slashdot</title>
+        <link>https://slashdot.org/</link>
+        <description>News for nerds, stuff that matters</description>
+        <dc:language>en-us</dc:language>
+        <dc:date>2024-01-15T10:00:00+00:00</dc:date>
+        <items>
+            <rdf:Seq>
+                <rdf:li rdf:resource="https://slashdot.org/story/24/01/15/1/first"/>
+            </rdf:Seq>
+        </items>
+    </channel>
+    <item rdf:about="https://slashdot.org/story/24/01/15/1/first">
+        <title>First Story</title>
+        <link>https://slashdot.org/story/24/01/15/1/first</link>
+        <description>Something happened.</description>
+        <dc:creator>msmash</dc:creator>
+        <dc:date>2024-01-15T09:30:00+00:00</dc:date>
+        <slash:comments>42</slash:comments>
+    </item>
+</rdf:RDF>"#;
+
+    let feed = parse_feed_bytes(
+        rdf.as_bytes(),
+        "https://rss.slashdot.org/Slashdot/slashdotMain",
+    )
+    .unwrap();
+
+    assert_eq!(feed.title, "Slashdot");
+    assert_eq!(feed.home_url, "https://slashdot.org/");
+    assert_eq!(feed.language.as_deref(), Some("en-us"));
+    assert_eq!(feed.published_ms, 1_705_312_800_000);
+    assert_eq!(feed.feed_type, "article");
+    assert_eq!(feed.items.len(), 1);
+
+    let item = &feed.items[0];
+    assert_eq!(item.title, "First Story");
+    assert_eq!(item.url, "https://slashdot.org/story/24/01/15/1/first");
+    assert_eq!(item.summary, "Something happened.");
+    assert_eq!(item.published_ms, 1_705_311_000_000);
+    assert_eq!(
+        item.author.as_ref().and_then(|a| a.name.as_deref()),
+        Some("msmash")
+    );
+}
+
+/// Tests that media:group renditions are all exposed and the best one becomes primary media.
+#[test]
+fn test_media_group_renditions_and_best_selection() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    <channel>
+        <title>Video Feed</title>
+        <link>https://example.com</link>
+        <description>Clips</description>
+        <item>
+            <title>Clip</title>
+            <link>https://example.com/clip</link>
+            <guid>clip-1</guid>
+            <media:group>
+                <media:content url="https://cdn.example.com/clip-360.mp4" type="video/mp4" medium="video" bitrate="600" width="640" height="360" fileSize="1000"/>
+                <media:content url="https://cdn.example.com/clip-1080.mp4" type="video/mp4" medium="video" bitrate="4500" width="1920" height="1080" fileSize="9000"/>
+                <media:content url="https://cdn.example.com/clip-720.mp4" type="video/mp4" medium="video" bitrate="2500" width="1280" height="720" isDefault="true"/>
+            </media:group>
+            <media:content url="https://cdn.example.com/poster.jpg" medium="image" width="1280" height="720"/>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/feed.xml").unwrap();
+    let item = &feed.items[0];
+
+    assert_eq!(item.media_renditions.len(), 4);
+    let hd = &item.media_renditions[1];
+    assert_eq!(hd.bitrate, Some(4500));
+    assert_eq!(hd.width, Some(1920));
+    assert_eq!(hd.height, Some(1080));
+    assert_eq!(hd.file_size, 9000);
+    assert_eq!(hd.group, Some(0));
+    assert!(item.media_renditions[2].is_default);
+    assert_eq!(item.media_renditions[3].medium.as_deref(), Some("image"));
+    assert_eq!(item.media_renditions[3].group, None);
+
+    // isDefault wins over the higher-bitrate rendition
+    assert_eq!(
+        item.primary_media_url.as_deref(),
+        Some("https://cdn.example.com/clip-720.mp4")
+    );
+}
+
+/// Tests that Dublin Core elements fill in missing author, dates, categories, and copyright.
+#[test]
+fn test_dublin_core_fallbacks() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <channel>
+        <title>Gazette</title>
+        <link>https://gov.example/</link>
+        <description>Official notices</description>
+        <dc:creator>Records Office</dc:creator>
+        <dc:date>2024-01-15T10:00:00Z</dc:date>
+        <dc:rights>Crown copyright</dc:rights>
+        <item>
+            <title>Notice 1</title>
+            <link>https://gov.example/notice-1</link>
+            <guid>notice-1</guid>
+            <dc:subject>Budget</dc:subject>
+            <dc:subject>Transport</dc:subject>
+            <dc:date>2024-01-14T09:30:00Z</dc:date>
+        </item>
+        <item>
+            <title>Notice 2</title>
+            <link>https://gov.example/notice-2</link>
+            <guid>notice-2</guid>
+            <category>Health</category>
+            <pubDate>Mon, 15 Jan 2024 10:00:00 GMT</pubDate>
+            <dc:subject>Ignored</dc:subject>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://gov.example/feed").unwrap();
+
+    assert_eq!(
+        feed.author.as_ref().and_then(|a| a.name.as_deref()),
+        Some("Records Office")
+    );
+    assert_eq!(feed.published_ms, 1_705_312_800_000);
+    assert_eq!(feed.copyright.as_deref(), Some("Crown copyright"));
+
+    let first = &feed.items[0];
+    assert_eq!(first.categories, vec!["Budget", "Transport"]);
+    assert_eq!(first.published_ms, 1_705_224_600_000);
+
+    let second = &feed.items[1];
+    assert_eq!(second.categories, vec!["Health"]);
+    assert_eq!(second.published_ms, 1_705_312_800_000);
+}
+
+/// Tests that GeoRSS points are exposed as item locations.
+#[test]
+fn test_georss_item_location() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:georss="http://www.georss.org/georss">
+    <channel>
+        <title>Quakes</title>
+        <link>https://quakes.example/</link>
+        <description>Recent events</description>
+        <item>
+            <title>M 4.5 - Offshore</title>
+            <link>https://quakes.example/1</link>
+            <guid>q-1</guid>
+            <georss:point>-33.45 -70.66</georss:point>
+            <georss:featurename>Santiago</georss:featurename>
+        </item>
+        <item>
+            <title>No location</title>
+            <link>https://quakes.example/2</link>
+            <guid>q-2</guid>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://quakes.example/feed").unwrap();
+    assert_eq!(feed.items[0].location, Some((-33.45, -70.66)));
+    assert_eq!(feed.items[0].location_name.as_deref(), Some("Santiago"));
+    assert_eq!(feed.items[1].location, None);
+}
+
+/// Tests that RSS comments links and slash:comments counts are exposed on items.
+#[test]
+fn test_comments_url_and_count() {
+    let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:slash="http://purl.org/rss/1.0/modules/slash/">
+    <channel>
+        <title>Discussed</title>
+        <link>https://example.com</link>
+        <description>Posts</description>
+        <item>
+            <title>Hot take</title>
+            <link>https://example.com/hot-take</link>
+            <guid>hot-take</guid>
+            <comments>https://example.com/hot-take#comments</comments>
+            <slash:comments>42</slash:comments>
+        </item>
+    </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/feed").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(
+        item.comments_url.as_deref(),
+        Some("https://example.com/hot-take#comments")
+    );
+    assert_eq!(item.comments_count, Some(42));
+}
+
+/// Tests that Atom threading (RFC 4685) links replies to their parent entry.
+#[test]
+fn test_atom_threading_in_reply_to() {
+    let atom = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:thr="http://purl.org/syndication/thread/1.0">
+    <title>Discussion</title>
+    <id>urn:example:feed</id>
+    <updated>2024-01-15T10:00:00Z</updated>
+    <entry>
+        <title>Original post</title>
+        <id>tag:example.com,2024:post-1</id>
+        <link href="https://example.com/post-1"/>
+        <updated>2024-01-15T10:00:00Z</updated>
+        <thr:total>1</thr:total>
+    </entry>
+    <entry>
+        <title>Re: Original post</title>
+        <id>tag:example.com,2024:reply-1</id>
+        <link href="https://example.com/post-1#reply-1"/>
+        <updated>2024-01-15T11:00:00Z</updated>
+        <thr:in-reply-to ref="tag:example.com,2024:post-1" href="https://example.com/post-1"/>
+    </entry>
+</feed>"#;
+
+    let feed = parse_feed_bytes(atom.as_bytes(), "https://example.com/atom").unwrap();
+    let post = &feed.items[0];
+    let reply = &feed.items[1];
+
+    assert_eq!(post.replies_count, Some(1));
+    assert!(post.in_reply_to_guid.is_none());
+    assert_eq!(reply.in_reply_to_guid.as_deref(), Some(post.guid.as_str()));
+    assert!(reply.replies_count.is_none());
+}
+
+/// Tests WebSub hub discovery from Atom `<link rel="hub">` / `<link rel="self">`.
+#[test]
+fn test_atom_websub_hub_discovery() {
+    let atom = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>Push Atom</title>
+    <id>urn:example:push</id>
+    <updated>2024-01-15T10:00:00Z</updated>
+    <link rel="alternate" href="https://example.com/"/>
+    <link rel="self" href="https://example.com/atom.xml"/>
+    <link rel="hub" href="https://hub.example.net/"/>
+    <entry>
+        <title>Entry</title>
+        <id>urn:example:entry-1</id>
+        <link href="https://example.com/entry-1"/>
+        <updated>2024-01-15T10:00:00Z</updated>
+    </entry>
+</feed>"#;
+
+    let feed = parse_feed_bytes(atom.as_bytes(), "https://example.com/atom.xml").unwrap();
+    assert_eq!(feed.hub_url.as_deref(), Some("https://hub.example.net/"));
+    assert_eq!(
+        feed.self_url.as_deref(),
+        Some("https://example.com/atom.xml")
+    );
+    assert!(feed.supports_push);
+    assert_eq!(feed.home_url, "https://example.com/");
+}
+
+/// Tests that ISO-8859-1 feeds are decoded from the XML declaration encoding
+#[test]
+fn test_iso_8859_1_feed_is_decoded() {
+    let mut xml = br#"<?xml version="1.0" encoding="ISO-8859-1"?>
+<rss version="2.0"><channel><title>Caf"#
+        .to_vec();
+    xml.push(0xE9);
+    xml.extend_from_slice(
+        br#"</title><link>https://example.com</link><description>d</description>
+<item><title>Cr"#,
+    );
+    xml.push(0xE8);
+    xml.extend_from_slice(br#"me br"#);
+    xml.push(0xFB);
+    xml.extend_from_slice(br#"l"#);
+    xml.push(0xE9);
+    xml.extend_from_slice(br#"e</title><link>https://example.com/1</link></item></channel></rss>"#);
+
+    let feed = parse_feed_bytes(&xml, "https://example.com/feed").unwrap();
+    assert_eq!(feed.title, "Café");
+    assert_eq!(feed.items[0].title, "Crème brûlée");
+}
+
+/// Tests that UTF-16LE feeds with a byte order mark parse like UTF-8 ones
+#[test]
+fn test_utf16le_feed_with_bom() {
+    let xml = r#"<?xml version="1.0" encoding="utf-16"?>
+<rss version="2.0"><channel><title>Überblick</title><link>https://example.com</link><description>d</description>
+<item><title>Ärger</title><link>https://example.com/1</link><guid>1</guid></item></channel></rss>"#;
+    let mut data = vec![0xFF, 0xFE];
+    data.extend(xml.encode_utf16().flat_map(|u| u.to_le_bytes()));
+
+    let feed = parse_feed_bytes(&data, "https://example.com/feed").unwrap();
+    assert_eq!(feed.title, "Überblick");
+    assert_eq!(feed.items[0].title, "Ärger");
+}
+
+/// Tests that elements from unmapped namespaces are preserved on Feed and FeedItem
+#[test]
+fn test_unknown_namespace_extensions_preserved() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+  <title>Blog</title><link>https://example.com</link><description>d</description>
+  <wp:base_site_url>https://example.com</wp:base_site_url>
+  <item><title>Post</title><link>https://example.com/p</link><wp:post_id>17</wp:post_id></item>
+</channel>
+</rss>"#;
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/feed").unwrap();
+    let ns = "http://wordpress.org/export/1.2/";
+    assert_eq!(
+        feed.extensions[ns][0].value.as_deref(),
+        Some("https://example.com")
+    );
+    let post_id = &feed.items[0].extensions[ns][0];
+    assert_eq!(post_id.name, "wp:post_id");
+    assert_eq!(post_id.value.as_deref(), Some("17"));
+}
+
+/// Tests that iTunes season/episode/episodeType/block are exposed per item
+#[test]
+fn test_itunes_season_episode_fields() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+<channel>
+  <title>Show</title><link>https://example.com</link><description>d</description>
+  <item>
+    <title>S2E5</title><guid>s2e5</guid>
+    <itunes:season>2</itunes:season>
+    <itunes:episode>5</itunes:episode>
+    <itunes:episodeType>Full</itunes:episodeType>
+    <itunes:image href="https://example.com/s2e5.jpg"/>
+  </item>
+  <item>
+    <title>Teaser</title><guid>teaser</guid>
+    <itunes:episodeType>trailer</itunes:episodeType>
+    <itunes:block>Yes</itunes:block>
+  </item>
+</channel>
+</rss>"#;
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/feed").unwrap();
+    let full = &feed.items[0];
+    assert_eq!(full.season, Some(2));
+    assert_eq!(full.episode, Some(5));
+    assert_eq!(full.episode_type.as_deref(), Some("full"));
+    assert!(!full.itunes_block);
+    assert_eq!(
+        full.thumbnail_url.as_deref(),
+        Some("https://example.com/s2e5.jpg")
+    );
+
+    let trailer = &feed.items[1];
+    assert_eq!(trailer.season, None);
+    assert_eq!(trailer.episode_type.as_deref(), Some("trailer"));
+    assert!(trailer.itunes_block);
+}
+
+/// Tests that multiple authors and contributors are collected per item
+#[test]
+fn test_multiple_authors_and_contributors() {
+    let atom = r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>A</title><id>urn:a</id><updated>2024-01-15T10:00:00Z</updated>
+  <entry>
+    <title>Joint post</title><id>urn:a:1</id><updated>2024-01-15T10:00:00Z</updated>
+    <author><name>Ada</name><email>ada@example.com</email></author>
+    <author><name>Grace</name></author>
+    <contributor><name>Linus</name></contributor>
+    <contributor><name>ada</name></contributor>
+  </entry>
+</feed>"#;
+    let feed = parse_feed_bytes(atom.as_bytes(), "https://example.com/atom").unwrap();
+    let item = &feed.items[0];
+    let names: Vec<_> = item
+        .authors
+        .iter()
+        .filter_map(|a| a.name.as_deref())
+        .collect();
+    assert_eq!(names, vec!["Ada", "Grace", "Linus"]);
+    assert_eq!(item.author.as_ref(), item.authors.first());
+    assert_eq!(item.authors[0].email.as_deref(), Some("ada@example.com"));
+
+    let rss = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>Co-written</title><guid>1</guid>
+    <dc:creator>Jane Roe</dc:creator><dc:creator>John Doe</dc:creator>
+  </item>
+</channel></rss>"#;
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/rss").unwrap();
+    let names: Vec<_> = feed.items[0]
+        .authors
+        .iter()
+        .filter_map(|a| a.name.as_deref())
+        .collect();
+    assert_eq!(names, vec!["Jane Roe", "John Doe"]);
+}
+
+/// Tests that Atom term/scheme/label and RSS domain are kept and categories are normalized
+#[test]
+fn test_structured_categories_normalized() {
+    let atom = r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>A</title><id>urn:a</id><updated>2024-01-15T10:00:00Z</updated>
+  <entry>
+    <title>Tagged</title><id>urn:a:1</id><updated>2024-01-15T10:00:00Z</updated>
+    <category term="rust-lang" scheme="https://example.com/tags" label="Rust"/>
+    <category term="  Web   Dev "/>
+    <category term="RUST-LANG"/>
+  </entry>
+</feed>"#;
+    let feed = parse_feed_bytes(atom.as_bytes(), "https://example.com/atom").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(item.categories, vec!["rust-lang", "Web   Dev"]);
+    assert_eq!(item.category_details.len(), 2);
+    let rust = &item.category_details[0];
+    assert_eq!(rust.scheme.as_deref(), Some("https://example.com/tags"));
+    assert_eq!(rust.label.as_deref(), Some("Rust"));
+    assert_eq!(item.category_details[1].normalized, "web dev");
+
+    let rss = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>1</guid><category domain="https://example.com/sections">News</category></item>
+</channel></rss>"#;
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://example.com/rss").unwrap();
+    let news = &feed.items[0].category_details[0];
+    assert_eq!(news.term, "News");
+    assert_eq!(news.normalized, "news");
+    assert_eq!(news.scheme.as_deref(), Some("https://example.com/sections"));
+}
+
+/// Tests that ParseOptions::sanitize_content yields sanitized item HTML alongside plain text
+#[test]
+fn test_sanitize_content_option() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+<channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>1</guid>
+    <description><![CDATA[<p onclick="steal()">Hi <script>alert(1)</script>there</p>]]></description>
+    <content:encoded><![CDATA[<p>Body <a href="javascript:alert(1)">x</a><iframe src="https://evil"></iframe></p>]]></content:encoded>
+  </item>
+</channel></rss>"#;
+
+    let plain = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    assert!(plain.items[0].summary_html.is_none());
+    assert!(plain.items[0].content_html.is_none());
+
+    let options = ParseOptions {
+        sanitize_content: true,
+        ..Default::default()
+    };
+    let feed =
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).unwrap();
+    let item = &feed.items[0];
+    let summary = item.summary_html.as_deref().unwrap();
+    assert!(summary.contains("<p>"));
+    assert!(!summary.contains("onclick"));
+    assert!(!summary.contains("<script"));
+    let content = item.content_html.as_deref().unwrap();
+    assert!(content.contains("Body"));
+    assert!(!content.contains("javascript:"));
+    assert!(!content.contains("<iframe"));
+    assert_eq!(item.summary, plain.items[0].summary);
+}
+
+/// preserve_paragraphs keeps block boundaries as line breaks in plain text.
+#[test]
+fn test_preserve_paragraphs_option() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+<channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>1</guid>
+    <description><![CDATA[First line<br/>second line]]></description>
+    <content:encoded><![CDATA[<p>Intro   text.</p><div>Middle &amp; more</div><ul><li>One</li><li>Two</li></ul>]]></content:encoded>
+  </item>
+</channel></rss>"#;
+
+    let flat = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    assert_eq!(flat.items[0].content, "Intro text.Middle & moreOneTwo");
+
+    let options = ParseOptions {
+        preserve_paragraphs: true,
+        ..Default::default()
+    };
+    let feed =
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).unwrap();
+    let item = &feed.items[0];
+    assert_eq!(item.summary, "First line\nsecond line");
+    assert_eq!(item.content, "Intro text.\nMiddle & more\nOne\nTwo");
+    assert_eq!(
+        item.content_hash, flat.items[0].content_hash,
+        "content_hash must not depend on parse options"
+    );
+}
+
+/// Relative links and images in item HTML are resolved against the item URL,
+/// falling back to the channel link.
+#[test]
+fn test_relative_urls_in_item_html_are_resolved() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+<channel><title>R</title><link>https://example.com/blog/</link><description>d</description>
+  <item><title>One</title><guid>1</guid><link>https://example.com/blog/2024/one</link>
+    <content:encoded><![CDATA[<p><a href="two">Next</a><img src="/img/a.png"></p>]]></content:encoded>
+  </item>
+  <item><title>Two</title><guid>2</guid>
+    <description><![CDATA[<a href="posts/two">Read</a>]]></description>
+  </item>
+</channel></rss>"#;
+
+    let options = ParseOptions {
+        sanitize_content: true,
+        ..Default::default()
+    };
+    let feed =
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).unwrap();
+    let content = feed.items[0].content_html.as_deref().unwrap();
+    assert!(content.contains(r#"href="https://example.com/blog/2024/two""#));
+    assert!(content.contains(r#"src="https://example.com/img/a.png""#));
+    let summary = feed.items[1].summary_html.as_deref().unwrap();
+    assert!(summary.contains(r#"href="https://example.com/blog/posts/two""#));
+}
+
+/// Enclosures repeated via media:content are merged, and the primary pick is recorded.
+#[test]
+fn test_enclosure_dedup_and_primary_index() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+<channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>1</guid>
+    <enclosure url="https://cdn.example.com/ep1.m4a" type="audio/mp4" length="900"/>
+    <enclosure url="https://cdn.example.com/ep1.mp3" length="0" type="audio/mpeg"/>
+    <media:content url="https://cdn.example.com/ep1.mp3" fileSize="1200"/>
+  </item>
+</channel></rss>"#;
+
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(item.enclosures.len(), 2);
+    assert_eq!(item.enclosures[1].mime_type.as_deref(), Some("audio/mpeg"));
+    assert_eq!(item.enclosures[1].length, 1200);
+    assert_eq!(
+        item.primary_media_url.as_deref(),
+        Some("https://cdn.example.com/ep1.mp3")
+    );
+    assert_eq!(item.primary_enclosure_index, Some(1));
+}
+
+/// Item artwork beats the show artwork; inline chapter images beat an item
+/// image that only repeats the show artwork.
+#[test]
+fn test_item_and_chapter_artwork_thumbnails() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"
+     xmlns:psc="http://podlove.org/simple-chapters">
+<channel><title>Pod</title><link>https://example.com</link><description>d</description>
+  <itunes:image href="https://example.com/show.jpg"/>
+  <item><title>One</title><guid>1</guid>
+    <itunes:image href="https://example.com/ep1.jpg"/>
+    <psc:chapters version="1.2">
+      <psc:chapter start="00:00:00" title="Intro" image="https://example.com/ch-intro.jpg"/>
+    </psc:chapters>
+  </item>
+  <item><title>Two</title><guid>2</guid>
+    <itunes:image href="https://example.com/show.jpg"/>
+    <psc:chapters version="1.2">
+      <psc:chapter start="00:00:00" title="Intro"/>
+      <psc:chapter start="00:01:30.5" title="Guest" image="/art/guest.jpg" href="https://guest.example.com"/>
+    </psc:chapters>
+  </item>
+  <item><title>Three</title><guid>3</guid>
+    <itunes:image href="https://example.com/show.jpg"/>
+  </item>
+</channel></rss>"#;
+
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    assert_eq!(
+        feed.items[0].thumbnail_url.as_deref(),
+        Some("https://example.com/ep1.jpg")
+    );
+
+    let two = &feed.items[1];
+    assert_eq!(
+        two.thumbnail_url.as_deref(),
+        Some("https://example.com/art/guest.jpg")
+    );
+    assert_eq!(two.chapters.len(), 2);
+    assert_eq!(two.chapters[1].start_seconds, 90.5);
+    assert_eq!(
+        two.chapters[1].url.as_deref(),
+        Some("https://guest.example.com")
+    );
+    assert!(two.extensions.is_empty());
+
+    assert_eq!(
+        feed.items[2].thumbnail_url.as_deref(),
+        Some("https://example.com/show.jpg")
+    );
+}
+
+/// Exceeding a parse limit fails with LimitExceeded before mapping.
+#[test]
+fn test_parse_limits() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>1</guid></item>
+  <item><title>Two</title><guid>2</guid></item>
+  <item><title>Three</title><guid>3</guid></item>
+</channel></rss>"#;
+    let with_limits = |limits: ParseLimits| ParseOptions {
+        limits,
+        ..Default::default()
+    };
+
+    let options = with_limits(ParseLimits {
+        max_items: Some(2),
+        ..Default::default()
+    });
+    let err = parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options)
+        .unwrap_err();
+    assert!(matches!(err, FeedError::LimitExceeded(_)));
+    assert!(err.to_string().contains("max_items"));
+
+    let options = with_limits(ParseLimits {
+        max_bytes: Some(100),
+        ..Default::default()
+    });
+    let err = parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options)
+        .unwrap_err();
+    assert!(err.to_string().contains("max_bytes"));
+
+    // rss > channel > item > title is four levels deep
+    let options = with_limits(ParseLimits {
+        max_depth: Some(3),
+        ..Default::default()
+    });
+    let err = parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options)
+        .unwrap_err();
+    assert!(err.to_string().contains("max_depth"));
+    let options = with_limits(ParseLimits {
+        max_depth: Some(4),
+        ..Default::default()
+    });
+    assert!(
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).is_ok()
+    );
+
+    let options = with_limits(ParseLimits::unlimited());
+    let feed =
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).unwrap();
+    assert_eq!(feed.items.len(), 3);
+}
+
+/// recover_malformed repairs bare ampersands, HTML entities, control
+/// characters and a truncated tail, and flags the feed as recovered.
+#[test]
+fn test_recover_malformed_feed() {
+    let xml = "<?xml version=\"1.0\"?>
+<rss version=\"2.0\"><channel><title>Q&A Weekly</title><link>https://example.com</link><description>d</description>
+  <item><title>Tips&nbsp;&amp; tricks \u{1}</title><guid>1</guid><link>https://example.com/?p=1&ref=rss</link></item>
+  <item><title>Second</title><guid>2</guid><description>Cut off mid-sen";
+
+    assert!(parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").is_err());
+
+    let options = ParseOptions {
+        recover_malformed: true,
+        ..Default::default()
+    };
+    let feed =
+        parse_feed_bytes_with_options(xml.as_bytes(), "https://example.com/rss", &options).unwrap();
+    assert!(feed.recovered);
+    assert_eq!(feed.title, "Q&A Weekly");
+    assert_eq!(feed.items.len(), 2);
+    assert_eq!(feed.items[0].title, "Tips\u{a0}& tricks");
+    assert_eq!(feed.items[0].url, "https://example.com/?p=1&ref=rss");
+    assert_eq!(feed.items[1].summary, "Cut off mid-sen");
+
+    let clean = parse_feed_bytes_with_options(
+        br#"<rss version="2.0"><channel><title>T</title><item><guid>1</guid><title>x</title></item></channel></rss>"#,
+        "https://example.com/rss",
+        &options,
+    )
+    .unwrap();
+    assert!(!clean.recovered);
+}
+
+/// RSS ttl, skipHours and skipDays are exposed for poll scheduling.
+#[test]
+fn test_ttl_skip_hours_and_days() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>R</title><link>https://example.com</link><description>d</description>
+  <ttl>60</ttl>
+  <skipHours><hour>0</hour><hour>23</hour><hour>24</hour></skipHours>
+  <skipDays><day>Sunday</day></skipDays>
+  <item><title>One</title><guid>1</guid></item>
+</channel></rss>"#;
+
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    assert_eq!(feed.ttl_minutes, Some(60));
+    assert_eq!(feed.skip_hours, vec![0, 23]);
+    assert_eq!(feed.skip_days, vec!["Sunday".to_string()]);
+    assert_eq!(feed.refresh_hint_minutes(), Some(60));
+}
+
+/// Atom feeds carry cache hints through the syndication module; they are typed
+/// fields rather than preserved extensions.
+#[test]
+fn test_syndication_update_period() {
+    let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:sy="http://purl.org/rss/1.0/modules/syndication/">
+ <title>A</title>
+ <id>urn:a</id>
+ <updated>2024-05-01T10:00:00Z</updated>
+ <sy:updatePeriod>Hourly</sy:updatePeriod>
+ <sy:updateFrequency>2</sy:updateFrequency>
+ <entry><title>One</title><id>urn:a:1</id><updated>2024-05-01T10:00:00Z</updated></entry>
+</feed>"#;
+
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/atom").unwrap();
+    assert_eq!(feed.ttl_minutes, None);
+    assert_eq!(feed.update_period.as_deref(), Some("hourly"));
+    assert_eq!(feed.update_frequency, Some(2));
+    assert_eq!(feed.refresh_hint_minutes(), Some(30));
+    assert!(feed.extensions.is_empty());
+}
+
+/// Items without a source guid are flagged so health checks can report them.
+#[test]
+fn test_guid_generated_flag() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><guid>one</guid><link>https://example.com/1</link></item>
+  <item><title>Two</title><link>https://example.com/2</link></item>
+</channel></rss>"#;
+
+    let feed = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    assert!(!feed.items[0].guid_generated);
+    assert!(feed.items[1].guid_generated);
+    assert!(!feed.items[1].guid.is_empty());
+}
+
+/// Synthetic guids are deterministic across parses and differ per item.
+#[test]
+fn test_synthetic_guid_is_stable() {
+    let xml = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>R</title><link>https://example.com</link><description>d</description>
+  <item><title>One</title><link>https://example.com/1</link><pubDate>Mon, 06 Jan 2025 10:00:00 GMT</pubDate></item>
+  <item><title>Two</title><link>https://example.com/2</link></item>
+  <item><description>No link or title</description></item>
+</channel></rss>"#;
+
+    let first = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    let second = parse_feed_bytes(xml.as_bytes(), "https://example.com/rss").unwrap();
+    let guids: Vec<&str> = first.items.iter().map(|i| i.guid.as_str()).collect();
+    assert!(guids.iter().all(|g| g.starts_with("urn:digests:item:")));
+    assert_eq!(
+        guids,
+        second
+            .items
+            .iter()
+            .map(|i| i.guid.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_ne!(guids[0], guids[1]);
+}
+
+/// YouTube channel feeds expose video/channel ids, the media:group description,
+/// and the feed's own high-res media:thumbnail with its declared size.
+#[test]
+fn test_youtube_feed() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:media="http://search.yahoo.com/mrss/" xmlns="http://www.w3.org/2005/Atom">
+ <id>yt:channel:UC123</id>
+ <title>Chan</title>
+ <entry>
+  <id>yt:video:vid1</id>
+  <yt:videoId>vid1</yt:videoId>
+  <yt:channelId>UC123</yt:channelId>
+  <title>Video One</title>
+  <link rel="alternate" href="https://www.youtube.com/watch?v=vid1"/>
+  <published>2024-05-01T10:00:00+00:00</published>
+  <media:group>
+   <media:title>Video One</media:title>
+   <media:thumbnail url="https://i1.ytimg.com/vi/vid1/hqdefault.jpg" width="480" height="360"/>
+   <media:description>Description of video one.</media:description>
+  </media:group>
+ </entry>
+</feed>"#;
+
+    let feed =
+        parse_feed_bytes(xml.as_bytes(), "https://www.youtube.com/feeds/videos.xml").unwrap();
+    assert_eq!(feed.feed_type, "video");
+    let item = &feed.items[0];
+    assert_eq!(item.video_id.as_deref(), Some("vid1"));
+    assert_eq!(item.channel_id.as_deref(), Some("UC123"));
+    assert_eq!(item.summary, "Description of video one.");
+    assert_eq!(
+        item.image_url.as_deref(),
+        Some("https://i1.ytimg.com/vi/vid1/hqdefault.jpg")
+    );
+    assert_eq!(
+        item.thumbnail_url.as_deref(),
+        Some("https://i1.ytimg.com/vi/vid1/hqdefault.jpg")
+    );
+    assert_eq!(item.thumbnail_width, Some(480));
+    assert_eq!(item.thumbnail_height, Some(360));
+    assert!(item.extensions.is_empty());
+}
+
+/// A YouTube entry without a media:thumbnail gets the hqdefault still, but
+/// only when its video id is well formed.
+#[test]
+fn test_youtube_thumbnail_synthesized_for_valid_ids_only() {
+    let feed_with = |video_id: &str| {
+        format!(
+            r#"<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns="http://www.w3.org/2005/Atom">
+ <id>yt:channel:UC123</id><title>Chan</title>
+ <entry><id>yt:video:{0}</id><yt:videoId>{0}</yt:videoId><title>V</title>
+  <published>2024-05-01T10:00:00+00:00</published></entry>
+</feed>"#,
+            video_id
+        )
+    };
+
+    let feed = parse_feed_bytes(feed_with("dQw4w9WgXcQ").as_bytes(), "").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(
+        item.thumbnail_url.as_deref(),
+        Some("https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg")
+    );
+    assert_eq!(item.thumbnail_width, Some(480));
+
+    let feed = parse_feed_bytes(feed_with("x/../../evil").as_bytes(), "").unwrap();
+    assert_eq!(feed.items[0].thumbnail_url, None);
+}
+
+/// Items in a feed without any declared language get one detected from
+/// their title and summary; a declared feed language always wins.
+#[cfg(feature = "language-detection")]
+#[test]
+fn test_item_language_detected_when_undeclared() {
+    let item = r#"<item>
+   <title>Was wir aus dem letzten Jahr gelernt haben</title>
+   <description>In dieser Folge sprechen wir darüber, warum die neue Version so lange gedauert hat und was wir beim nächsten Mal anders machen.</description>
+  </item>"#;
+    let undeclared = format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><link>https://example.com/</link>{}</channel></rss>"#,
+        item
+    );
+    let declared = format!(
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><link>https://example.com/</link><language>en</language>{}</channel></rss>"#,
+        item
+    );
+
+    let feed = parse_feed_bytes(undeclared.as_bytes(), "https://example.com/feed").unwrap();
+    assert_eq!(feed.language, None);
+    assert_eq!(feed.items[0].language.as_deref(), Some("de"));
+
+    let feed = parse_feed_bytes(declared.as_bytes(), "https://example.com/feed").unwrap();
+    assert_eq!(feed.items[0].language.as_deref(), Some("en"));
+}
+
+/// RSS `<source url>` and Atom `<source>` attribution from aggregator feeds
+/// lands in `source_title`/`source_url`, and an Atom source's `<id>` is not
+/// mistaken for the entry's own id.
+#[test]
+fn test_item_source_attribution() {
+    let rss = r#"<?xml version="1.0"?>
+<rss version="2.0">
+ <channel>
+  <title>Planet Example</title>
+  <link>https://planet.example.com/</link>
+  <item>
+   <title>Post</title>
+   <link>https://alice.example.com/post</link>
+   <source url="https://alice.example.com/feed.xml">Alice's Blog</source>
+  </item>
+  <item>
+   <title>Unattributed</title>
+   <link>https://bob.example.com/post</link>
+  </item>
+ </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://planet.example.com/rss").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(item.source_title.as_deref(), Some("Alice's Blog"));
+    assert_eq!(
+        item.source_url.as_deref(),
+        Some("https://alice.example.com/feed.xml")
+    );
+    assert_eq!(feed.items[1].source_title, None);
+    assert_eq!(feed.items[1].source_url, None);
+
+    let atom = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+ <title>Planet Example</title>
+ <id>urn:planet</id>
+ <updated>2024-05-01T10:00:00Z</updated>
+ <entry>
+  <title>Post</title>
+  <link href="https://carol.example.com/post"/>
+  <updated>2024-05-01T10:00:00Z</updated>
+  <source>
+   <id>urn:carol</id>
+   <title>Carol Writes</title>
+   <link rel="self" href="https://carol.example.com/atom.xml"/>
+   <link rel="alternate" href="https://carol.example.com/"/>
+  </source>
+ </entry>
+</feed>"#;
+
+    let feed = parse_feed_bytes(atom.as_bytes(), "https://planet.example.com/atom").unwrap();
+    let item = &feed.items[0];
+    assert_eq!(item.source_title.as_deref(), Some("Carol Writes"));
+    assert_eq!(
+        item.source_url.as_deref(),
+        Some("https://carol.example.com/")
+    );
+    assert!(item.guid_generated);
+}
+
+/// A blog that embeds `<audio>` players in content:encoded without declaring
+/// enclosures gets synthesized enclosures, so it is detected as a podcast.
+#[test]
+fn test_inline_audio_synthesizes_enclosures() {
+    let rss = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+ <channel>
+  <title>Audio Blog</title>
+  <link>https://blog.example.com/</link>
+  <item>
+   <title>Episode 1</title>
+   <link>https://blog.example.com/ep1</link>
+   <content:encoded><![CDATA[<p>Show notes</p><audio controls><source src="/audio/ep1.mp3" type="audio/mpeg"></audio>]]></content:encoded>
+  </item>
+  <item>
+   <title>Episode 2</title>
+   <link>https://blog.example.com/ep2</link>
+   <content:encoded><![CDATA[<audio src="https://cdn.example.com/ep2.m4a"></audio>]]></content:encoded>
+  </item>
+  <item>
+   <title>Declared</title>
+   <link>https://blog.example.com/ep3</link>
+   <enclosure url="https://cdn.example.com/ep3.mp3" type="audio/mpeg" length="100"/>
+   <content:encoded><![CDATA[<audio src="https://player.example.com/ep3.mp3"></audio>]]></content:encoded>
+  </item>
+ </channel>
+</rss>"#;
+
+    let feed = parse_feed_bytes(rss.as_bytes(), "https://blog.example.com/feed").unwrap();
+    assert_eq!(feed.feed_type, "podcast");
+
+    let first = &feed.items[0];
+    assert_eq!(first.enclosures.len(), 1);
+    assert_eq!(
+        first.enclosures[0].url,
+        "https://blog.example.com/audio/ep1.mp3"
+    );
+    assert_eq!(first.enclosures[0].mime_type.as_deref(), Some("audio/mpeg"));
+    assert_eq!(
+        first.primary_media_url.as_deref(),
+        Some("https://blog.example.com/audio/ep1.mp3")
+    );
+    assert_eq!(
+        feed.items[1].enclosures[0].mime_type.as_deref(),
+        Some("audio/mp4")
+    );
+
+    // Declared enclosures are left alone
+    let declared = &feed.items[2];
+    assert_eq!(declared.enclosures.len(), 1);
+    assert_eq!(
+        declared.enclosures[0].url,
+        "https://cdn.example.com/ep3.mp3"
+    );
+}
+
+/// `content_hash` is stable across parses and changes when an item's content
+/// is edited in place, which `mark_edited_items` reports.
+#[test]
+fn test_content_hash_detects_edits() {
+    let rss = |body: &str| {
+        format!(
+            r#"<?xml version="1.0"?><rss version="2.0"><channel><title>T</title><link>https://example.com/</link>
+<item><title>Post</title><guid>post-1</guid><description>{}</description></item>
+<item><title>Other</title><guid>post-2</guid><description>Unchanged</description></item>
+</channel></rss>"#,
+            body
+        )
+    };
+
+    let first =
+        parse_feed_bytes(rss("Original text").as_bytes(), "https://example.com/rss").unwrap();
+    let again =
+        parse_feed_bytes(rss("Original text").as_bytes(), "https://example.com/rss").unwrap();
+    assert_eq!(first.items[0].content_hash.len(), 16);
+    assert_eq!(first.items[0].content_hash, again.items[0].content_hash);
+
+    let mut edited =
+        parse_feed_bytes(rss("Corrected text").as_bytes(), "https://example.com/rss").unwrap();
+    assert_ne!(first.items[0].content_hash, edited.items[0].content_hash);
+    assert_eq!(digests_feed::mark_edited_items(&first, &mut edited), 1);
+    assert!(edited.items[0].edited);
+    assert!(!edited.items[1].edited);
+}
@@ -3,8 +3,9 @@
 
 use digests_feed::{
     decode_entities, extract_first_image, is_valid_image_url, parse_duration_seconds,
-    parse_flexible_time, resolve_image_url, strip_html,
+    parse_flexible_time, resolve_image_url, strip_html, strip_html_with_blocks,
 };
+use digests_hermes::formats::BLOCK_TAGS;
 
 mod time_parse_tests {
     use super::*;
@@ -44,6 +45,45 @@ mod time_parse_tests {
         let dt = result.unwrap();
         assert_eq!(dt, Utc.with_ymd_and_hms(2023, 12, 25, 0, 0, 0).unwrap());
     }
+
+    /// pubDate/updated values seen in real-world feeds, with the expected UTC instant.
+    #[test]
+    fn test_parse_real_world_corpus() {
+        let corpus = [
+            ("Mon, 01 Jan 2024 10:00:00 EST", (2024, 1, 1, 15, 0, 0)),
+            ("Tue, 04 Jun 2024 09:30:00 PDT", (2024, 6, 4, 16, 30, 0)),
+            ("Wed, 5 Jun 2024 18:00:00 CEST", (2024, 6, 5, 16, 0, 0)),
+            ("Thu, 06 Jun 2024 08:00:00 AEST", (2024, 6, 5, 22, 0, 0)),
+            ("Fri, 07 Jun 2024 12:00:00 BST", (2024, 6, 7, 11, 0, 0)),
+            ("Sat, 08 Jun 2024 10:00:00 JST", (2024, 6, 8, 1, 0, 0)),
+            ("Sun, 09 Jun 2024 10:00:00 utc", (2024, 6, 9, 10, 0, 0)),
+            ("Mon, 10 Jun 2024 10:00 GMT", (2024, 6, 10, 10, 0, 0)),
+            (
+                "Mon, 10 Jun 2024 10:00:00 +0000 GMT",
+                (2024, 6, 10, 10, 0, 0),
+            ),
+            // Wrong weekday (11 Jun 2024 is a Tuesday)
+            ("Fri, 11 Jun 2024 10:00:00 -0400", (2024, 6, 11, 14, 0, 0)),
+            ("Friday, 11 Jun 2024 10:00:00 EDT", (2024, 6, 11, 14, 0, 0)),
+            ("Wednesday, 12-Jun-24 10:00:00 GMT", (2024, 6, 12, 10, 0, 0)),
+            ("2024/01/05 10:00", (2024, 1, 5, 10, 0, 0)),
+            ("2024/01/05 10:00:30", (2024, 1, 5, 10, 0, 30)),
+            ("2024/01/05", (2024, 1, 5, 0, 0, 0)),
+            ("2024-01-05 10:00", (2024, 1, 5, 10, 0, 0)),
+            ("2024-01-05T10:00", (2024, 1, 5, 10, 0, 0)),
+            ("2024-01-05 10:00:00 +0100", (2024, 1, 5, 9, 0, 0)),
+            ("2024-01-05 10:00:00 PST", (2024, 1, 5, 18, 0, 0)),
+            ("05 Jan 2024 10:00 +0000", (2024, 1, 5, 10, 0, 0)),
+            ("05 Jan 2024", (2024, 1, 5, 0, 0, 0)),
+            ("January 5, 2024", (2024, 1, 5, 0, 0, 0)),
+            ("Fri Jan  5 10:00:00 2024", (2024, 1, 5, 10, 0, 0)),
+        ];
+
+        for (input, (y, mo, d, h, mi, sec)) in corpus {
+            let expected = Utc.with_ymd_and_hms(y, mo, d, h, mi, sec).unwrap();
+            assert_eq!(parse_flexible_time(input), Some(expected), "input: {input}");
+        }
+    }
 }
 
 mod duration_parse_tests {
@@ -110,6 +150,29 @@ mod html_utils_tests {
         );
     }
 
+    #[test]
+    fn test_strip_html_with_blocks_breaks_on_added_section() {
+        let html = "<section>First</section><section>Second</section>";
+        assert_eq!(strip_html_with_blocks(html, BLOCK_TAGS), "FirstSecond");
+
+        let mut tags = BLOCK_TAGS.to_vec();
+        tags.push("section");
+        assert_eq!(strip_html_with_blocks(html, &tags), "First\nSecond");
+    }
+
+    #[test]
+    fn test_strip_html_with_blocks_without_li_joins_list_items() {
+        let html = "<ul><li>One</li><li>Two</li></ul>";
+        assert_eq!(strip_html_with_blocks(html, BLOCK_TAGS), "One\nTwo");
+
+        let tags: Vec<&str> = BLOCK_TAGS
+            .iter()
+            .copied()
+            .filter(|t| *t != "li" && *t != "ul")
+            .collect();
+        assert_eq!(strip_html_with_blocks(html, &tags), "OneTwo");
+    }
+
     #[test]
     fn test_strip_html_collapses_whitespace() {
         assert_eq!(strip_html("<p>Hello</p>  <p>World</p>"), "Hello World");
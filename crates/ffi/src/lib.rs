@@ -3,14 +3,17 @@
 
 use std::panic;
 use std::ptr;
+use std::sync::RwLock;
 
 use bumpalo::Bump;
 use digests_feed::{
-    apply_metadata_to_feed, enrich_items_with_metadata, parse_feed_bytes, pick_site_url,
-    Author as FAuthor, Enclosure as FEnclosure, Feed as FFeed, FeedItem as FFeedItem,
+    apply_metadata_to_feed, enrich_items_with_metadata, parse_feed_bytes_with_options,
+    pick_site_url, Author as FAuthor, Enclosure as FEnclosure, Feed as FFeed, FeedError,
+    FeedItem as FFeedItem, ParseLimits, ParseOptions, Transcript as FTranscript, UNLIMITED_FETCHES,
 };
 use digests_hermes::{
-    extract_metadata_only, extract_reader_sync, ErrorCode, Metadata, ReaderResult,
+    extract_metadata_only, extract_reader_sync, set_log_hook, ErrorCode, LogLevel, Metadata,
+    ReaderResult,
 };
 use reqwest::blocking::Client as HttpClient;
 
@@ -82,6 +85,39 @@ impl DError {
     }
 }
 
+// ----------------------------------------------------------------------------
+// Logging
+// ----------------------------------------------------------------------------
+
+/// Log callback invoked for notable core events (SSRF blocks, fallbacks, fetch failures).
+///
+/// `level` is one of 0 (debug), 1 (info), 2 (warn), 3 (error). `msg` points to
+/// `len` bytes of UTF-8 (not null-terminated) that are only valid for the
+/// duration of the call; copy them if they must outlive it.
+pub type DLogCallback = extern "C" fn(level: u32, msg: *const u8, len: usize);
+
+static LOG_CALLBACK: RwLock<Option<DLogCallback>> = RwLock::new(None);
+
+/// Delivers a message to the registered C callback, if any.
+/// Also installed as the Hermes diagnostic hook while a callback is registered.
+fn log_event(level: LogLevel, message: &str) {
+    let cb = *LOG_CALLBACK.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(cb) = cb {
+        let buf = message.as_bytes().to_vec();
+        let _ = panic::catch_unwind(|| cb(level as u32, buf.as_ptr(), buf.len()));
+    }
+}
+
+/// Registers a log callback for core diagnostics. Passing null disables logging.
+///
+/// The callback may be invoked from any thread that calls into the library.
+#[no_mangle]
+pub extern "C" fn digests_set_log_callback(cb: Option<DLogCallback>) {
+    let mut guard = LOG_CALLBACK.write().unwrap_or_else(|e| e.into_inner());
+    *guard = cb;
+    set_log_hook(cb.map(|_| log_event as fn(LogLevel, &str)));
+}
+
 // ----------------------------------------------------------------------------
 // DReaderView - matches C ABI struct
 // ----------------------------------------------------------------------------
@@ -105,6 +141,36 @@ pub struct DReaderView {
     pub rendered_pages: u32,
     pub has_video_metadata: bool,
     pub video_url: DString,
+    pub content_truncated: bool,
+    pub canonical_url: DString,
+    pub reading_time_minutes: u32,
+}
+
+/// Options for reader extraction, matching C ABI DReaderOptions.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct DReaderOptions {
+    /// Maximum bytes of `content` copied into the arena; 0 means unbounded.
+    /// Truncation happens at a UTF-8 char boundary.
+    pub max_content_len: usize,
+}
+
+/// Options for feed parsing, matching C ABI DFeedOptions.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct DFeedOptions {
+    /// Fill DFeedItem.summary_html/content_html with sanitized item HTML.
+    /// When false those fields are empty and only plain text is returned.
+    pub sanitize_content: bool,
+    /// Keep line breaks at block elements in DFeedItem.summary/content.
+    pub preserve_paragraphs: bool,
+    /// Parse limits; 0 keeps the library default for that limit.
+    pub max_bytes: u64,
+    pub max_items: u32,
+    pub max_depth: u32,
+    pub max_text_bytes: u64,
+    /// Repair malformed XML (bare `&`, invalid chars, truncated tails) and retry.
+    pub recover_malformed: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -123,6 +189,22 @@ pub struct DMetadata {
     pub icon_url: DString,
     pub theme_color: DString,
     pub language: DString,
+    pub image_width: u32,
+    pub image_height: u32,
+    pub twitter_card: DString,
+    pub twitter_site: DString,
+    pub twitter_creator: DString,
+    pub published_time: DString,
+    pub modified_time: DString,
+    pub article_author: DString,
+    pub article_tags: *const DString,
+    pub article_tags_len: usize,
+    pub ios_app_url: DString,
+    pub ios_app_store_id: DString,
+    pub ios_app_name: DString,
+    pub android_app_url: DString,
+    pub android_package: DString,
+    pub android_app_name: DString,
 }
 
 // ----------------------------------------------------------------------------
@@ -137,6 +219,15 @@ pub struct DEnclosure {
     pub length: u64,
 }
 
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct DTranscript {
+    pub url: DString,
+    pub r#type: DString,
+    pub language: DString,
+    pub rel: DString,
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct DAuthor {
@@ -167,6 +258,29 @@ pub struct DFeedItem {
     pub thumbnail_url: DString,
     pub explicit_flag: bool,
     pub duration_seconds: u32,
+    pub transcripts: *const DTranscript,
+    pub transcripts_len: usize,
+    pub chapters_url: DString,
+    pub comments_url: DString,
+    pub comments_count: u32,
+    pub season: u32,
+    pub episode: u32,
+    pub episode_type: DString,
+    pub itunes_block: bool,
+    pub authors: *const DAuthor,
+    pub authors_len: usize,
+    pub summary_html: DString,
+    pub content_html: DString,
+    pub thumbnail_width: u32,
+    pub thumbnail_height: u32,
+    pub video_id: DString,
+    pub channel_id: DString,
+    pub guid_generated: bool,
+    pub source_title: DString,
+    pub source_url: DString,
+    pub content_hash: DString,
+    pub primary_enclosure_index: i64,
+    pub edited: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -186,6 +300,13 @@ pub struct DFeed {
     pub generator: DString,
     pub copyright: DString,
     pub feed_type: DString,
+    pub description_html: DString,
+    pub hub_url: DString,
+    pub self_url: DString,
+    pub supports_push: bool,
+    pub icon_url: DString,
+    pub theme_color: DString,
+    pub recovered: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -241,13 +362,27 @@ fn copy_str_to_arena(bump: &Bump, s: &str) -> DString {
     }
 }
 
+/// Returns the longest prefix of `s` within `max_len` bytes that ends on a char boundary.
+/// A `max_len` of 0 means unbounded.
+fn truncate_at_char_boundary(s: &str, max_len: usize) -> &str {
+    if max_len == 0 || s.len() <= max_len {
+        return s;
+    }
+    let mut end = max_len;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
 /// Creates a DReaderView in the arena from a ReaderResult.
-fn make_reader_view(bump: &Bump, rr: &ReaderResult) -> *const DReaderView {
+fn make_reader_view(bump: &Bump, rr: &ReaderResult, opts: &DReaderOptions) -> *const DReaderView {
+    let content = truncate_at_char_boundary(&rr.content, opts.max_content_len);
     let view = bump.alloc(DReaderView {
         title: copy_str_to_arena(bump, &rr.title),
         author: copy_str_to_arena(bump, &rr.author),
         excerpt: copy_str_to_arena(bump, &rr.excerpt),
-        content: copy_str_to_arena(bump, &rr.content),
+        content: copy_str_to_arena(bump, content),
         url: copy_str_to_arena(bump, &rr.url),
         site_name: copy_str_to_arena(bump, &rr.site_name),
         domain: copy_str_to_arena(bump, &rr.domain),
@@ -261,12 +396,17 @@ fn make_reader_view(bump: &Bump, rr: &ReaderResult) -> *const DReaderView {
         rendered_pages: rr.rendered_pages,
         has_video_metadata: rr.has_video_metadata,
         video_url: copy_str_to_arena(bump, &rr.video_url),
+        content_truncated: content.len() < rr.content.len(),
+        canonical_url: copy_str_to_arena(bump, &rr.canonical_url),
+        reading_time_minutes: rr.reading_time_minutes,
     });
     view as *const DReaderView
 }
 
 /// Creates a DMetadata in the arena from a Metadata.
 fn make_metadata_view(bump: &Bump, meta: &Metadata) -> *const DMetadata {
+    let tags =
+        bump.alloc_slice_fill_iter(meta.article_tags.iter().map(|t| copy_str_to_arena(bump, t)));
     let dm = bump.alloc(DMetadata {
         title: copy_str_to_arena(bump, &meta.title),
         description: copy_str_to_arena(bump, &meta.description),
@@ -278,6 +418,22 @@ fn make_metadata_view(bump: &Bump, meta: &Metadata) -> *const DMetadata {
         icon_url: copy_str_to_arena(bump, &meta.icon_url),
         theme_color: copy_str_to_arena(bump, &meta.theme_color),
         language: copy_str_to_arena(bump, &meta.language),
+        image_width: meta.image_width,
+        image_height: meta.image_height,
+        twitter_card: copy_str_to_arena(bump, &meta.twitter_card),
+        twitter_site: copy_str_to_arena(bump, &meta.twitter_site),
+        twitter_creator: copy_str_to_arena(bump, &meta.twitter_creator),
+        published_time: copy_str_to_arena(bump, &meta.published_time),
+        modified_time: copy_str_to_arena(bump, &meta.modified_time),
+        article_author: copy_str_to_arena(bump, &meta.article_author),
+        article_tags: tags.as_ptr(),
+        article_tags_len: tags.len(),
+        ios_app_url: copy_str_to_arena(bump, &meta.ios_app_url),
+        ios_app_store_id: copy_str_to_arena(bump, &meta.ios_app_store_id),
+        ios_app_name: copy_str_to_arena(bump, &meta.ios_app_name),
+        android_app_url: copy_str_to_arena(bump, &meta.android_app_url),
+        android_package: copy_str_to_arena(bump, &meta.android_package),
+        android_app_name: copy_str_to_arena(bump, &meta.android_app_name),
     });
     dm as *const DMetadata
 }
@@ -302,6 +458,17 @@ fn make_enclosures<'a>(bump: &'a Bump, encs: &[FEnclosure]) -> (&'a [DEnclosure]
     (slice, slice.len())
 }
 
+/// Creates a DTranscript slice from Podcast 2.0 transcripts.
+fn make_transcripts<'a>(bump: &'a Bump, transcripts: &[FTranscript]) -> &'a [DTranscript] {
+    let out_iter = transcripts.iter().map(|t| DTranscript {
+        url: copy_str_to_arena(bump, &t.url),
+        r#type: copy_str_to_arena(bump, t.mime_type.as_deref().unwrap_or("")),
+        language: copy_str_to_arena(bump, t.language.as_deref().unwrap_or("")),
+        rel: copy_str_to_arena(bump, t.rel.as_deref().unwrap_or("")),
+    });
+    bump.alloc_slice_fill_iter(out_iter)
+}
+
 /// Creates a DFeedItem slice from feed items.
 fn make_feed_items<'a>(bump: &'a Bump, items: &[FFeedItem]) -> (&'a [DFeedItem], usize) {
     let mut out = Vec::with_capacity(items.len());
@@ -313,6 +480,13 @@ fn make_feed_items<'a>(bump: &'a Bump, items: &[FFeedItem]) -> (&'a [DFeedItem],
         // Enclosures
         let (enc_slice, enc_len) = make_enclosures(bump, &it.enclosures);
 
+        // Podcast 2.0 transcripts
+        let transcript_slice = make_transcripts(bump, &it.transcripts);
+
+        // All credited authors and contributors
+        let author_slice =
+            bump.alloc_slice_fill_iter(it.authors.iter().map(|a| make_author(bump, a)));
+
         out.push(DFeedItem {
             title: copy_str_to_arena(bump, &it.title),
             url: copy_str_to_arena(bump, &it.url),
@@ -336,6 +510,29 @@ fn make_feed_items<'a>(bump: &'a Bump, items: &[FFeedItem]) -> (&'a [DFeedItem],
             thumbnail_url: copy_str_to_arena(bump, it.thumbnail_url.as_deref().unwrap_or("")),
             explicit_flag: it.explicit_flag,
             duration_seconds: it.duration_seconds,
+            transcripts: transcript_slice.as_ptr(),
+            transcripts_len: transcript_slice.len(),
+            chapters_url: copy_str_to_arena(bump, it.chapters_url.as_deref().unwrap_or("")),
+            comments_url: copy_str_to_arena(bump, it.comments_url.as_deref().unwrap_or("")),
+            comments_count: it.comments_count.unwrap_or(0),
+            season: it.season.unwrap_or(0),
+            episode: it.episode.unwrap_or(0),
+            episode_type: copy_str_to_arena(bump, it.episode_type.as_deref().unwrap_or("")),
+            itunes_block: it.itunes_block,
+            authors: author_slice.as_ptr(),
+            authors_len: author_slice.len(),
+            summary_html: copy_str_to_arena(bump, it.summary_html.as_deref().unwrap_or("")),
+            content_html: copy_str_to_arena(bump, it.content_html.as_deref().unwrap_or("")),
+            thumbnail_width: it.thumbnail_width.unwrap_or(0),
+            thumbnail_height: it.thumbnail_height.unwrap_or(0),
+            video_id: copy_str_to_arena(bump, it.video_id.as_deref().unwrap_or("")),
+            channel_id: copy_str_to_arena(bump, it.channel_id.as_deref().unwrap_or("")),
+            guid_generated: it.guid_generated,
+            source_title: copy_str_to_arena(bump, it.source_title.as_deref().unwrap_or("")),
+            source_url: copy_str_to_arena(bump, it.source_url.as_deref().unwrap_or("")),
+            content_hash: copy_str_to_arena(bump, &it.content_hash),
+            primary_enclosure_index: it.primary_enclosure_index.map_or(-1, |i| i as i64),
+            edited: it.edited,
         });
     }
     let slice = bump.alloc_slice_fill_iter(out.into_iter());
@@ -360,6 +557,13 @@ fn make_feed_view(bump: &Bump, feed: &FFeed) -> *const DFeed {
         generator: copy_str_to_arena(bump, feed.generator.as_deref().unwrap_or("")),
         copyright: copy_str_to_arena(bump, feed.copyright.as_deref().unwrap_or("")),
         feed_type: copy_str_to_arena(bump, &feed.feed_type),
+        description_html: copy_str_to_arena(bump, feed.description_html.as_deref().unwrap_or("")),
+        hub_url: copy_str_to_arena(bump, feed.hub_url.as_deref().unwrap_or("")),
+        self_url: copy_str_to_arena(bump, feed.self_url.as_deref().unwrap_or("")),
+        supports_push: feed.supports_push,
+        icon_url: copy_str_to_arena(bump, feed.icon_url.as_deref().unwrap_or("")),
+        theme_color: copy_str_to_arena(bump, feed.theme_color.as_deref().unwrap_or("")),
+        recovered: feed.recovered,
     });
     df as *const DFeed
 }
@@ -421,6 +625,29 @@ pub unsafe extern "C" fn digests_extract_reader(
     html_len: usize,
     out_err: *mut DError,
 ) -> *mut DReaderArena {
+    digests_extract_reader_with_options(url, url_len, html, html_len, ptr::null(), out_err)
+}
+
+/// Blocking reader extraction with options. Same as digests_extract_reader, but
+/// `opts` (may be null for defaults) bounds how much content is copied into the arena.
+///
+/// # Safety
+/// Same requirements as digests_extract_reader; `opts` must be null or point to a valid DReaderOptions.
+#[no_mangle]
+pub unsafe extern "C" fn digests_extract_reader_with_options(
+    url: *const u8,
+    url_len: usize,
+    html: *const u8,
+    html_len: usize,
+    opts: *const DReaderOptions,
+    out_err: *mut DError,
+) -> *mut DReaderArena {
+    let opts = if opts.is_null() {
+        DReaderOptions::default()
+    } else {
+        *opts
+    };
+
     // Create a temporary bump for error messages if we fail early
     let err_bump = Bump::new();
 
@@ -480,7 +707,7 @@ pub unsafe extern "C" fn digests_extract_reader(
         Ok(Ok(reader_result)) => {
             // Success - create arena and view
             let bump = Bump::new();
-            let view = make_reader_view(&bump, &reader_result);
+            let view = make_reader_view(&bump, &reader_result, &opts);
             let arena = Box::new(DReaderArena { bump, view });
             set_success(out_err);
             Box::into_raw(arena)
@@ -682,6 +909,61 @@ pub unsafe extern "C" fn digests_parse_feed(
     data_len: usize,
     out_err: *mut DError,
 ) -> *mut DFeedArena {
+    digests_parse_feed_with_options(
+        feed_url_ptr,
+        feed_url_len,
+        data_ptr,
+        data_len,
+        ptr::null(),
+        out_err,
+    )
+}
+
+/// Maps DFeedOptions limit fields onto ParseLimits; zero keeps each default.
+fn feed_limits(opts: &DFeedOptions) -> ParseLimits {
+    let defaults = ParseLimits::default();
+    let or_default = |value: u64, default: Option<usize>| {
+        if value == 0 {
+            default
+        } else {
+            Some(usize::try_from(value).unwrap_or(usize::MAX))
+        }
+    };
+    ParseLimits {
+        max_bytes: or_default(opts.max_bytes, defaults.max_bytes),
+        max_items: or_default(u64::from(opts.max_items), defaults.max_items),
+        max_depth: or_default(u64::from(opts.max_depth), defaults.max_depth),
+        max_text_bytes: or_default(opts.max_text_bytes, defaults.max_text_bytes),
+    }
+}
+
+/// Feed parsing with options. Same as digests_parse_feed, but `opts` (may be null
+/// for defaults) can request sanitized item HTML in `summary_html`/`content_html`
+/// and tighten parse limits (exceeding one fails with D_ERR_INVALID).
+///
+/// # Safety
+/// Same requirements as digests_parse_feed; `opts` must be null or point to a valid DFeedOptions.
+#[no_mangle]
+pub unsafe extern "C" fn digests_parse_feed_with_options(
+    feed_url_ptr: *const u8,
+    feed_url_len: usize,
+    data_ptr: *const u8,
+    data_len: usize,
+    opts: *const DFeedOptions,
+    out_err: *mut DError,
+) -> *mut DFeedArena {
+    let opts = if opts.is_null() {
+        DFeedOptions::default()
+    } else {
+        *opts
+    };
+    let parse_options = ParseOptions {
+        sanitize_content: opts.sanitize_content,
+        preserve_paragraphs: opts.preserve_paragraphs,
+        limits: feed_limits(&opts),
+        recover_malformed: opts.recover_malformed,
+    };
+
     let err_bump = Bump::new();
 
     if feed_url_ptr.is_null() || data_ptr.is_null() || feed_url_len == 0 || data_len == 0 {
@@ -710,12 +992,17 @@ pub unsafe extern "C" fn digests_parse_feed(
         }
     };
 
-    let feed_result = panic::catch_unwind(|| parse_feed_bytes(data_bytes, feed_url));
+    let feed_result =
+        panic::catch_unwind(|| parse_feed_bytes_with_options(data_bytes, feed_url, &parse_options));
 
     let mut feed = match feed_result {
         Ok(Ok(f)) => f,
         Ok(Err(e)) => {
-            set_error(out_err, &err_bump, DErrorCode::Parse as u32, &e.to_string());
+            let code = match e {
+                FeedError::LimitExceeded(_) => DErrorCode::Invalid,
+                _ => DErrorCode::Parse,
+            };
+            set_error(out_err, &err_bump, code as u32, &e.to_string());
             return ptr::null_mut();
         }
         Err(_) => {
@@ -733,18 +1020,31 @@ pub unsafe extern "C" fn digests_parse_feed(
     if let Ok(http_client) = HttpClient::builder().user_agent("digests-core/ffi").build() {
         // Feed-level metadata from site/homepage
         if let Some(site_url) = pick_site_url(&feed) {
-            if let Ok(site_html) = fetch_html(&http_client, &site_url) {
-                if let Ok(meta) = extract_metadata_only(&site_html, &site_url) {
-                    apply_metadata_to_feed(&mut feed, &meta);
+            match fetch_html(&http_client, &site_url) {
+                Ok(site_html) => {
+                    if let Ok(meta) = extract_metadata_only(&site_html, &site_url) {
+                        apply_metadata_to_feed(&mut feed, &meta);
+                    }
                 }
+                Err(e) => log_event(
+                    LogLevel::Warn,
+                    &format!("feed enrichment fetch failed for {}: {}", site_url, e),
+                ),
             }
         }
 
         // Item-level metadata thumbnails (only when missing)
-        enrich_items_with_metadata(&mut feed, |url| {
-            fetch_html(&http_client, url)
-                .ok()
-                .and_then(|html| extract_metadata_only(&html, url).ok())
+        enrich_items_with_metadata(&mut feed, UNLIMITED_FETCHES, |url| {
+            match fetch_html(&http_client, url) {
+                Ok(html) => extract_metadata_only(&html, url).ok(),
+                Err(e) => {
+                    log_event(
+                        LogLevel::Warn,
+                        &format!("item enrichment fetch failed for {}: {}", url, e),
+                    );
+                    None
+                }
+            }
         });
     }
 
@@ -808,6 +1108,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reader_content_budget_truncates() {
+        let paragraph = "<p>Café owners along the river say the long summer season, with its steady tourist traffic, kept them busy well into the autumn months.</p>";
+        let html = format!(
+            "<html><head><title>Long</title></head><body><article>{}</article></body></html>",
+            paragraph.repeat(50)
+        );
+        let url = "https://example.com/long";
+        let opts = DReaderOptions {
+            max_content_len: 200,
+        };
+        let mut err = DError::ok();
+
+        unsafe {
+            let arena = digests_extract_reader_with_options(
+                url.as_ptr(),
+                url.len(),
+                html.as_ptr(),
+                html.len(),
+                &opts,
+                &mut err,
+            );
+            assert!(!arena.is_null());
+            let view = &*digests_reader_result(arena);
+            assert!(view.content.len > 0 && view.content.len <= 200);
+            assert!(view.content_truncated);
+            let slice = std::slice::from_raw_parts(view.content.data, view.content.len);
+            assert!(std::str::from_utf8(slice).is_ok());
+            digests_free_reader(arena);
+
+            let arena = digests_extract_reader(
+                url.as_ptr(),
+                url.len(),
+                html.as_ptr(),
+                html.len(),
+                &mut err,
+            );
+            let view = &*digests_reader_result(arena);
+            assert!(view.content.len > 200);
+            assert!(!view.content_truncated);
+            digests_free_reader(arena);
+        }
+    }
+
+    #[test]
+    fn test_truncate_at_char_boundary() {
+        assert_eq!(truncate_at_char_boundary("café", 4), "caf");
+        assert_eq!(truncate_at_char_boundary("café", 0), "café");
+        assert_eq!(truncate_at_char_boundary("abc", 10), "abc");
+    }
+
     #[test]
     fn test_copy_empty_str_to_arena() {
         let bump = Bump::new();
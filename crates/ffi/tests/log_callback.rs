@@ -0,0 +1,54 @@
+// ABOUTME: Integration tests for the FFI log callback hook.
+// ABOUTME: Kept in its own test binary because the callback is process-global state.
+
+use std::slice;
+use std::str;
+use std::sync::Mutex;
+
+use digests_ffi::{
+    digests_extract_reader, digests_free_reader, digests_set_log_callback, DError, DErrorCode,
+    DString,
+};
+
+static MESSAGES: Mutex<Vec<(u32, String)>> = Mutex::new(Vec::new());
+
+extern "C" fn record_log(level: u32, msg: *const u8, len: usize) {
+    let text = unsafe { str::from_utf8(slice::from_raw_parts(msg, len)) }
+        .unwrap_or("")
+        .to_string();
+    MESSAGES.lock().unwrap().push((level, text));
+}
+
+#[test]
+fn test_log_callback_receives_fallback_message() {
+    // Too little HTML content forces the JSON-LD articleBody fallback.
+    let html = r#"<html><head><title>Short</title>
+        <script type="application/ld+json">
+        {"@type": "NewsArticle", "articleBody": "The full story lives in structured data."}
+        </script></head><body>hi</body></html>"#;
+    let url = "https://example.com/short";
+
+    digests_set_log_callback(Some(record_log));
+
+    unsafe {
+        let mut err = DError {
+            code: DErrorCode::Internal as u32,
+            message: DString::empty(),
+        };
+        let arena =
+            digests_extract_reader(url.as_ptr(), url.len(), html.as_ptr(), html.len(), &mut err);
+        assert!(!arena.is_null(), "extraction should succeed");
+        digests_free_reader(arena);
+    }
+
+    digests_set_log_callback(None);
+
+    let messages = MESSAGES.lock().unwrap();
+    assert!(
+        messages
+            .iter()
+            .any(|(level, m)| *level == 1 && m.contains("JSON-LD articleBody")),
+        "expected a fallback log message, got: {:?}",
+        *messages
+    );
+}
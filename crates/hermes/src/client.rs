@@ -1,32 +1,54 @@
 // ABOUTME: The main Client struct for Hermes that handles HTTP requests and HTML parsing.
 // ABOUTME: Provides async parse() and parse_html() methods to extract article content from URLs or HTML strings.
 
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use chrono::{DateTime, Utc};
 use dom_query::Document;
 
+use crate::diagnostics;
 use crate::dom::brs::brs_to_ps_inplace;
+use crate::dom::strip_pull_quotes;
 use crate::error::ParseError;
+use crate::extractors::breadcrumbs::extract_breadcrumbs;
 use crate::extractors::content::{
     extract_content_first_html, extract_content_html_opts, extract_content_raw_first_html,
+    matching_content_selector,
 };
 #[cfg(test)]
 use crate::extractors::custom::ContentExtractor;
 use crate::extractors::custom::{ExtractorRegistry, FieldExtractor, SelectorSpec};
+use crate::extractors::embeds::{extract_embeds, video_info};
 use crate::extractors::fields::{
     extract_attr_first, extract_field_text_single, extract_first_attr, extract_meta_content,
-    normalize_lang,
+    extract_meta_contents_all, normalize_lang, normalize_locale,
 };
+use crate::extractors::images::extract_images;
+use crate::extractors::ld_json::{extract_ld_article, LdArticle};
+use crate::extractors::lead_image::{is_unlikely_lead_image, score_lead_image};
+use crate::extractors::links::extract_links;
 use crate::extractors::loader::load_builtin_registry;
-use crate::extractors::select::extract_field_first_text;
+use crate::extractors::oembed::{extract_oembed_url, parse_oembed};
+use crate::extractors::rdfa::extract_rdfa_article;
+use crate::extractors::select::{extract_field_first_text, extract_field_text_all};
+use crate::extractors::structured::{extract_event, extract_product, extract_recipe};
+use crate::extractors::tables::extract_tables;
+use crate::extractors::title::clean_title;
 use crate::formats::{
-    extract_excerpt, extract_title, html_to_markdown, html_to_text, sanitize_html,
+    absolutize_urls, extract_excerpt, extract_title, html_to_markdown, html_to_text,
+    image_source_urls, inline_image_sources, sanitize_html, select_image_sources,
 };
+use crate::keywords::extract_keywords;
 use crate::options::{ClientBuilder, ContentType, Options};
-use crate::resource::{fetch, FetchOptions};
-use crate::result::{word_count, ParseResult};
-#[cfg(test)]
+use crate::resource::{
+    fetch, fetch_with_limit, normalize_url, resolve_http_url, same_origin, FetchOptions,
+};
+use crate::result::{
+    reading_time_minutes, word_count, ExtractionInfo, ExtractionMethod, OEmbed, ParseResult,
+    VideoInfo,
+};
+use crate::summarize::summarize;
 use std::collections::HashMap;
-use std::net::ToSocketAddrs;
 use url::Url;
 
 /// Build a generic title FieldExtractor with fallback selectors.
@@ -46,6 +68,93 @@ fn build_generic_title_extractor() -> FieldExtractor {
     }
 }
 
+/// Extract the title: custom extractor, then JSON-LD headline, then `<title>`
+/// and generic heuristics. Heuristic titles are cleaned of site name and
+/// section labels (see `clean_title`).
+///
+/// Returns `(title, raw_title)`, where `raw_title` is the title before cleaning.
+fn extract_page_title(
+    doc: &Document,
+    html: &str,
+    custom: Option<&FieldExtractor>,
+    ld: Option<&LdArticle>,
+    domain: &str,
+) -> (String, Option<String>) {
+    let exact = custom
+        .and_then(|te| extract_field_first_text(doc, te))
+        .or_else(|| ld.and_then(|a| a.headline.clone()));
+    if let Some(title) = exact {
+        return (title.clone(), Some(title));
+    }
+
+    let Some(raw) = extract_title(html).or_else(|| {
+        let title_extractor = build_generic_title_extractor();
+        extract_field_first_text(doc, &title_extractor)
+    }) else {
+        return (String::new(), None);
+    };
+    let site_name = extract_site_name(doc).or_else(|| ld.and_then(|a| a.publisher.clone()));
+    let h1 = extract_field_text_single(doc, &["h1"]);
+    let title = clean_title(&raw, site_name.as_deref(), h1.as_deref(), domain);
+    (title, Some(raw))
+}
+
+/// Reading time of the extracted content HTML: its words plus an allowance
+/// per `<img>`.
+fn estimate_reading_time(content_html: &str, wpm: u32) -> u32 {
+    let words = word_count(&html_to_text(content_html)).max(0) as usize;
+    let images = Document::from(content_html).select("img").length();
+    reading_time_minutes(words, images, wpm)
+}
+
+/// Up to `sentences` key sentences of the content HTML joined into one
+/// paragraph; `None` when disabled or nothing qualifies.
+fn summarize_content(content_html: &str, sentences: usize) -> Option<String> {
+    if sentences == 0 {
+        return None;
+    }
+    let picked = summarize(&html_to_text(content_html), sentences);
+    (!picked.is_empty()).then(|| picked.join(" "))
+}
+
+/// Most keywords reported on a result.
+const MAX_KEYWORDS: usize = 15;
+
+/// Page keywords, best first: publisher-curated terms (`keywords`,
+/// `news_keywords`, `article:tag`) followed by phrases ranked from the
+/// content. Content ranking is English-only and is skipped for pages in
+/// other languages.
+fn extract_page_keywords(
+    doc: &Document,
+    content_html: &str,
+    language: Option<&str>,
+) -> Vec<String> {
+    let mut keywords: Vec<String> = Vec::new();
+    let mut push = |keyword: &str| {
+        let keyword = keyword.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !keyword.is_empty() && !keywords.iter().any(|k| k.eq_ignore_ascii_case(&keyword)) {
+            keywords.push(keyword);
+        }
+    };
+
+    for sel in ["meta[name='keywords']", "meta[name='news_keywords']"] {
+        for value in extract_meta_contents_all(doc, sel) {
+            value.split([',', ';']).for_each(&mut push);
+        }
+    }
+    extract_meta_contents_all(doc, "meta[property='article:tag']")
+        .iter()
+        .for_each(|tag| push(tag));
+
+    if language.is_none_or(|lang| lang.starts_with("en")) {
+        extract_keywords(&html_to_text(content_html), MAX_KEYWORDS)
+            .iter()
+            .for_each(|phrase| push(phrase));
+    }
+    keywords.truncate(MAX_KEYWORDS);
+    keywords
+}
+
 /// Extract body inner HTML from a parsed document.
 ///
 /// Tries to select "body" element and return its inner HTML.
@@ -94,6 +203,27 @@ fn wrap_plaintext_as_html(text: &str) -> String {
 
 /// Extract generic content using the Go-equivalent readability/scoring pipeline.
 fn score_generic_content(raw_html: &str, title: &str) -> Option<String> {
+    score_generic_content_with_locator(raw_html, title).map(|(html, _, _)| html)
+}
+
+/// Describe a scored candidate as `tag.class1.class2` for provenance reporting.
+fn candidate_locator(candidate: &dom_query::Selection) -> String {
+    let mut locator = crate::dom::get_tag_name(candidate);
+    if let Some(class) = candidate.attr("class") {
+        for c in class.split_whitespace() {
+            locator.push('.');
+            locator.push_str(c);
+        }
+    }
+    locator
+}
+
+/// Like [`score_generic_content`], also returning the `readability:<tag.class>` locator
+/// and score of the winning candidate.
+fn score_generic_content_with_locator(
+    raw_html: &str,
+    title: &str,
+) -> Option<(String, String, i32)> {
     // Parse once, then normalize BRs in-place for paragraph detection
     let mut doc = Document::from(raw_html);
     brs_to_ps_inplace(&mut doc);
@@ -123,6 +253,7 @@ fn score_generic_content(raw_html: &str, title: &str) -> Option<String> {
         );
     }
 
+    let locator = format!("readability:{}", candidate_locator(&candidate));
     let merged = crate::dom::merge_siblings(candidate, top_score, &scores, &text_metrics);
 
     // Clean merged content (includes div->p, unlikely stripping, conditional cleaning, br->p, top-level rewrite)
@@ -135,17 +266,64 @@ fn score_generic_content(raw_html: &str, title: &str) -> Option<String> {
         cleaned.len()
     );
 
-    Some(cleaned)
+    Some((cleaned, locator, top_score))
 }
 
-/// Generic author selectors in priority order.
-const GENERIC_AUTHOR_SELECTORS: &[&str] = &[
-    "meta[name='author']",
-    "meta[property='article:author']",
-    ".byline",
-    ".author",
-    "[itemprop='author']",
-];
+/// Content with at least this many characters of text counts as a full article.
+const CONFIDENT_CONTENT_CHARS: usize = 1500;
+
+/// Extraction diagnostics for the final content HTML and the locator recorded
+/// in `content_selector` (`amp:` prefixed when the AMP page was used).
+fn extraction_info(
+    selector: &str,
+    candidate_score: Option<i32>,
+    content_html: &str,
+) -> ExtractionInfo {
+    let from_amp = selector.starts_with("amp:");
+    let selector = selector.trim_start_matches("amp:");
+    let method = if selector.starts_with("readability:") {
+        ExtractionMethod::Readability
+    } else if selector.starts_with("json-ld:") {
+        ExtractionMethod::JsonLd
+    } else if selector == "body" {
+        ExtractionMethod::Body
+    } else {
+        ExtractionMethod::CustomExtractor
+    };
+
+    let doc = Document::from(content_html);
+    let link_density = crate::dom::link_density(&doc.select("body"));
+    let content_length = html_to_text(content_html).trim().chars().count();
+
+    let method_weight = match method {
+        ExtractionMethod::CustomExtractor => 1.0,
+        ExtractionMethod::Readability => 0.9,
+        ExtractionMethod::JsonLd => 0.7,
+        ExtractionMethod::Body => 0.4,
+    };
+    let length_weight = (content_length as f64 / CONFIDENT_CONTENT_CHARS as f64).min(1.0);
+    let confidence = (method_weight * length_weight * (1.0 - link_density)).clamp(0.0, 1.0);
+
+    ExtractionInfo {
+        method,
+        from_amp,
+        candidate_score,
+        link_density,
+        content_length,
+        confidence,
+    }
+}
+
+/// Generic author meta tags in priority order; a page may repeat a tag once per author.
+const GENERIC_AUTHOR_META_SELECTORS: &[&str] =
+    &["meta[name='author']", "meta[property='article:author']"];
+
+/// Links to author pages; every match is an author.
+const AUTHOR_LINK_SELECTOR: &str = "a[rel~='author']";
+
+/// Generic byline selectors in priority order. Only the first match is used,
+/// since these classes also mark bylines of related-article teasers.
+const GENERIC_AUTHOR_SELECTORS: &[&str] = &[".byline", ".author", "[itemprop='author']"];
 
 /// Generic date selectors for meta tags (content attribute).
 const GENERIC_DATE_META_SELECTORS: &[&str] = &[
@@ -153,11 +331,30 @@ const GENERIC_DATE_META_SELECTORS: &[&str] = &[
     "meta[name='date']",
 ];
 
-/// Generic lead image selectors in priority order.
+/// Generic date-modified meta selectors in priority order.
+const GENERIC_DATE_MODIFIED_META_SELECTORS: &[&str] = &[
+    "meta[property='article:modified_time']",
+    "meta[property='og:updated_time']",
+    "meta[itemprop='dateModified']",
+    "meta[name='last-modified']",
+];
+
+/// Minimum gap between publish and modify dates for an article to count as updated.
+const UPDATED_AFTER_PUBLISH_THRESHOLD_SECS: i64 = 60 * 60;
+
+/// Maximum number of `<meta http-equiv="refresh">` redirects followed per parse.
+const MAX_META_REFRESH_HOPS: usize = 3;
+
+/// Refreshes with a longer delay are page reloads (e.g. news homepages), not redirects.
+const MAX_META_REFRESH_DELAY_SECS: f64 = 5.0;
+
+/// Extracted text shorter than this (in bytes) triggers the AMP fallback.
+const AMP_FALLBACK_MIN_TEXT_LEN: usize = 500;
+
+/// Declared lead image meta tags in priority order.
 const GENERIC_IMAGE_SELECTORS: &[(&str, &str)] = &[
     ("meta[property='og:image']", "content"),
     ("meta[name='twitter:image']", "content"),
-    ("img", "src"),
 ];
 
 /// Parse a date string, trying RFC3339 first then falling back to dateparser.
@@ -198,23 +395,63 @@ fn parse_date(s: &str) -> Option<DateTime<Utc>> {
     None
 }
 
-/// Extract author using custom extractor field if available, falling back to generic heuristics.
-fn extract_author(doc: &Document, custom: Option<&FieldExtractor>) -> Option<String> {
+/// Extract all authors using custom extractor field if available, then JSON-LD, falling back to
+/// generic heuristics (author meta tags, `rel="author"` links, then byline classes).
+///
+/// Every match of the winning source is kept, in document order and deduplicated.
+fn extract_authors(
+    doc: &Document,
+    custom: Option<&FieldExtractor>,
+    ld: Option<&LdArticle>,
+) -> Vec<String> {
     // Try custom extractor first
-    if let Some(fe) = custom {
-        if let Some(author) = extract_field_first_text(doc, fe) {
-            return Some(author);
-        }
+    if let Some(authors) = custom.and_then(|fe| extract_field_text_all(doc, fe)) {
+        return dedupe(authors);
+    }
+
+    if let Some(ld) = ld.filter(|a| !a.authors.is_empty()) {
+        return ld.authors.clone();
     }
 
     // Fall back to generic heuristics
+    for sel in GENERIC_AUTHOR_META_SELECTORS {
+        let authors = extract_meta_contents_all(doc, sel);
+        if !authors.is_empty() {
+            return dedupe(authors);
+        }
+    }
+
+    let linked: Vec<String> = doc
+        .select(AUTHOR_LINK_SELECTOR)
+        .iter()
+        .map(|a| a.text().split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|name| !name.is_empty())
+        .collect();
+    if !linked.is_empty() {
+        return dedupe(linked);
+    }
+
     extract_field_text_single(doc, GENERIC_AUTHOR_SELECTORS)
+        .into_iter()
+        .collect()
+}
+
+/// Drops repeated values, keeping the first occurrence of each.
+fn dedupe(values: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(values.len());
+    for value in values {
+        if !out.contains(&value) {
+            out.push(value);
+        }
+    }
+    out
 }
 
-/// Extract date_published using custom extractor field if available, falling back to generic heuristics.
+/// Extract date_published using custom extractor field if available, then JSON-LD, falling back to generic heuristics.
 fn extract_date_published(
     doc: &Document,
     custom: Option<&FieldExtractor>,
+    ld: Option<&LdArticle>,
 ) -> Option<DateTime<Utc>> {
     // Try custom extractor first
     if let Some(fe) = custom {
@@ -225,6 +462,13 @@ fn extract_date_published(
         }
     }
 
+    if let Some(dt) = ld
+        .and_then(|a| a.date_published.as_deref())
+        .and_then(parse_date)
+    {
+        return Some(dt);
+    }
+
     // Fall back to generic heuristics: meta tags first
     for sel in GENERIC_DATE_META_SELECTORS {
         if let Some(content) = extract_meta_content(doc, sel) {
@@ -251,8 +495,51 @@ fn extract_date_published(
     None
 }
 
-/// Extract lead_image_url using custom extractor field if available, falling back to generic heuristics.
-fn extract_lead_image_url(doc: &Document, custom: Option<&FieldExtractor>) -> Option<String> {
+/// Extract date_modified from JSON-LD, generic meta tags, or a `time[itemprop=dateModified]` element.
+fn extract_date_modified(doc: &Document, ld: Option<&LdArticle>) -> Option<DateTime<Utc>> {
+    if let Some(dt) = ld
+        .and_then(|a| a.date_modified.as_deref())
+        .and_then(parse_date)
+    {
+        return Some(dt);
+    }
+
+    for sel in GENERIC_DATE_MODIFIED_META_SELECTORS {
+        if let Some(content) = extract_meta_content(doc, sel) {
+            if let Some(dt) = parse_date(&content) {
+                return Some(dt);
+            }
+        }
+    }
+
+    extract_attr_first(doc, "time[itemprop='dateModified'][datetime]", "datetime")
+        .and_then(|dt_str| parse_date(&dt_str))
+}
+
+/// Whether the article was meaningfully modified after it was published.
+fn was_updated_after_publish(
+    published: Option<DateTime<Utc>>,
+    modified: Option<DateTime<Utc>>,
+) -> bool {
+    match (published, modified) {
+        (Some(p), Some(m)) => (m - p).num_seconds() > UPDATED_AFTER_PUBLISH_THRESHOLD_SECS,
+        _ => false,
+    }
+}
+
+/// Extract lead_image_url using custom extractor field if available, then JSON-LD, falling back to generic heuristics.
+///
+/// Declared images (`og:image`, `twitter:image`) win unless they look like a
+/// logo or placeholder; then the best-scoring `<img>` is used, and the
+/// declared image only when no `<img>` scores well. `base_url` resolves
+/// image sources when matching them against the content.
+fn extract_lead_image_url(
+    doc: &Document,
+    content_html: &str,
+    base_url: &str,
+    custom: Option<&FieldExtractor>,
+    ld: Option<&LdArticle>,
+) -> Option<String> {
     // Try custom extractor first
     if let Some(fe) = custom {
         if let Some(url) = extract_field_first_text(doc, fe) {
@@ -260,14 +547,19 @@ fn extract_lead_image_url(doc: &Document, custom: Option<&FieldExtractor>) -> Op
         }
     }
 
-    // Fall back to generic heuristics: og:image, twitter:image, then first img
-    for (sel, attr) in GENERIC_IMAGE_SELECTORS {
-        if let Some(url) = extract_attr_first(doc, sel, attr) {
-            return Some(url);
-        }
+    if let Some(url) = ld.and_then(|a| a.image.clone()) {
+        return Some(url);
     }
 
-    None
+    // Fall back to generic heuristics: og:image, twitter:image, then scored img
+    let declared: Vec<String> = GENERIC_IMAGE_SELECTORS
+        .iter()
+        .filter_map(|(sel, attr)| extract_attr_first(doc, sel, attr))
+        .collect();
+    if let Some(url) = declared.iter().find(|u| !is_unlikely_lead_image(u)) {
+        return Some(url.clone());
+    }
+    score_lead_image(doc, content_html, base_url).or_else(|| declared.into_iter().next())
 }
 
 /// Extract site_name using generic heuristics.
@@ -326,11 +618,160 @@ fn extract_language(doc: &Document) -> Option<String> {
     None
 }
 
+/// Minimum whatlang confidence for a language detected from the content.
+#[cfg(feature = "language-detection")]
+const LANGUAGE_DETECTION_MIN_CONFIDENCE: f64 = 0.8;
+
+/// Language of the extracted content, for pages that declare none via
+/// `lang` or meta tags.
+#[cfg(feature = "language-detection")]
+fn detect_content_language(content_html: &str) -> Option<String> {
+    crate::language::detect_language_with_confidence(
+        &html_to_text(content_html),
+        LANGUAGE_DETECTION_MIN_CONFIDENCE,
+    )
+}
+
+/// Extract the normalized `og:locale` and its deduplicated `og:locale:alternate` values.
+fn extract_locales(doc: &Document) -> (Option<String>, Vec<String>) {
+    let locale = extract_meta_content(doc, "meta[property='og:locale']")
+        .map(|v| normalize_locale(&v))
+        .filter(|v| !v.is_empty());
+
+    let mut alternates: Vec<String> = Vec::new();
+    for raw in extract_meta_contents_all(doc, "meta[property='og:locale:alternate']") {
+        let normalized = normalize_locale(&raw);
+        if normalized.is_empty()
+            || locale.as_deref() == Some(normalized.as_str())
+            || alternates.contains(&normalized)
+        {
+            continue;
+        }
+        alternates.push(normalized);
+    }
+
+    (locale, alternates)
+}
+
 /// Extract theme_color using generic heuristics.
 fn extract_theme_color(doc: &Document) -> Option<String> {
     extract_first_attr(doc, &["meta[name='theme-color']"], "content")
 }
 
+/// Extract the canonical URL from `<link rel="canonical">`, falling back to
+/// `og:url`, resolved against the page URL. Non-http(s) results are ignored.
+fn extract_canonical_url(doc: &Document, page_url: &str) -> Option<String> {
+    let base = Url::parse(page_url).ok();
+    let candidates = [
+        extract_first_attr(doc, &["link[rel~='canonical']"], "href"),
+        extract_first_attr(doc, &["meta[property='og:url']"], "content"),
+    ];
+    candidates
+        .into_iter()
+        .flatten()
+        .find_map(|href| resolve_http_url(base.as_ref(), &href))
+}
+
+/// Extract `<link rel="alternate" hreflang>` language versions as
+/// `(hreflang, url)` pairs, deduplicated by language.
+fn extract_hreflang_alternates(doc: &Document, page_url: &str) -> Vec<(String, String)> {
+    let base = Url::parse(page_url).ok();
+    let mut alternates: Vec<(String, String)> = Vec::new();
+    for el in doc.select("link[rel~='alternate'][hreflang]").iter() {
+        let (Some(lang), Some(href)) = (el.attr("hreflang"), el.attr("href")) else {
+            continue;
+        };
+        let lang = if lang.trim().eq_ignore_ascii_case("x-default") {
+            "x-default".to_string()
+        } else {
+            normalize_locale(&lang)
+        };
+        if lang.is_empty() || alternates.iter().any(|(l, _)| *l == lang) {
+            continue;
+        }
+        if let Some(url) = resolve_http_url(base.as_ref(), &href) {
+            alternates.push((lang, url));
+        }
+    }
+    alternates
+}
+
+/// Extract the target of a `<meta http-equiv="refresh" content="0;url=...">`
+/// redirect, resolved against the page URL. Refreshes without a URL, with a
+/// long delay, or pointing back at the page itself are ignored.
+fn extract_meta_refresh_url(doc: &Document, page_url: &str) -> Option<String> {
+    let content = doc
+        .select("meta[http-equiv][content]")
+        .iter()
+        .find(|el| {
+            el.attr("http-equiv")
+                .is_some_and(|v| v.trim().eq_ignore_ascii_case("refresh"))
+        })?
+        .attr("content")?;
+
+    let (delay, target) = content.split_once([';', ','])?;
+    let delay: f64 = delay.trim().parse().ok()?;
+    if !(0.0..=MAX_META_REFRESH_DELAY_SECS).contains(&delay) {
+        return None;
+    }
+    let target = target.trim();
+    let target = match target.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("url=") => &target[4..],
+        _ => target,
+    };
+    let target = target.trim().trim_matches(|c| c == '\'' || c == '"');
+    if target.is_empty() {
+        return None;
+    }
+
+    let base = Url::parse(page_url).ok();
+    let resolved = resolve_http_url(base.as_ref(), target)?;
+    (resolved != page_url).then_some(resolved)
+}
+
+/// Extract the AMP version URL from `<link rel="amphtml">`.
+fn extract_amp_url(doc: &Document, page_url: &str) -> Option<String> {
+    let href = extract_first_attr(doc, &["link[rel~='amphtml']"], "href")?;
+    resolve_http_url(Url::parse(page_url).ok().as_ref(), &href)
+}
+
+/// Base URL for relative links in the document: `<base href>` resolved
+/// against the page URL, else the page URL itself.
+fn document_base_url(doc: &Document, page_url: &str) -> String {
+    extract_first_attr(doc, &["base[href]"], "href")
+        .and_then(|href| resolve_http_url(Url::parse(page_url).ok().as_ref(), &href))
+        .unwrap_or_else(|| page_url.to_string())
+}
+
+/// Feed MIME types recognized during `<link rel="alternate">` autodiscovery.
+const FEED_LINK_TYPES: &[&str] = &[
+    "application/rss+xml",
+    "application/atom+xml",
+    "application/feed+json",
+];
+
+/// Extract feed URLs advertised via `<link rel="alternate" type="...">`, in
+/// document order and deduplicated.
+fn extract_feed_links(doc: &Document, page_url: &str) -> Vec<String> {
+    let base = Url::parse(page_url).ok();
+    let mut feeds: Vec<String> = Vec::new();
+    for el in doc.select("link[rel~='alternate'][type][href]").iter() {
+        let (Some(mime), Some(href)) = (el.attr("type"), el.attr("href")) else {
+            continue;
+        };
+        let mime = mime.split(';').next().unwrap_or("").trim();
+        if !FEED_LINK_TYPES.iter().any(|t| t.eq_ignore_ascii_case(mime)) {
+            continue;
+        }
+        if let Some(url) = resolve_http_url(base.as_ref(), &href) {
+            if !feeds.contains(&url) {
+                feeds.push(url);
+            }
+        }
+    }
+    feeds
+}
+
 /// Extract favicon URL using generic heuristics.
 fn extract_favicon(doc: &Document) -> Option<String> {
     let selectors = &[
@@ -392,6 +833,16 @@ fn extract_video_url(doc: &Document) -> Option<String> {
     None
 }
 
+/// Player details for `video_url`, falling back to the lead image for the
+/// thumbnail when the platform's cannot be derived.
+fn extract_video_info(video_url: Option<&str>, lead_image_url: Option<&str>) -> Option<VideoInfo> {
+    let mut video = video_info(video_url?)?;
+    if video.thumbnail_url.is_none() {
+        video.thumbnail_url = lead_image_url.map(str::to_string);
+    }
+    Some(video)
+}
+
 /// Extract video metadata as a JSON object with available og:video:* properties.
 fn extract_video_metadata(doc: &Document) -> Option<serde_json::Value> {
     let mut map = serde_json::Map::new();
@@ -468,84 +919,14 @@ fn is_rtl_char(ch: char) -> bool {
         || (0xFE70..=0xFEFF).contains(&code)
 }
 
-/// Extract articleBody from JSON-LD when HTML content is missing or too short.
-fn extract_article_body_from_ld_json(doc: &Document) -> Option<String> {
-    for script in doc.select("script[type='application/ld+json']").iter() {
-        let text = script.text().to_string();
-        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&text) {
-            if let Some(body) = find_article_body(&value) {
-                if !body.trim().is_empty() {
-                    return Some(body);
-                }
-            }
-        }
-    }
-    None
-}
-
-fn find_article_body(value: &serde_json::Value) -> Option<String> {
-    match value {
-        serde_json::Value::Object(map) => {
-            let mut is_article = false;
-            if let Some(t) = map.get("@type") {
-                is_article = matches_type(t, "NewsArticle") || matches_type(t, "BlogPosting");
-            }
-            if is_article {
-                if let Some(body) = map.get("articleBody") {
-                    if let Some(s) = body.as_str() {
-                        return Some(s.to_string());
-                    }
-                    if let Some(arr) = body.as_array() {
-                        let joined = arr
-                            .iter()
-                            .filter_map(|v| v.as_str())
-                            .collect::<Vec<_>>()
-                            .join("\n\n");
-                        if !joined.is_empty() {
-                            return Some(joined);
-                        }
-                    }
-                }
-            }
-            // Recurse into common graph holders
-            for key in [
-                "@graph",
-                "graph",
-                "mainEntity",
-                "mainEntityOfPage",
-                "itemListElement",
-            ] {
-                if let Some(v) = map.get(key) {
-                    if let Some(res) = find_article_body(v) {
-                        return Some(res);
-                    }
-                }
-            }
-            // Recurse values
-            for v in map.values() {
-                if let Some(res) = find_article_body(v) {
-                    return Some(res);
-                }
-            }
-            None
-        }
-        serde_json::Value::Array(arr) => {
-            for v in arr {
-                if let Some(res) = find_article_body(v) {
-                    return Some(res);
-                }
-            }
-            None
+/// Structured article metadata: JSON-LD first, with RDFa filling any gaps.
+fn extract_structured_article(doc: &Document) -> Option<LdArticle> {
+    match (extract_ld_article(doc), extract_rdfa_article(doc)) {
+        (Some(mut ld), Some(rdfa)) => {
+            ld.merge(rdfa);
+            Some(ld)
         }
-        _ => None,
-    }
-}
-
-fn matches_type(value: &serde_json::Value, expected: &str) -> bool {
-    match value {
-        serde_json::Value::String(s) => s.eq_ignore_ascii_case(expected),
-        serde_json::Value::Array(arr) => arr.iter().any(|v| matches_type(v, expected)),
-        _ => false,
+        (ld, rdfa) => ld.or(rdfa),
     }
 }
 
@@ -594,42 +975,9 @@ impl Client {
     /// Create a new Client with the given options.
     pub fn new(opts: Options) -> Self {
         let http_client = opts.http_client.clone().unwrap_or_else(|| {
-            let allow_private = opts.allow_private_networks;
-            let redirect_policy = reqwest::redirect::Policy::custom(move |attempt| {
-                let next = attempt.url().clone();
-                if !allow_private {
-                    if let Some(host) = next.host_str() {
-                        let scheme = next.scheme();
-                        let port = next
-                            .port()
-                            .unwrap_or(if scheme == "https" { 443 } else { 80 });
-                        if let Ok(ip) = host.parse::<std::net::IpAddr>() {
-                            if crate::resource::is_private_ip(&ip) {
-                                return attempt.error("redirect to private IP blocked");
-                            }
-                        } else {
-                            // synchronous DNS resolution to avoid async in redirect policy
-                            let addr_str = format!("{}:{}", host, port);
-                            match addr_str.to_socket_addrs() {
-                                Ok(addrs) => {
-                                    for sa in addrs {
-                                        if crate::resource::is_private_ip(&sa.ip()) {
-                                            return attempt.error("redirect to private IP blocked");
-                                        }
-                                    }
-                                }
-                                Err(_) => {
-                                    return attempt.error("DNS lookup failed during redirect");
-                                }
-                            }
-                        }
-                    }
-                }
-                attempt.follow()
-            });
-
+            // Redirects are followed by resource::fetch so each hop is SSRF-checked and recorded
             reqwest::Client::builder()
-                .redirect(redirect_policy)
+                .redirect(reqwest::redirect::Policy::none())
                 .user_agent(&opts.user_agent)
                 .timeout(opts.timeout)
                 .cookie_store(true)
@@ -667,20 +1015,73 @@ impl Client {
         }
 
         // Prepare fetch options
-        let fetch_opts = FetchOptions {
-            headers: self.opts.headers.clone(),
-            allow_private_networks: self.opts.allow_private_networks,
-            parse_non_200: false,
-        };
+        let fetch_opts = self.fetch_options();
 
         // Fetch the resource
-        let fetch_result = fetch(&self.http_client, url, &fetch_opts).await?;
+        let mut fetch_result = fetch(&self.http_client, url, &fetch_opts).await?;
+        let mut redirects = std::mem::take(&mut fetch_result.redirects);
+        let mut redirect_chain: Vec<String> = redirects.iter().map(|hop| hop.url.clone()).collect();
 
         // Decode the body as UTF-8 text
-        let raw_html = fetch_result.text_utf8(None)?;
+        let mut raw_html = fetch_result.text_utf8(None)?;
 
         // Parse the document for extraction
-        let doc = Document::from(raw_html.as_str());
+        let mut doc = Document::from(raw_html.as_str());
+
+        // Follow meta-refresh redirects; fetch() applies the SSRF checks to each hop
+        for _ in 0..MAX_META_REFRESH_HOPS {
+            let Some(target) = extract_meta_refresh_url(&doc, &fetch_result.final_url) else {
+                break;
+            };
+            if redirect_chain.contains(&target) {
+                break;
+            }
+            diagnostics::info(&format!(
+                "{}: following meta refresh to {}",
+                fetch_result.final_url, target
+            ));
+            // A broken refresh target keeps the page already fetched; only
+            // SSRF rejections are fatal. Credentials stay on the page's origin.
+            let hop_opts = fetch_opts.for_target(&fetch_result.final_url, &target);
+            let mut next = match fetch(&self.http_client, &target, &hop_opts).await {
+                Ok(next) => next,
+                Err(e) if e.is_ssrf() => return Err(e),
+                Err(e) => {
+                    diagnostics::info(&format!(
+                        "{}: meta refresh target failed, keeping page: {}",
+                        fetch_result.final_url, e
+                    ));
+                    break;
+                }
+            };
+            if next
+                .content_type
+                .as_deref()
+                .is_some_and(|ct| !ct.to_ascii_lowercase().contains("html"))
+            {
+                diagnostics::info(&format!(
+                    "{}: meta refresh target is not HTML, keeping page",
+                    fetch_result.final_url
+                ));
+                break;
+            }
+            let Ok(next_html) = next.text_utf8(None) else {
+                break;
+            };
+            redirect_chain.push(fetch_result.final_url.clone());
+            redirect_chain.extend(next.redirects.iter().map(|hop| hop.url.clone()));
+            redirects.append(&mut next.redirects);
+            fetch_result = next;
+            raw_html = next_html;
+            doc = Document::from(raw_html.as_str());
+        }
+
+        if self.opts.normalize_url {
+            fetch_result.final_url = normalize_url(&fetch_result.final_url);
+        }
+
+        // Structured article metadata takes priority over generic meta heuristics
+        let ld = extract_structured_article(&doc);
 
         // Extract domain from final URL
         let domain = url::Url::parse(&fetch_result.final_url)
@@ -691,23 +1092,34 @@ impl Client {
         // Look up custom extractor for this domain
         let custom_extractor = self.registry.get(&domain);
 
-        // Extract title: prefer custom extractor if available, then extract_title, then generic
-        let title = custom_extractor
-            .and_then(|ce| ce.title.as_ref())
-            .and_then(|te| extract_field_first_text(&doc, te))
-            .or_else(|| extract_title(&raw_html))
-            .or_else(|| {
-                let title_extractor = build_generic_title_extractor();
-                extract_field_first_text(&doc, &title_extractor)
-            })
-            .unwrap_or_default();
+        let (title, raw_title) = extract_page_title(
+            &doc,
+            &raw_html,
+            custom_extractor.and_then(|ce| ce.title.as_ref()),
+            ld.as_ref(),
+            &domain,
+        );
+        let raw_title = raw_title.filter(|_| self.opts.keep_raw_title);
 
         // Extract content: prefer custom extractor if available, then best generic, then body
-        let mut content_html = custom_extractor
+        let (mut content_html, mut content_selector, mut candidate_score) = custom_extractor
             .and_then(|ce| ce.content.as_ref())
-            .and_then(|ce| extract_content_html_opts(&doc, ce, true).map(|v| v.join("\n\n")))
-            .or_else(|| score_generic_content(&raw_html, &title))
-            .unwrap_or_else(|| extract_body_inner_html(&doc));
+            .and_then(|ce| {
+                let html = extract_content_html_opts(&doc, ce, true)?.join("\n\n");
+                Some((
+                    html,
+                    matching_content_selector(&doc, ce).unwrap_or_default(),
+                    None,
+                ))
+            })
+            .or_else(|| {
+                score_generic_content_with_locator(&raw_html, &title)
+                    .map(|(html, locator, score)| (html, locator, Some(score)))
+            })
+            .unwrap_or_else(|| {
+                diagnostics::info(&format!("{}: no content candidate, using <body>", url));
+                (extract_body_inner_html(&doc), "body".to_string(), None)
+            });
 
         // Fallback: if content contains no tags, try raw inner_html (no cleaning)
         if !content_html.contains('<') {
@@ -723,46 +1135,122 @@ impl Client {
         content_html =
             crate::extractors::content::apply_domain_function_transforms(&domain, &content_html);
 
+        // AMP fallback: thin content on the canonical page, try its AMP version
+        let amp_url = extract_amp_url(&doc, &fetch_result.final_url);
+        let oembed_url = extract_oembed_url(&doc, &fetch_result.final_url);
+        let mut used_amp = false;
+        if let Some((amp_html, amp_selector, amp_score)) = self
+            .amp_fallback_content(
+                amp_url.as_deref(),
+                &fetch_result.final_url,
+                &content_html,
+                &title,
+                &fetch_opts,
+            )
+            .await
+        {
+            content_html = amp_html;
+            content_selector = amp_selector;
+            candidate_score = amp_score;
+            used_amp = true;
+        }
+
+        let oembed = match oembed_url.as_deref() {
+            Some(endpoint) if self.opts.resolve_oembed => {
+                self.fetch_oembed(endpoint, &fetch_result.final_url, &fetch_opts)
+                    .await
+            }
+            _ => None,
+        };
+
+        // Pull quotes repeat body text; drop them so they are not read twice
+        let (stripped, pull_quotes) = strip_pull_quotes(&content_html);
+        content_html = stripped;
+
         // Fallback: only use JSON-LD articleBody if we truly extracted nothing
         // (lower threshold to avoid losing HTML formatting from proper extraction)
         let content_plain = html_to_text(&content_html);
         if content_plain.trim().len() < 50 {
-            if let Some(ld_body) = extract_article_body_from_ld_json(&doc) {
+            if let Some(ld_body) = ld.as_ref().and_then(|a| a.body.clone()) {
+                diagnostics::info(&format!(
+                    "{}: content too short, using JSON-LD articleBody",
+                    url
+                ));
                 content_html = wrap_plaintext_as_html(&ld_body);
+                content_selector = "json-ld:articleBody".to_string();
+                candidate_score = None;
             }
         }
 
+        // Pick srcset candidates, then resolve relative URLs so the content
+        // renders outside its origin
+        content_html = select_image_sources(&content_html, self.opts.image_target_width);
+        content_html = absolutize_urls(
+            &content_html,
+            &document_base_url(&doc, &fetch_result.final_url),
+        );
+
         // Sanitize the extracted HTML before conversion (skip for raw HTML output to preserve structure)
+        let tables = if self.opts.extract_tables {
+            extract_tables(&content_html)
+        } else {
+            Vec::new()
+        };
+        let links = extract_links(&content_html, &doc, &fetch_result.final_url);
+        let images = extract_images(&content_html, &fetch_result.final_url);
+        let embeds = extract_embeds(&content_html);
+
         let sanitized_html = match self.opts.content_type {
             ContentType::Html => content_html.clone(),
             _ => sanitize_html(&content_html),
         };
 
         // Extract author, date_published, lead_image_url
-        let author = extract_author(&doc, custom_extractor.and_then(|ce| ce.author.as_ref()));
+        let authors = extract_authors(
+            &doc,
+            custom_extractor.and_then(|ce| ce.author.as_ref()),
+            ld.as_ref(),
+        );
+        let author = (!authors.is_empty()).then(|| authors.join(", "));
         let date_published = extract_date_published(
             &doc,
             custom_extractor.and_then(|ce| ce.date_published.as_ref()),
+            ld.as_ref(),
         );
+        let date_modified = extract_date_modified(&doc, ld.as_ref());
         let lead_image_url = extract_lead_image_url(
             &doc,
+            &content_html,
+            &document_base_url(&doc, &fetch_result.final_url),
             custom_extractor.and_then(|ce| ce.lead_image_url.as_ref()),
+            ld.as_ref(),
         );
 
         // Extract additional metadata fields
         let dek = extract_dek(&doc, custom_extractor.and_then(|ce| ce.dek.as_ref()));
         let custom_excerpt =
             extract_custom_excerpt(&doc, custom_extractor.and_then(|ce| ce.excerpt.as_ref()));
-        let site_name = extract_site_name(&doc);
+        let site_name =
+            extract_site_name(&doc).or_else(|| ld.as_ref().and_then(|a| a.publisher.clone()));
+        let publisher = ld.as_ref().and_then(|a| a.publisher.clone());
         let site_title = extract_site_title(&doc);
         let site_image = extract_site_image(&doc);
         let language = extract_language(&doc);
+        let (locale, alternate_locales) = extract_locales(&doc);
         let theme_color = extract_theme_color(&doc);
         let favicon = extract_favicon(&doc);
+        let canonical_url = extract_canonical_url(&doc, &fetch_result.final_url);
+        let alternates = extract_hreflang_alternates(&doc, &fetch_result.final_url);
+        let feeds = extract_feed_links(&doc, &fetch_result.final_url);
+        let breadcrumbs = extract_breadcrumbs(&doc, &fetch_result.final_url);
+        let recipe = extract_recipe(&doc);
+        let product = extract_product(&doc);
+        let event = extract_event(&doc);
 
         // Extract video URL and metadata
         let video_url = extract_video_url(&doc);
         let video_metadata = extract_video_metadata(&doc);
+        let video = extract_video_info(video_url.as_deref(), lead_image_url.as_deref());
 
         // Extract next page URL
         let mut next_page_url = extract_next_page_url(
@@ -843,12 +1331,20 @@ impl Client {
                                 let next_plain = html_to_text(&next_content_html);
                                 if next_plain.trim().len() < 500 {
                                     if let Some(ld_body) =
-                                        extract_article_body_from_ld_json(&next_doc)
+                                        extract_structured_article(&next_doc).and_then(|a| a.body)
                                     {
                                         next_content_html = ld_body;
                                     }
                                 }
 
+                                next_content_html = select_image_sources(
+                                    &next_content_html,
+                                    self.opts.image_target_width,
+                                );
+                                next_content_html = absolutize_urls(
+                                    &next_content_html,
+                                    &document_base_url(&next_doc, &next_fetch_result.final_url),
+                                );
                                 let next_sanitized_html = sanitize_html(&next_content_html);
 
                                 // Append content based on content type
@@ -897,12 +1393,25 @@ impl Client {
         }
 
         // Calculate word count from plain text of final content
-        let wc = if did_follow {
+        let wc = if did_follow || used_amp {
             let final_text = html_to_text(&final_sanitized_html);
             word_count(&final_text)
         } else {
             word_count(&plain_text)
         };
+        let reading_time_minutes =
+            estimate_reading_time(&final_sanitized_html, self.opts.reading_wpm);
+        let summary = summarize_content(&final_sanitized_html, self.opts.summary_sentences);
+        let extraction = extraction_info(&content_selector, candidate_score, &final_sanitized_html);
+        #[cfg(feature = "language-detection")]
+        let language = language.or_else(|| detect_content_language(&final_sanitized_html));
+        let keywords = extract_page_keywords(&doc, &final_sanitized_html, language.as_deref());
+
+        if self.opts.inline_images && self.opts.content_type == ContentType::Html {
+            final_content = self
+                .inline_images(&final_content, &fetch_result.final_url, &fetch_opts)
+                .await;
+        }
 
         // Determine description: if custom excerpt is set and dek is not, use custom_excerpt for description
         let description = if custom_excerpt.is_some() && dek.is_none() {
@@ -920,27 +1429,213 @@ impl Client {
             content: final_content,
             raw_html: Some(raw_html),
             title,
+            raw_title,
             excerpt,
+            summary,
+            keywords,
             word_count: wc,
+            reading_time_minutes,
             author,
+            authors,
             date_published,
+            date_modified,
+            was_updated_after_publish: was_updated_after_publish(date_published, date_modified),
             lead_image_url,
             dek,
             site_name,
+            publisher,
             site_title,
             site_image,
             description,
             language,
+            locale,
+            alternate_locales,
             theme_color,
             favicon,
             video_url,
             video_metadata,
+            video,
             next_page_url,
+            canonical_url,
+            alternates,
+            feeds,
+            breadcrumbs,
+            amp_url,
+            oembed_url,
+            oembed,
+            redirect_chain,
+            redirects,
+            http_status: Some(fetch_result.status),
             direction,
+            tables,
+            links,
+            images,
+            embeds,
+            content_selector: self.opts.track_provenance.then_some(content_selector),
+            extraction: Some(extraction),
+            recipe,
+            product,
+            event,
+            pull_quotes: if self.opts.collect_pull_quotes {
+                pull_quotes
+            } else {
+                Vec::new()
+            },
             ..Default::default()
         })
     }
 
+    /// Fetch an AMP page and extract its content HTML, locator and readability
+    /// score, or `None` when the fetch fails or no content candidate is found.
+    async fn extract_amp_content(
+        &self,
+        amp_url: &str,
+        title: &str,
+        fetch_opts: &FetchOptions,
+    ) -> Option<(String, String, Option<i32>)> {
+        let fetch_result = fetch(&self.http_client, amp_url, fetch_opts).await.ok()?;
+        let raw_html = fetch_result.text_utf8(None).ok()?;
+        let doc = Document::from(raw_html.as_str());
+        let domain = Url::parse(&fetch_result.final_url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
+            .unwrap_or_default();
+        let custom_extractor = self.registry.get(&domain);
+
+        let (content_html, selector, score) = custom_extractor
+            .and_then(|ce| ce.content.as_ref())
+            .and_then(|ce| {
+                let html = extract_content_html_opts(&doc, ce, true)?.join("\n\n");
+                Some((
+                    html,
+                    matching_content_selector(&doc, ce).unwrap_or_default(),
+                    None,
+                ))
+            })
+            .or_else(|| {
+                score_generic_content_with_locator(&raw_html, title)
+                    .map(|(html, locator, score)| (html, locator, Some(score)))
+            })?;
+
+        let content_html =
+            crate::extractors::content::apply_domain_function_transforms(&domain, &content_html);
+        let content_html = select_image_sources(&content_html, self.opts.image_target_width);
+        let content_html = absolutize_urls(
+            &content_html,
+            &document_base_url(&doc, &fetch_result.final_url),
+        );
+        Some((content_html, selector, score))
+    }
+
+    /// Content from the page's AMP version when `amp_fallback` is set, the
+    /// page's own content is thin, and the AMP copy yields more text.
+    async fn amp_fallback_content(
+        &self,
+        amp_url: Option<&str>,
+        page_url: &str,
+        content_html: &str,
+        title: &str,
+        fetch_opts: &FetchOptions,
+    ) -> Option<(String, String, Option<i32>)> {
+        if !self.opts.amp_fallback {
+            return None;
+        }
+        let current_len = html_to_text(content_html).trim().len();
+        if current_len >= AMP_FALLBACK_MIN_TEXT_LEN {
+            return None;
+        }
+        let amp = amp_url.filter(|amp| *amp != page_url)?;
+        // AMP copies often live on another host or an AMP cache
+        let amp_opts = fetch_opts.for_target(page_url, amp);
+        let (amp_html, amp_selector, amp_score) =
+            self.extract_amp_content(amp, title, &amp_opts).await?;
+        if html_to_text(&amp_html).trim().len() <= current_len {
+            return None;
+        }
+        diagnostics::info(&format!("{}: thin content, using AMP page", page_url));
+        Some((amp_html, format!("amp:{}", amp_selector), amp_score))
+    }
+
+    /// Fetch options for the page and the resources it links to.
+    fn fetch_options(&self) -> FetchOptions {
+        FetchOptions {
+            headers: self.opts.headers.clone(),
+            allow_private_networks: self.opts.allow_private_networks,
+            parse_non_200: false,
+        }
+    }
+
+    /// Fetch and parse an oEmbed document, or `None` when the fetch fails or
+    /// the response is not valid oEmbed JSON. The endpoint comes from the page,
+    /// so credentials are only sent when it shares the page's origin.
+    async fn fetch_oembed(
+        &self,
+        endpoint: &str,
+        page_url: &str,
+        fetch_opts: &FetchOptions,
+    ) -> Option<OEmbed> {
+        let fetch_opts = fetch_opts.for_target(page_url, endpoint);
+        let fetch_result = fetch(&self.http_client, endpoint, &fetch_opts).await.ok()?;
+        let body = fetch_result.text_utf8(None).ok()?;
+        let oembed = parse_oembed(&body);
+        if oembed.is_none() {
+            diagnostics::info(&format!("{}: invalid oEmbed response", endpoint));
+        }
+        oembed
+    }
+
+    /// Replace content image sources with `data:` URIs, fetching images in
+    /// document order until `inline_images_max_bytes` is spent. Each fetch is
+    /// capped at the budget left, so an image that does not fit is abandoned
+    /// at its Content-Length or once its body passes the cap. Images that
+    /// fail to load, are not `image/*`, or do not fit keep their URL.
+    /// Credential headers only go to the page's own origin.
+    async fn inline_images(&self, html: &str, page_url: &str, fetch_opts: &FetchOptions) -> String {
+        let page = Url::parse(page_url).ok();
+        let foreign_opts = fetch_opts.without_credentials();
+        let mut remaining = self.opts.inline_images_max_bytes;
+        let mut data_uris: HashMap<String, String> = HashMap::new();
+        for url in image_source_urls(html) {
+            if remaining == 0 {
+                break;
+            }
+            let page_origin = page
+                .as_ref()
+                .zip(Url::parse(&url).ok())
+                .is_some_and(|(page, image)| same_origin(page, &image));
+            let opts = if page_origin {
+                fetch_opts
+            } else {
+                &foreign_opts
+            };
+            let fetch_result =
+                match fetch_with_limit(&self.http_client, &url, opts, remaining).await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        diagnostics::info(&format!("{}: image not inlined: {}", url, e));
+                        continue;
+                    }
+                };
+            let mime = fetch_result
+                .content_type
+                .as_deref()
+                .and_then(|ct| ct.split(';').next())
+                .map(|m| m.trim().to_ascii_lowercase())
+                .unwrap_or_default();
+            if !mime.starts_with("image/") {
+                continue;
+            }
+            remaining -= fetch_result.body.len();
+            let data_uri = format!(
+                "data:{};base64,{}",
+                mime,
+                STANDARD.encode(&fetch_result.body)
+            );
+            data_uris.insert(url, data_uri);
+        }
+        inline_image_sources(html, &data_uris)
+    }
+
     /// Parse content from an HTML string.
     ///
     /// Extracts article content from the provided HTML, using the given URL for context.
@@ -962,6 +1657,13 @@ impl Client {
             ParseError::invalid_url(url, "ParseHTML", Some(anyhow::anyhow!("malformed URL")))
         })?;
 
+        let url = if self.opts.normalize_url {
+            normalize_url(url)
+        } else {
+            url.to_string()
+        };
+        let url = url.as_str();
+
         // Extract domain from URL
         let domain = parsed_url
             .host_str()
@@ -970,66 +1672,147 @@ impl Client {
 
         // Parse the document for extraction
         let doc = Document::from(html);
+        let ld = extract_structured_article(&doc);
+
+        // Options that resolve related resources (oEmbed, images) fetch with
+        // the same checks as parse()
+        let fetch_opts = self.fetch_options();
 
         // Look up custom extractor for this domain
         let custom_extractor = self.registry.get(&domain);
 
-        // Extract title: prefer custom extractor if available, then extract_title, then generic
-        let title = custom_extractor
-            .and_then(|ce| ce.title.as_ref())
-            .and_then(|te| extract_field_first_text(&doc, te))
-            .or_else(|| extract_title(html))
-            .or_else(|| {
-                let title_extractor = build_generic_title_extractor();
-                extract_field_first_text(&doc, &title_extractor)
-            })
-            .unwrap_or_default();
+        let (title, raw_title) = extract_page_title(
+            &doc,
+            html,
+            custom_extractor.and_then(|ce| ce.title.as_ref()),
+            ld.as_ref(),
+            &domain,
+        );
+        let raw_title = raw_title.filter(|_| self.opts.keep_raw_title);
 
         // Extract content: prefer custom extractor if available, then best generic, then body
-        let mut content_html = custom_extractor
+        let (mut content_html, mut content_selector, mut candidate_score) = custom_extractor
             .and_then(|ce| ce.content.as_ref())
-            .and_then(|ce| extract_content_first_html(&doc, ce))
-            .or_else(|| score_generic_content(html, &title))
-            .unwrap_or_else(|| extract_body_inner_html(&doc));
+            .and_then(|ce| {
+                let html = extract_content_first_html(&doc, ce)?;
+                Some((
+                    html,
+                    matching_content_selector(&doc, ce).unwrap_or_default(),
+                    None,
+                ))
+            })
+            .or_else(|| {
+                score_generic_content_with_locator(html, &title)
+                    .map(|(html, locator, score)| (html, locator, Some(score)))
+            })
+            .unwrap_or_else(|| {
+                diagnostics::info(&format!("{}: no content candidate, using <body>", url));
+                (extract_body_inner_html(&doc), "body".to_string(), None)
+            });
+
+        // AMP fallback: thin content in the given HTML, try its AMP version
+        let amp_url = extract_amp_url(&doc, url);
+        let mut used_amp = false;
+        if let Some((amp_html, amp_selector, amp_score)) = self
+            .amp_fallback_content(amp_url.as_deref(), url, &content_html, &title, &fetch_opts)
+            .await
+        {
+            content_html = amp_html;
+            content_selector = amp_selector;
+            candidate_score = amp_score;
+            used_amp = true;
+        }
+
+        // Pull quotes repeat body text; drop them so they are not read twice
+        let (stripped, pull_quotes) = strip_pull_quotes(&content_html);
+        content_html = stripped;
 
         // Fallback: only use JSON-LD articleBody if we truly extracted nothing
         // (lower threshold to avoid losing HTML formatting from proper extraction)
         let content_plain = html_to_text(&content_html);
         if content_plain.trim().len() < 50 {
-            if let Some(ld_body) = extract_article_body_from_ld_json(&doc) {
+            if let Some(ld_body) = ld.as_ref().and_then(|a| a.body.clone()) {
+                diagnostics::info(&format!(
+                    "{}: content too short, using JSON-LD articleBody",
+                    url
+                ));
                 content_html = wrap_plaintext_as_html(&ld_body);
+                content_selector = "json-ld:articleBody".to_string();
+                candidate_score = None;
                 _ = html_to_text(&content_html);
             }
         }
 
+        // Pick srcset candidates, then resolve relative URLs so the content
+        // renders outside its origin
+        content_html = select_image_sources(&content_html, self.opts.image_target_width);
+        content_html = absolutize_urls(&content_html, &document_base_url(&doc, url));
+
         // Sanitize the extracted HTML before conversion
         let sanitized_html = sanitize_html(&content_html);
 
+        let tables = if self.opts.extract_tables {
+            extract_tables(&content_html)
+        } else {
+            Vec::new()
+        };
+        let links = extract_links(&content_html, &doc, url);
+        let images = extract_images(&content_html, url);
+        let embeds = extract_embeds(&content_html);
+
         // Extract author, date_published, lead_image_url
-        let author = extract_author(&doc, custom_extractor.and_then(|ce| ce.author.as_ref()));
+        let authors = extract_authors(
+            &doc,
+            custom_extractor.and_then(|ce| ce.author.as_ref()),
+            ld.as_ref(),
+        );
+        let author = (!authors.is_empty()).then(|| authors.join(", "));
         let date_published = extract_date_published(
             &doc,
             custom_extractor.and_then(|ce| ce.date_published.as_ref()),
+            ld.as_ref(),
         );
+        let date_modified = extract_date_modified(&doc, ld.as_ref());
         let lead_image_url = extract_lead_image_url(
             &doc,
+            &content_html,
+            &document_base_url(&doc, url),
             custom_extractor.and_then(|ce| ce.lead_image_url.as_ref()),
+            ld.as_ref(),
         );
 
         // Extract additional metadata fields
         let dek = extract_dek(&doc, custom_extractor.and_then(|ce| ce.dek.as_ref()));
         let custom_excerpt =
             extract_custom_excerpt(&doc, custom_extractor.and_then(|ce| ce.excerpt.as_ref()));
-        let site_name = extract_site_name(&doc);
+        let site_name =
+            extract_site_name(&doc).or_else(|| ld.as_ref().and_then(|a| a.publisher.clone()));
+        let publisher = ld.as_ref().and_then(|a| a.publisher.clone());
         let site_title = extract_site_title(&doc);
         let site_image = extract_site_image(&doc);
         let language = extract_language(&doc);
+        let (locale, alternate_locales) = extract_locales(&doc);
         let theme_color = extract_theme_color(&doc);
         let favicon = extract_favicon(&doc);
+        let canonical_url = extract_canonical_url(&doc, url);
+        let alternates = extract_hreflang_alternates(&doc, url);
+        let feeds = extract_feed_links(&doc, url);
+        let breadcrumbs = extract_breadcrumbs(&doc, url);
+        let oembed_url = extract_oembed_url(&doc, url);
+        let oembed = match oembed_url.as_deref() {
+            Some(endpoint) if self.opts.resolve_oembed => {
+                self.fetch_oembed(endpoint, url, &fetch_opts).await
+            }
+            _ => None,
+        };
+        let recipe = extract_recipe(&doc);
+        let product = extract_product(&doc);
+        let event = extract_event(&doc);
 
         // Extract video URL and metadata
         let video_url = extract_video_url(&doc);
         let video_metadata = extract_video_metadata(&doc);
+        let video = extract_video_info(video_url.as_deref(), lead_image_url.as_deref());
 
         // Extract next page URL
         let next_page_url = extract_next_page_url(
@@ -1043,8 +1826,19 @@ impl Client {
         // Extract direction using plain text for RTL detection
         let direction = Some(extract_direction(&doc, &plain_text));
 
-        // Calculate word count from plain text of raw HTML
-        let wc = word_count(&plain_text);
+        // Calculate word count from plain text of raw HTML, or of the AMP
+        // content when it replaced the page's
+        let wc = if used_amp {
+            word_count(&html_to_text(&sanitized_html))
+        } else {
+            word_count(&plain_text)
+        };
+        let reading_time_minutes = estimate_reading_time(&sanitized_html, self.opts.reading_wpm);
+        let summary = summarize_content(&sanitized_html, self.opts.summary_sentences);
+        let extraction = extraction_info(&content_selector, candidate_score, &sanitized_html);
+        #[cfg(feature = "language-detection")]
+        let language = language.or_else(|| detect_content_language(&sanitized_html));
+        let keywords = extract_page_keywords(&doc, &sanitized_html, language.as_deref());
 
         // Convert content based on requested content type (using sanitized HTML)
         let content = match self.opts.content_type {
@@ -1052,6 +1846,11 @@ impl Client {
             ContentType::Text => html_to_text(&sanitized_html),
             ContentType::Html => sanitized_html,
         };
+        let content = if self.opts.inline_images && self.opts.content_type == ContentType::Html {
+            self.inline_images(&content, url, &fetch_opts).await
+        } else {
+            content
+        };
 
         // Determine description: if custom excerpt is set and dek is not, use custom_excerpt for description
         let description = if custom_excerpt.is_some() && dek.is_none() {
@@ -1069,23 +1868,55 @@ impl Client {
             content,
             raw_html: Some(html.to_string()),
             title,
+            raw_title,
             excerpt,
+            summary,
+            keywords,
             word_count: wc,
+            reading_time_minutes,
             author,
+            authors,
             date_published,
+            date_modified,
+            was_updated_after_publish: was_updated_after_publish(date_published, date_modified),
             lead_image_url,
             dek,
             site_name,
+            publisher,
             site_title,
             site_image,
             description,
             language,
+            locale,
+            alternate_locales,
             theme_color,
             favicon,
             video_url,
             video_metadata,
+            video,
             next_page_url,
+            canonical_url,
+            alternates,
+            feeds,
+            breadcrumbs,
+            amp_url,
+            oembed_url,
+            oembed,
             direction,
+            tables,
+            links,
+            images,
+            embeds,
+            content_selector: self.opts.track_provenance.then_some(content_selector),
+            extraction: Some(extraction),
+            recipe,
+            product,
+            event,
+            pull_quotes: if self.opts.collect_pull_quotes {
+                pull_quotes
+            } else {
+                Vec::new()
+            },
             ..Default::default()
         })
     }
@@ -1095,6 +1926,7 @@ impl Client {
 mod tests {
     use super::*;
     use crate::error::ErrorCode;
+    use crate::result::{EmbedPlatform, RedirectHop};
     use chrono::{Datelike, Timelike};
     use httpmock::prelude::*;
 
@@ -1491,6 +2323,70 @@ mod tests {
         assert_eq!(result.author, Some("Jane".to_string()));
     }
 
+    #[tokio::test]
+    async fn parse_cleans_title_and_keeps_raw_on_request() {
+        let html = r#"<html><head>
+    <title>Storm closes harbor | Coast Herald - Weather</title>
+    <meta property="og:site_name" content="Coast Herald">
+</head><body><p>Hello</p></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.title, "Storm closes harbor");
+        assert_eq!(result.raw_title, None);
+
+        let result = Client::builder()
+            .keep_raw_title(true)
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.title, "Storm closes harbor");
+        assert_eq!(
+            result.raw_title.as_deref(),
+            Some("Storm closes harbor | Coast Herald - Weather")
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_collects_multiple_authors() {
+        let client = Client::builder().content_type(ContentType::Html).build();
+
+        let html = r#"<html><head>
+    <meta name="author" content="Jane Doe">
+    <meta name="author" content="John Roe">
+    <meta name="author" content="Jane Doe">
+</head><body><p>Hello</p></body></html>"#;
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.authors, vec!["Jane Doe", "John Roe"]);
+        assert_eq!(result.author.as_deref(), Some("Jane Doe, John Roe"));
+
+        let html = r#"<html><body>
+    <p class="byline">By <a rel="author" href="/ann">Ann  Lee</a> and
+        <a rel="author" href="/bo">Bo Chen</a></p>
+    <p>Hello</p>
+</body></html>"#;
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.authors, vec!["Ann Lee", "Bo Chen"]);
+
+        let html = "<html><body><p>No byline</p></body></html>";
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert!(result.authors.is_empty());
+        assert_eq!(result.author, None);
+    }
+
     #[tokio::test]
     async fn parse_generic_lead_image_prefers_og() {
         let html = r#"<!DOCTYPE html>
@@ -1515,6 +2411,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parse_generic_lead_image_scores_images() {
+        let html = r#"<html><head>
+<meta property="og:image" content="https://nocustom.test/static/logo.png">
+</head><body>
+<header><img src="/static/masthead.gif" width="300" height="40"></header>
+<article>
+<p>The harbor reopened on Friday after repairs, and crews returned before dawn to unload their catch at the piers.</p>
+<figure><img src="/uploads/harbor.jpg" alt="Harbor" width="1200" height="800"><figcaption>The harbor.</figcaption></figure>
+<p>Buyers lined the rebuilt docks, waiting, watching, bidding, while the harbor master counted the boats coming in.</p>
+</article></body></html>"#;
+
+        let client = Client::builder().build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(
+            result.lead_image_url.as_deref(),
+            Some("/uploads/harbor.jpg")
+        );
+
+        // A logo og:image is still better than no lead image at all
+        let html = r#"<html><head>
+<meta property="og:image" content="https://nocustom.test/static/logo.png">
+</head><body><p>No pictures here.</p></body></html>"#;
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(
+            result.lead_image_url.as_deref(),
+            Some("https://nocustom.test/static/logo.png")
+        );
+    }
+
     #[tokio::test]
     async fn parse_generic_date_time_tag() {
         let html = r#"<!DOCTYPE html>
@@ -1541,6 +2473,122 @@ mod tests {
         assert_eq!(dt.hour(), 12);
     }
 
+    #[tokio::test]
+    async fn parse_html_flags_updated_after_publish() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+<title>Updated Story</title>
+<meta property="article:published_time" content="2024-03-01T08:00:00Z">
+<meta property="article:modified_time" content="2024-03-02T10:30:00Z">
+</head>
+<body><p>Content</p></body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/updated")
+            .await
+            .expect("parse_html should succeed");
+
+        let modified = result.date_modified.expect("expected date_modified");
+        assert_eq!(modified.day(), 2);
+        assert_eq!(modified.hour(), 10);
+        assert!(result.was_updated_after_publish);
+    }
+
+    #[tokio::test]
+    async fn parse_html_equal_dates_not_updated() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+<title>Fresh Story</title>
+<meta property="article:published_time" content="2024-03-01T08:00:00Z">
+<meta property="article:modified_time" content="2024-03-01T08:00:00Z">
+</head>
+<body><p>Content</p></body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/fresh")
+            .await
+            .expect("parse_html should succeed");
+
+        assert!(result.date_modified.is_some());
+        assert!(!result.was_updated_after_publish);
+    }
+
+    #[tokio::test]
+    async fn parse_prefers_ld_json_metadata() {
+        let html = r#"<html><head>
+    <title>Meta Title | Site</title>
+    <meta name="author" content="Meta Author">
+    <meta property="article:published_time" content="2020-01-01T00:00:00Z">
+    <meta property="og:image" content="https://example.com/og.jpg">
+    <script type="application/ld+json">{
+        "@context": "https://schema.org",
+        "@type": "NewsArticle",
+        "headline": "Structured Headline",
+        "author": [{"@type": "Person", "name": "Ada"}, {"@type": "Person", "name": "Grace"}],
+        "datePublished": "2024-05-01T08:00:00Z",
+        "dateModified": "2024-05-03T08:00:00Z",
+        "publisher": {"@type": "Organization", "name": "The Ledger"},
+        "image": {"@type": "ImageObject", "url": "https://example.com/ld.jpg"}
+    }</script>
+</head><body><article><p>Body text.</p></article></body></html>"#;
+
+        let client = Client::builder().build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/story")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(result.title, "Structured Headline");
+        assert_eq!(result.author.as_deref(), Some("Ada, Grace"));
+        assert_eq!(result.authors, vec!["Ada", "Grace"]);
+        assert_eq!(
+            result.date_published.map(|d| d.to_rfc3339()),
+            Some("2024-05-01T08:00:00+00:00".to_string())
+        );
+        assert!(result.was_updated_after_publish);
+        assert_eq!(
+            result.lead_image_url.as_deref(),
+            Some("https://example.com/ld.jpg")
+        );
+        assert_eq!(result.publisher.as_deref(), Some("The Ledger"));
+        assert_eq!(result.site_name.as_deref(), Some("The Ledger"));
+    }
+
+    #[tokio::test]
+    async fn parse_uses_rdfa_metadata() {
+        let path = format!(
+            "{}/tests/fixtures/html/rdfa_drupal.html",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let html = std::fs::read_to_string(path).unwrap();
+
+        let client = Client::builder().build();
+        let result = client
+            .parse_html(
+                &html,
+                "https://www.example.org/news/2013/05/community-garden-opens",
+            )
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(result.title, "Community garden opens on Elm Street");
+        assert_eq!(result.author.as_deref(), Some("Maria Garcia"));
+        assert_eq!(
+            result.date_published.map(|d| d.to_rfc3339()),
+            Some("2013-05-04T13:30:00+00:00".to_string())
+        );
+        assert_eq!(
+            result.lead_image_url.as_deref(),
+            Some("https://www.example.org/sites/default/files/styles/large/public/garden.jpg")
+        );
+    }
+
     #[tokio::test]
     async fn parse_generic_meta_fields() {
         let html = r##"<!DOCTYPE html>
@@ -1576,13 +2624,15 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn parse_favicon_prefers_icon_order() {
+    async fn parse_og_locale_and_alternates() {
         let html = r#"<!DOCTYPE html>
 <html>
 <head>
-    <link rel="shortcut icon" href="/shortcut.ico">
-    <link rel="icon" href="/icon.png">
-    <link rel="apple-touch-icon" href="/apple.png">
+    <meta property="og:locale" content="en_US">
+    <meta property="og:locale:alternate" content="fr_FR">
+    <meta property="og:locale:alternate" content="es_ES">
+    <meta property="og:locale:alternate" content="fr-fr">
+    <title>Localized</title>
 </head>
 <body><p>Content</p></body>
 </html>"#;
@@ -1594,151 +2644,490 @@ mod tests {
             .await
             .expect("parse_html should succeed");
 
-        // rel="icon" should win because it's first in selector order
-        assert_eq!(result.favicon, Some("/icon.png".to_string()));
+        assert_eq!(result.locale, Some("en-US".to_string()));
+        assert_eq!(
+            result.alternate_locales,
+            vec!["fr-FR".to_string(), "es-ES".to_string()]
+        );
     }
 
     #[tokio::test]
-    async fn parse_custom_dek_and_excerpt() {
-        // Build a custom registry with dek and excerpt selectors for sample.org
-        let mut registry = ExtractorRegistry::new();
-        registry.register(crate::extractors::custom::CustomExtractor {
-            domain: "sample.org".to_string(),
-            supported_domains: vec![],
-            title: Some(FieldExtractor {
-                selectors: vec![SelectorSpec::Css("title".to_string())],
-                allow_multiple: false,
-                ..Default::default()
-            }),
-            content: Some(ContentExtractor {
-                field: FieldExtractor {
-                    selectors: vec![SelectorSpec::Css("div.content".to_string())],
-                    allow_multiple: false,
-                    ..Default::default()
-                },
-                clean: vec![],
-                transforms: HashMap::new(),
-            }),
-            dek: Some(FieldExtractor {
-                selectors: vec![SelectorSpec::Css("p.dek".to_string())],
-                allow_multiple: false,
-                ..Default::default()
-            }),
-            excerpt: Some(FieldExtractor {
-                selectors: vec![SelectorSpec::Css("p.excerpt".to_string())],
-                allow_multiple: false,
-                ..Default::default()
-            }),
-            ..Default::default()
-        });
-
-        let html = r#"<!DOCTYPE html>
-<html>
-<head><title>Article</title></head>
-<body>
-<p class="dek">Dek text</p>
-<p class="excerpt">Excerpt text</p>
-<div class="content">Main content here</div>
-</body>
-</html>"#;
+    async fn parse_canonical_url() {
+        let client = Client::builder().content_type(ContentType::Html).build();
 
-        let client = Client::builder()
-            .content_type(ContentType::Html)
-            .registry(registry)
-            .build();
+        // rel=canonical wins over og:url and is resolved against the page URL
+        let html = r#"<html><head>
+    <link rel="canonical" href="/articles/42">
+    <meta property="og:url" content="https://example.com/og">
+    <title>Canonical</title>
+</head><body><p>Content</p></body></html>"#;
+        let result = client
+            .parse_html(html, "https://nocustom.test/page?utm_source=feed")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(
+            result.canonical_url,
+            Some("https://nocustom.test/articles/42".to_string())
+        );
 
+        // og:url is the fallback; non-http canonical links are ignored
+        let html = r#"<html><head>
+    <link rel="canonical" href="javascript:void(0)">
+    <meta property="og:url" content="https://example.com/og">
+</head><body><p>Content</p></body></html>"#;
         let result = client
-            .parse_html(html, "https://sample.org/article")
+            .parse_html(html, "https://nocustom.test/page")
             .await
             .expect("parse_html should succeed");
+        assert_eq!(
+            result.canonical_url,
+            Some("https://example.com/og".to_string())
+        );
 
-        assert_eq!(result.dek, Some("Dek text".to_string()));
-        assert_eq!(result.excerpt, Some("Excerpt text".to_string()));
+        let result = client
+            .parse_html(
+                "<html><body><p>x</p></body></html>",
+                "https://nocustom.test/page",
+            )
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.canonical_url, None);
     }
 
     #[tokio::test]
-    async fn parse_parses_loose_date() {
-        let html = r#"<!DOCTYPE html>
-<html>
-<head>
-    <meta name="date" content="Jan 5, 2024">
-</head>
-<body><p>Content</p></body>
-</html>"#;
+    async fn parse_hreflang_alternates() {
+        let html = r#"<html><head>
+    <link rel="alternate" hreflang="en_us" href="/en/article">
+    <link rel="alternate" hreflang="de" href="https://example.de/artikel">
+    <link rel="alternate" hreflang="DE" href="https://example.de/duplicate">
+    <link rel="alternate" hreflang="X-Default" href="/article">
+    <link rel="alternate" hreflang="fr" href="mailto:fr@example.com">
+    <link rel="alternate" type="application/rss+xml" href="/feed.xml">
+</head><body><p>Content</p></body></html>"#;
 
         let client = Client::builder().content_type(ContentType::Html).build();
-
         let result = client
             .parse_html(html, "https://nocustom.test/page")
             .await
             .expect("parse_html should succeed");
 
-        assert!(
-            result.date_published.is_some(),
-            "expected date_published to be set"
+        assert_eq!(
+            result.alternates,
+            vec![
+                (
+                    "en-US".to_string(),
+                    "https://nocustom.test/en/article".to_string()
+                ),
+                ("de".to_string(), "https://example.de/artikel".to_string()),
+                (
+                    "x-default".to_string(),
+                    "https://nocustom.test/article".to_string()
+                ),
+            ]
         );
-        let dt = result.date_published.unwrap();
-        assert_eq!(dt.year(), 2024);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 5);
     }
 
     #[tokio::test]
-    async fn parse_extracts_video_url() {
-        let html = r#"<!DOCTYPE html>
-<html>
-<head>
-    <meta property="og:video" content="https://example.com/video.mp4">
-    <meta property="og:video:type" content="video/mp4">
-    <meta property="og:video:width" content="1280">
-    <meta property="og:video:height" content="720">
-</head>
-<body><p>Content</p></body>
-</html>"#;
+    async fn parse_discovers_feed_links() {
+        let html = r#"<html><head>
+    <link rel="alternate" type="application/rss+xml" title="RSS" href="/feed.xml">
+    <link rel="alternate" type="Application/Atom+XML; charset=utf-8" href="https://cdn.test/atom">
+    <link rel="alternate" type="application/rss+xml" href="/feed.xml">
+    <link rel="alternate" type="text/html" href="/print">
+    <link rel="stylesheet" type="application/rss+xml" href="/not-a-feed">
+</head><body><p>Content</p></body></html>"#;
 
         let client = Client::builder().content_type(ContentType::Html).build();
-
         let result = client
-            .parse_html(html, "https://nocustom.test/page")
+            .parse_html(html, "https://nocustom.test/blog/post")
             .await
             .expect("parse_html should succeed");
 
         assert_eq!(
-            result.video_url,
-            Some("https://example.com/video.mp4".to_string())
+            result.feeds,
+            vec![
+                "https://nocustom.test/feed.xml".to_string(),
+                "https://cdn.test/atom".to_string(),
+            ]
         );
+    }
 
-        // Check video metadata
-        assert!(result.video_metadata.is_some());
-        let meta = result.video_metadata.unwrap();
-        assert!(meta.is_object());
-        let obj = meta.as_object().unwrap();
-        assert_eq!(
-            obj.get("og:video:type"),
-            Some(&serde_json::Value::String("video/mp4".to_string()))
-        );
-        assert_eq!(
-            obj.get("og:video:width"),
-            Some(&serde_json::Value::String("1280".to_string()))
-        );
+    #[tokio::test]
+    async fn parse_html_normalize_url() {
+        let html = r#"<html><head><link rel="amphtml" href="amp"></head>
+<body><p>Content</p></body></html>"#;
+        let page = "https://nocustom.test/story/?utm_source=feed&id=7&fbclid=abc#comments";
+
+        let client = Client::builder().normalize_url(true).build();
+        let result = client.parse_html(html, page).await.unwrap();
+        assert_eq!(result.url, "https://nocustom.test/story/?id=7");
         assert_eq!(
-            obj.get("og:video:height"),
-            Some(&serde_json::Value::String("720".to_string()))
+            result.amp_url,
+            Some("https://nocustom.test/story/amp".to_string())
         );
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, page)
+            .await
+            .unwrap();
+        assert_eq!(result.url, page);
     }
 
     #[tokio::test]
-    async fn parse_detects_direction_rtl() {
-        // Hebrew text: "Shalom" (Peace) written in Hebrew characters
+    async fn parse_html_extracts_tables_when_enabled() {
         let html = r#"<!DOCTYPE html>
 <html>
+<head><title>Budget Data</title></head>
 <body>
-<p>This is some Hebrew text: </p>
+<article>
+<p>The city council published its annual budget figures this week, showing spending by department.</p>
+<table>
+  <thead><tr><th>Department</th><th>2023</th><th>2024</th></tr></thead>
+  <tbody>
+    <tr><td>Parks</td><td>1.2M</td><td>1.4M</td></tr>
+    <tr><td>Transit</td><td>8.9M</td></tr>
+  </tbody>
+</table>
+<p>Officials said transit figures for 2024 were still being finalized by the finance office.</p>
+</article>
 </body>
 </html>"#;
 
-        let client = Client::builder().content_type(ContentType::Html).build();
-
+        let client = Client::builder()
+            .content_type(ContentType::Html)
+            .extract_tables(true)
+            .build();
+
+        let result = client
+            .parse_html(html, "https://nocustom.test/budget")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(result.tables.len(), 1);
+        let table = &result.tables[0];
+        assert_eq!(table.headers, vec!["Department", "2023", "2024"]);
+        assert_eq!(
+            table.rows,
+            vec![
+                vec!["Parks".to_string(), "1.2M".to_string(), "1.4M".to_string()],
+                vec!["Transit".to_string(), "8.9M".to_string(), String::new()],
+            ]
+        );
+
+        let default_client = Client::builder().content_type(ContentType::Html).build();
+        let result = default_client
+            .parse_html(html, "https://nocustom.test/budget")
+            .await
+            .expect("parse_html should succeed");
+        assert!(result.tables.is_empty());
+    }
+
+    #[tokio::test]
+    async fn parse_favicon_prefers_icon_order() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+    <link rel="shortcut icon" href="/shortcut.ico">
+    <link rel="icon" href="/icon.png">
+    <link rel="apple-touch-icon" href="/apple.png">
+</head>
+<body><p>Content</p></body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+
+        // rel="icon" should win because it's first in selector order
+        assert_eq!(result.favicon, Some("/icon.png".to_string()));
+    }
+
+    #[tokio::test]
+    async fn parse_html_reports_content_selector_when_tracking() {
+        let mut registry = ExtractorRegistry::new();
+        registry.register(crate::extractors::custom::CustomExtractor {
+            domain: "sample.org".to_string(),
+            supported_domains: vec![],
+            content: Some(ContentExtractor {
+                field: FieldExtractor {
+                    selectors: vec![
+                        SelectorSpec::Css("div.missing".to_string()),
+                        SelectorSpec::Css("div.story-body".to_string()),
+                    ],
+                    allow_multiple: false,
+                    ..Default::default()
+                },
+                clean: vec![],
+                transforms: HashMap::new(),
+            }),
+            ..Default::default()
+        });
+
+        let client = Client::builder()
+            .content_type(ContentType::Html)
+            .registry(registry)
+            .track_provenance(true)
+            .build();
+
+        let custom_html = r#"<html><head><title>Custom</title></head><body>
+<div class="story-body"><p>The story body lives in a site-specific container that the custom extractor targets.</p></div>
+</body></html>"#;
+        let result = client
+            .parse_html(custom_html, "https://sample.org/article")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.content_selector.as_deref(), Some("div.story-body"));
+
+        let generic_html = r#"<html><head><title>Generic</title></head><body>
+<div class="sidebar"><a href="/a">Link</a></div>
+<article class="post main">
+<p>This is a long paragraph of article text, with commas, that the readability scorer should prefer, clearly.</p>
+<p>Another paragraph follows here, adding more prose, more commas, and more weight to this candidate node.</p>
+</article>
+</body></html>"#;
+        let result = client
+            .parse_html(generic_html, "https://nocustom.test/generic")
+            .await
+            .expect("parse_html should succeed");
+        let selector = result.content_selector.expect("expected content_selector");
+        assert!(
+            selector.starts_with("readability:"),
+            "unexpected selector: {}",
+            selector
+        );
+
+        let untracked = Client::builder().content_type(ContentType::Html).build();
+        let result = untracked
+            .parse_html(generic_html, "https://nocustom.test/generic")
+            .await
+            .expect("parse_html should succeed");
+        assert!(result.content_selector.is_none());
+    }
+
+    #[tokio::test]
+    async fn parse_html_separates_pull_quotes_from_blockquotes() {
+        let html = r#"<html><head><title>Quotes</title></head><body>
+<article>
+<p>The council met on Tuesday to discuss the new transit plan, which has drawn comments from many residents.</p>
+<aside class="pullquote">We cannot keep building roads forever.</aside>
+<p>Several speakers argued, at length, that the plan favoured cars, and asked for more bus lanes downtown.</p>
+<blockquote><p>Transit is a public good, not a luxury.</p></blockquote>
+<div class="article__pullquote"><p>Transit is a public good.</p></div>
+<p>The vote was postponed until next month, pending a revised budget from the planning department staff.</p>
+</article>
+</body></html>"#;
+
+        let client = Client::builder()
+            .content_type(ContentType::Html)
+            .collect_pull_quotes(true)
+            .build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/quotes")
+            .await
+            .expect("parse_html should succeed");
+
+        assert!(!result.content.contains("building roads forever"));
+        assert!(!result.content.contains("pullquote"));
+        assert!(result.content.contains("<blockquote>"));
+        assert!(result.content.contains("not a luxury"));
+        assert_eq!(
+            result.pull_quotes,
+            vec![
+                "We cannot keep building roads forever.".to_string(),
+                "Transit is a public good.".to_string(),
+            ]
+        );
+
+        let default_client = Client::builder().content_type(ContentType::Html).build();
+        let result = default_client
+            .parse_html(html, "https://nocustom.test/quotes")
+            .await
+            .expect("parse_html should succeed");
+        assert!(!result.content.contains("building roads forever"));
+        assert!(result.pull_quotes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn parse_custom_dek_and_excerpt() {
+        // Build a custom registry with dek and excerpt selectors for sample.org
+        let mut registry = ExtractorRegistry::new();
+        registry.register(crate::extractors::custom::CustomExtractor {
+            domain: "sample.org".to_string(),
+            supported_domains: vec![],
+            title: Some(FieldExtractor {
+                selectors: vec![SelectorSpec::Css("title".to_string())],
+                allow_multiple: false,
+                ..Default::default()
+            }),
+            content: Some(ContentExtractor {
+                field: FieldExtractor {
+                    selectors: vec![SelectorSpec::Css("div.content".to_string())],
+                    allow_multiple: false,
+                    ..Default::default()
+                },
+                clean: vec![],
+                transforms: HashMap::new(),
+            }),
+            dek: Some(FieldExtractor {
+                selectors: vec![SelectorSpec::Css("p.dek".to_string())],
+                allow_multiple: false,
+                ..Default::default()
+            }),
+            excerpt: Some(FieldExtractor {
+                selectors: vec![SelectorSpec::Css("p.excerpt".to_string())],
+                allow_multiple: false,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let html = r#"<!DOCTYPE html>
+<html>
+<head><title>Article</title></head>
+<body>
+<p class="dek">Dek text</p>
+<p class="excerpt">Excerpt text</p>
+<div class="content">Main content here</div>
+</body>
+</html>"#;
+
+        let client = Client::builder()
+            .content_type(ContentType::Html)
+            .registry(registry)
+            .build();
+
+        let result = client
+            .parse_html(html, "https://sample.org/article")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(result.dek, Some("Dek text".to_string()));
+        assert_eq!(result.excerpt, Some("Excerpt text".to_string()));
+    }
+
+    #[tokio::test]
+    async fn parse_parses_loose_date() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta name="date" content="Jan 5, 2024">
+</head>
+<body><p>Content</p></body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+
+        assert!(
+            result.date_published.is_some(),
+            "expected date_published to be set"
+        );
+        let dt = result.date_published.unwrap();
+        assert_eq!(dt.year(), 2024);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 5);
+    }
+
+    #[tokio::test]
+    async fn parse_derives_video_info() {
+        let html = r#"<html><head>
+<meta property="og:image" content="https://nocustom.test/poster.jpg">
+<meta property="og:video" content="https://player.vimeo.com/video/76979871">
+</head><body><p>Content</p></body></html>"#;
+
+        let client = Client::builder().build();
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let video = result.video.expect("video info should be derived");
+        assert_eq!(video.platform, EmbedPlatform::Vimeo);
+        assert_eq!(video.id, "76979871");
+        assert_eq!(video.url, "https://vimeo.com/76979871");
+        assert_eq!(
+            video.thumbnail_url.as_deref(),
+            Some("https://nocustom.test/poster.jpg")
+        );
+
+        let html = html.replace(
+            "https://player.vimeo.com/video/76979871",
+            "https://www.youtube.com/embed/dQw4w9WgXcQ",
+        );
+        let result = client
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let video = result.video.expect("video info should be derived");
+        assert_eq!(
+            video.thumbnail_url.as_deref(),
+            Some("https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg")
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_extracts_video_url() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta property="og:video" content="https://example.com/video.mp4">
+    <meta property="og:video:type" content="video/mp4">
+    <meta property="og:video:width" content="1280">
+    <meta property="og:video:height" content="720">
+</head>
+<body><p>Content</p></body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(
+            result.video_url,
+            Some("https://example.com/video.mp4".to_string())
+        );
+
+        // Check video metadata
+        assert!(result.video_metadata.is_some());
+        let meta = result.video_metadata.unwrap();
+        assert!(meta.is_object());
+        let obj = meta.as_object().unwrap();
+        assert_eq!(
+            obj.get("og:video:type"),
+            Some(&serde_json::Value::String("video/mp4".to_string()))
+        );
+        assert_eq!(
+            obj.get("og:video:width"),
+            Some(&serde_json::Value::String("1280".to_string()))
+        );
+        assert_eq!(
+            obj.get("og:video:height"),
+            Some(&serde_json::Value::String("720".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_detects_direction_rtl() {
+        // Hebrew text: "Shalom" (Peace) written in Hebrew characters
+        let html = r#"<!DOCTYPE html>
+<html>
+<body>
+<p>This is some Hebrew text: </p>
+</body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+
         let result = client
             .parse_html(html, "https://nocustom.test/page")
             .await
@@ -1823,43 +3212,419 @@ mod tests {
 
         let client = Client::builder().content_type(ContentType::Html).build();
 
-        let result = client
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(
+            result.next_page_url,
+            Some("/page2".to_string()),
+            "expected .next a pattern to be detected"
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_next_page_pagination_pattern() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<body>
+<p>Content</p>
+<div class="pagination">
+    <a href="/page1">Prev</a>
+    <a rel="next" href="/page2">Next</a>
+</div>
+</body>
+</html>"#;
+
+        let client = Client::builder().content_type(ContentType::Html).build();
+
+        let result = client
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+
+        assert_eq!(
+            result.next_page_url,
+            Some("/page2".to_string()),
+            "expected .pagination a[rel=next] pattern to be detected"
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_estimates_reading_time_from_content() {
+        let words = "word ".repeat(300);
+        let html = format!(
+            r#"<html><body><article><p>{}</p><img src="https://example.com/a.png"></article></body></html>"#,
+            words
+        );
+
+        let result = Client::builder()
+            .build()
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        // 300 words at 238 wpm plus 12s for the image
+        assert_eq!(result.reading_time_minutes, 2);
+
+        let result = Client::builder()
+            .reading_wpm(600)
+            .build()
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.reading_time_minutes, 1);
+    }
+
+    #[cfg(feature = "language-detection")]
+    #[tokio::test]
+    async fn parse_detects_language_when_undeclared() {
+        let client = Client::builder().build();
+        let body = "<article><p>Die Stadtverwaltung hat gestern Abend den neuen Haushalt \
+            beschlossen. Nach langen Verhandlungen stimmten die meisten Mitglieder des \
+            Rates für den Entwurf, der mehr Geld für Schulen und den Nahverkehr vorsieht.</p></article>";
+
+        let html = format!("<html><body>{}</body></html>", body);
+        let result = client
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.language.as_deref(), Some("de"));
+
+        // A declared language always wins over detection
+        let html = format!(r#"<html lang="en"><body>{}</body></html>"#, body);
+        let result = client
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.language.as_deref(), Some("en"));
+    }
+
+    #[tokio::test]
+    async fn parse_summary_only_when_requested() {
+        let html = r#"<html><body><article>
+<p>The harbor reopened on Friday after a week of storm repairs to the harbor piers.</p>
+<p>Fishing crews returned to the harbor piers before dawn to unload their catch.</p>
+<p>A new cafe opened downtown.</p>
+<p>Officials said the harbor repairs finished two days ahead of schedule.</p>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.summary, None);
+
+        let result = Client::builder()
+            .summary_sentences(1)
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let summary = result.summary.expect("summary should be set");
+        assert!(summary.contains("harbor"), "{}", summary);
+        assert!(!summary.contains("cafe"));
+    }
+
+    #[tokio::test]
+    async fn parse_collects_keywords() {
+        let html = r#"<html><head>
+<meta name="keywords" content="Transit, Budget; city hall">
+<meta property="article:tag" content="budget">
+<meta property="article:tag" content="Buses">
+</head><body><article>
+<p>The council approved the transit budget on Tuesday after a long debate.</p>
+<p>Critics of the transit budget said bus routes in the north were ignored.</p>
+<p>Supporters said new bus routes would follow once the transit budget passed.</p>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(
+            &result.keywords[..4],
+            &["Transit", "Budget", "city hall", "Buses"]
+        );
+        assert!(result.keywords.contains(&"transit budget".to_string()));
+        assert!(result.keywords.contains(&"bus routes".to_string()));
+
+        let html = html.replace("<html>", r#"<html lang="fr">"#);
+        let result = Client::builder()
+            .build()
+            .parse_html(&html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(
+            result.keywords,
+            vec!["Transit", "Budget", "city hall", "Buses"]
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_reports_extraction_info() {
+        let client = Client::builder().build();
+
+        let paragraph =
+            "<p>The harbor reopened on Friday after a week of repairs, and crews said the \
+            piers were stronger than before, with new pilings, rails, and lighting.</p>";
+        let article_html = format!(
+            r#"<html><body><div class="nav"><a href="/a">Home</a> <a href="/b">World</a></div>
+<article class="story">{}</article></body></html>"#,
+            paragraph.repeat(12)
+        );
+        let result = client
+            .parse_html(&article_html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let info = result.extraction.expect("extraction info should be set");
+        assert_eq!(info.method, ExtractionMethod::Readability);
+        assert!(!info.from_amp);
+        assert!(info.candidate_score.is_some_and(|s| s > 0));
+        assert!(info.content_length > CONFIDENT_CONTENT_CHARS);
+        assert!(info.link_density < 0.05);
+        assert!(info.confidence > 0.8, "confidence {}", info.confidence);
+
+        let ld_html = r#"<html><head><script type="application/ld+json">
+{"@type": "NewsArticle", "headline": "Short", "articleBody": "A body that only exists in JSON-LD."}
+</script></head><body><div><a href="/a">Home</a></div></body></html>"#;
+        let result = client
+            .parse_html(ld_html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let info = result.extraction.expect("extraction info should be set");
+        assert_eq!(info.method, ExtractionMethod::JsonLd);
+        assert_eq!(info.candidate_score, None);
+        assert!(info.confidence < 0.1, "confidence {}", info.confidence);
+    }
+
+    #[tokio::test]
+    async fn parse_collects_content_links() {
+        let html = r#"<html><body>
+<nav><a href="/home">Home</a></nav>
+<article>
+<p>The harbor reopened on Friday, according to <a href="https://port.test/notice" rel="nofollow">the port notice</a>, after a week of repairs.</p>
+<p>Crews returned before dawn, as <a href="/2024/crews">we reported earlier</a>, to unload their catch at the piers.</p>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let urls: Vec<(&str, bool)> = result
+            .links
+            .iter()
+            .map(|l| (l.url.as_str(), l.external))
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                ("https://port.test/notice", true),
+                ("https://nocustom.test/2024/crews", false),
+            ]
+        );
+        assert_eq!(result.links[0].rel, vec!["nofollow"]);
+    }
+
+    #[tokio::test]
+    async fn parse_collects_content_images() {
+        let html = r#"<html><body><article>
+<p>The harbor reopened on Friday after a week of repairs, and crews said the piers were stronger, with new rails.</p>
+<figure><img src="/img/piers.jpg" alt="Piers" width="800" height="600"><figcaption>The rebuilt piers.</figcaption></figure>
+<p>Fishing crews returned before dawn to unload their catch, and buyers lined the docks, waiting, watching, bidding.</p>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert_eq!(result.images.len(), 1, "{:?}", result.images);
+        let image = &result.images[0];
+        assert_eq!(image.url, "https://nocustom.test/img/piers.jpg");
+        assert_eq!(image.caption.as_deref(), Some("The rebuilt piers."));
+        assert_eq!((image.width, image.height), (Some(800), Some(600)));
+    }
+
+    #[tokio::test]
+    async fn parse_collects_embeds() {
+        let html = r#"<html><body><article>
+<p>The harbor reopened on Friday after a week of repairs, and crews said the piers were stronger, with new rails.</p>
+<iframe width="560" height="315" src="https://www.youtube.com/embed/dQw4w9WgXcQ"></iframe>
+<p>Fishing crews returned before dawn to unload their catch, and buyers lined the docks, waiting, watching, bidding.</p>
+<blockquote class="twitter-tweet"><p>The harbor is open again!</p>&mdash; Port Authority <a href="https://twitter.com/port/status/1234567890">May 1, 2024</a></blockquote>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        let found: Vec<(EmbedPlatform, &str)> = result
+            .embeds
+            .iter()
+            .map(|e| (e.platform, e.id.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (EmbedPlatform::YouTube, "dQw4w9WgXcQ"),
+                (EmbedPlatform::Twitter, "1234567890"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_resolves_relative_content_urls() {
+        let html = r#"<html><head><base href="/static/"></head><body><article>
+<p>The harbor reopened on Friday after repairs, as <a href="/2024/harbor">we reported</a>, and crews returned before dawn.</p>
+<p><img src="piers.jpg" alt="Piers"></p>
+<p>Fishing crews unloaded their catch at the rebuilt piers, and buyers lined the docks, waiting, watching, bidding.</p>
+</article></body></html>"#;
+
+        for content_type in [ContentType::Html, ContentType::Markdown] {
+            let result = Client::builder()
+                .content_type(content_type)
+                .build()
+                .parse_html(html, "https://nocustom.test/news/page")
+                .await
+                .expect("parse_html should succeed");
+            assert!(
+                result.content.contains("https://nocustom.test/2024/harbor"),
+                "{}",
+                result.content
+            );
+            assert!(
+                result
+                    .content
+                    .contains("https://nocustom.test/static/piers.jpg"),
+                "{}",
+                result.content
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn parse_selects_srcset_candidate() {
+        let html = r#"<html><body><article>
+<p>The harbor reopened on Friday after repairs, and crews returned before dawn to unload their catch at the piers.</p>
+<p><img src="data:image/gif;base64,R0lGODlh" srcset="/p-640.jpg 640w, /p-1280.jpg 1280w, /p-3840.jpg 3840w" alt="Piers"></p>
+<p>Buyers lined the rebuilt docks, waiting, watching, bidding, while the harbor master counted the boats coming in.</p>
+</article></body></html>"#;
+
+        let result = Client::builder()
+            .build()
+            .parse_html(html, "https://nocustom.test/page")
+            .await
+            .expect("parse_html should succeed");
+        assert!(
+            result
+                .content
+                .contains(r#"src="https://nocustom.test/p-1280.jpg""#),
+            "{}",
+            result.content
+        );
+        assert!(
+            result
+                .content
+                .contains("https://nocustom.test/p-640.jpg 640w"),
+            "{}",
+            result.content
+        );
+
+        let result = Client::builder()
+            .image_target_width(600)
+            .build()
             .parse_html(html, "https://nocustom.test/page")
             .await
             .expect("parse_html should succeed");
-
-        assert_eq!(
-            result.next_page_url,
-            Some("/page2".to_string()),
-            "expected .next a pattern to be detected"
+        assert!(
+            result
+                .content
+                .contains(r#"src="https://nocustom.test/p-640.jpg""#),
+            "{}",
+            result.content
         );
     }
 
     #[tokio::test]
-    async fn parse_next_page_pagination_pattern() {
-        let html = r#"<!DOCTYPE html>
-<html>
-<body>
-<p>Content</p>
-<div class="pagination">
-    <a href="/page1">Prev</a>
-    <a rel="next" href="/page2">Next</a>
-</div>
-</body>
-</html>"#;
-
-        let client = Client::builder().content_type(ContentType::Html).build();
+    async fn inline_images_embeds_content_images_within_budget() {
+        let server = MockServer::start();
+        let paragraph = "<p>The harbor fills with boats at dawn, when the fishing crews \
+                         return with the night's catch and the market opens.</p>";
+        let html = format!(
+            r#"<html><head><title>Harbor at dawn</title></head><body><article>
+<h1>Harbor at dawn</h1>{p}
+<p><img src="/img/a.png" srcset="/img/a.png 1x, /img/a@2x.png 2x" alt="Boats"></p>{p}
+<p><img src="/img/b.png" alt="Market"></p>{p}
+<p><img src="/img/c.png" alt="Crew"></p>{p}
+</article></body></html>"#,
+            p = paragraph
+        );
+        let page = server.mock(|when, then| {
+            when.method(GET).path("/story");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(&html);
+        });
+        let png = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        let a = server.mock(|when, then| {
+            when.method(GET).path("/img/a.png");
+            then.status(200)
+                .header("content-type", "image/png")
+                .body(png);
+        });
+        let b = server.mock(|when, then| {
+            when.method(GET).path("/img/b.png");
+            then.status(200)
+                .header("content-type", "text/html")
+                .body("<html>");
+        });
+        let c = server.mock(|when, then| {
+            when.method(GET).path("/img/c.png");
+            then.status(200)
+                .header("content-type", "image/png")
+                .body(png);
+        });
 
-        let result = client
-            .parse_html(html, "https://nocustom.test/page")
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .image_target_width(0)
+            .inline_images(true)
+            .inline_images_max_bytes(12)
+            .build()
+            .parse(&server.url("/story"))
             .await
-            .expect("parse_html should succeed");
-
-        assert_eq!(
-            result.next_page_url,
-            Some("/page2".to_string()),
-            "expected .pagination a[rel=next] pattern to be detected"
+            .expect("parse should succeed");
+        page.assert();
+        a.assert();
+        b.assert();
+        c.assert();
+        assert!(
+            result
+                .content
+                .contains(r#"src="data:image/png;base64,iVBORw0KGgo=""#),
+            "{}",
+            result.content
         );
+        assert!(!result.content.contains("a@2x.png"), "{}", result.content);
+        // Not an image, and over the remaining budget
+        assert!(result.content.contains(&server.url("/img/b.png")));
+        assert!(result.content.contains(&server.url("/img/c.png")));
+        assert_eq!(result.images[0].url, server.url("/img/a.png"));
+
+        // Private network images stay blocked unless allowed
+        let result = Client::builder()
+            .inline_images(true)
+            .build()
+            .parse_html(&html, &server.url("/story"))
+            .await
+            .expect("parse_html should succeed");
+        a.assert_calls(1);
+        assert!(!result.content.contains("data:image/png"));
     }
 
     #[tokio::test]
@@ -2319,6 +4084,475 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn resolve_oembed_fetches_advertised_document() {
+        let server = MockServer::start();
+        let page = server.mock(|when, then| {
+            when.method(GET).path("/v/1");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(
+                    r#"<html><head><title>Harbor at dawn</title>
+<link rel="alternate" type="application/json+oembed" href="/oembed?id=1">
+</head><body><div class="player"></div></body></html>"#,
+                );
+        });
+        let oembed = server.mock(|when, then| {
+            when.method(GET).path("/oembed").query_param("id", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{"type": "video", "title": "Harbor at dawn", "provider_name": "VideoSite",
+                        "thumbnail_url": "https://video.test/t/1.jpg", "html": "<iframe></iframe>"}"#,
+                );
+        });
+
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .build()
+            .parse(&server.url("/v/1"))
+            .await
+            .expect("parse should succeed");
+        assert_eq!(result.oembed_url, Some(server.url("/oembed?id=1")));
+        assert_eq!(result.oembed, None);
+        oembed.assert_calls(0);
+
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .resolve_oembed(true)
+            .build()
+            .parse(&server.url("/v/1"))
+            .await
+            .expect("parse should succeed");
+        page.assert_calls(2);
+        oembed.assert();
+        let resolved = result.oembed.expect("oEmbed should be resolved");
+        assert_eq!(resolved.kind, "video");
+        assert_eq!(resolved.provider_name.as_deref(), Some("VideoSite"));
+        assert_eq!(
+            resolved.thumbnail_url.as_deref(),
+            Some("https://video.test/t/1.jpg")
+        );
+
+        // parse_html resolves it the same way
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .resolve_oembed(true)
+            .build()
+            .parse_html(
+                r#"<html><head><link rel="alternate" type="application/json+oembed" href="/oembed?id=1"></head>
+<body><div class="player"></div></body></html>"#,
+                &server.url("/v/1"),
+            )
+            .await
+            .expect("parse_html should succeed");
+        oembed.assert_calls(2);
+        assert_eq!(result.oembed.map(|o| o.kind).as_deref(), Some("video"));
+    }
+
+    #[tokio::test]
+    async fn resolve_oembed_drops_credentials_across_origins() {
+        let page_server = MockServer::start();
+        let provider = MockServer::start();
+        let oembed = provider.mock(|when, then| {
+            when.method(GET)
+                .path("/oembed")
+                .header_missing("authorization");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"version":"1.0","type":"video","title":"Clip"}"#);
+        });
+
+        let html = format!(
+            r#"<html><head><link rel="alternate" type="application/json+oembed" href="{}"></head>
+<body><div class="player"></div></body></html>"#,
+            provider.url("/oembed?id=1")
+        );
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .resolve_oembed(true)
+            .header("Authorization", "Bearer secret")
+            .build()
+            .parse_html(&html, &page_server.url("/v/1"))
+            .await
+            .expect("parse_html should succeed");
+        oembed.assert();
+        assert_eq!(result.oembed.map(|o| o.kind).as_deref(), Some("video"));
+    }
+
+    #[tokio::test]
+    async fn amp_fallback_replaces_thin_content() {
+        let server = MockServer::start();
+        let amp_body = "The AMP version carries the full article text that the canonical page hides behind scripts. ".repeat(8);
+
+        let canonical_html = r#"<!DOCTYPE html>
+<html>
+<head><title>Story</title><link rel="amphtml" href="/story/amp"></head>
+<body><article><p>Loading the story, please wait.</p></article></body>
+</html>"#;
+        let canonical = server.mock(|when, then| {
+            when.method(GET).path("/story");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(canonical_html);
+        });
+        let amp = server.mock(|when, then| {
+            when.method(GET).path("/story/amp");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(format!(
+                    r#"<!DOCTYPE html>
+<html amp>
+<head><title>Story</title></head>
+<body><article><p>{}</p></article></body>
+</html>"#,
+                    amp_body
+                ));
+        });
+
+        let client = Client::builder()
+            .allow_private_networks(true)
+            .content_type(ContentType::Text)
+            .amp_fallback(true)
+            .track_provenance(true)
+            .build();
+        let result = client
+            .parse(&server.url("/story"))
+            .await
+            .expect("parse should succeed");
+        canonical.assert();
+        amp.assert();
+
+        assert_eq!(result.amp_url, Some(server.url("/story/amp")));
+        assert!(
+            result.content.contains("The AMP version carries"),
+            "expected AMP content, got: {}",
+            result.content
+        );
+        assert!(!result.content.contains("Loading the story"));
+        assert!(result
+            .content_selector
+            .as_deref()
+            .is_some_and(|s| s.starts_with("amp:")));
+        assert!(result.word_count >= 100);
+
+        // parse_html applies the same fallback
+        let result = client
+            .parse_html(canonical_html, &server.url("/story"))
+            .await
+            .expect("parse_html should succeed");
+        amp.assert_calls(2);
+        assert!(
+            result.content.contains("The AMP version carries"),
+            "expected AMP content, got: {}",
+            result.content
+        );
+        assert!(result.word_count >= 100);
+    }
+
+    #[tokio::test]
+    async fn amp_fallback_drops_credentials_across_origins() {
+        let page_server = MockServer::start();
+        let amp_cache = MockServer::start();
+        let amp_body =
+            "The AMP cache copy carries the full article text for thin pages. ".repeat(8);
+        let amp = amp_cache.mock(|when, then| {
+            when.method(GET)
+                .path("/story/amp")
+                .header_missing("authorization");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(format!(
+                    "<html amp><head><title>Story</title></head><body><article><p>{}</p></article></body></html>",
+                    amp_body
+                ));
+        });
+
+        let html = format!(
+            r#"<html><head><title>Story</title><link rel="amphtml" href="{}"></head>
+<body><article><p>Loading the story, please wait.</p></article></body></html>"#,
+            amp_cache.url("/story/amp")
+        );
+        let result = Client::builder()
+            .allow_private_networks(true)
+            .content_type(ContentType::Text)
+            .amp_fallback(true)
+            .header("Authorization", "Bearer secret")
+            .build()
+            .parse_html(&html, &page_server.url("/story"))
+            .await
+            .expect("parse_html should succeed");
+        amp.assert();
+        assert!(
+            result.content.contains("AMP cache copy"),
+            "{}",
+            result.content
+        );
+    }
+
+    #[tokio::test]
+    async fn amp_fallback_respects_flag() {
+        let server = MockServer::start();
+        let canonical = server.mock(|when, then| {
+            when.method(GET).path("/story");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(
+                    r#"<html><head><link rel="amphtml" href="/story/amp"></head>
+<body><article><p>Loading the story, please wait.</p></article></body></html>"#,
+                );
+        });
+        let amp = server.mock(|when, then| {
+            when.method(GET).path("/story/amp");
+            then.status(200)
+                .body("<html><body><p>AMP</p></body></html>");
+        });
+
+        let client = Client::builder()
+            .allow_private_networks(true)
+            .content_type(ContentType::Text)
+            .build();
+        let result = client
+            .parse(&server.url("/story"))
+            .await
+            .expect("parse should succeed");
+        canonical.assert();
+        amp.assert_calls(0);
+
+        assert_eq!(result.amp_url, Some(server.url("/story/amp")));
+        assert!(result.content.contains("Loading the story"));
+    }
+
+    #[test]
+    fn meta_refresh_url_parsing() {
+        let page = "https://example.com/a/old";
+        let refresh = |content: &str| {
+            let html = format!(
+                r#"<html><head><meta http-equiv="Refresh" content="{}"></head></html>"#,
+                content
+            );
+            extract_meta_refresh_url(&Document::from(html.as_str()), page)
+        };
+
+        assert_eq!(
+            refresh("0;url=/new"),
+            Some("https://example.com/new".to_string())
+        );
+        assert_eq!(
+            refresh("1; URL='https://other.test/x'"),
+            Some("https://other.test/x".to_string())
+        );
+        assert_eq!(
+            refresh("0, next"),
+            Some("https://example.com/a/next".to_string())
+        );
+        // Reloads, long delays, self-references and non-http targets are not redirects
+        assert_eq!(refresh("30"), None);
+        assert_eq!(refresh("600;url=/"), None);
+        assert_eq!(refresh("0;url=https://example.com/a/old"), None);
+        assert_eq!(refresh("0;url=javascript:alert(1)"), None);
+    }
+
+    #[tokio::test]
+    async fn parse_follows_meta_refresh() {
+        let server = MockServer::start();
+        let shell = server.mock(|when, then| {
+            when.method(GET).path("/go");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(
+                    r#"<html><head><meta http-equiv="refresh" content="0;url=/article"></head>
+<body>Redirecting...</body></html>"#,
+                );
+        });
+        let article = server.mock(|when, then| {
+            when.method(GET).path("/article");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(
+                    r#"<html><head><title>Real Article</title></head>
+<body><article><p>The real article body lives behind the meta refresh redirect.</p></article></body></html>"#,
+                );
+        });
+
+        let client = Client::builder()
+            .allow_private_networks(true)
+            .content_type(ContentType::Text)
+            .build();
+        let result = client
+            .parse(&server.url("/go"))
+            .await
+            .expect("parse should succeed");
+        shell.assert();
+        article.assert();
+
+        assert_eq!(result.url, server.url("/article"));
+        assert_eq!(result.redirect_chain, vec![server.url("/go")]);
+        assert!(result.content.contains("The real article body"));
+    }
+
+    #[tokio::test]
+    async fn parse_meta_refresh_loop_stops() {
+        let server = MockServer::start();
+        let a = server.mock(|when, then| {
+            when.method(GET).path("/a");
+            then.status(200).body(
+                r#"<html><head><meta http-equiv="refresh" content="0;url=/b"></head></html>"#,
+            );
+        });
+        let b = server.mock(|when, then| {
+            when.method(GET).path("/b");
+            then.status(200).body(
+                r#"<html><head><meta http-equiv="refresh" content="0;url=/a"></head></html>"#,
+            );
+        });
+
+        let client = Client::builder().allow_private_networks(true).build();
+        let result = client
+            .parse(&server.url("/a"))
+            .await
+            .expect("parse should succeed");
+        a.assert_calls(1);
+        b.assert_calls(1);
+        assert_eq!(result.url, server.url("/b"));
+        assert_eq!(result.redirect_chain, vec![server.url("/a")]);
+    }
+
+    #[tokio::test]
+    async fn parse_meta_refresh_target_failure_keeps_page() {
+        let server = MockServer::start();
+        let page = server.mock(|when, then| {
+            when.method(GET).path("/story");
+            then.status(200).body(
+                r#"<html><head><title>Harbor at dawn</title>
+<meta http-equiv="refresh" content="0;url=/broken"></head>
+<body><article><p>The harbor fills with boats at dawn.</p></article></body></html>"#,
+            );
+        });
+        let broken = server.mock(|when, then| {
+            when.method(GET).path("/broken");
+            then.status(500).body("oops");
+        });
+
+        let client = Client::builder().allow_private_networks(true).build();
+        let result = client
+            .parse(&server.url("/story"))
+            .await
+            .expect("parse should fall back to the original page");
+        page.assert();
+        broken.assert();
+        assert_eq!(result.url, server.url("/story"));
+        assert!(result.redirect_chain.is_empty());
+        assert!(
+            result.content.contains("boats at dawn"),
+            "{}",
+            result.content
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_meta_refresh_drops_credentials_across_origins() {
+        let page_server = MockServer::start();
+        let other_server = MockServer::start();
+        let page = page_server.mock(|when, then| {
+            when.method(GET)
+                .path("/go")
+                .header("authorization", "Bearer secret");
+            then.status(200).body(format!(
+                r#"<html><head><meta http-equiv="refresh" content="0;url={}"></head></html>"#,
+                other_server.url("/article")
+            ));
+        });
+        let article = other_server.mock(|when, then| {
+            when.method(GET)
+                .path("/article")
+                .header_missing("authorization");
+            then.status(200)
+                .header("content-type", "text/html; charset=utf-8")
+                .body(
+                    r#"<html><head><title>Elsewhere</title></head>
+<body><article><p>The article lives on another origin entirely.</p></article></body></html>"#,
+                );
+        });
+
+        let client = Client::builder()
+            .allow_private_networks(true)
+            .header("Authorization", "Bearer secret")
+            .build();
+        let result = client
+            .parse(&page_server.url("/go"))
+            .await
+            .expect("parse should succeed");
+        page.assert();
+        article.assert();
+        assert_eq!(result.url, other_server.url("/article"));
+    }
+
+    #[tokio::test]
+    async fn parse_exposes_redirects_and_status() {
+        let server = MockServer::start();
+        let redirect = server.mock(|when, then| {
+            when.method(GET).path("/short");
+            then.status(301).header("Location", "/go");
+        });
+        let shell = server.mock(|when, then| {
+            when.method(GET).path("/go");
+            then.status(200).body(
+                r#"<html><head><meta http-equiv="refresh" content="0;url=/tracked"></head></html>"#,
+            );
+        });
+        let tracked = server.mock(|when, then| {
+            when.method(GET).path("/tracked");
+            then.status(307).header("Location", "/article");
+        });
+        let article = server.mock(|when, then| {
+            when.method(GET).path("/article");
+            then.status(200)
+                .body("<html><body><article><p>Article body.</p></article></body></html>");
+        });
+
+        let client = Client::builder().allow_private_networks(true).build();
+        let result = client
+            .parse(&server.url("/short"))
+            .await
+            .expect("parse should succeed");
+        redirect.assert();
+        shell.assert();
+        tracked.assert();
+        article.assert();
+
+        assert_eq!(result.url, server.url("/article"));
+        assert_eq!(result.http_status, Some(200));
+        assert_eq!(
+            result.redirects,
+            vec![
+                RedirectHop {
+                    url: server.url("/short"),
+                    status: 301,
+                },
+                RedirectHop {
+                    url: server.url("/tracked"),
+                    status: 307,
+                },
+            ]
+        );
+        assert_eq!(
+            result.redirect_chain,
+            vec![
+                server.url("/short"),
+                server.url("/go"),
+                server.url("/tracked")
+            ]
+        );
+
+        let result = client
+            .parse_html("<html></html>", "https://nocustom.test/")
+            .await
+            .unwrap();
+        assert_eq!(result.http_status, None);
+        assert!(result.redirects.is_empty());
+    }
+
     #[tokio::test]
     async fn dateparser_loose_formats() {
         // Test that loose date formats like "5 Jan 2024" are parsed
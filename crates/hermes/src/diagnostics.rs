@@ -0,0 +1,60 @@
+// ABOUTME: Process-wide diagnostic hook for notable parser events.
+// ABOUTME: Lets embedders (e.g. the FFI layer) observe SSRF blocks, fallbacks, and fetch failures.
+
+use std::fmt;
+use std::panic;
+use std::sync::RwLock;
+
+/// Severity of a diagnostic event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LogLevel {
+    Debug = 0,
+    Info = 1,
+    Warn = 2,
+    Error = 3,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Signature of an installed diagnostic hook.
+pub type LogHook = fn(LogLevel, &str);
+
+static LOG_HOOK: RwLock<Option<LogHook>> = RwLock::new(None);
+
+/// Install (or clear, with `None`) the process-wide diagnostic hook.
+pub fn set_log_hook(hook: Option<LogHook>) {
+    let mut guard = LOG_HOOK.write().unwrap_or_else(|e| e.into_inner());
+    *guard = hook;
+}
+
+/// Emit a diagnostic event to the installed hook, if any.
+///
+/// Panics raised by the hook are caught and discarded so diagnostics can never
+/// abort a parse.
+pub fn emit(level: LogLevel, message: &str) {
+    let hook = *LOG_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(hook) = hook {
+        let _ = panic::catch_unwind(|| hook(level, message));
+    }
+}
+
+/// Emit a warning-level diagnostic event.
+pub fn warn(message: &str) {
+    emit(LogLevel::Warn, message);
+}
+
+/// Emit an info-level diagnostic event.
+pub fn info(message: &str) {
+    emit(LogLevel::Info, message);
+}
@@ -52,6 +52,9 @@ static WHITELIST_ATTRS_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?i)^(src|srcset|sizes|type|href|class|id|alt|xlink:href|width|height)$").unwrap()
 });
 
+/// Class markers for decorative pull quotes (`pullquote`, `pull-quote`, `article__pullquote`, ...).
+static PULL_QUOTE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)pull[-_]?quote").unwrap());
+
 const CLEAN_CONDITIONALLY_TAGS_LIST: &str = "ul,ol,table,div,button,form";
 
 pub fn is_unlikely_candidate(sel: &Selection) -> bool {
@@ -405,6 +408,46 @@ pub fn clean_article(html: &str, title: &str) -> String {
     doc.html().to_string()
 }
 
+/// Remove pull-quote elements (by class marker) from an HTML fragment.
+///
+/// Returns the cleaned fragment and the normalized text of each removed pull quote,
+/// in document order. Plain `<blockquote>` citations without a marker are untouched.
+pub fn strip_pull_quotes(html: &str) -> (String, Vec<String>) {
+    if !PULL_QUOTE_RE.is_match(html) {
+        return (html.to_string(), Vec::new());
+    }
+
+    let doc = Document::from(html);
+    let mut matched: HashSet<NodeId> = HashSet::new();
+    let mut quotes = Vec::new();
+    for node in doc.select("[class]").nodes() {
+        let is_pull_quote = node
+            .attr("class")
+            .map(|c| PULL_QUOTE_RE.is_match(&c))
+            .unwrap_or(false);
+        if !is_pull_quote {
+            continue;
+        }
+        // Nested markers (e.g. figure.pullquote > blockquote.pullquote__text) count once
+        if node.ancestors_it(None).any(|a| matched.contains(&a.id)) {
+            continue;
+        }
+        matched.insert(node.id);
+        let text = normalize_spaces(&node.text());
+        if !text.is_empty() {
+            quotes.push(text);
+        }
+    }
+
+    for node in doc.select("[class]").nodes() {
+        if matched.contains(&node.id) {
+            node.remove_from_parent();
+        }
+    }
+
+    (doc.select("body").inner_html().to_string(), quotes)
+}
+
 #[allow(dead_code)]
 fn convert_divs_to_paragraphs(doc: &Document) -> String {
     let html = doc.html();
@@ -470,6 +513,21 @@ fn escape_attr(s: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_strip_pull_quotes_keeps_plain_blockquotes() {
+        let html = r#"<p>Body</p><figure class="pull-quote"><blockquote class="pull-quote__text">Big words</blockquote></figure><blockquote>Cited source</blockquote>"#;
+        let (cleaned, quotes) = strip_pull_quotes(html);
+        assert_eq!(quotes, vec!["Big words".to_string()]);
+        assert!(!cleaned.contains("Big words"));
+        assert!(cleaned.contains("<blockquote>Cited source</blockquote>"));
+
+        let untouched = "<p>No quotes here</p>";
+        assert_eq!(
+            strip_pull_quotes(untouched),
+            (untouched.to_string(), vec![])
+        );
+    }
+
     #[test]
     fn test_process_h1_tags_removes_when_less_than_three() {
         let html = r#"<div><h1>Look at this!</h1><p>Body</p><h1>Another</h1></div>"#;
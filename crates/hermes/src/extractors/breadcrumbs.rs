@@ -0,0 +1,299 @@
+// ABOUTME: Breadcrumb trail extraction from BreadcrumbList JSON-LD, microdata, or common breadcrumb markup.
+// ABOUTME: Produces ordered (title, url) pairs with absolute http(s) URLs.
+
+use dom_query::{Document, Selection};
+use serde_json::{Map, Value};
+use url::Url;
+
+use crate::extractors::ld_json::{collect_typed_nodes, ld_json_values, text_value};
+use crate::extractors::structured::{
+    collapse_whitespace, find_microdata_item, item_properties, local_type, microdata_value,
+};
+use crate::resource::resolve_http_url;
+
+/// Breadcrumb containers, most specific first. Only the first match is read.
+const BREADCRUMB_SELECTORS: &[&str] = &[
+    "[aria-label='breadcrumb' i]",
+    "[aria-label='breadcrumbs' i]",
+    "ol.breadcrumb",
+    "ul.breadcrumb",
+    ".breadcrumbs",
+    ".breadcrumb",
+    "#breadcrumbs",
+    "#breadcrumb",
+];
+
+/// Characters trimmed from markup crumb titles ("Home ›", "/ News").
+const SEPARATORS: &[char] = &['›', '»', '>', '/', '|', '→', '·'];
+
+/// A crumb before URL resolution.
+type RawCrumb = (String, Option<String>);
+
+/// Extract the page's breadcrumb trail as `(title, url)` pairs, in order.
+///
+/// Sources, first non-empty wins: BreadcrumbList JSON-LD, BreadcrumbList
+/// microdata, then breadcrumb markup (`aria-label="breadcrumb"`,
+/// `.breadcrumb`). URLs are resolved against `page_url`; the last crumb
+/// without a link is the current page and gets `page_url`. Other crumbs
+/// without a usable http(s) URL are dropped.
+pub fn extract_breadcrumbs(doc: &Document, page_url: &str) -> Vec<(String, String)> {
+    let raw = breadcrumbs_from_ld_json(doc)
+        .or_else(|| breadcrumbs_from_microdata(doc))
+        .or_else(|| breadcrumbs_from_markup(doc))
+        .unwrap_or_default();
+
+    let base = Url::parse(page_url).ok();
+    let last = raw.len().saturating_sub(1);
+    raw.into_iter()
+        .enumerate()
+        .filter_map(|(i, (title, href))| {
+            let url = match href.and_then(|h| resolve_http_url(base.as_ref(), &h)) {
+                Some(url) => url,
+                None if i == last => page_url.to_string(),
+                None => return None,
+            };
+            Some((title, url))
+        })
+        .collect()
+}
+
+fn is_breadcrumb_list_type(value: &Value) -> bool {
+    match value {
+        Value::String(s) => local_type(s) == "BreadcrumbList",
+        Value::Array(arr) => arr.iter().any(is_breadcrumb_list_type),
+        _ => false,
+    }
+}
+
+fn breadcrumbs_from_ld_json(doc: &Document) -> Option<Vec<RawCrumb>> {
+    ld_json_values(doc).iter().find_map(|value| {
+        let mut nodes = Vec::new();
+        collect_typed_nodes(value, &is_breadcrumb_list_type, &mut nodes);
+        nodes.into_iter().find_map(crumbs_from_list_node)
+    })
+}
+
+fn crumbs_from_list_node(map: &Map<String, Value>) -> Option<Vec<RawCrumb>> {
+    let items: Vec<&Map<String, Value>> = match map.get("itemListElement")? {
+        Value::Array(arr) => arr.iter().filter_map(Value::as_object).collect(),
+        Value::Object(item) => vec![item],
+        _ => return None,
+    };
+
+    let mut positioned: Vec<(Option<f64>, RawCrumb)> = Vec::new();
+    for item in items {
+        // `item` is a URL string or a Thing with `@id`/`url` and maybe `name`
+        let (name, href) = match item.get("item") {
+            Some(Value::Object(thing)) => (
+                thing.get("name").and_then(text_value),
+                thing
+                    .get("@id")
+                    .or_else(|| thing.get("url"))
+                    .and_then(text_value),
+            ),
+            Some(value) => (None, text_value(value)),
+            None => (None, item.get("url").and_then(text_value)),
+        };
+        let Some(title) = item
+            .get("name")
+            .and_then(text_value)
+            .or(name)
+            .map(|t| collapse_whitespace(&t))
+        else {
+            continue;
+        };
+        let position = item.get("position").and_then(|p| match p {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        });
+        positioned.push((position, (title, href)));
+    }
+    Some(sort_by_position(positioned)).filter(|c| !c.is_empty())
+}
+
+/// Orders crumbs by `position` when every crumb has one; otherwise keeps
+/// document order.
+fn sort_by_position(mut positioned: Vec<(Option<f64>, RawCrumb)>) -> Vec<RawCrumb> {
+    if positioned.iter().all(|(p, _)| p.is_some()) {
+        positioned.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    }
+    positioned.into_iter().map(|(_, crumb)| crumb).collect()
+}
+
+fn breadcrumbs_from_microdata(doc: &Document) -> Option<Vec<RawCrumb>> {
+    let scope = find_microdata_item(doc, |t| t == "BreadcrumbList")?;
+
+    let mut positioned: Vec<(Option<f64>, RawCrumb)> = Vec::new();
+    for (prop, list_item) in item_properties(doc, &scope) {
+        if prop != "itemListElement" {
+            continue;
+        }
+        let (mut title, mut href, mut position) = (None, None, None);
+        for (name, node) in item_properties(doc, &list_item) {
+            match name.as_str() {
+                "name" if title.is_none() => title = microdata_value(&node),
+                "position" => position = microdata_value(&node).and_then(|p| p.parse().ok()),
+                // Schema.org's own examples make `item` an itemscope whose
+                // `name` belongs to it rather than to the ListItem
+                "item" if node.has_attr("itemscope") => {
+                    href = node
+                        .attr("itemid")
+                        .or_else(|| node.attr("href"))
+                        .map(|h| h.to_string());
+                    if title.is_none() {
+                        title = item_properties(doc, &node)
+                            .into_iter()
+                            .find(|(n, _)| n == "name")
+                            .and_then(|(_, n)| microdata_value(&n));
+                    }
+                }
+                "item" => href = microdata_value(&node),
+                _ => {}
+            }
+        }
+        if let Some(title) = title {
+            positioned.push((position, (title, href)));
+        }
+    }
+    Some(sort_by_position(positioned)).filter(|c| !c.is_empty())
+}
+
+fn breadcrumbs_from_markup(doc: &Document) -> Option<Vec<RawCrumb>> {
+    let container = BREADCRUMB_SELECTORS
+        .iter()
+        .map(|sel| doc.select(sel).first())
+        .find(|sel| !sel.is_empty())?;
+
+    let items = container.select("li");
+    let crumbs: Vec<RawCrumb> = if items.is_empty() {
+        container
+            .select("a[href]")
+            .iter()
+            .filter_map(|a| markup_crumb(&a, &a))
+            .collect()
+    } else {
+        items
+            .iter()
+            .filter_map(|li| {
+                let link = li.select("a[href]").first();
+                if link.is_empty() {
+                    markup_crumb(&li, &link)
+                } else {
+                    markup_crumb(&link, &link)
+                }
+            })
+            .collect()
+    };
+    Some(crumbs).filter(|c| !c.is_empty())
+}
+
+/// Crumb titled by `text_el`'s text, linked by `link`'s `href` when present.
+fn markup_crumb(text_el: &Selection, link: &Selection) -> Option<RawCrumb> {
+    let text = collapse_whitespace(&text_el.text());
+    let title = text.trim_matches(|c: char| c.is_whitespace() || SEPARATORS.contains(&c));
+    if title.is_empty() {
+        return None;
+    }
+    let href = link.attr("href").map(|h| h.to_string());
+    Some((title.to_string(), href))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "https://news.test/world/europe/story";
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(t, u)| (t.to_string(), u.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_ld_json_sorted_by_position() {
+        let html = r#"<html><head><script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@graph": [{
+                "@type": "BreadcrumbList",
+                "itemListElement": [
+                    {"@type": "ListItem", "position": 2, "name": "World", "item": "/world"},
+                    {"@type": "ListItem", "position": 1, "name": "Home", "item": "https://news.test/"},
+                    {"@type": "ListItem", "position": "3", "item": {"@id": "/world/europe", "name": "Europe"}},
+                    {"@type": "ListItem", "position": 4, "name": "Story"}
+                ]
+            }]
+        }</script></head><body></body></html>"#;
+
+        assert_eq!(
+            extract_breadcrumbs(&Document::from(html), PAGE),
+            pairs(&[
+                ("Home", "https://news.test/"),
+                ("World", "https://news.test/world"),
+                ("Europe", "https://news.test/world/europe"),
+                ("Story", PAGE),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_microdata() {
+        let html = r#"<html><body>
+<ol itemscope itemtype="https://schema.org/BreadcrumbList">
+  <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+    <a itemprop="item" href="/"><span itemprop="name">Home</span></a>
+    <meta itemprop="position" content="1">
+  </li>
+  <li itemprop="itemListElement" itemscope itemtype="https://schema.org/ListItem">
+    <a itemscope itemtype="https://schema.org/WebPage" itemprop="item" itemid="/world"
+       href="/world"><span itemprop="name">World</span></a>
+    <meta itemprop="position" content="2">
+  </li>
+</ol>
+</body></html>"#;
+
+        assert_eq!(
+            extract_breadcrumbs(&Document::from(html), PAGE),
+            pairs(&[
+                ("Home", "https://news.test/"),
+                ("World", "https://news.test/world"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_markup() {
+        let html = r#"<html><body>
+<nav aria-label="Breadcrumb"><ol>
+  <li><a href="/">Home</a> ›</li>
+  <li><a href="/world">World</a> ›</li>
+  <li><a href="javascript:void(0)">Menu</a></li>
+  <li aria-current="page">Story</li>
+</ol></nav>
+<div class="breadcrumbs"><a href="/ignored">Ignored</a></div>
+</body></html>"#;
+
+        assert_eq!(
+            extract_breadcrumbs(&Document::from(html), PAGE),
+            pairs(&[
+                ("Home", "https://news.test/"),
+                ("World", "https://news.test/world"),
+                ("Story", PAGE),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_breadcrumbs_links_without_list_and_none() {
+        let html = r#"<div class="breadcrumbs"><a href="/a">A</a> / <a href="/a/b">B</a></div>"#;
+        assert_eq!(
+            extract_breadcrumbs(&Document::from(html), PAGE),
+            pairs(&[("A", "https://news.test/a"), ("B", "https://news.test/a/b")])
+        );
+
+        let html = "<html><body><p>No trail here</p></body></html>";
+        assert!(extract_breadcrumbs(&Document::from(html), PAGE).is_empty());
+    }
+}
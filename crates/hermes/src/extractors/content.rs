@@ -139,6 +139,26 @@ pub fn extract_content_raw_first_html(doc: &Document, ce: &ContentExtractor) ->
     None
 }
 
+/// Return the first content selector that matches the document, mirroring the
+/// order used by [`extract_content_html_opts`].
+pub fn matching_content_selector(doc: &Document, ce: &ContentExtractor) -> Option<String> {
+    for spec in &ce.field.selectors {
+        let css = match spec {
+            SelectorSpec::Css(s) => s,
+            SelectorSpec::CssAttr(parts) if !parts.is_empty() => &parts[0],
+            _ => continue,
+        };
+        let matcher = match get_or_compile(css) {
+            Some(m) => m,
+            None => continue,
+        };
+        if doc.select_matcher(&matcher).length() > 0 {
+            return Some(css.clone());
+        }
+    }
+    None
+}
+
 /// Apply domain-specific function-like transforms (ported from Go FunctionTransform)
 /// to an HTML fragment. This is a minimal set covering the noop transforms
 /// present in the Go extractor corpus (e.g., Verge/Vox noscript imgs, Reddit role=img,
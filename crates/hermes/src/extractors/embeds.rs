@@ -0,0 +1,316 @@
+// ABOUTME: Social and video embed detection (tweets, Instagram posts, YouTube, Vimeo, TikTok).
+// ABOUTME: Turns embed iframes and embed blockquotes in the content into typed (platform, id, url) values.
+
+//! Embed extraction.
+//!
+//! Converts embeds in extracted content into [`Embed`] values, since the
+//! sanitizer strips iframes and embed scripts from the content itself.
+//!
+//! Key behaviors:
+//! - Sources are `<iframe src>` players and the blockquotes that embed
+//!   scripts replace (`twitter-tweet`, `instagram-media`, `tiktok-embed`).
+//! - A blockquote is identified by its `cite` / `data-instgrm-permalink`
+//!   attribute or, failing that, its last recognizable link (a tweet's
+//!   status link follows the quoted text).
+//! - URLs are rewritten to the platform's canonical page for the item.
+//! - Each (platform, id) is listed once, in document order.
+//! - [`video_info`] applies the same URL recognition to a page's `video_url`,
+//!   adding a thumbnail where one can be derived from the id.
+
+use dom_query::{Document, Selection};
+use url::Url;
+
+use crate::result::{Embed, EmbedPlatform, VideoInfo};
+
+/// Blockquote classes that embed scripts turn into rich embeds.
+const EMBED_BLOCKQUOTE_CLASSES: &[&str] = &[
+    "twitter-tweet",
+    "twitter-video",
+    "instagram-media",
+    "tiktok-embed",
+];
+
+/// Extract the embeds in `content_html`, in document order.
+pub fn extract_embeds(content_html: &str) -> Vec<Embed> {
+    let doc = Document::from(content_html);
+
+    let mut embeds: Vec<Embed> = Vec::new();
+    for el in doc.select("iframe[src], blockquote").iter() {
+        let embed = if el.is("iframe") {
+            el.attr("src").and_then(|src| classify_url(&src))
+        } else {
+            embed_from_blockquote(&el)
+        };
+        if let Some(embed) = embed {
+            if !embeds
+                .iter()
+                .any(|e| e.platform == embed.platform && e.id == embed.id)
+            {
+                embeds.push(embed);
+            }
+        }
+    }
+    embeds
+}
+
+/// Player details for a YouTube, Vimeo or TikTok video URL.
+///
+/// Only YouTube thumbnails can be derived from the id
+/// (`https://i.ytimg.com/vi/<id>/hqdefault.jpg`); other platforms get `None`.
+pub fn video_info(video_url: &str) -> Option<VideoInfo> {
+    let embed = classify_url(video_url)?;
+    let thumbnail_url = match embed.platform {
+        EmbedPlatform::YouTube => {
+            Some(format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", embed.id))
+        }
+        EmbedPlatform::Vimeo | EmbedPlatform::TikTok => None,
+        EmbedPlatform::Twitter | EmbedPlatform::Instagram => return None,
+    };
+    Some(VideoInfo {
+        platform: embed.platform,
+        id: embed.id,
+        url: embed.url,
+        thumbnail_url,
+    })
+}
+
+/// Embed described by a platform blockquote, or `None` for other blockquotes.
+fn embed_from_blockquote(el: &Selection) -> Option<Embed> {
+    if !EMBED_BLOCKQUOTE_CLASSES.iter().any(|c| el.has_class(c)) {
+        return None;
+    }
+    let attrs = ["cite", "data-instgrm-permalink"]
+        .iter()
+        .filter_map(|name| el.attr(name))
+        .map(|v| v.to_string());
+    let links: Vec<String> = el
+        .select("a[href]")
+        .iter()
+        .filter_map(|a| a.attr("href").map(|h| h.to_string()))
+        .collect();
+    attrs
+        .chain(links.into_iter().rev())
+        .find_map(|url| classify_url(&url))
+}
+
+/// Recognize a player, post, or embed URL of a supported platform.
+fn classify_url(raw: &str) -> Option<Embed> {
+    let raw = raw.trim();
+    // Protocol-relative player URLs are common in embed snippets
+    let url = if raw.starts_with("//") {
+        Url::parse(&format!("https:{}", raw)).ok()?
+    } else {
+        Url::parse(raw).ok()?
+    };
+    let host = url.host_str()?.to_lowercase();
+    let host = ["www.", "m.", "mobile.", "player."]
+        .iter()
+        .find_map(|p| host.strip_prefix(p))
+        .unwrap_or(&host)
+        .to_string();
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+
+    let (platform, id, canonical) = match host.as_str() {
+        "twitter.com" | "x.com" | "platform.twitter.com" => {
+            let (user, id) = match segments.as_slice() {
+                ["embed", "Tweet.html"] => (None, query("id")?),
+                ["i", "web", "status", id, ..] | ["i", "status", id, ..] => (None, id.to_string()),
+                [user, "status", id, ..] => (Some(*user), id.to_string()),
+                _ => return None,
+            };
+            let canonical = match user {
+                Some(user) => format!("https://twitter.com/{}/status/{}", user, id),
+                None => format!("https://twitter.com/i/web/status/{}", id),
+            };
+            (EmbedPlatform::Twitter, id, canonical)
+        }
+        "youtube.com" | "youtube-nocookie.com" | "youtu.be" => {
+            let id = match segments.as_slice() {
+                [id] if host == "youtu.be" => id.to_string(),
+                ["embed", id, ..] | ["shorts", id, ..] | ["live", id, ..] | ["v", id, ..] => {
+                    id.to_string()
+                }
+                ["watch"] => query("v")?,
+                _ => return None,
+            };
+            let canonical = format!("https://www.youtube.com/watch?v={}", id);
+            (EmbedPlatform::YouTube, id, canonical)
+        }
+        "vimeo.com" => {
+            let id = match segments.as_slice() {
+                ["video", id, ..] => id.to_string(),
+                [id] => id.to_string(),
+                _ => return None,
+            };
+            let canonical = format!("https://vimeo.com/{}", id);
+            (EmbedPlatform::Vimeo, id, canonical)
+        }
+        "instagram.com" => {
+            let id = match segments.as_slice() {
+                ["p" | "reel" | "tv", id, ..] => id.to_string(),
+                _ => return None,
+            };
+            let canonical = format!("https://www.instagram.com/p/{}/", id);
+            (EmbedPlatform::Instagram, id, canonical)
+        }
+        "tiktok.com" => {
+            let (user, id) = match segments.as_slice() {
+                [user, "video", id, ..] if user.starts_with('@') => (Some(*user), id.to_string()),
+                ["embed", "v2", id, ..] | ["embed", id, ..] => (None, id.to_string()),
+                _ => return None,
+            };
+            let canonical = match user {
+                Some(user) => format!("https://www.tiktok.com/{}/video/{}", user, id),
+                None => format!("https://www.tiktok.com/embed/v2/{}", id),
+            };
+            (EmbedPlatform::TikTok, id, canonical)
+        }
+        _ => return None,
+    };
+
+    let valid_id = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let numeric = matches!(
+        platform,
+        EmbedPlatform::Twitter | EmbedPlatform::Vimeo | EmbedPlatform::TikTok
+    );
+    if !valid_id || (numeric && !id.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    Some(Embed {
+        platform,
+        id,
+        url: canonical,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embed(platform: EmbedPlatform, id: &str, url: &str) -> Embed {
+        Embed {
+            platform,
+            id: id.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_classify_url() {
+        assert_eq!(
+            classify_url("https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ?start=30"),
+            Some(embed(
+                EmbedPlatform::YouTube,
+                "dQw4w9WgXcQ",
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+            ))
+        );
+        assert_eq!(
+            classify_url("https://youtu.be/dQw4w9WgXcQ").map(|e| e.id),
+            Some("dQw4w9WgXcQ".to_string())
+        );
+        assert_eq!(
+            classify_url("//player.vimeo.com/video/76979871?h=8272103f6e"),
+            Some(embed(
+                EmbedPlatform::Vimeo,
+                "76979871",
+                "https://vimeo.com/76979871"
+            ))
+        );
+        assert_eq!(
+            classify_url("https://x.com/rustlang/status/1234567890?ref_src=twsrc"),
+            Some(embed(
+                EmbedPlatform::Twitter,
+                "1234567890",
+                "https://twitter.com/rustlang/status/1234567890"
+            ))
+        );
+        assert_eq!(
+            classify_url("https://platform.twitter.com/embed/Tweet.html?id=42"),
+            Some(embed(
+                EmbedPlatform::Twitter,
+                "42",
+                "https://twitter.com/i/web/status/42"
+            ))
+        );
+        assert_eq!(
+            classify_url("https://www.tiktok.com/embed/v2/7100000000000000000"),
+            Some(embed(
+                EmbedPlatform::TikTok,
+                "7100000000000000000",
+                "https://www.tiktok.com/embed/v2/7100000000000000000"
+            ))
+        );
+        assert_eq!(classify_url("https://twitter.com/rustlang"), None);
+        assert_eq!(classify_url("https://vimeo.com/channels/staffpicks"), None);
+        assert_eq!(classify_url("https://example.com/embed/abc"), None);
+    }
+
+    #[test]
+    fn test_video_info() {
+        assert_eq!(
+            video_info("https://www.youtube.com/embed/dQw4w9WgXcQ?autoplay=1"),
+            Some(VideoInfo {
+                platform: EmbedPlatform::YouTube,
+                id: "dQw4w9WgXcQ".to_string(),
+                url: "https://www.youtube.com/watch?v=dQw4w9WgXcQ".to_string(),
+                thumbnail_url: Some("https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg".to_string()),
+            })
+        );
+        let vimeo = video_info("https://player.vimeo.com/video/76979871").expect("vimeo");
+        assert_eq!(vimeo.url, "https://vimeo.com/76979871");
+        assert_eq!(vimeo.thumbnail_url, None);
+        assert_eq!(video_info("https://twitter.com/port/status/1"), None);
+        assert_eq!(video_info("https://example.com/video.mp4"), None);
+    }
+
+    #[test]
+    fn test_extract_embeds() {
+        let html = r#"
+<blockquote class="twitter-tweet"><p>Big news today <a href="https://t.co/abc">pic.twitter.com/abc</a></p>
+&mdash; Rust (@rustlang) <a href="https://twitter.com/rustlang/status/1234567890?ref_src=twsrc">May 1, 2024</a></blockquote>
+<iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ"></iframe>
+<blockquote class="instagram-media" data-instgrm-permalink="https://www.instagram.com/reel/C1a2B3c4D5e/?utm_source=ig_embed"></blockquote>
+<blockquote class="tiktok-embed" cite="https://www.tiktok.com/@chef/video/7100000000000000000" data-video-id="7100000000000000000"></blockquote>
+<blockquote><p>A plain quote with <a href="https://twitter.com/someone/status/99">a tweet link</a>.</p></blockquote>
+<iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ?autoplay=1"></iframe>
+<iframe src="https://maps.example.com/embed?pb=1"></iframe>"#;
+
+        assert_eq!(
+            extract_embeds(html),
+            vec![
+                embed(
+                    EmbedPlatform::Twitter,
+                    "1234567890",
+                    "https://twitter.com/rustlang/status/1234567890"
+                ),
+                embed(
+                    EmbedPlatform::YouTube,
+                    "dQw4w9WgXcQ",
+                    "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+                ),
+                embed(
+                    EmbedPlatform::Instagram,
+                    "C1a2B3c4D5e",
+                    "https://www.instagram.com/p/C1a2B3c4D5e/"
+                ),
+                embed(
+                    EmbedPlatform::TikTok,
+                    "7100000000000000000",
+                    "https://www.tiktok.com/@chef/video/7100000000000000000"
+                ),
+            ]
+        );
+    }
+}
@@ -87,6 +87,46 @@ pub fn normalize_lang(value: &str) -> String {
         .to_string()
 }
 
+/// Normalizes a locale string to a hyphenated BCP 47-style tag.
+///
+/// Underscores become hyphens, the primary subtag is lowercased via
+/// [`normalize_lang`], and two-letter region subtags are uppercased.
+/// For example: "en_US" -> "en-US", "FR" -> "fr", "zh_hant_tw" -> "zh-hant-TW".
+pub fn normalize_locale(value: &str) -> String {
+    let primary = normalize_lang(value);
+    if primary.is_empty() {
+        return primary;
+    }
+    let mut parts = vec![primary];
+    for sub in value.trim().split(['-', '_']).skip(1) {
+        if sub.is_empty() {
+            continue;
+        }
+        if sub.len() == 2 {
+            parts.push(sub.to_ascii_uppercase());
+        } else {
+            parts.push(sub.to_ascii_lowercase());
+        }
+    }
+    parts.join("-")
+}
+
+/// Extracts the trimmed, non-empty `content` attribute of every matching meta tag.
+pub fn extract_meta_contents_all(doc: &Document, selector: &str) -> Vec<String> {
+    let matcher = match get_or_compile(selector) {
+        Some(m) => m,
+        None => return Vec::new(),
+    };
+    doc.select_matcher(&matcher)
+        .iter()
+        .filter_map(|el| {
+            let content = el.attr("content")?;
+            let trimmed = content.trim();
+            (!trimmed.is_empty()).then(|| trimmed.to_string())
+        })
+        .collect()
+}
+
 /// Extracts the `content` attribute from the first matching meta tag.
 ///
 /// Parses the selector (which should target a `<meta>` element), then
@@ -340,6 +380,14 @@ mod tests {
         assert_eq!(normalize_lang("  de_AT  "), "de");
     }
 
+    #[test]
+    fn test_normalize_locale_region() {
+        assert_eq!(normalize_locale("en_US"), "en-US");
+        assert_eq!(normalize_locale(" PT-br "), "pt-BR");
+        assert_eq!(normalize_locale("de"), "de");
+        assert_eq!(normalize_locale(""), "");
+    }
+
     #[test]
     fn test_normalize_lang_empty() {
         assert_eq!(normalize_lang(""), "");
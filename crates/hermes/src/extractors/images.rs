@@ -0,0 +1,152 @@
+// ABOUTME: Content image extraction with alt text, figure captions, and dimensions.
+// ABOUTME: Resolves each <img> to an absolute http(s) URL, falling back to lazy-load attributes and srcset.
+
+//! Content image extraction.
+//!
+//! Converts each `<img>` in extracted content into an [`ImageInfo`] value.
+//!
+//! Key behaviors:
+//! - The URL is `src`, else `data-src`, else the widest `srcset` candidate;
+//!   `data:` placeholders are skipped. URLs are resolved against the page URL.
+//! - The caption is the `<figcaption>` of the nearest enclosing `<figure>`.
+//! - `width` and `height` come from the attributes ("640" or "640px").
+//! - Each URL is listed once, at its first occurrence; later copies only fill
+//!   in missing alt text, caption, or dimensions.
+
+use dom_query::{Document, NodeRef, Selection};
+use url::Url;
+
+use crate::extractors::structured::collapse_whitespace;
+use crate::resource::resolve_http_url;
+use crate::result::ImageInfo;
+
+/// Extract the images in `content_html`, in document order, resolving their
+/// URLs against `page_url`.
+pub fn extract_images(content_html: &str, page_url: &str) -> Vec<ImageInfo> {
+    let base = Url::parse(page_url).ok();
+    let doc = Document::from(content_html);
+
+    let mut images: Vec<ImageInfo> = Vec::new();
+    for node in doc.select("img").nodes() {
+        let Some(url) = image_url(node, base.as_ref()) else {
+            continue;
+        };
+        let image = ImageInfo {
+            url,
+            alt: non_empty(node.attr("alt").as_deref()),
+            caption: figure_caption(node),
+            width: dimension(node.attr("width").as_deref()),
+            height: dimension(node.attr("height").as_deref()),
+        };
+        match images.iter_mut().find(|i| i.url == image.url) {
+            Some(existing) => {
+                existing.alt = existing.alt.take().or(image.alt);
+                existing.caption = existing.caption.take().or(image.caption);
+                existing.width = existing.width.or(image.width);
+                existing.height = existing.height.or(image.height);
+            }
+            None => images.push(image),
+        }
+    }
+    images
+}
+
+/// Absolute http(s) URL of an `<img>` from `src`, `data-src`, or `srcset`.
+fn image_url(node: &NodeRef, base: Option<&Url>) -> Option<String> {
+    let usable = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty() && !value.starts_with("data:"))
+            .then(|| resolve_http_url(base, value))
+            .flatten()
+    };
+    ["src", "data-src"]
+        .iter()
+        .filter_map(|name| node.attr(name))
+        .find_map(|value| usable(&value))
+        .or_else(|| {
+            let srcset = node.attr("srcset")?;
+            widest_srcset_candidate(&srcset).and_then(usable)
+        })
+}
+
+/// URL of the `srcset` candidate with the largest `w` (or `x`) descriptor;
+/// candidates without a descriptor count as `1x`.
+fn widest_srcset_candidate(srcset: &str) -> Option<&str> {
+    srcset
+        .split(',')
+        .filter_map(|candidate| {
+            let mut parts = candidate.split_whitespace();
+            let url = parts.next()?;
+            let size = parts
+                .next()
+                .and_then(|d| d.trim_end_matches(['w', 'x']).parse::<f64>().ok())
+                .unwrap_or(1.0);
+            Some((url, size))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(url, _)| url)
+}
+
+/// Text of the `<figcaption>` in the nearest `<figure>` around `node`.
+fn figure_caption(node: &NodeRef) -> Option<String> {
+    let figure = node.ancestors_it(None).find(|n| {
+        n.node_name()
+            .is_some_and(|name| name.eq_ignore_ascii_case("figure"))
+    })?;
+    let caption = Selection::from(figure).select("figcaption").text();
+    non_empty(Some(&caption))
+}
+
+/// Pixel size from a `width`/`height` attribute; percentages and zero are ignored.
+fn dimension(value: Option<&str>) -> Option<u32> {
+    value?
+        .trim()
+        .trim_end_matches("px")
+        .parse::<u32>()
+        .ok()
+        .filter(|&px| px > 0)
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    let value = collapse_whitespace(value?);
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_images() {
+        let html = r#"<figure>
+  <img src="/img/harbor.jpg" alt="The  harbor" width="1200" height="800px">
+  <figcaption>Boats return to the
+  harbor.</figcaption>
+</figure>
+<p><img src="data:image/gif;base64,R0lG" data-src="https://cdn.test/lazy.jpg" width="50%"></p>
+<p><img srcset="/s.jpg 480w, /l.jpg 1080w, /m.jpg 800w"></p>
+<p><img src="javascript:void(0)"><img src="/img/harbor.jpg" width="600"></p>"#;
+
+        let images = extract_images(html, "https://example.com/news/story");
+        assert_eq!(
+            images,
+            vec![
+                ImageInfo {
+                    url: "https://example.com/img/harbor.jpg".to_string(),
+                    alt: Some("The harbor".to_string()),
+                    caption: Some("Boats return to the harbor.".to_string()),
+                    width: Some(1200),
+                    height: Some(800),
+                },
+                ImageInfo {
+                    url: "https://cdn.test/lazy.jpg".to_string(),
+                    ..Default::default()
+                },
+                ImageInfo {
+                    url: "https://example.com/l.jpg".to_string(),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+}
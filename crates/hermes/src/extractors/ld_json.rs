@@ -0,0 +1,292 @@
+// ABOUTME: JSON-LD (schema.org) article metadata extraction from `<script type="application/ld+json">`.
+// ABOUTME: Pulls headline, authors, dates, publisher, image and articleBody from Article-like nodes.
+
+use dom_query::Document;
+use serde_json::{Map, Value};
+
+/// schema.org types treated as articles.
+const ARTICLE_TYPES: &[&str] = &[
+    "Article",
+    "NewsArticle",
+    "BlogPosting",
+    "ReportageNewsArticle",
+    "AnalysisNewsArticle",
+    "OpinionNewsArticle",
+    "BackgroundNewsArticle",
+    "ReviewNewsArticle",
+    "LiveBlogPosting",
+    "TechArticle",
+    "ScholarlyArticle",
+    "Report",
+];
+
+/// Keys that commonly hold nested nodes in JSON-LD graphs.
+const GRAPH_KEYS: &[&str] = &[
+    "@graph",
+    "graph",
+    "mainEntity",
+    "mainEntityOfPage",
+    "itemListElement",
+];
+
+/// Article metadata gathered from JSON-LD (or RDFa, see `extractors::rdfa`).
+/// When a page has several article nodes, each field comes from the first
+/// node that provides it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LdArticle {
+    pub headline: Option<String>,
+    pub authors: Vec<String>,
+    /// Raw `datePublished` value.
+    pub date_published: Option<String>,
+    /// Raw `dateModified` value.
+    pub date_modified: Option<String>,
+    pub publisher: Option<String>,
+    pub image: Option<String>,
+    pub body: Option<String>,
+}
+
+impl LdArticle {
+    /// Authors joined for display, e.g. "Jane Doe, John Roe".
+    pub fn author(&self) -> Option<String> {
+        (!self.authors.is_empty()).then(|| self.authors.join(", "))
+    }
+
+    /// Fills fields still missing on `self` from `other`.
+    pub(crate) fn merge(&mut self, other: LdArticle) {
+        self.headline = self.headline.take().or(other.headline);
+        if self.authors.is_empty() {
+            self.authors = other.authors;
+        }
+        self.date_published = self.date_published.take().or(other.date_published);
+        self.date_modified = self.date_modified.take().or(other.date_modified);
+        self.publisher = self.publisher.take().or(other.publisher);
+        self.image = self.image.take().or(other.image);
+        self.body = self.body.take().or(other.body);
+    }
+}
+
+/// Extract article metadata from every JSON-LD script in the document.
+///
+/// Returns `None` when no Article-like node is found. Scripts that fail to
+/// parse are skipped.
+pub fn extract_ld_article(doc: &Document) -> Option<LdArticle> {
+    let mut article: Option<LdArticle> = None;
+    for value in ld_json_values(doc) {
+        let mut nodes = Vec::new();
+        collect_typed_nodes(&value, &is_article_type, &mut nodes);
+        for node in nodes {
+            article
+                .get_or_insert_with(LdArticle::default)
+                .merge(article_from_node(node));
+        }
+    }
+    article
+}
+
+/// Parsed contents of every JSON-LD script in the document, skipping invalid ones.
+pub(crate) fn ld_json_values(doc: &Document) -> Vec<Value> {
+    doc.select("script[type='application/ld+json']")
+        .iter()
+        .filter_map(|script| serde_json::from_str::<Value>(&script.text()).ok())
+        .collect()
+}
+
+/// Depth-first collection of objects whose `@type` satisfies `is_type`, in document order.
+pub(crate) fn collect_typed_nodes<'a>(
+    value: &'a Value,
+    is_type: &dyn Fn(&Value) -> bool,
+    out: &mut Vec<&'a Map<String, Value>>,
+) {
+    match value {
+        Value::Object(map) => {
+            if map.get("@type").is_some_and(is_type) {
+                out.push(map);
+            }
+            for key in GRAPH_KEYS {
+                if let Some(v) = map.get(*key) {
+                    collect_typed_nodes(v, is_type, out);
+                }
+            }
+            for (key, v) in map {
+                if !GRAPH_KEYS.contains(&key.as_str()) {
+                    collect_typed_nodes(v, is_type, out);
+                }
+            }
+        }
+        Value::Array(arr) => {
+            for v in arr {
+                collect_typed_nodes(v, is_type, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn article_from_node(map: &Map<String, Value>) -> LdArticle {
+    LdArticle {
+        headline: map
+            .get("headline")
+            .or_else(|| map.get("name"))
+            .and_then(text_value),
+        authors: map.get("author").map(names).unwrap_or_default(),
+        date_published: map.get("datePublished").and_then(text_value),
+        date_modified: map.get("dateModified").and_then(text_value),
+        publisher: map
+            .get("publisher")
+            .and_then(|p| names(p).into_iter().next()),
+        image: map.get("image").and_then(image_url),
+        body: map.get("articleBody").and_then(article_body),
+    }
+}
+
+fn is_article_type(value: &Value) -> bool {
+    match value {
+        Value::String(s) => is_article_type_name(s),
+        Value::Array(arr) => arr.iter().any(is_article_type),
+        _ => false,
+    }
+}
+
+/// True for schema.org Article types (case-insensitive, unprefixed).
+pub(crate) fn is_article_type_name(name: &str) -> bool {
+    ARTICLE_TYPES.iter().any(|t| name.eq_ignore_ascii_case(t))
+}
+
+/// Trimmed, non-empty string value.
+pub(crate) fn text_value(value: &Value) -> Option<String> {
+    let s = value.as_str()?.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/// Names from a Person/Organization reference: a string, an object with
+/// `name`, or an array of either. Duplicates are dropped.
+fn names(value: &Value) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let mut push = |name: Option<String>| {
+        if let Some(name) = name {
+            if !out.contains(&name) {
+                out.push(name);
+            }
+        }
+    };
+    match value {
+        Value::Array(arr) => {
+            for v in arr {
+                match v {
+                    Value::Object(map) => push(map.get("name").and_then(text_value)),
+                    _ => push(text_value(v)),
+                }
+            }
+        }
+        Value::Object(map) => push(map.get("name").and_then(text_value)),
+        _ => push(text_value(value)),
+    }
+    out
+}
+
+/// First URL from an image reference: a string, an ImageObject (`url` or
+/// `contentUrl`), or an array of either.
+pub(crate) fn image_url(value: &Value) -> Option<String> {
+    match value {
+        Value::Array(arr) => arr.iter().find_map(image_url),
+        Value::Object(map) => map
+            .get("url")
+            .or_else(|| map.get("contentUrl"))
+            .and_then(text_value),
+        _ => text_value(value),
+    }
+}
+
+/// `articleBody` as a string, or an array of paragraphs joined by blank lines.
+fn article_body(value: &Value) -> Option<String> {
+    match value {
+        Value::Array(arr) => {
+            let joined = arr
+                .iter()
+                .filter_map(|v| v.as_str())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            (!joined.trim().is_empty()).then_some(joined)
+        }
+        _ => value
+            .as_str()
+            .filter(|s| !s.trim().is_empty())
+            .map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(scripts: &[&str]) -> Document {
+        let body: String = scripts
+            .iter()
+            .map(|s| format!(r#"<script type="application/ld+json">{}</script>"#, s))
+            .collect();
+        Document::from(format!("<html><head>{}</head><body></body></html>", body).as_str())
+    }
+
+    #[test]
+    fn test_extracts_article_fields() {
+        let d = doc(&[r#"{
+            "@context": "https://schema.org",
+            "@type": "NewsArticle",
+            "headline": " Big News ",
+            "author": [{"@type": "Person", "name": "Jane Doe"}, "John Roe", {"name": "Jane Doe"}],
+            "datePublished": "2024-03-01T10:00:00Z",
+            "dateModified": "2024-03-02T12:00:00Z",
+            "publisher": {"@type": "Organization", "name": "Daily Planet"},
+            "image": [{"@type": "ImageObject", "url": "https://example.com/lead.jpg"}],
+            "articleBody": ["First.", "Second."]
+        }"#]);
+
+        let article = extract_ld_article(&d).unwrap();
+        assert_eq!(article.headline.as_deref(), Some("Big News"));
+        assert_eq!(article.authors, vec!["Jane Doe", "John Roe"]);
+        assert_eq!(article.author().as_deref(), Some("Jane Doe, John Roe"));
+        assert_eq!(
+            article.date_published.as_deref(),
+            Some("2024-03-01T10:00:00Z")
+        );
+        assert_eq!(
+            article.date_modified.as_deref(),
+            Some("2024-03-02T12:00:00Z")
+        );
+        assert_eq!(article.publisher.as_deref(), Some("Daily Planet"));
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://example.com/lead.jpg")
+        );
+        assert_eq!(article.body.as_deref(), Some("First.\n\nSecond."));
+    }
+
+    #[test]
+    fn test_merges_graph_nodes_and_scripts() {
+        let d = doc(&[
+            "not json",
+            r#"{"@type": "WebSite", "name": "Ignored"}"#,
+            r#"{"@graph": [
+                {"@type": "WebPage", "name": "Page"},
+                {"@type": ["Article", "BlogPosting"], "headline": "Graph Post", "author": "Ann"}
+            ]}"#,
+            r#"{"@type": "Article", "headline": "Second", "image": "https://example.com/i.png",
+                "author": "Bob"}"#,
+        ]);
+
+        let article = extract_ld_article(&d).unwrap();
+        assert_eq!(article.headline.as_deref(), Some("Graph Post"));
+        assert_eq!(article.authors, vec!["Ann"]);
+        assert_eq!(article.image.as_deref(), Some("https://example.com/i.png"));
+        assert_eq!(article.body, None);
+    }
+
+    #[test]
+    fn test_no_article_nodes() {
+        assert_eq!(extract_ld_article(&doc(&[])), None);
+        assert_eq!(
+            extract_ld_article(&doc(&[r#"{"@type": "Organization", "name": "Acme"}"#])),
+            None
+        );
+    }
+}
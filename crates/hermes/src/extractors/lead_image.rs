@@ -0,0 +1,213 @@
+// ABOUTME: Lead image scoring over the page's <img> elements when no declared image is usable.
+// ABOUTME: Weighs URL hints, alt text, figure context, dimensions, aspect ratio, and position in the page.
+
+//! Lead image scoring.
+//!
+//! Key behaviors:
+//! - URLs hinting at content photos ("upload", "wp-content", "photo") score
+//!   up; URLs hinting at chrome ("logo", "sprite", "avatar", "icon") score
+//!   down, as do GIFs.
+//! - Images inside a `<figure>`, next to a `<figcaption>`, with alt text, or
+//!   inside the extracted content score up.
+//! - Tiny images (either side at most 50px) and banner-like aspect ratios
+//!   (wider than 3:1 or taller than 1:3) score down; large areas score up.
+//! - Earlier images score higher than later ones.
+//! - Only a positive best score is returned.
+
+use std::collections::HashSet;
+
+use dom_query::{Document, NodeRef, Selection};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use url::Url;
+
+static POSITIVE_URL_HINTS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)upload|wp-content|large|photo|wp-image").unwrap());
+
+static NEGATIVE_URL_HINTS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)logo|sprite|avatar|icon|spacer|blank|pixel|placeholder|throbber|spinner|loader|loading|gradient|badge|rating|share|social|facebook|twitter|advert|promo|wp-includes",
+    )
+    .unwrap()
+});
+
+/// Chrome hints for declared images; narrower than [`NEGATIVE_URL_HINTS`]
+/// since `og:image` files are often named "social-card" or "share".
+static UNLIKELY_DECLARED_IMAGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)logo|sprite|avatar|icon|spacer|blank|pixel|placeholder|default[-_]?(image|img|thumb|og)").unwrap()
+});
+
+static PHOTO_CLASS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)figure|photo|image|caption").unwrap());
+
+/// True when a declared image URL (such as `og:image`) looks like site chrome
+/// rather than an article image.
+pub fn is_unlikely_lead_image(url: &str) -> bool {
+    UNLIKELY_DECLARED_IMAGE.is_match(url)
+}
+
+/// The `src` of the best-scoring `<img>` in `doc`, if any scores above zero.
+///
+/// `content_html` is the extracted content; images that also appear there
+/// are preferred. Sources are compared after resolving against `base_url`.
+pub fn score_lead_image(doc: &Document, content_html: &str, base_url: &str) -> Option<String> {
+    let base = Url::parse(base_url).ok();
+    let resolve = |src: &str| match &base {
+        Some(base) => base
+            .join(src)
+            .map(|u| u.to_string())
+            .unwrap_or_else(|_| src.to_string()),
+        None => src.to_string(),
+    };
+    let content_doc = Document::from(content_html);
+    let content_srcs: HashSet<String> = content_doc
+        .select("img[src]")
+        .iter()
+        .filter_map(|img| img.attr("src").map(|s| resolve(s.trim())))
+        .collect();
+
+    let images: Vec<NodeRef> = doc.select("img[src]").nodes().to_vec();
+    let count = images.len() as i32;
+    let mut best: Option<(i32, String)> = None;
+    for (index, img) in images.iter().enumerate() {
+        let Some(src) = img.attr("src").map(|s| s.trim().to_string()) else {
+            continue;
+        };
+        if src.is_empty() || src.starts_with("data:") {
+            continue;
+        }
+
+        let mut score = url_score(&src);
+        if img.attr("alt").is_some_and(|a| !a.trim().is_empty()) {
+            score += 5;
+        }
+        score += context_score(img);
+        score += dimension_score(img);
+        if content_srcs.contains(&resolve(&src)) {
+            score += 25;
+        }
+        // Earlier images are more likely to lead the article
+        score += count / 2 - index as i32;
+
+        if best.as_ref().is_none_or(|(top, _)| score > *top) {
+            best = Some((score, src));
+        }
+    }
+    best.filter(|(score, _)| *score > 0).map(|(_, src)| src)
+}
+
+fn url_score(src: &str) -> i32 {
+    let mut score = 0;
+    if POSITIVE_URL_HINTS.is_match(src) {
+        score += 20;
+    }
+    if NEGATIVE_URL_HINTS.is_match(src) {
+        score -= 20;
+    }
+    let path = src.split(['?', '#']).next().unwrap_or(src).to_lowercase();
+    if path.ends_with(".gif") {
+        score -= 10;
+    } else if path.ends_with(".jpg") || path.ends_with(".jpeg") || path.ends_with(".webp") {
+        score += 10;
+    }
+    score
+}
+
+/// Bonus for figure and photo containers around the image and a caption beside it.
+fn context_score(img: &NodeRef) -> i32 {
+    let mut score = 0;
+    let parents: Vec<NodeRef> = img.ancestors_it(Some(2)).collect();
+    for parent in &parents {
+        if parent
+            .node_name()
+            .is_some_and(|n| n.eq_ignore_ascii_case("figure"))
+        {
+            score += 25;
+        }
+        if parent
+            .attr("class")
+            .is_some_and(|c| PHOTO_CLASS.is_match(&c))
+        {
+            score += 15;
+        }
+    }
+    if let Some(parent) = parents.first() {
+        if !Selection::from(*parent).select("figcaption").is_empty() {
+            score += 25;
+        }
+    }
+    score
+}
+
+fn dimension_score(img: &NodeRef) -> i32 {
+    let px =
+        |name: &str| -> Option<u32> { img.attr(name)?.trim().trim_end_matches("px").parse().ok() };
+    let (width, height) = (px("width"), px("height"));
+    let mut score = 0;
+    if width.is_some_and(|w| w <= 50) || height.is_some_and(|h| h <= 50) {
+        score -= 50;
+    }
+    if let (Some(w), Some(h)) = (width, height) {
+        if w > 0 && h > 0 {
+            let area = w * h;
+            if area < 5000 {
+                score -= 100;
+            } else {
+                score += (area / 1000).min(100) as i32;
+            }
+            let ratio = w as f64 / h as f64;
+            if !(1.0 / 3.0..=3.0).contains(&ratio) {
+                score -= 30;
+            }
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_lead_image_prefers_representative_image() {
+        let doc = Document::from(
+            r#"<html><body>
+<header><img src="/static/site-logo.png" width="200" height="60"></header>
+<img src="/ads/banner.jpg" width="970" height="90">
+<article>
+  <p>Intro</p>
+  <figure><img src="/wp-content/uploads/harbor.jpg" alt="Harbor" width="1200" height="800">
+  <figcaption>The harbor.</figcaption></figure>
+  <img src="/img/author-avatar.jpg" width="48" height="48">
+</article>
+</body></html>"#,
+        );
+        let content =
+            r#"<figure><img src="https://example.com/wp-content/uploads/harbor.jpg"></figure>"#;
+        assert_eq!(
+            score_lead_image(&doc, content, "https://example.com/news/story").as_deref(),
+            Some("/wp-content/uploads/harbor.jpg")
+        );
+    }
+
+    #[test]
+    fn test_score_lead_image_rejects_only_chrome() {
+        let doc = Document::from(
+            r#"<html><body><img src="/sprite.gif" width="16" height="16">
+<img src="data:image/gif;base64,R0lGODlh"></body></html>"#,
+        );
+        assert_eq!(score_lead_image(&doc, "", "https://example.com/"), None);
+        assert!(is_unlikely_lead_image(
+            "https://example.com/assets/logo.png"
+        ));
+        assert!(is_unlikely_lead_image(
+            "https://example.com/img/default-og.jpg"
+        ));
+        assert!(!is_unlikely_lead_image(
+            "https://example.com/uploads/social-card.jpg"
+        ));
+        assert!(!is_unlikely_lead_image(
+            "https://example.org/sites/default/files/harbor.jpg"
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+// ABOUTME: Outbound link extraction from the article content region.
+// ABOUTME: Resolves each <a href> to an absolute http(s) URL with its anchor text, rel tokens and external flag.
+
+//! Content link extraction.
+//!
+//! Converts each `<a href>` in extracted content into a [`LinkInfo`] value.
+//!
+//! Key behaviors:
+//! - Hrefs are resolved against the page URL; non-http(s) links (`mailto:`,
+//!   `javascript:`) are skipped.
+//! - Links back to the page itself, such as `#footnote` anchors, are skipped.
+//! - Each URL is listed once, at its first occurrence; later anchors only fill
+//!   in missing text.
+//! - Content cleaning drops `rel`, so it is read from the matching link in
+//!   the source page when the content copy has none.
+//! - A link is external when its host differs from the page host, ignoring a
+//!   leading `www.`.
+
+use std::collections::HashMap;
+
+use dom_query::Document;
+use url::Url;
+
+use crate::extractors::structured::collapse_whitespace;
+use crate::resource::resolve_http_url;
+use crate::result::LinkInfo;
+
+/// Extract the links in `content_html`, in document order, resolving them
+/// against `page_url`. `source` is the page the content was extracted from.
+pub fn extract_links(content_html: &str, source: &Document, page_url: &str) -> Vec<LinkInfo> {
+    let base = Url::parse(page_url).ok();
+    let page_host = base.as_ref().and_then(|u| u.host_str()).map(bare_host);
+    let doc = Document::from(content_html);
+
+    // rel values by resolved URL, first occurrence wins
+    let mut source_rels: HashMap<String, String> = HashMap::new();
+    for a in source.select("a[href][rel]").iter() {
+        if let (Some(href), Some(rel)) = (a.attr("href"), a.attr("rel")) {
+            if let Some(url) = resolve_http_url(base.as_ref(), &href) {
+                source_rels.entry(url).or_insert_with(|| rel.to_string());
+            }
+        }
+    }
+
+    let mut links: Vec<LinkInfo> = Vec::new();
+    for a in doc.select("a[href]").iter() {
+        let Some(href) = a.attr("href") else {
+            continue;
+        };
+        let Some(url) = resolve_http_url(base.as_ref(), &href) else {
+            continue;
+        };
+        let Ok(parsed) = Url::parse(&url) else {
+            continue;
+        };
+        if base.as_ref().is_some_and(|b| same_page(b, &parsed)) {
+            continue;
+        }
+
+        let text = collapse_whitespace(&a.text());
+        if let Some(existing) = links.iter_mut().find(|l| l.url == url) {
+            if existing.text.is_empty() {
+                existing.text = text;
+            }
+            continue;
+        }
+        let rel = a
+            .attr("rel")
+            .map(|r| r.to_string())
+            .or_else(|| source_rels.get(&url).cloned())
+            .map(|r| r.split_whitespace().map(str::to_lowercase).collect())
+            .unwrap_or_default();
+        let external = parsed.host_str().map(bare_host) != page_host;
+        links.push(LinkInfo {
+            url,
+            text,
+            rel,
+            external,
+        });
+    }
+    links
+}
+
+/// Lowercased host without a leading `www.`.
+fn bare_host(host: &str) -> String {
+    let host = host.to_lowercase();
+    host.strip_prefix("www.")
+        .map(str::to_string)
+        .unwrap_or(host)
+}
+
+/// True when `url` points at `page` itself, ignoring the fragment.
+fn same_page(page: &Url, url: &Url) -> bool {
+    let mut page = page.clone();
+    let mut url = url.clone();
+    page.set_fragment(None);
+    url.set_fragment(None);
+    page == url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_links() {
+        let html = r##"<p>See <a href="/news/other" rel="Bookmark">another   story</a>,
+<a href="https://elsewhere.test/report" rel="nofollow noopener">the report</a>,
+<a href="https://example.com/news/other"><img src="x.png"></a>
+<a href="#note-1">1</a>, <a href="mailto:desk@example.com">email us</a> and
+<a href="http://blog.example.com/post">the blog</a>.</p>"##;
+
+        let links = extract_links(
+            html,
+            &Document::from(html),
+            "https://www.example.com/news/story",
+        );
+        assert_eq!(
+            links,
+            vec![
+                LinkInfo {
+                    url: "https://www.example.com/news/other".to_string(),
+                    text: "another story".to_string(),
+                    rel: vec!["bookmark".to_string()],
+                    external: false,
+                },
+                LinkInfo {
+                    url: "https://elsewhere.test/report".to_string(),
+                    text: "the report".to_string(),
+                    rel: vec!["nofollow".to_string(), "noopener".to_string()],
+                    external: true,
+                },
+                LinkInfo {
+                    url: "https://example.com/news/other".to_string(),
+                    text: String::new(),
+                    rel: vec![],
+                    external: false,
+                },
+                LinkInfo {
+                    url: "http://blog.example.com/post".to_string(),
+                    text: "the blog".to_string(),
+                    rel: vec![],
+                    external: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_links_dedupes_and_fills_missing_text() {
+        let html =
+            r#"<a href="/a"><img src="a.png"></a> <a href="/a">Read A</a> <a href="/a">again</a>"#;
+        let links = extract_links(html, &Document::from(html), "https://example.com/");
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].text, "Read A");
+    }
+
+    #[test]
+    fn test_extract_links_reads_rel_from_source() {
+        let source = Document::from(r#"<p><a href="/b" rel="sponsored">B</a></p>"#);
+        let links = extract_links(r#"<a href="/b">B</a>"#, &source, "https://example.com/");
+        assert_eq!(links[0].rel, vec!["sponsored"]);
+    }
+}
@@ -8,13 +8,35 @@
 //! and structured data (JSON-LD, OpenGraph, etc.) handling.
 //!
 //! Submodules:
+//! - `breadcrumbs`: Breadcrumb trails from BreadcrumbList data or breadcrumb markup.
 //! - `compiled`: Pre-compiled CSS selector cache.
 //! - `custom`: Custom site-specific extractors with configurable selectors.
+//! - `embeds`: Typed tweet, Instagram, TikTok and video player embeds.
+//! - `images`: Content images with alt text, captions and dimensions.
+//! - `ld_json`: JSON-LD (schema.org) article metadata.
+//! - `lead_image`: Scoring of page images to pick a representative lead image.
+//! - `links`: Outbound links in the extracted content.
+//! - `oembed`: oEmbed endpoint discovery and response parsing.
+//! - `rdfa`: RDFa / RDFa Lite article metadata.
 //! - `select`: Selector-based field extraction utilities.
+//! - `structured`: Typed schema.org items (Recipe, Product, Event) from JSON-LD, microdata or OpenGraph.
+//! - `tables`: Structured extraction of table headers and rows.
+//! - `title`: Cleaning of raw page titles (site name and section labels).
 
+pub mod breadcrumbs;
 pub mod compiled;
 pub mod content;
 pub mod custom;
+pub mod embeds;
 pub mod fields;
+pub mod images;
+pub mod ld_json;
+pub mod lead_image;
+pub mod links;
 pub mod loader;
+pub mod oembed;
+pub mod rdfa;
 pub mod select;
+pub mod structured;
+pub mod tables;
+pub mod title;
@@ -0,0 +1,116 @@
+// ABOUTME: oEmbed discovery from <link rel="alternate" type="application/json+oembed"> and response parsing.
+// ABOUTME: Reads the provider's JSON response into an OEmbed value (type, title, author, thumbnail, html).
+
+//! oEmbed support.
+//!
+//! Key behaviors:
+//! - Discovery reads the first `<link rel="alternate">` whose type is
+//!   `application/json+oembed` (or the older `text/json+oembed`); XML
+//!   endpoints are ignored.
+//! - Responses are parsed leniently: numbers given as strings are accepted,
+//!   and unknown fields are ignored.
+//! - A response needs a `type` to be accepted.
+
+use dom_query::Document;
+use serde_json::Value;
+use url::Url;
+
+use crate::resource::resolve_http_url;
+use crate::result::OEmbed;
+
+/// MIME types of JSON oEmbed endpoints.
+const OEMBED_JSON_TYPES: &[&str] = &["application/json+oembed", "text/json+oembed"];
+
+/// Absolute URL of the page's JSON oEmbed endpoint, if advertised.
+pub fn extract_oembed_url(doc: &Document, page_url: &str) -> Option<String> {
+    let base = Url::parse(page_url).ok();
+    doc.select("link[rel~='alternate'][type][href]")
+        .iter()
+        .filter(|el| {
+            el.attr("type").is_some_and(|t| {
+                let mime = t.split(';').next().unwrap_or("").trim();
+                OEMBED_JSON_TYPES
+                    .iter()
+                    .any(|o| o.eq_ignore_ascii_case(mime))
+            })
+        })
+        .find_map(|el| resolve_http_url(base.as_ref(), &el.attr("href")?))
+}
+
+/// Parse an oEmbed JSON response, or `None` when it is not a valid oEmbed object.
+pub fn parse_oembed(json: &str) -> Option<OEmbed> {
+    let value: Value = serde_json::from_str(json).ok()?;
+    let obj = value.as_object()?;
+    let text = |key: &str| {
+        obj.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let number = |key: &str| match obj.get(key)? {
+        Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    };
+
+    Some(OEmbed {
+        kind: text("type")?.to_lowercase(),
+        title: text("title"),
+        author_name: text("author_name"),
+        provider_name: text("provider_name"),
+        thumbnail_url: text("thumbnail_url"),
+        thumbnail_width: number("thumbnail_width"),
+        thumbnail_height: number("thumbnail_height"),
+        html: text("html"),
+        width: number("width"),
+        height: number("height"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_oembed_url() {
+        let doc = Document::from(
+            r#"<html><head>
+<link rel="alternate" type="text/xml+oembed" href="/oembed?format=xml">
+<link rel="alternate" type="application/json+oembed" href="/oembed?url=https%3A%2F%2Fvideo.test%2Fv%2F1&amp;format=json">
+</head></html>"#,
+        );
+        assert_eq!(
+            extract_oembed_url(&doc, "https://video.test/v/1"),
+            Some(
+                "https://video.test/oembed?url=https%3A%2F%2Fvideo.test%2Fv%2F1&format=json"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            extract_oembed_url(&Document::from("<html></html>"), "https://video.test/"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_oembed() {
+        let oembed = parse_oembed(
+            r#"{"version": "1.0", "type": "video", "title": "Harbor at dawn",
+                "author_name": "Port Films", "provider_name": "VideoSite",
+                "thumbnail_url": "https://video.test/t/1.jpg", "thumbnail_width": 480,
+                "thumbnail_height": "360", "html": "<iframe src=\"https://video.test/embed/1\"></iframe>",
+                "width": 640, "height": 360}"#,
+        )
+        .expect("valid oEmbed");
+        assert_eq!(oembed.kind, "video");
+        assert_eq!(oembed.title.as_deref(), Some("Harbor at dawn"));
+        assert_eq!(oembed.author_name.as_deref(), Some("Port Films"));
+        assert_eq!(oembed.thumbnail_height, Some(360));
+        assert_eq!((oembed.width, oembed.height), (Some(640), Some(360)));
+        assert!(oembed.html.is_some_and(|h| h.starts_with("<iframe")));
+
+        assert_eq!(parse_oembed(r#"{"title": "No type"}"#), None);
+        assert_eq!(parse_oembed("not json"), None);
+    }
+}
@@ -0,0 +1,239 @@
+// ABOUTME: RDFa / RDFa Lite article metadata extraction (typeof="Article", property="headline", dc:*, og:*).
+// ABOUTME: Reads the first Article-typed subtree into the same LdArticle shape JSON-LD produces.
+
+use dom_query::{Document, NodeRef};
+
+use crate::extractors::ld_json::{is_article_type_name, LdArticle};
+
+/// Extra `typeof` values that mark an article subject. Drupal 7 tags nodes
+/// as `sioc:Item foaf:Document` rather than using schema.org types.
+const DOCUMENT_TYPES: &[&str] = &["Document", "Item"];
+
+/// `typeof` values for people, whose `name` is an author (Drupal puts
+/// `property="foaf:name"` directly on a `sioc:UserAccount` link).
+const PERSON_TYPES: &[&str] = &["Person", "UserAccount"];
+
+/// Extract article metadata from the first element whose `typeof` is an
+/// Article type (schema.org, or Dublin Core/SIOC as emitted by Drupal).
+///
+/// Only properties whose nearest typed ancestor is that element are read, so
+/// an author's `url` or a publisher logo's `url` is not mistaken for the
+/// article's. Returns `None` when there is no such element or it carries no
+/// recognized properties.
+pub fn extract_rdfa_article(doc: &Document) -> Option<LdArticle> {
+    let typed = doc.select("[typeof]");
+    let scope = typed.nodes().iter().find(|node| {
+        has_term(node, "typeof", |t| {
+            is_article_type_name(t) || DOCUMENT_TYPES.contains(&t)
+        })
+    })?;
+
+    let mut article = LdArticle::default();
+    let mut name: Option<String> = None;
+    let candidates = doc.select("[property], [rel]");
+    for node in candidates.nodes() {
+        if owner(node).map(|o| o.id) != Some(scope.id) {
+            continue;
+        }
+
+        if node.has_attr("typeof") {
+            // A nested item (author, publisher): its value is its name
+            let item_name = item_name(node);
+            for term in terms(node, "property") {
+                match term.as_str() {
+                    "author" | "creator" => push_unique(&mut article.authors, item_name.clone()),
+                    "publisher" => set_once(&mut article.publisher, item_name.clone()),
+                    "name" if has_term(node, "typeof", |t| PERSON_TYPES.contains(&t)) => {
+                        push_unique(&mut article.authors, item_name.clone())
+                    }
+                    "image" | "thumbnailUrl" => set_once(&mut article.image, iri_value(node)),
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        for term in terms(node, "property") {
+            match term.as_str() {
+                "headline" | "title" => set_once(&mut article.headline, literal_value(node)),
+                "name" => set_once(&mut name, literal_value(node)),
+                "author" | "creator" => push_unique(&mut article.authors, literal_value(node)),
+                "datePublished" | "published_time" | "date" | "created" | "issued" => {
+                    set_once(&mut article.date_published, literal_value(node))
+                }
+                "dateModified" | "modified_time" | "modified" => {
+                    set_once(&mut article.date_modified, literal_value(node))
+                }
+                "publisher" => set_once(&mut article.publisher, literal_value(node)),
+                "image" | "thumbnailUrl" => set_once(&mut article.image, iri_value(node)),
+                "articleBody" | "encoded" => set_once(&mut article.body, body_text(node)),
+                _ => {}
+            }
+        }
+        // RDFa 1.0 links resources with `rel` (Drupal: rel="og:image")
+        if has_term(node, "rel", |t| t == "image") {
+            set_once(&mut article.image, iri_value(node));
+        }
+    }
+
+    set_once(&mut article.headline, name);
+    (article != LdArticle::default()).then_some(article)
+}
+
+/// Local names of the space-separated terms in `attr`: `dc:title`,
+/// `http://schema.org/headline` and `headline` all yield `headline`.
+fn terms(node: &NodeRef, attr: &str) -> Vec<String> {
+    let Some(value) = node.attr(attr) else {
+        return Vec::new();
+    };
+    value
+        .split_whitespace()
+        .map(|t| {
+            let local = t.rsplit(['/', '#']).next().unwrap_or(t);
+            local.rsplit(':').next().unwrap_or(local).to_string()
+        })
+        .collect()
+}
+
+/// True when `attr` on `node` contains a term whose local name satisfies `pred`.
+fn has_term(node: &NodeRef, attr: &str, pred: impl Fn(&str) -> bool) -> bool {
+    terms(node, attr).iter().any(|t| pred(t))
+}
+
+/// Nearest ancestor (excluding `node`) that starts a new item.
+fn owner<'a>(node: &NodeRef<'a>) -> Option<NodeRef<'a>> {
+    node.ancestors_it(None).find(|a| a.has_attr("typeof"))
+}
+
+/// Name of a nested item: a `name` property it owns, else its own text.
+fn item_name(node: &NodeRef) -> Option<String> {
+    node.descendants_it()
+        .filter(|d| d.is_element())
+        .find(|d| {
+            has_term(d, "property", |t| t == "name") && owner(d).map(|o| o.id) == Some(node.id)
+        })
+        .and_then(|d| literal_value(&d))
+        .or_else(|| literal_value(node))
+}
+
+/// RDFa literal: `content`, then `datetime`, then the element's text.
+fn literal_value(node: &NodeRef) -> Option<String> {
+    node.attr("content")
+        .or_else(|| node.attr("datetime"))
+        .map(|v| v.trim().to_string())
+        .or_else(|| Some(collapse_whitespace(&node.text())))
+        .filter(|v| !v.is_empty())
+}
+
+/// RDFa resource: `resource`, `src`, `href`, then `content`.
+fn iri_value(node: &NodeRef) -> Option<String> {
+    ["resource", "src", "href", "content"]
+        .iter()
+        .find_map(|attr| node.attr(attr))
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Article body as plain text, one paragraph per `<p>` when present.
+fn body_text(node: &NodeRef) -> Option<String> {
+    let paragraphs: Vec<String> = node
+        .descendants_it()
+        .filter(|d| d.node_name().is_some_and(|n| n.eq_ignore_ascii_case("p")))
+        .map(|p| collapse_whitespace(&p.text()))
+        .filter(|p| !p.is_empty())
+        .collect();
+    if paragraphs.is_empty() {
+        literal_value(node)
+    } else {
+        Some(paragraphs.join("\n\n"))
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn set_once(slot: &mut Option<String>, value: Option<String>) {
+    if slot.is_none() {
+        *slot = value;
+    }
+}
+
+fn push_unique(list: &mut Vec<String>, value: Option<String>) {
+    if let Some(value) = value {
+        if !list.contains(&value) {
+            list.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> Document {
+        let path = format!(
+            "{}/tests/fixtures/html/{}.html",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        Document::from(std::fs::read_to_string(path).unwrap().as_str())
+    }
+
+    #[test]
+    fn test_drupal_rdfa_fixture() {
+        let article = extract_rdfa_article(&fixture("rdfa_drupal")).unwrap();
+        assert_eq!(
+            article.headline.as_deref(),
+            Some("Community garden opens on Elm Street")
+        );
+        assert_eq!(article.authors, vec!["Maria Garcia"]);
+        assert_eq!(
+            article.date_published.as_deref(),
+            Some("2013-05-04T09:30:00-04:00")
+        );
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://www.example.org/sites/default/files/styles/large/public/garden.jpg")
+        );
+        let body = article.body.unwrap();
+        assert!(body.starts_with("After two years of planning"));
+        assert!(body.contains("\n\nVolunteers will run"));
+    }
+
+    #[test]
+    fn test_rdfa_lite_fixture() {
+        let article = extract_rdfa_article(&fixture("rdfa_lite_blog")).unwrap();
+        assert_eq!(
+            article.headline.as_deref(),
+            Some("Counting anemones in the tide pools")
+        );
+        assert_eq!(article.authors, vec!["Lena Ortiz", "Sam Kim"]);
+        assert_eq!(
+            article.date_published.as_deref(),
+            Some("2021-07-18T06:45:00Z")
+        );
+        assert_eq!(
+            article.date_modified.as_deref(),
+            Some("2021-07-20T12:00:00Z")
+        );
+        assert_eq!(article.publisher.as_deref(), Some("Field Notes Collective"));
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://fieldnotes.example.com/img/anemones.jpg")
+        );
+        assert!(article
+            .body
+            .unwrap()
+            .starts_with("Low tide came at 6:12 this morning"));
+    }
+
+    #[test]
+    fn test_no_article_scope() {
+        let doc = Document::from(
+            r#"<html><body><div typeof="Person"><span property="name">X</span></div>
+<span property="headline">Loose</span></body></html>"#,
+        );
+        assert_eq!(extract_rdfa_article(&doc), None);
+    }
+}
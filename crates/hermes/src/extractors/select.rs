@@ -11,7 +11,8 @@
 //! - Text extraction joins inner text with spaces and normalizes whitespace.
 //! - Attribute extraction returns the attribute value trimmed.
 //! - `allow_multiple`: when true, returns all matches from the winning selector;
-//!   when false, returns only the first match.
+//!   when false, returns only the first match. `extract_field_text_all` ignores
+//!   it for fields that are lists by nature (e.g. authors).
 
 use dom_query::Document;
 
@@ -33,18 +34,23 @@ fn normalize_whitespace(s: &str) -> String {
 /// If `fe.allow_multiple` is false, returns only the first non-empty value (as a single-element vec).
 /// If no selector yields matches, returns `None`.
 pub fn extract_field_text(doc: &Document, fe: &FieldExtractor) -> Option<Vec<String>> {
-    for spec in &fe.selectors {
-        let results = extract_from_spec(doc, spec);
-        if !results.is_empty() {
-            if fe.allow_multiple {
-                return Some(results);
-            } else {
-                // Return only the first match
-                return Some(vec![results.into_iter().next().unwrap()]);
-            }
-        }
+    let mut results = extract_field_text_all(doc, fe)?;
+    if !fe.allow_multiple {
+        results.truncate(1);
     }
-    None
+    Some(results)
+}
+
+/// Like `extract_field_text`, but returns every match from the winning
+/// selector regardless of `fe.allow_multiple`.
+///
+/// Used for fields that are naturally lists even when the extractor was
+/// written for a single value, such as several bylines matching one selector.
+pub fn extract_field_text_all(doc: &Document, fe: &FieldExtractor) -> Option<Vec<String>> {
+    fe.selectors
+        .iter()
+        .map(|spec| extract_from_spec(doc, spec))
+        .find(|results| !results.is_empty())
 }
 
 /// Convenience function that returns only the first extracted value.
@@ -196,6 +202,32 @@ mod tests {
         assert_eq!(values[2], "Item Three");
     }
 
+    #[test]
+    fn test_extract_all_ignores_allow_multiple() {
+        let doc = parse_html();
+        let fe = FieldExtractor {
+            selectors: vec![
+                SelectorSpec::Css(".missing".to_string()),
+                SelectorSpec::Css("ul.items li".to_string()),
+            ],
+            allow_multiple: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            extract_field_text(&doc, &fe),
+            Some(vec!["Item One".to_string()])
+        );
+        assert_eq!(
+            extract_field_text_all(&doc, &fe),
+            Some(vec![
+                "Item One".to_string(),
+                "Item Two".to_string(),
+                "Item Three".to_string()
+            ])
+        );
+    }
+
     #[test]
     fn test_selector_precedence_first_match_wins() {
         let doc = parse_html();
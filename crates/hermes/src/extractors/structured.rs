@@ -0,0 +1,937 @@
+// ABOUTME: Typed extraction of schema.org structured items (Recipe, Product, Event) from JSON-LD, microdata and OpenGraph.
+// ABOUTME: Normalizes recipe steps/durations, product offers and event venues into typed results.
+
+//! Structured item extraction.
+//!
+//! Key behaviors:
+//! - JSON-LD wins over microdata; the first Recipe found is used.
+//! - Instructions may be a string (split on newlines), `HowToStep`s, or
+//!   `HowToSection`s, which are flattened into one step list.
+//! - Durations (`PT1H30M`) become seconds; a missing `totalTime` is the sum of
+//!   prep and cook time when both are known.
+//! - Products come from JSON-LD or microdata, with OpenGraph `product:*` tags
+//!   filling gaps (or standing alone on `og:type=product` pages).
+//! - Availability is normalized to the schema.org name (`InStock`, `OutOfStock`, ...).
+//! - Events include every `*Event` subtype; dates are kept as written and the
+//!   venue is the `location` Place's name, with its PostalAddress flattened.
+
+use dom_query::{Document, NodeRef};
+use serde_json::{Map, Value};
+
+use crate::extractors::fields::{extract_meta_content, extract_meta_contents_all};
+use crate::extractors::ld_json::{collect_typed_nodes, image_url, ld_json_values, text_value};
+use crate::result::{Event, Product, Recipe};
+
+/// PostalAddress properties in display order.
+const ADDRESS_PARTS: &[&str] = &[
+    "streetAddress",
+    "addressLocality",
+    "addressRegion",
+    "postalCode",
+    "addressCountry",
+];
+
+/// Extract the first schema.org Recipe from JSON-LD, falling back to microdata.
+pub fn extract_recipe(doc: &Document) -> Option<Recipe> {
+    let mut recipe = recipe_from_ld_json(doc).or_else(|| recipe_from_microdata(doc))?;
+    if recipe.total_time_secs.is_none() {
+        if let (Some(prep), Some(cook)) = (recipe.prep_time_secs, recipe.cook_time_secs) {
+            recipe.total_time_secs = Some(prep + cook);
+        }
+    }
+    Some(recipe)
+}
+
+/// Parse an ISO 8601 duration (`PT1H30M`, `P1DT2H`, `PT90S`) into seconds.
+pub fn parse_iso_duration(s: &str) -> Option<u64> {
+    let rest = s.trim().strip_prefix(['P', 'p'])?;
+    let (date, time) = match rest.find(['T', 't']) {
+        Some(i) => (&rest[..i], &rest[i + 1..]),
+        None => (rest, ""),
+    };
+
+    let mut total = 0f64;
+    let mut any = false;
+    for (part, units) in [
+        (date, &[('W', 604_800.0), ('D', 86_400.0)][..]),
+        (time, &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)][..]),
+    ] {
+        let mut num = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                num.push(c);
+                continue;
+            }
+            let factor = units
+                .iter()
+                .find(|(u, _)| u.eq_ignore_ascii_case(&c))
+                .map(|(_, f)| *f)?;
+            let value: f64 = num.parse().ok()?;
+            total += value * factor;
+            any = true;
+            num.clear();
+        }
+        if !num.is_empty() {
+            return None;
+        }
+    }
+    any.then_some(total.round() as u64)
+}
+
+fn is_recipe_type(value: &Value) -> bool {
+    match value {
+        Value::String(s) => local_type(s) == "Recipe",
+        Value::Array(arr) => arr.iter().any(is_recipe_type),
+        _ => false,
+    }
+}
+
+/// `Recipe` from `Recipe`, `schema:Recipe` or `https://schema.org/Recipe`.
+pub(crate) fn local_type(s: &str) -> &str {
+    let s = s.trim();
+    let s = s.rsplit('/').next().unwrap_or(s);
+    s.rsplit(':').next().unwrap_or(s)
+}
+
+fn recipe_from_ld_json(doc: &Document) -> Option<Recipe> {
+    ld_json_values(doc).iter().find_map(|value| {
+        let mut nodes = Vec::new();
+        collect_typed_nodes(value, &is_recipe_type, &mut nodes);
+        nodes.first().map(|node| recipe_from_node(node))
+    })
+}
+
+fn recipe_from_node(map: &Map<String, Value>) -> Recipe {
+    let duration = |key: &str| {
+        map.get(key)
+            .and_then(text_value)
+            .and_then(|d| parse_iso_duration(&d))
+    };
+    Recipe {
+        name: map.get("name").and_then(text_value),
+        description: map.get("description").and_then(text_value),
+        image: map.get("image").and_then(image_url),
+        ingredients: map
+            .get("recipeIngredient")
+            .or_else(|| map.get("ingredients"))
+            .map(string_list)
+            .unwrap_or_default(),
+        steps: map
+            .get("recipeInstructions")
+            .map(|v| {
+                let mut steps = Vec::new();
+                collect_ld_steps(v, &mut steps);
+                steps
+            })
+            .unwrap_or_default(),
+        prep_time_secs: duration("prepTime"),
+        cook_time_secs: duration("cookTime"),
+        total_time_secs: duration("totalTime"),
+        recipe_yield: map.get("recipeYield").and_then(yield_value),
+    }
+}
+
+/// A string or array of strings as trimmed, non-empty entries.
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(arr) => arr.iter().filter_map(text_value).collect(),
+        _ => text_value(value).into_iter().collect(),
+    }
+}
+
+fn collect_ld_steps(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => out.extend(split_lines(s)),
+        Value::Array(arr) => arr.iter().for_each(|v| collect_ld_steps(v, out)),
+        Value::Object(map) => {
+            // HowToSection nests its steps; HowToStep carries text (or just a name)
+            if let Some(items) = map.get("itemListElement") {
+                collect_ld_steps(items, out);
+            } else if let Some(text) = map
+                .get("text")
+                .or_else(|| map.get("name"))
+                .and_then(text_value)
+            {
+                out.push(text);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn split_lines(s: &str) -> impl Iterator<Item = String> + '_ {
+    s.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+}
+
+/// `recipeYield` may be a string, a number, or an array mixing both; the
+/// most descriptive (longest) entry wins, so "4 servings" beats "4".
+fn yield_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::Array(arr) => arr.iter().filter_map(yield_value).max_by_key(|s| s.len()),
+        _ => text_value(value),
+    }
+}
+
+fn recipe_from_microdata(doc: &Document) -> Option<Recipe> {
+    let scope = find_microdata_item(doc, |t| t == "Recipe")?;
+
+    let mut recipe = Recipe::default();
+    for (name, node) in item_properties(doc, &scope) {
+        match name.as_str() {
+            "name" => set_once(&mut recipe.name, microdata_value(&node)),
+            "description" => set_once(&mut recipe.description, microdata_value(&node)),
+            "image" => set_once(&mut recipe.image, microdata_value(&node)),
+            "recipeIngredient" | "ingredients" => recipe.ingredients.extend(microdata_value(&node)),
+            "recipeInstructions" => recipe.steps.extend(microdata_steps(&node)),
+            "prepTime" => set_once_duration(&mut recipe.prep_time_secs, &node),
+            "cookTime" => set_once_duration(&mut recipe.cook_time_secs, &node),
+            "totalTime" => set_once_duration(&mut recipe.total_time_secs, &node),
+            "recipeYield" => set_once(&mut recipe.recipe_yield, microdata_value(&node)),
+            _ => {}
+        }
+    }
+    Some(recipe)
+}
+
+/// First microdata item with an `itemtype` whose local name satisfies `is_type`.
+pub(crate) fn find_microdata_item<'a>(
+    doc: &'a Document,
+    is_type: impl Fn(&str) -> bool,
+) -> Option<NodeRef<'a>> {
+    let items = doc.select("[itemscope][itemtype]");
+    let found = items.nodes().iter().find(|node| {
+        node.attr("itemtype")
+            .is_some_and(|t| t.split_whitespace().any(|t| is_type(local_type(t))))
+    });
+    found.copied()
+}
+
+/// `(property name, element)` pairs belonging directly to the item `scope`,
+/// in document order; properties of nested items are excluded.
+pub(crate) fn item_properties<'a>(
+    doc: &'a Document,
+    scope: &NodeRef<'a>,
+) -> Vec<(String, NodeRef<'a>)> {
+    let props = doc.select("[itemprop]");
+    let mut out = Vec::new();
+    for node in props.nodes() {
+        if owner(node).map(|o| o.id) != Some(scope.id) {
+            continue;
+        }
+        if let Some(names) = node.attr("itemprop") {
+            for name in names.split_whitespace() {
+                out.push((name.to_string(), *node));
+            }
+        }
+    }
+    out
+}
+
+/// Extract a product from schema.org Product data (JSON-LD, then microdata),
+/// with OpenGraph product tags filling missing fields.
+pub fn extract_product(doc: &Document) -> Option<Product> {
+    let og = product_from_open_graph(doc);
+    let mut product = match product_from_ld_json(doc).or_else(|| product_from_microdata(doc)) {
+        Some(product) => product,
+        None => return og,
+    };
+    if let Some(og) = og {
+        set_once(&mut product.name, og.name);
+        set_once(&mut product.description, og.description);
+        set_once(&mut product.brand, og.brand);
+        if product.price.is_none() {
+            product.price = og.price;
+            product.currency = product.currency.or(og.currency);
+        }
+        set_once(&mut product.availability, og.availability);
+        for image in og.images {
+            if !product.images.contains(&image) {
+                product.images.push(image);
+            }
+        }
+    }
+    Some(product)
+}
+
+/// Maps schema.org availability IRIs and OpenGraph values (`in stock`,
+/// `oos`, `preorder`) to the schema.org name, e.g. `InStock`.
+pub fn normalize_availability(value: &str) -> Option<String> {
+    let local = local_type(value);
+    let key: String = local
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let name = match key.as_str() {
+        "" => return None,
+        "instock" | "available" | "availablefororder" => "InStock",
+        "outofstock" | "oos" | "soldout" => "OutOfStock",
+        "preorder" => "PreOrder",
+        "presale" => "PreSale",
+        "backorder" => "BackOrder",
+        "discontinued" => "Discontinued",
+        "limitedavailability" => "LimitedAvailability",
+        "onlineonly" => "OnlineOnly",
+        "instoreonly" => "InStoreOnly",
+        "pending" => "Pending",
+        _ => local,
+    };
+    Some(name.to_string())
+}
+
+fn is_product_type(value: &Value) -> bool {
+    match value {
+        Value::String(s) => matches!(
+            local_type(s),
+            "Product" | "ProductGroup" | "IndividualProduct"
+        ),
+        Value::Array(arr) => arr.iter().any(is_product_type),
+        _ => false,
+    }
+}
+
+fn product_from_ld_json(doc: &Document) -> Option<Product> {
+    ld_json_values(doc).iter().find_map(|value| {
+        let mut nodes = Vec::new();
+        collect_typed_nodes(value, &is_product_type, &mut nodes);
+        nodes.first().map(|node| product_from_node(node))
+    })
+}
+
+fn product_from_node(map: &Map<String, Value>) -> Product {
+    let mut product = Product {
+        name: map.get("name").and_then(text_value),
+        description: map.get("description").and_then(text_value),
+        brand: map.get("brand").and_then(|b| match b {
+            Value::Object(brand) => brand.get("name").and_then(text_value),
+            _ => text_value(b),
+        }),
+        images: map.get("image").map(image_urls).unwrap_or_default(),
+        ..Default::default()
+    };
+
+    // First offer with a price; AggregateOffer uses lowPrice
+    let offers: Vec<&Map<String, Value>> = match map.get("offers") {
+        Some(Value::Array(arr)) => arr.iter().filter_map(Value::as_object).collect(),
+        Some(Value::Object(offer)) => vec![offer],
+        _ => Vec::new(),
+    };
+    for offer in offers {
+        let price = offer
+            .get("price")
+            .or_else(|| offer.get("lowPrice"))
+            .and_then(price_value);
+        set_once(
+            &mut product.availability,
+            offer
+                .get("availability")
+                .and_then(text_value)
+                .and_then(|a| normalize_availability(&a)),
+        );
+        if price.is_some() {
+            product.price = price;
+            product.currency = offer.get("priceCurrency").and_then(text_value);
+            break;
+        }
+    }
+    product
+}
+
+/// Every image URL from a string, ImageObject, or array of either.
+fn image_urls(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(arr) => arr.iter().filter_map(image_url).collect(),
+        _ => image_url(value).into_iter().collect(),
+    }
+}
+
+/// A price as a string, accepting JSON numbers.
+fn price_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        _ => text_value(value),
+    }
+}
+
+fn product_from_microdata(doc: &Document) -> Option<Product> {
+    let scope = find_microdata_item(doc, |t| t == "Product")?;
+
+    let mut product = Product::default();
+    for (name, node) in item_properties(doc, &scope) {
+        match name.as_str() {
+            "name" => set_once(&mut product.name, microdata_value(&node)),
+            "description" => set_once(&mut product.description, microdata_value(&node)),
+            "brand" => {
+                let brand = item_properties(doc, &node)
+                    .into_iter()
+                    .find(|(n, _)| n == "name")
+                    .and_then(|(_, n)| microdata_value(&n));
+                set_once(&mut product.brand, brand.or_else(|| microdata_value(&node)));
+            }
+            "image" => product.images.extend(microdata_value(&node)),
+            "offers" if product.price.is_none() => {
+                for (offer_prop, offer_node) in item_properties(doc, &node) {
+                    let value = microdata_value(&offer_node);
+                    match offer_prop.as_str() {
+                        "price" | "lowPrice" => set_once(&mut product.price, value),
+                        "priceCurrency" => set_once(&mut product.currency, value),
+                        "availability" => set_once(
+                            &mut product.availability,
+                            value.and_then(|a| normalize_availability(&a)),
+                        ),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(product)
+}
+
+/// Product from OpenGraph tags; requires `og:type` of `product*` or a
+/// `product:price:amount` tag.
+fn product_from_open_graph(doc: &Document) -> Option<Product> {
+    let meta = |props: &[&str]| {
+        props
+            .iter()
+            .find_map(|p| extract_meta_content(doc, &format!("meta[property='{}']", p)))
+    };
+    let price = meta(&["product:price:amount", "og:price:amount"]);
+    let is_product = extract_meta_content(doc, "meta[property='og:type']")
+        .is_some_and(|t| t.to_ascii_lowercase().starts_with("product"))
+        || price.is_some();
+    if !is_product {
+        return None;
+    }
+
+    let mut images: Vec<String> = Vec::new();
+    for image in extract_meta_contents_all(doc, "meta[property='og:image']") {
+        if !images.contains(&image) {
+            images.push(image);
+        }
+    }
+    Some(Product {
+        name: meta(&["og:title"]),
+        description: meta(&["og:description"]),
+        brand: meta(&["product:brand", "og:brand"]),
+        currency: meta(&["product:price:currency", "og:price:currency"]),
+        price,
+        availability: meta(&["product:availability", "og:availability"])
+            .and_then(|a| normalize_availability(&a)),
+        images,
+    })
+}
+
+/// Extract the first schema.org Event (any `*Event` subtype) from JSON-LD,
+/// falling back to microdata.
+pub fn extract_event(doc: &Document) -> Option<Event> {
+    event_from_ld_json(doc).or_else(|| event_from_microdata(doc))
+}
+
+/// `Event`, its `*Event` subtypes (`MusicEvent`, `BusinessEvent`, ...) and
+/// the two subtypes without the suffix.
+fn is_event_type_name(local: &str) -> bool {
+    local.ends_with("Event") || matches!(local, "Festival" | "Hackathon")
+}
+
+fn is_event_type(value: &Value) -> bool {
+    match value {
+        Value::String(s) => is_event_type_name(local_type(s)),
+        Value::Array(arr) => arr.iter().any(is_event_type),
+        _ => false,
+    }
+}
+
+fn event_from_ld_json(doc: &Document) -> Option<Event> {
+    ld_json_values(doc).iter().find_map(|value| {
+        let mut nodes = Vec::new();
+        collect_typed_nodes(value, &is_event_type, &mut nodes);
+        nodes.first().map(|node| event_from_node(node))
+    })
+}
+
+fn event_from_node(map: &Map<String, Value>) -> Event {
+    let mut event = Event {
+        name: map.get("name").and_then(text_value),
+        description: map.get("description").and_then(text_value),
+        start_date: map.get("startDate").and_then(text_value),
+        end_date: map.get("endDate").and_then(text_value),
+        url: map.get("url").and_then(text_value),
+        image: map.get("image").and_then(image_url),
+        ..Default::default()
+    };
+
+    // `location` may be a Place, a bare string, a VirtualLocation, or an array
+    let locations: Vec<&Value> = match map.get("location") {
+        Some(Value::Array(arr)) => arr.iter().collect(),
+        Some(location) => vec![location],
+        None => Vec::new(),
+    };
+    for location in locations {
+        match location {
+            Value::Object(place) if !place.get("@type").is_some_and(is_virtual_location) => {
+                set_once(&mut event.venue, place.get("name").and_then(text_value));
+                set_once(
+                    &mut event.address,
+                    place.get("address").and_then(address_value),
+                );
+            }
+            Value::String(_) => set_once(&mut event.venue, text_value(location)),
+            _ => {}
+        }
+    }
+    event
+}
+
+fn is_virtual_location(value: &Value) -> bool {
+    match value {
+        Value::String(s) => local_type(s) == "VirtualLocation",
+        Value::Array(arr) => arr.iter().any(is_virtual_location),
+        _ => false,
+    }
+}
+
+/// Address as one line: a string, or a PostalAddress's parts joined by ", ".
+fn address_value(value: &Value) -> Option<String> {
+    let Value::Object(map) = value else {
+        return text_value(value);
+    };
+    let parts: Vec<String> = ADDRESS_PARTS
+        .iter()
+        .filter_map(|key| match map.get(*key)? {
+            Value::Object(country) => country.get("name").and_then(text_value),
+            part => text_value(part),
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn event_from_microdata(doc: &Document) -> Option<Event> {
+    let scope = find_microdata_item(doc, is_event_type_name)?;
+
+    let mut event = Event::default();
+    for (name, node) in item_properties(doc, &scope) {
+        match name.as_str() {
+            "name" => set_once(&mut event.name, microdata_value(&node)),
+            "description" => set_once(&mut event.description, microdata_value(&node)),
+            "startDate" => set_once(&mut event.start_date, microdata_value(&node)),
+            "endDate" => set_once(&mut event.end_date, microdata_value(&node)),
+            "url" => set_once(&mut event.url, microdata_value(&node)),
+            "image" => set_once(&mut event.image, microdata_value(&node)),
+            "location" if !node.has_attr("itemscope") => {
+                set_once(&mut event.venue, microdata_value(&node))
+            }
+            "location" => {
+                for (place_prop, place_node) in item_properties(doc, &node) {
+                    match place_prop.as_str() {
+                        "name" => set_once(&mut event.venue, microdata_value(&place_node)),
+                        "address" => {
+                            set_once(&mut event.address, microdata_address(doc, &place_node))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(event)
+}
+
+/// Microdata address: a PostalAddress item's parts joined by ", ", or the
+/// element's own value.
+fn microdata_address(doc: &Document, node: &NodeRef) -> Option<String> {
+    if !node.has_attr("itemscope") {
+        return microdata_value(node);
+    }
+    let props = item_properties(doc, node);
+    let parts: Vec<String> = ADDRESS_PARTS
+        .iter()
+        .filter_map(|key| {
+            props
+                .iter()
+                .find(|(name, _)| name == key)
+                .and_then(|(_, n)| microdata_value(n))
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Nearest ancestor (excluding `node`) that starts a microdata item.
+fn owner<'a>(node: &NodeRef<'a>) -> Option<NodeRef<'a>> {
+    node.ancestors_it(None).find(|a| a.has_attr("itemscope"))
+}
+
+/// Microdata property value per the HTML spec's element-specific rules.
+pub(crate) fn microdata_value(node: &NodeRef) -> Option<String> {
+    let tag = node
+        .node_name()
+        .map(|n| n.to_ascii_lowercase())
+        .unwrap_or_default();
+    let attr = match tag.as_str() {
+        "meta" => Some("content"),
+        "a" | "area" | "link" => Some("href"),
+        "img" | "audio" | "video" | "source" | "iframe" | "embed" | "track" => Some("src"),
+        "object" => Some("data"),
+        "data" | "meter" => Some("value"),
+        "time" => Some("datetime"),
+        _ => None,
+    };
+    attr.and_then(|a| node.attr(a))
+        .map(|v| v.trim().to_string())
+        .or_else(|| Some(collapse_whitespace(&node.text())))
+        .filter(|v| !v.is_empty())
+}
+
+/// Steps from a `recipeInstructions` element: a `HowToStep` item's text, one
+/// step per `<li>` (or `<p>`), or the element's own text.
+fn microdata_steps(node: &NodeRef) -> Vec<String> {
+    if node.has_attr("itemscope") {
+        let text = node.descendants_it().find(|d| {
+            d.attr("itemprop")
+                .is_some_and(|p| p.split_whitespace().any(|p| p == "text"))
+        });
+        return text
+            .as_ref()
+            .and_then(microdata_value)
+            .or_else(|| microdata_value(node))
+            .into_iter()
+            .collect();
+    }
+    for tag in ["li", "p"] {
+        let steps: Vec<String> = node
+            .descendants_it()
+            .filter(|d| d.node_name().is_some_and(|n| n.eq_ignore_ascii_case(tag)))
+            .map(|d| collapse_whitespace(&d.text()))
+            .filter(|s| !s.is_empty())
+            .collect();
+        if !steps.is_empty() {
+            return steps;
+        }
+    }
+    microdata_value(node).into_iter().collect()
+}
+
+fn set_once_duration(slot: &mut Option<u64>, node: &NodeRef) {
+    if slot.is_none() {
+        *slot = microdata_value(node).and_then(|d| parse_iso_duration(&d));
+    }
+}
+
+fn set_once(slot: &mut Option<String>, value: Option<String>) {
+    if slot.is_none() {
+        *slot = value;
+    }
+}
+
+pub(crate) fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso_duration() {
+        assert_eq!(parse_iso_duration("PT1H30M"), Some(5400));
+        assert_eq!(parse_iso_duration("PT20M"), Some(1200));
+        assert_eq!(parse_iso_duration("P0DT0H45M"), Some(2700));
+        assert_eq!(parse_iso_duration("P1DT2H"), Some(93_600));
+        assert_eq!(parse_iso_duration("PT1.5H"), Some(5400));
+        assert_eq!(parse_iso_duration("pt90s"), Some(90));
+        assert_eq!(parse_iso_duration("20 minutes"), None);
+        assert_eq!(parse_iso_duration("PT"), None);
+        assert_eq!(parse_iso_duration("PT5"), None);
+    }
+
+    #[test]
+    fn test_recipe_from_ld_json() {
+        let html = r#"<html><head><script type="application/ld+json">{
+            "@context": "https://schema.org",
+            "@graph": [
+                {"@type": "WebPage", "name": "Page"},
+                {
+                    "@type": "Recipe",
+                    "name": "Weeknight Dal",
+                    "image": ["https://example.com/dal.jpg"],
+                    "recipeIngredient": ["1 cup red lentils", " 2 cups water ", ""],
+                    "recipeInstructions": [
+                        {"@type": "HowToSection", "name": "Prep", "itemListElement": [
+                            {"@type": "HowToStep", "text": "Rinse the lentils."}
+                        ]},
+                        {"@type": "HowToStep", "text": "Simmer for 20 minutes."},
+                        "Season to taste."
+                    ],
+                    "prepTime": "PT10M",
+                    "cookTime": "PT25M",
+                    "recipeYield": ["4", "4 servings"]
+                }
+            ]
+        }</script></head><body></body></html>"#;
+
+        let recipe = extract_recipe(&Document::from(html)).unwrap();
+        assert_eq!(recipe.name.as_deref(), Some("Weeknight Dal"));
+        assert_eq!(recipe.image.as_deref(), Some("https://example.com/dal.jpg"));
+        assert_eq!(
+            recipe.ingredients,
+            vec!["1 cup red lentils", "2 cups water"]
+        );
+        assert_eq!(
+            recipe.steps,
+            vec![
+                "Rinse the lentils.",
+                "Simmer for 20 minutes.",
+                "Season to taste."
+            ]
+        );
+        assert_eq!(recipe.prep_time_secs, Some(600));
+        assert_eq!(recipe.cook_time_secs, Some(1500));
+        assert_eq!(recipe.total_time_secs, Some(2100));
+        assert_eq!(recipe.recipe_yield.as_deref(), Some("4 servings"));
+    }
+
+    #[test]
+    fn test_recipe_instructions_string_is_split_on_lines() {
+        let html = r#"<script type="application/ld+json">{"@type": ["Recipe"], "name": "Toast",
+            "recipeInstructions": "Toast the bread.\n\nButter it.", "recipeYield": 2}</script>"#;
+        let recipe = extract_recipe(&Document::from(html)).unwrap();
+        assert_eq!(recipe.steps, vec!["Toast the bread.", "Butter it."]);
+        assert_eq!(recipe.recipe_yield.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_recipe_from_microdata() {
+        let html = r#"<html><body>
+<div itemscope itemtype="https://schema.org/Recipe">
+  <h1 itemprop="name">Lemon Bars</h1>
+  <img itemprop="image" src="https://example.com/bars.jpg">
+  <meta itemprop="prepTime" content="PT15M">
+  <time itemprop="cookTime" datetime="PT30M">30 minutes</time>
+  <span itemprop="recipeYield">16 bars</span>
+  <ul>
+    <li itemprop="recipeIngredient">1 cup flour</li>
+    <li itemprop="recipeIngredient">2 lemons</li>
+  </ul>
+  <div itemprop="author" itemscope itemtype="https://schema.org/Person">
+    <span itemprop="name">Not The Recipe Name</span>
+  </div>
+  <ol itemprop="recipeInstructions">
+    <li>Press the crust into the pan.</li>
+    <li>Pour over the filling and bake.</li>
+  </ol>
+</div>
+</body></html>"#;
+
+        let recipe = extract_recipe(&Document::from(html)).unwrap();
+        assert_eq!(recipe.name.as_deref(), Some("Lemon Bars"));
+        assert_eq!(
+            recipe.image.as_deref(),
+            Some("https://example.com/bars.jpg")
+        );
+        assert_eq!(recipe.ingredients, vec!["1 cup flour", "2 lemons"]);
+        assert_eq!(
+            recipe.steps,
+            vec![
+                "Press the crust into the pan.",
+                "Pour over the filling and bake."
+            ]
+        );
+        assert_eq!(recipe.prep_time_secs, Some(900));
+        assert_eq!(recipe.cook_time_secs, Some(1800));
+        assert_eq!(recipe.total_time_secs, Some(2700));
+        assert_eq!(recipe.recipe_yield.as_deref(), Some("16 bars"));
+    }
+
+    #[test]
+    fn test_product_from_ld_json_with_og_fallback() {
+        let html = r#"<html><head>
+<meta property="og:type" content="product">
+<meta property="og:title" content="OG Kettle">
+<meta property="og:description" content="Boils water fast.">
+<meta property="og:image" content="https://shop.test/k1.jpg">
+<meta property="og:image" content="https://shop.test/k3.jpg">
+<script type="application/ld+json">{
+    "@context": "https://schema.org",
+    "@type": "Product",
+    "name": "Electric Kettle",
+    "brand": {"@type": "Brand", "name": "Boilco"},
+    "image": ["https://shop.test/k1.jpg", {"@type": "ImageObject", "url": "https://shop.test/k2.jpg"}],
+    "offers": [
+        {"@type": "Offer", "availability": "https://schema.org/OutOfStock"},
+        {"@type": "Offer", "price": 39.99, "priceCurrency": "EUR", "availability": "http://schema.org/InStock"}
+    ]
+}</script></head><body></body></html>"#;
+
+        let product = extract_product(&Document::from(html)).unwrap();
+        assert_eq!(product.name.as_deref(), Some("Electric Kettle"));
+        assert_eq!(product.description.as_deref(), Some("Boils water fast."));
+        assert_eq!(product.brand.as_deref(), Some("Boilco"));
+        assert_eq!(product.price.as_deref(), Some("39.99"));
+        assert_eq!(product.currency.as_deref(), Some("EUR"));
+        assert_eq!(product.availability.as_deref(), Some("OutOfStock"));
+        assert_eq!(
+            product.images,
+            vec![
+                "https://shop.test/k1.jpg",
+                "https://shop.test/k2.jpg",
+                "https://shop.test/k3.jpg"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_product_from_microdata() {
+        let html = r#"<html><body>
+<div itemscope itemtype="http://schema.org/Product">
+  <h1 itemprop="name">Trail Shoe</h1>
+  <img itemprop="image" src="https://shop.test/shoe.jpg">
+  <div itemprop="brand" itemscope itemtype="http://schema.org/Brand"><span itemprop="name">Rockstep</span></div>
+  <div itemprop="offers" itemscope itemtype="http://schema.org/AggregateOffer">
+    <meta itemprop="priceCurrency" content="USD">
+    <span itemprop="lowPrice" content="89.00">$89</span>
+    <link itemprop="availability" href="https://schema.org/PreOrder">
+  </div>
+</div>
+</body></html>"#;
+
+        let product = extract_product(&Document::from(html)).unwrap();
+        assert_eq!(product.name.as_deref(), Some("Trail Shoe"));
+        assert_eq!(product.brand.as_deref(), Some("Rockstep"));
+        assert_eq!(product.price.as_deref(), Some("$89"));
+        assert_eq!(product.currency.as_deref(), Some("USD"));
+        assert_eq!(product.availability.as_deref(), Some("PreOrder"));
+        assert_eq!(product.images, vec!["https://shop.test/shoe.jpg"]);
+    }
+
+    #[test]
+    fn test_product_from_open_graph_only() {
+        let html = r#"<html><head>
+<meta property="og:title" content="Desk Lamp">
+<meta property="product:price:amount" content="25.00">
+<meta property="product:price:currency" content="GBP">
+<meta property="product:availability" content="in stock">
+</head></html>"#;
+        let product = extract_product(&Document::from(html)).unwrap();
+        assert_eq!(product.name.as_deref(), Some("Desk Lamp"));
+        assert_eq!(product.price.as_deref(), Some("25.00"));
+        assert_eq!(product.currency.as_deref(), Some("GBP"));
+        assert_eq!(product.availability.as_deref(), Some("InStock"));
+
+        let article = r#"<meta property="og:type" content="article"><meta property="og:title" content="News">"#;
+        assert_eq!(extract_product(&Document::from(article)), None);
+    }
+
+    #[test]
+    fn test_normalize_availability() {
+        assert_eq!(
+            normalize_availability("https://schema.org/InStock").as_deref(),
+            Some("InStock")
+        );
+        assert_eq!(normalize_availability("oos").as_deref(), Some("OutOfStock"));
+        assert_eq!(
+            normalize_availability("Out of stock").as_deref(),
+            Some("OutOfStock")
+        );
+        assert_eq!(
+            normalize_availability("schema:LimitedAvailability").as_deref(),
+            Some("LimitedAvailability")
+        );
+        assert_eq!(normalize_availability(" "), None);
+    }
+
+    #[test]
+    fn test_event_from_ld_json() {
+        let html = r#"<html><head><script type="application/ld+json">[
+            {"@type": "Organization", "name": "Hall Trust"},
+            {
+                "@context": "https://schema.org",
+                "@type": "MusicEvent",
+                "name": "Spring Concert",
+                "startDate": "2025-04-12T19:30:00-05:00",
+                "endDate": "2025-04-12T22:00:00-05:00",
+                "url": "https://hall.test/events/spring",
+                "location": [
+                    {"@type": "VirtualLocation", "url": "https://stream.test/spring"},
+                    {
+                        "@type": "Place",
+                        "name": "Riverside Hall",
+                        "address": {
+                            "@type": "PostalAddress",
+                            "streetAddress": "12 River Rd",
+                            "addressLocality": "Springfield",
+                            "addressRegion": "IL",
+                            "addressCountry": {"@type": "Country", "name": "US"}
+                        }
+                    }
+                ]
+            }
+        ]</script></head><body></body></html>"#;
+
+        let event = extract_event(&Document::from(html)).unwrap();
+        assert_eq!(event.name.as_deref(), Some("Spring Concert"));
+        assert_eq!(
+            event.start_date.as_deref(),
+            Some("2025-04-12T19:30:00-05:00")
+        );
+        assert_eq!(event.end_date.as_deref(), Some("2025-04-12T22:00:00-05:00"));
+        assert_eq!(
+            event.url.as_deref(),
+            Some("https://hall.test/events/spring")
+        );
+        assert_eq!(event.venue.as_deref(), Some("Riverside Hall"));
+        assert_eq!(
+            event.address.as_deref(),
+            Some("12 River Rd, Springfield, IL, US")
+        );
+    }
+
+    #[test]
+    fn test_event_from_microdata() {
+        let html = r#"<html><body>
+<div itemscope itemtype="https://schema.org/Festival">
+  <a itemprop="url" href="https://fest.test/2025"><span itemprop="name">Harbor Fest</span></a>
+  <time itemprop="startDate" datetime="2025-08-01">Aug 1</time>
+  <div itemprop="location" itemscope itemtype="https://schema.org/Place">
+    <span itemprop="name">Pier 9</span>
+    <div itemprop="address" itemscope itemtype="https://schema.org/PostalAddress">
+      <span itemprop="addressLocality">Portland</span>, <span itemprop="addressRegion">ME</span>
+    </div>
+  </div>
+</div>
+</body></html>"#;
+
+        let event = extract_event(&Document::from(html)).unwrap();
+        assert_eq!(event.name.as_deref(), Some("Harbor Fest"));
+        assert_eq!(event.url.as_deref(), Some("https://fest.test/2025"));
+        assert_eq!(event.start_date.as_deref(), Some("2025-08-01"));
+        assert_eq!(event.end_date, None);
+        assert_eq!(event.venue.as_deref(), Some("Pier 9"));
+        assert_eq!(event.address.as_deref(), Some("Portland, ME"));
+    }
+
+    #[test]
+    fn test_event_location_string_and_no_event() {
+        let html = r#"<script type="application/ld+json">
+            {"@type": "Event", "name": "Meetup", "location": "Room 4B"}
+        </script>"#;
+        let event = extract_event(&Document::from(html)).unwrap();
+        assert_eq!(event.venue.as_deref(), Some("Room 4B"));
+        assert_eq!(event.address, None);
+
+        let html = r#"<script type="application/ld+json">{"@type": "EventSeries"}</script>"#;
+        assert_eq!(extract_event(&Document::from(html)), None);
+    }
+
+    #[test]
+    fn test_no_recipe() {
+        let html = r#"<script type="application/ld+json">{"@type": "Article"}</script>
+<div itemscope itemtype="https://schema.org/Person"><span itemprop="name">X</span></div>"#;
+        assert_eq!(extract_recipe(&Document::from(html)), None);
+    }
+}
@@ -0,0 +1,136 @@
+// ABOUTME: Structured extraction of <table> elements into header and row cell text.
+// ABOUTME: Normalizes ragged tables by padding short rows to a consistent width.
+
+//! Table extraction.
+//!
+//! Converts each `<table>` in extracted content into a [`TableData`] value.
+//!
+//! Key behaviors:
+//! - Headers come from the first `<thead>` row, or else a leading row made only of `<th>` cells.
+//! - Rows of nested tables are attributed to the nested table, not the outer one.
+//! - Cell text is whitespace-normalized; short rows are padded with empty strings.
+//! - Tables without any cells are skipped.
+
+use dom_query::{Document, NodeRef};
+
+use crate::result::TableData;
+
+/// Normalizes whitespace in a string by collapsing runs of whitespace into single spaces.
+fn normalize_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn tag_name(node: &NodeRef) -> String {
+    node.node_name()
+        .map(|n| n.to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+/// A table row's cell texts plus whether it was a header row.
+struct RawRow {
+    cells: Vec<String>,
+    all_th: bool,
+    in_thead: bool,
+}
+
+fn read_row(tr: &NodeRef, in_thead: bool) -> RawRow {
+    let mut cells = Vec::new();
+    let mut all_th = true;
+    for cell in tr.element_children() {
+        match tag_name(&cell).as_str() {
+            "th" => cells.push(normalize_whitespace(&cell.text())),
+            "td" => {
+                all_th = false;
+                cells.push(normalize_whitespace(&cell.text()));
+            }
+            _ => {}
+        }
+    }
+    RawRow {
+        all_th: all_th && !cells.is_empty(),
+        cells,
+        in_thead,
+    }
+}
+
+/// Collects the direct rows of a table (including those inside thead/tbody/tfoot).
+fn read_rows(table: &NodeRef) -> Vec<RawRow> {
+    let mut rows = Vec::new();
+    for child in table.element_children() {
+        match tag_name(&child).as_str() {
+            "tr" => rows.push(read_row(&child, false)),
+            section @ ("thead" | "tbody" | "tfoot") => {
+                for tr in child.element_children() {
+                    if tag_name(&tr) == "tr" {
+                        rows.push(read_row(&tr, section == "thead"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    rows
+}
+
+fn table_from_rows(mut raw: Vec<RawRow>) -> Option<TableData> {
+    raw.retain(|r| !r.cells.is_empty());
+    if raw.is_empty() {
+        return None;
+    }
+
+    let header_idx = raw
+        .iter()
+        .position(|r| r.in_thead)
+        .or_else(|| raw[0].all_th.then_some(0));
+    let headers = match header_idx {
+        Some(idx) => raw.remove(idx).cells,
+        None => Vec::new(),
+    };
+    let mut rows: Vec<Vec<String>> = raw.into_iter().map(|r| r.cells).collect();
+
+    let width = rows
+        .iter()
+        .map(Vec::len)
+        .chain(std::iter::once(headers.len()))
+        .max()
+        .unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, String::new());
+    }
+
+    Some(TableData { headers, rows })
+}
+
+/// Extract every table in `html` as structured headers and rows.
+pub fn extract_tables(html: &str) -> Vec<TableData> {
+    let doc = Document::from(html);
+    doc.select("table")
+        .nodes()
+        .iter()
+        .filter_map(|table| table_from_rows(read_rows(table)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading_th_row_used_as_headers() {
+        let html =
+            "<table><tr><th>Name</th><th>Age</th></tr><tr><td>Ann</td><td>31</td></tr></table>";
+        let tables = extract_tables(html);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].headers, vec!["Name", "Age"]);
+        assert_eq!(tables[0].rows, vec![vec!["Ann", "31"]]);
+    }
+
+    #[test]
+    fn test_nested_table_rows_stay_with_inner_table() {
+        let html = "<table><tr><td>outer<table><tr><td>inner</td></tr></table></td></tr></table>";
+        let tables = extract_tables(html);
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].rows.len(), 1);
+        assert_eq!(tables[1].rows, vec![vec!["inner"]]);
+    }
+}
@@ -0,0 +1,213 @@
+// ABOUTME: Title cleaning: strips site names and section labels from raw <title> values.
+// ABOUTME: Splits on |, ::, and spaced dashes, then picks the headline using site_name, domain and h1.
+
+//! Title cleaning.
+//!
+//! Key behaviors:
+//! - Titles without a separator (`|`, `::`, or a dash with spaces around it)
+//!   are returned unchanged.
+//! - A run of segments equal to the page's `<h1>` wins outright.
+//! - A segment naming the site (`site_name` or the domain) marks the split:
+//!   segments before it are the headline; when it leads, the headline follows.
+//! - Otherwise short trailing segments ("Section", "Site") are dropped while
+//!   the remaining headline is longer.
+
+/// Segments with at most this many words may be dropped as trailing labels.
+const MAX_LABEL_WORDS: usize = 3;
+
+/// Clean a raw page title such as "Article Headline | Site Name - Section"
+/// down to the headline.
+///
+/// `site_name` and `h1` are optional hints; `domain` is the page host
+/// (e.g. "www.example.com"). The input is returned whitespace-normalized
+/// when no split can be justified.
+pub fn clean_title(raw: &str, site_name: Option<&str>, h1: Option<&str>, domain: &str) -> String {
+    let title = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    let segments = split_segments(&title);
+    if segments.len() < 2 {
+        return title;
+    }
+    let span = |from: usize, to: usize| &title[segments[from].0..segments[to].1];
+
+    if let Some(h1) = h1.map(comparable).filter(|h| !h.is_empty()) {
+        for from in 0..segments.len() {
+            for to in from..segments.len() {
+                if comparable(span(from, to)) == h1 {
+                    return span(from, to).to_string();
+                }
+            }
+        }
+    }
+
+    let last = segments.len() - 1;
+    let site_key = site_name.map(comparable).filter(|s| !s.is_empty());
+    let is_site = |i: usize| {
+        let seg = comparable(span(i, i));
+        site_key.as_deref() == Some(seg.as_str()) || matches_domain(&seg, domain)
+    };
+    match (0..segments.len()).find(|&i| is_site(i)) {
+        Some(0) => {
+            // "Site | Section | Headline": breadcrumb-style, headline last
+            if last > 1 && word_count(span(last, last)) > MAX_LABEL_WORDS {
+                return span(last, last).to_string();
+            }
+            span(1, last).to_string()
+        }
+        Some(k) => span(0, k - 1).to_string(),
+        None => {
+            let mut end = last;
+            while end > 0 {
+                let label = word_count(span(end, end));
+                let rest = word_count(span(0, end - 1));
+                if label > MAX_LABEL_WORDS || rest <= label {
+                    break;
+                }
+                end -= 1;
+            }
+            span(0, end).to_string()
+        }
+    }
+}
+
+/// Byte ranges of the non-empty segments between title separators.
+fn split_segments(title: &str) -> Vec<(usize, usize)> {
+    let mut cuts: Vec<(usize, usize)> = Vec::new();
+    let chars: Vec<(usize, char)> = title.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        let spaced = |n: usize| {
+            i > 0 && chars[i - 1].1 == ' ' && chars.get(i + n).is_some_and(|&(_, next)| next == ' ')
+        };
+        let len = match c {
+            '|' => 1,
+            ':' if chars.get(i + 1).is_some_and(|&(_, n)| n == ':') => 2,
+            '-' | '–' | '—' if spaced(1) => 1,
+            _ => 0,
+        };
+        if len > 0 {
+            let end = chars.get(i + len).map_or(title.len(), |&(p, _)| p);
+            cuts.push((pos, end));
+            i += len;
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for (cut_start, cut_end) in cuts.into_iter().chain([(title.len(), title.len())]) {
+        let piece = &title[start..cut_start];
+        let trimmed = piece.trim();
+        if !trimmed.is_empty() {
+            let offset = start + (piece.len() - piece.trim_start().len());
+            segments.push((offset, offset + trimmed.len()));
+        }
+        start = cut_end;
+    }
+    segments
+}
+
+/// Lowercased alphanumerics only, so "The  Verge" matches "the verge".
+fn comparable(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// True when a comparable segment names the host: "example.com" or
+/// "example" for `www.example.com`.
+fn matches_domain(segment: &str, domain: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+    let host = domain.trim_start_matches("www.");
+    segment == comparable(host)
+        || host.split('.').next().map(comparable).as_deref() == Some(segment)
+}
+
+fn word_count(s: &str) -> usize {
+    s.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strips_site_name_and_section() {
+        assert_eq!(
+            clean_title(
+                "Article Headline | Site Name - Section",
+                Some("Site Name"),
+                None,
+                "news.test"
+            ),
+            "Article Headline"
+        );
+        assert_eq!(
+            clean_title(
+                "Rust 2.0 released :: Example",
+                None,
+                None,
+                "www.example.com"
+            ),
+            "Rust 2.0 released"
+        );
+        assert_eq!(
+            clean_title(
+                "The Verge – Reviews – The best laptops we tested this year",
+                Some("The  Verge"),
+                None,
+                "theverge.com"
+            ),
+            "The best laptops we tested this year"
+        );
+    }
+
+    #[test]
+    fn test_prefers_matching_h1() {
+        assert_eq!(
+            clean_title(
+                "Ask Me Anything - Live Q&A | Daily Planet",
+                None,
+                Some("Ask me anything - live Q&A"),
+                "planet.test"
+            ),
+            "Ask Me Anything - Live Q&A"
+        );
+    }
+
+    #[test]
+    fn test_drops_short_trailing_labels_without_hints() {
+        assert_eq!(
+            clean_title(
+                "Why the city is rethinking its bus lanes - Transport - Gazette",
+                None,
+                None,
+                "citypaper.test"
+            ),
+            "Why the city is rethinking its bus lanes"
+        );
+        // The headline must stay longer than the label it would replace
+        assert_eq!(
+            clean_title("Obituaries - Gazette", None, None, "x.test"),
+            "Obituaries - Gazette"
+        );
+    }
+
+    #[test]
+    fn test_leaves_unsplittable_titles_alone() {
+        assert_eq!(
+            clean_title(
+                "  A well-known  result: proofs ",
+                Some("Site"),
+                None,
+                "site.test"
+            ),
+            "A well-known result: proofs"
+        );
+        assert_eq!(clean_title("|", None, None, "x.test"), "|");
+    }
+}
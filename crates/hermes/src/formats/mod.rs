@@ -6,9 +6,14 @@
 //! This module handles converting extracted content to various output formats
 //! including cleaned HTML, Markdown, and plain text representations.
 
+use std::collections::HashMap;
+
 use dom_query::Document;
+use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::resource::is_tracking_param;
+
 /// Sanitize HTML using an ammonia policy that mirrors the Go bluemonday article policy.
 ///
 /// Allowed elements: p, br, strong, b, em, i, u, h1-h6, ul, ol, li, blockquote, pre, code,
@@ -66,11 +71,337 @@ pub fn sanitize_html(html: &str) -> String {
         .to_string()
 }
 
+/// Attributes whose values are URLs rewritten by [`absolutize_urls`].
+const URL_ATTRIBUTES: &[&str] = &["href", "src", "poster", "cite", "srcset"];
+
+/// Resolve relative `href`, `src`, `srcset`, `poster` and `cite` values in an
+/// HTML fragment against `base_url`, so the content renders outside its origin.
+///
+/// Other markup is left byte-for-byte intact. Fragment-only links (`#note-1`)
+/// and values that already carry a scheme (`https:`, `mailto:`, `data:`) are
+/// kept. Returns the input unchanged when `base_url` is not an absolute URL.
+pub fn absolutize_urls(html: &str, base_url: &str) -> String {
+    let Ok(base) = url::Url::parse(base_url.trim()) else {
+        return html.to_string();
+    };
+
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(pos) = rest.find('<') {
+        result.push_str(&rest[..=pos]);
+        rest = &rest[pos + 1..];
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            continue;
+        }
+        let end = tag_end(rest);
+        rewrite_tag_urls(&rest[..end], &base, &mut result);
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Returns the byte offset of the `>` closing the tag that `s` starts in,
+/// skipping quoted attribute values, or `s.len()` when it is unterminated.
+fn tag_end(s: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return i,
+            None => {}
+        }
+    }
+    s.len()
+}
+
+/// Copies a tag body (name and attributes, without `<`/`>`) into `out`,
+/// resolving the values of [`URL_ATTRIBUTES`].
+fn rewrite_tag_urls(tag: &str, base: &url::Url, out: &mut String) {
+    let bytes = tag.as_bytes();
+    let is_name_end = |b: u8| b.is_ascii_whitespace() || b == b'=' || b == b'/';
+    let mut i = bytes
+        .iter()
+        .position(|&b| is_name_end(b))
+        .unwrap_or(bytes.len());
+    let mut copied = 0;
+
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() || bytes[i] == b'/' {
+            i += 1;
+            continue;
+        }
+        let name_start = i;
+        while i < bytes.len() && !is_name_end(bytes[i]) {
+            i += 1;
+        }
+        let name = &tag[name_start..i];
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let (value_start, value_end) = match bytes.get(i) {
+            Some(&q) if q == b'"' || q == b'\'' => {
+                let close = tag[i + 1..]
+                    .find(q as char)
+                    .map_or(bytes.len(), |n| i + 1 + n);
+                (i + 1, close)
+            }
+            _ => {
+                let len = tag[i..].find(|c: char| c.is_ascii_whitespace());
+                (i, len.map_or(bytes.len(), |n| i + n))
+            }
+        };
+        i = (value_end + 1).min(bytes.len());
+
+        if !URL_ATTRIBUTES.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        let value = &tag[value_start..value_end];
+        let resolved = if name.eq_ignore_ascii_case("srcset") {
+            resolve_srcset(value, base)
+        } else {
+            resolve_url(value, base)
+        };
+        if let Some(resolved) = resolved {
+            out.push_str(&tag[copied..value_start]);
+            out.push_str(&resolved);
+            copied = value_end;
+        }
+    }
+    out.push_str(&tag[copied..]);
+}
+
+/// Resolves one relative URL; `None` when it should be left untouched.
+fn resolve_url(value: &str, base: &url::Url) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || value.starts_with('#') || has_scheme(value) {
+        return None;
+    }
+    base.join(value).ok().map(|u| u.to_string())
+}
+
+/// Resolves each candidate URL of a `srcset` list, keeping its descriptor.
+fn resolve_srcset(value: &str, base: &url::Url) -> Option<String> {
+    // data: URLs contain commas, so their candidates cannot be split safely
+    if value.contains("data:") {
+        return None;
+    }
+    let mut changed = false;
+    let candidates: Vec<String> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(|candidate| {
+            let (url, descriptor) = candidate
+                .split_once(char::is_whitespace)
+                .unwrap_or((candidate, ""));
+            match resolve_url(url, base) {
+                Some(resolved) => {
+                    changed = true;
+                    format!("{} {}", resolved, descriptor.trim())
+                        .trim_end()
+                        .to_string()
+                }
+                None => candidate.to_string(),
+            }
+        })
+        .collect();
+    changed.then(|| candidates.join(", "))
+}
+
+/// Returns true when `value` starts with a URL scheme such as `https:`.
+fn has_scheme(value: &str) -> bool {
+    let end = value.find([':', '/', '?', '#']);
+    end.is_some_and(|n| {
+        n > 0
+            && value.as_bytes()[n] == b':'
+            && value[..n]
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-' || b == b'.')
+    })
+}
+
+/// Default `Options.image_target_width`: wide enough for a reading column on
+/// high-density phone screens.
+pub const DEFAULT_IMAGE_TARGET_WIDTH: u32 = 1200;
+
+/// Point each `<img srcset>` in an HTML fragment at its best candidate for a
+/// `target_width` pixel slot.
+///
+/// The chosen candidate becomes `src` (replacing lazy-load placeholders) and
+/// `srcset` is rewritten without `data:` and duplicate entries. With width
+/// descriptors the narrowest candidate at least `target_width` wide wins,
+/// else the widest; with density descriptors the `1x` (or lowest) candidate
+/// wins. A `data-srcset` is used when `srcset` is missing. Returns the input
+/// unchanged when `target_width` is 0.
+pub fn select_image_sources(html: &str, target_width: u32) -> String {
+    if target_width == 0 || !html.contains("srcset") {
+        return html.to_string();
+    }
+    let doc = Document::from(html);
+    for el in doc.select("img[srcset], img[data-srcset]").iter() {
+        let Some(srcset) = el.attr("srcset").or_else(|| el.attr("data-srcset")) else {
+            continue;
+        };
+        let candidates = parse_srcset(&srcset);
+        let Some(best) = best_srcset_candidate(&candidates, target_width) else {
+            continue;
+        };
+        el.set_attr("src", &best.url);
+        let cleaned: Vec<String> = candidates
+            .iter()
+            .map(|c| match &c.descriptor {
+                Some(d) => format!("{} {}", c.url, d),
+                None => c.url.clone(),
+            })
+            .collect();
+        el.set_attr("srcset", &cleaned.join(", "));
+        el.remove_attr("data-srcset");
+    }
+    doc.select("body").inner_html().to_string()
+}
+
+/// One `srcset` entry: URL plus its `480w` / `2x` descriptor, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SrcsetCandidate {
+    pub url: String,
+    pub descriptor: Option<String>,
+}
+
+impl SrcsetCandidate {
+    /// Width in pixels of a `480w` descriptor.
+    pub fn width(&self) -> Option<u32> {
+        self.descriptor.as_deref()?.strip_suffix('w')?.parse().ok()
+    }
+
+    /// Pixel density of a `2x` descriptor; a missing descriptor counts as `1x`.
+    pub fn density(&self) -> Option<f64> {
+        match self.descriptor.as_deref() {
+            None => Some(1.0),
+            Some(d) => d.strip_suffix('x')?.parse().ok(),
+        }
+    }
+}
+
+/// Usable `srcset` candidates in order, skipping `data:` URLs and repeats.
+///
+/// Follows the HTML tokenization: a URL runs to the next whitespace (so
+/// commas inside `data:` URLs are kept), and its descriptors run to the
+/// next comma.
+pub fn parse_srcset(srcset: &str) -> Vec<SrcsetCandidate> {
+    let mut candidates: Vec<SrcsetCandidate> = Vec::new();
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+        let url_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (raw_url, after) = rest.split_at(url_end);
+        let url = raw_url.trim_end_matches(',');
+        let descriptor = if raw_url.ends_with(',') {
+            rest = after;
+            None
+        } else {
+            let desc_end = after.find(',').unwrap_or(after.len());
+            let descriptor = after[..desc_end].split_whitespace().next();
+            rest = &after[desc_end..];
+            descriptor
+        };
+        if url.is_empty() || url.starts_with("data:") || candidates.iter().any(|c| c.url == url) {
+            continue;
+        }
+        candidates.push(SrcsetCandidate {
+            url: url.to_string(),
+            descriptor: descriptor.map(str::to_lowercase),
+        });
+    }
+    candidates
+}
+
+fn best_srcset_candidate(
+    candidates: &[SrcsetCandidate],
+    target_width: u32,
+) -> Option<&SrcsetCandidate> {
+    let widths: Vec<(u32, &SrcsetCandidate)> = candidates
+        .iter()
+        .filter_map(|c| c.width().map(|w| (w, c)))
+        .collect();
+    if !widths.is_empty() {
+        return widths
+            .iter()
+            .filter(|(w, _)| *w >= target_width)
+            .min_by_key(|(w, _)| *w)
+            .or_else(|| widths.iter().max_by_key(|(w, _)| *w))
+            .map(|(_, c)| *c);
+    }
+    candidates
+        .iter()
+        .filter_map(|c| c.density().map(|d| (d, c)))
+        .min_by(|a, b| {
+            (a.0 - 1.0)
+                .abs()
+                .total_cmp(&(b.0 - 1.0).abs())
+                .then(a.0.total_cmp(&b.0))
+        })
+        .map(|(_, c)| c)
+}
+
+/// Default total size, in bytes, of the images inlined as data URIs.
+pub const DEFAULT_INLINE_IMAGES_MAX_BYTES: usize = 5 * 1024 * 1024;
+
+/// Distinct absolute http(s) `src` URLs of the `<img>` elements in `html`,
+/// in document order.
+pub fn image_source_urls(html: &str) -> Vec<String> {
+    let doc = Document::from(html);
+    let mut urls: Vec<String> = Vec::new();
+    for el in doc.select("img[src]").iter() {
+        let Some(src) = el.attr("src").map(|s| s.trim().to_string()) else {
+            continue;
+        };
+        let http = src.starts_with("http://") || src.starts_with("https://");
+        if http && !urls.contains(&src) {
+            urls.push(src);
+        }
+    }
+    urls
+}
+
+/// Replace `<img>` sources found in `data_uris` (keyed by `src`) with their
+/// data URI. `srcset` and `sizes` are dropped from replaced images so the
+/// browser does not fetch the network candidates instead.
+pub fn inline_image_sources(html: &str, data_uris: &HashMap<String, String>) -> String {
+    if data_uris.is_empty() {
+        return html.to_string();
+    }
+    let doc = Document::from(html);
+    for el in doc.select("img[src]").iter() {
+        let Some(data_uri) = el.attr("src").and_then(|s| data_uris.get(s.trim())) else {
+            continue;
+        };
+        el.set_attr("src", data_uri);
+        el.remove_attr("srcset");
+        el.remove_attr("sizes");
+    }
+    doc.select("body").inner_html().to_string()
+}
+
+static BR_TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?\s*>").unwrap());
+
 /// Preprocess HTML before conversion: replace <br> tags with newlines.
 fn preprocess_br_tags(html: &str) -> String {
     // Replace <br>, <br/>, <br /> variants with newline
-    let re = Regex::new(r"(?i)<br\s*/?\s*>").unwrap();
-    re.replace_all(html, "\n").to_string()
+    BR_TAG_RE.replace_all(html, "\n").to_string()
 }
 
 /// Collapse more than 2 consecutive blank lines to exactly 2.
@@ -157,20 +488,80 @@ fn convert_video_placeholders(text: &str) -> String {
     .to_string()
 }
 
+/// Default block-level tags that start a new line in plain-text conversion.
+pub const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "ul",
+    "ol",
+    "li",
+    "dl",
+    "dt",
+    "dd",
+    "blockquote",
+    "pre",
+    "table",
+    "tr",
+    "hr",
+    "figcaption",
+];
+
+static HORIZONTAL_SPACE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^\S\n]+").unwrap());
+
+/// Tag pattern for the default [`BLOCK_TAGS`], compiled once since
+/// [`html_to_text`] uses it on every call.
+static DEFAULT_BLOCK_TAG_RE: Lazy<Regex> = Lazy::new(|| block_tag_regex(BLOCK_TAGS));
+
+fn block_tag_regex(block_tags: &[&str]) -> Regex {
+    let names: Vec<String> = block_tags.iter().map(|t| regex::escape(t)).collect();
+    Regex::new(&format!(r"(?i)</?(?:{})\b[^>]*>", names.join("|"))).unwrap()
+}
+
+/// Insert a newline before every opening or closing tag named in `block_tags`.
+///
+/// Tag names are matched case-insensitively; all other markup is left intact.
+/// The pattern for [`BLOCK_TAGS`] is precompiled; other lists are compiled
+/// per call.
+pub fn insert_block_breaks(html: &str, block_tags: &[&str]) -> String {
+    if block_tags.is_empty() {
+        return html.to_string();
+    }
+    if block_tags == BLOCK_TAGS {
+        return DEFAULT_BLOCK_TAG_RE.replace_all(html, "\n$0").to_string();
+    }
+    block_tag_regex(block_tags)
+        .replace_all(html, "\n$0")
+        .to_string()
+}
+
 /// Convert HTML to plain text by extracting text nodes.
 ///
-/// Treats <br> as newline, collapses multiple blank lines to one,
-/// and trims leading/trailing whitespace.
+/// Treats <br> and the default [`BLOCK_TAGS`] as newlines, collapses multiple
+/// blank lines to one, and trims leading/trailing whitespace.
+///
+/// Block boundaries break lines even without whitespace between the
+/// elements: `<p>a</p><p>b</p>` gives `"a\nb"`, where earlier releases ran
+/// the text together as `"ab"`.
 pub fn html_to_text(html: &str) -> String {
-    // Preprocess: convert <br> to newlines
-    let preprocessed = preprocess_br_tags(html);
+    html_to_text_with_blocks(html, BLOCK_TAGS)
+}
+
+/// Convert HTML to plain text, breaking lines at the caller-supplied block tags.
+pub fn html_to_text_with_blocks(html: &str, block_tags: &[&str]) -> String {
+    // Preprocess: convert <br> and block boundaries to newlines
+    let preprocessed = insert_block_breaks(&preprocess_br_tags(html), block_tags);
 
     let document = Document::from(&*preprocessed);
     let raw_text = document.text().to_string();
 
     // Collapse horizontal whitespace (spaces/tabs) but preserve newlines
-    let re_spaces = Regex::new(r"[^\S\n]+").unwrap();
-    let normalized = re_spaces.replace_all(&raw_text, " ");
+    let normalized = HORIZONTAL_SPACE_RE.replace_all(&raw_text, " ");
 
     // Collapse multiple newlines to one
     let collapsed = collapse_newlines_to_one(&normalized);
@@ -255,10 +646,289 @@ pub fn extract_excerpt(html: &str) -> Option<String> {
     Some(excerpt)
 }
 
+/// Attributes dropped from canonical output because they change between fetches
+/// without reflecting an edit to the article.
+const VOLATILE_ATTRS: &[&str] = &["nonce", "loading", "decoding", "fetchpriority"];
+
+/// Image URL query keys that CMSes rotate to bust caches; dropped from
+/// canonical output along with tracking parameters.
+const CACHE_BUSTER_PARAMS: &[&str] = &["v", "ver", "version", "cb", "t", "ts", "timestamp", "_"];
+
+/// Normalize HTML into a deterministic form suitable for diffing.
+///
+/// Tags are lowercased, attributes are sorted by name, whitespace in text is
+/// collapsed, comments are dropped, and volatile attributes (nonces, `data-*`,
+/// lazy-loading hints) are removed, as are known tracking and cache-busting
+/// parameters on image URLs. Other query parameters, such as CDN signatures and
+/// sizes, are kept.
+/// Parsing the same markup twice always yields byte-identical output.
+pub fn canonicalize_html(html: &str) -> String {
+    let fragment = scraper::Html::parse_fragment(html);
+    let mut out = String::with_capacity(html.len());
+    for child in fragment.root_element().children() {
+        write_canonical_node(child, &mut out);
+    }
+    out.trim().to_string()
+}
+
+fn write_canonical_node(node: ego_tree::NodeRef<scraper::Node>, out: &mut String) {
+    match node.value() {
+        scraper::Node::Text(text) => {
+            // Runs of whitespace (including leading/trailing) collapse to one space
+            let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.starts_with(char::is_whitespace) && !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+            out.push_str(&escape_canonical(&collapsed, false));
+            if !collapsed.is_empty() && text.ends_with(char::is_whitespace) {
+                out.push(' ');
+            }
+        }
+        scraper::Node::Element(el) => {
+            let tag = el.name().to_lowercase();
+            let mut attrs: Vec<(String, String)> = el
+                .attrs()
+                .filter_map(|(name, value)| {
+                    let name = name.to_lowercase();
+                    if VOLATILE_ATTRS.contains(&name.as_str()) || name.starts_with("data-") {
+                        return None;
+                    }
+                    let value = match (tag.as_str(), name.as_str()) {
+                        ("img" | "source", "src") => strip_volatile_params(value),
+                        ("img" | "source", "srcset") => value
+                            .split(',')
+                            .map(|candidate| {
+                                let mut parts = candidate.split_whitespace();
+                                let url = strip_volatile_params(parts.next().unwrap_or(""));
+                                std::iter::once(url)
+                                    .chain(parts.map(str::to_string))
+                                    .collect::<Vec<_>>()
+                                    .join(" ")
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        _ => value.split_whitespace().collect::<Vec<_>>().join(" "),
+                    };
+                    Some((name, value))
+                })
+                .collect();
+            attrs.sort();
+
+            out.push('<');
+            out.push_str(&tag);
+            for (name, value) in attrs {
+                out.push(' ');
+                out.push_str(&name);
+                out.push_str("=\"");
+                out.push_str(&escape_canonical(&value, true));
+                out.push('"');
+            }
+            out.push('>');
+
+            if is_void_tag(&tag) {
+                return;
+            }
+            for child in node.children() {
+                write_canonical_node(child, out);
+            }
+            if out.ends_with(' ') {
+                out.pop();
+            }
+            out.push_str("</");
+            out.push_str(&tag);
+            out.push('>');
+        }
+        _ => {}
+    }
+}
+
+/// Drop known tracking parameters (see [`is_tracking_param`]) and
+/// [`CACHE_BUSTER_PARAMS`] from an image URL, leaving the rest of it
+/// byte-for-byte intact.
+fn strip_volatile_params(url: &str) -> String {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((path, query)) = rest.split_once('?') else {
+        return url.to_string();
+    };
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split('=').next().unwrap_or("");
+            let cache_buster = CACHE_BUSTER_PARAMS
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(key));
+            !pair.is_empty() && !is_tracking_param(key) && !cache_buster
+        })
+        .collect();
+
+    let mut out = path.to_string();
+    if !kept.is_empty() {
+        out.push('?');
+        out.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        out.push('#');
+        out.push_str(fragment);
+    }
+    out
+}
+
+fn escape_canonical(s: &str, in_attr: bool) -> String {
+    let escaped = s
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if in_attr {
+        escaped.replace('"', "&quot;")
+    } else {
+        escaped
+    }
+}
+
+fn is_void_tag(tag: &str) -> bool {
+    matches!(
+        tag,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn select_image_sources_picks_candidate_for_target_width() {
+        let html = r#"<img src="data:image/gif;base64,R0lGODlh" srcset="a-480.jpg 480w, a-1080.jpg 1080w, a-3840.jpg 3840w, a-1080.jpg 1080w" alt="A">
+<img srcset="b.jpg, b@2x.jpg 2x">
+<img data-srcset="c-320.jpg 320w, c-640.jpg 640w">
+<img src="d.jpg">"#;
+
+        let out = select_image_sources(html, 1000);
+        assert!(
+            out.contains(
+                r#"src="a-1080.jpg" srcset="a-480.jpg 480w, a-1080.jpg 1080w, a-3840.jpg 3840w""#
+            ),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains(r#"srcset="b.jpg, b@2x.jpg 2x" src="b.jpg""#),
+            "{}",
+            out
+        );
+        assert!(out.contains(r#"src="c-640.jpg""#), "{}", out);
+        assert!(!out.contains("data-srcset"), "{}", out);
+        assert!(out.contains(r#"<img src="d.jpg">"#), "{}", out);
+
+        assert!(select_image_sources(html, 2000).contains(r#"src="a-3840.jpg""#));
+        assert_eq!(select_image_sources(html, 0), html);
+    }
+
+    #[test]
+    fn inline_image_sources_replaces_listed_images() {
+        let html = r#"<img src="https://cdn.test/a.png" srcset="https://cdn.test/a-2x.png 2x" sizes="50vw">
+<img src="https://cdn.test/b.png"><img src="https://cdn.test/a.png"><img src="data:image/gif;base64,R0lG">"#;
+        assert_eq!(
+            image_source_urls(html),
+            vec!["https://cdn.test/a.png", "https://cdn.test/b.png"]
+        );
+
+        let data_uris = HashMap::from([(
+            "https://cdn.test/a.png".to_string(),
+            "data:image/png;base64,iVBORw0KGgo=".to_string(),
+        )]);
+        let out = inline_image_sources(html, &data_uris);
+        assert_eq!(
+            out.matches(r#"src="data:image/png;base64,iVBORw0KGgo=""#)
+                .count(),
+            2
+        );
+        assert!(!out.contains("srcset") && !out.contains("sizes"), "{}", out);
+        assert!(
+            out.contains(r#"<img src="https://cdn.test/b.png">"#),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn parse_srcset_skips_data_urls() {
+        let candidates = parse_srcset("data:image/png;base64,iVBORw0KGgo= 1x, real.png 2x");
+        let urls: Vec<&str> = candidates.iter().map(|c| c.url.as_str()).collect();
+        assert_eq!(urls, vec!["real.png"]);
+    }
+
+    #[test]
+    fn absolutize_urls_resolves_relative_values() {
+        let html = r##"<p><a href="/news/other">Other</a> <a href="#note-1">1</a>
+<a href="https://elsewhere.test/x">X</a> <a href="mailto:desk@example.com">Mail</a></p>
+<img src="img/a.jpg" srcset="img/a-480.jpg 480w, //cdn.example.com/a-960.jpg 960w">
+<video poster="../poster.jpg" src="clip.mp4"></video>
+<blockquote cite="/sources/1">Quote</blockquote>"##;
+
+        let out = absolutize_urls(html, "https://example.com/news/2024/story");
+        assert!(
+            out.contains(r#"href="https://example.com/news/other""#),
+            "{}",
+            out
+        );
+        assert!(out.contains(r##"href="#note-1""##), "{}", out);
+        assert!(
+            out.contains(r#"href="https://elsewhere.test/x""#),
+            "{}",
+            out
+        );
+        assert!(out.contains(r#"href="mailto:desk@example.com""#), "{}", out);
+        assert!(
+            out.contains(r#"src="https://example.com/news/2024/img/a.jpg""#),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains(
+                r#"srcset="https://example.com/news/2024/img/a-480.jpg 480w, https://cdn.example.com/a-960.jpg 960w""#
+            ),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains(r#"poster="https://example.com/news/poster.jpg""#),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains(r#"src="https://example.com/news/2024/clip.mp4""#),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains(r#"cite="https://example.com/sources/1""#),
+            "{}",
+            out
+        );
+
+        assert_eq!(absolutize_urls("<p>x</p>", "not a url"), "<p>x</p>");
+
+        let inline = r#"<img srcset="data:image/png;base64,AA 1x, b.png 2x">"#;
+        assert_eq!(absolutize_urls(inline, "https://example.com/"), inline);
+    }
+
     #[test]
     fn html_to_markdown_converts_h1() {
         let html = "<h1>Hello</h1>";
@@ -422,6 +1092,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn html_to_text_with_blocks_breaks_on_added_section() {
+        let html = "<section>First</section><section>Second</section>";
+        assert_eq!(html_to_text(html), "FirstSecond");
+
+        let mut tags = BLOCK_TAGS.to_vec();
+        tags.push("section");
+        assert_eq!(html_to_text_with_blocks(html, &tags), "First\nSecond");
+    }
+
+    #[test]
+    fn html_to_text_with_blocks_without_li_joins_list_items() {
+        let html = "<ul><li>One</li><li>Two</li></ul>";
+        assert_eq!(html_to_text(html), "One\nTwo");
+
+        let tags: Vec<&str> = BLOCK_TAGS
+            .iter()
+            .copied()
+            .filter(|t| *t != "li" && *t != "ul")
+            .collect();
+        assert_eq!(html_to_text_with_blocks(html, &tags), "OneTwo");
+    }
+
     #[test]
     fn extract_title_finds_title_tag() {
         let html =
@@ -488,6 +1181,33 @@ mod tests {
         assert_eq!(excerpt, Some("trimmed content".to_string()));
     }
 
+    #[test]
+    fn canonicalize_html_sorts_attrs_and_strips_volatile() {
+        let html = r#"<P CLASS="x"  id="a">Hello
+            <IMG src="https://cdn.example.com/a.jpg?w=640&utm_source=rss&sig=ab12" alt="A" data-lazy="1" loading="lazy" style="float:left">
+            world</P>"#;
+        let canonical = canonicalize_html(html);
+        assert_eq!(
+            canonical,
+            r#"<p class="x" id="a">Hello <img alt="A" src="https://cdn.example.com/a.jpg?w=640&amp;sig=ab12" style="float:left"> world</p>"#
+        );
+    }
+
+    #[test]
+    fn canonicalize_html_ignores_rotating_cache_busters() {
+        let first = canonicalize_html(
+            r#"<img src="https://cdn.example.com/a.jpg?w=640&v=1699999999" srcset="/a.jpg?ver=3 2x">"#,
+        );
+        let second = canonicalize_html(
+            r#"<img src="https://cdn.example.com/a.jpg?w=640&v=1700000042" srcset="/a.jpg?ver=4 2x">"#,
+        );
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            r#"<img src="https://cdn.example.com/a.jpg?w=640" srcset="/a.jpg 2x">"#
+        );
+    }
+
     #[test]
     fn preprocess_br_handles_variants() {
         assert_eq!(preprocess_br_tags("<br>"), "\n");
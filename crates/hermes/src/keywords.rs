@@ -0,0 +1,163 @@
+// ABOUTME: Keyword extraction from article text using RAKE (Rapid Automatic Keyword Extraction).
+// ABOUTME: Splits text at stopwords and punctuation into short phrases and ranks recurring ones.
+
+//! Keyword extraction.
+//!
+//! Key behaviors:
+//! - Candidate phrases are runs of one to three words between stopwords or
+//!   punctuation; longer runs are dropped as noise.
+//! - Each phrase scores the sum of its words' degree/frequency ratio (RAKE),
+//!   multiplied by how often the phrase occurs. Phrases seen once are ignored.
+//! - A phrase is skipped when a higher-ranked phrase already contains it, so
+//!   "transit budget" is not followed by "budget".
+//! - The stopword list is English; callers should skip other languages.
+
+use std::collections::{HashMap, HashSet};
+
+use once_cell::sync::Lazy;
+
+/// Longest phrase, in words, kept as a candidate.
+const MAX_PHRASE_WORDS: usize = 3;
+
+/// Phrases must occur at least this often to be a keyword.
+const MIN_OCCURRENCES: usize = 2;
+
+/// Common English words that separate candidate phrases.
+static STOPWORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    "a about above after again against all also am an and any are as at be because been \
+     before being below between both but by can could did do does doing down during each even \
+     few for from further had has have having he her here hers herself him himself his how i \
+     if in into is it its itself just last like many may me might more most much must my \
+     myself new no nor not now of off on once one only or other our ours ourselves out over \
+     own said same says she should so some still such than that the their theirs them \
+     themselves then there these they this those through to too two under until up very was \
+     we were what when where which while who whom why will with would year years you your \
+     yours yourself"
+        .split_whitespace()
+        .collect()
+});
+
+/// Up to `max` keywords from `text`, best first, lowercased.
+pub fn extract_keywords(text: &str, max: usize) -> Vec<String> {
+    let phrases = candidate_phrases(text);
+
+    let mut word_freq: HashMap<&str, f64> = HashMap::new();
+    let mut word_degree: HashMap<&str, f64> = HashMap::new();
+    for phrase in &phrases {
+        for word in phrase {
+            *word_freq.entry(word).or_default() += 1.0;
+            *word_degree.entry(word).or_default() += phrase.len() as f64;
+        }
+    }
+
+    // (first position, occurrences) per phrase
+    let mut seen: HashMap<String, (usize, usize)> = HashMap::new();
+    for (pos, phrase) in phrases.iter().enumerate() {
+        seen.entry(phrase.join(" ")).or_insert((pos, 0)).1 += 1;
+    }
+    let mut ranked: Vec<(String, f64, usize)> = seen
+        .into_iter()
+        .filter(|(_, (_, count))| *count >= MIN_OCCURRENCES)
+        .map(|(phrase, (pos, count))| {
+            let score: f64 = phrase
+                .split(' ')
+                .map(|w| word_degree[w] / word_freq[w])
+                .sum();
+            (phrase, score * count as f64, pos)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+
+    let mut keywords: Vec<String> = Vec::new();
+    for (phrase, _, _) in ranked {
+        if keywords.len() >= max {
+            break;
+        }
+        let padded = format!(" {} ", phrase);
+        if !keywords
+            .iter()
+            .any(|k| format!(" {} ", k).contains(&padded))
+        {
+            keywords.push(phrase);
+        }
+    }
+    keywords
+}
+
+/// Runs of content words between stopwords and punctuation, as lowercased words.
+fn candidate_phrases(text: &str) -> Vec<Vec<String>> {
+    let mut phrases = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut flush = |current: &mut Vec<String>| {
+        let phrase = std::mem::take(current);
+        if !phrase.is_empty() && phrase.len() <= MAX_PHRASE_WORDS {
+            phrases.push(phrase);
+        }
+    };
+
+    for token in text.split(|c: char| c.is_whitespace() || c == '/') {
+        let trimmed = token.trim_matches(|c: char| !c.is_alphanumeric());
+        let word = trimmed
+            .trim_end_matches("'s")
+            .trim_end_matches("’s")
+            .to_lowercase();
+        let is_word = word.chars().count() >= 2
+            && word.chars().any(char::is_alphabetic)
+            && word
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '\'' || c == '’');
+        if !is_word || STOPWORDS.contains(word.as_str()) {
+            flush(&mut current);
+            continue;
+        }
+        current.push(word);
+        // Punctuation after the word ends the phrase
+        if trimmed.len()
+            < token
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .len()
+        {
+            flush(&mut current);
+        }
+    }
+    flush(&mut current);
+    phrases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidate_phrases_split_on_stopwords_and_punctuation() {
+        // "funds electric bus routes" and "shiny electric ferry boats" are too long
+        let phrases = candidate_phrases(
+            "The city's transit budget, approved on Tuesday, funds electric bus routes \
+             and a very long list of brand new shiny electric ferry boats.",
+        );
+        let joined: Vec<String> = phrases.iter().map(|p| p.join(" ")).collect();
+        assert_eq!(
+            joined,
+            vec![
+                "city transit budget",
+                "approved",
+                "tuesday",
+                "long list",
+                "brand",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_keywords_ranks_recurring_phrases() {
+        let text = "The transit budget passed. Critics of the transit budget said bus routes \
+            were ignored. Bus routes in the north need repairs. The mayor defended the transit \
+            budget. Weather was mild.";
+        assert_eq!(
+            extract_keywords(text, 5),
+            vec!["transit budget", "bus routes"]
+        );
+        assert_eq!(extract_keywords(text, 1), vec!["transit budget"]);
+        assert!(extract_keywords("", 5).is_empty());
+    }
+}
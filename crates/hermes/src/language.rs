@@ -0,0 +1,158 @@
+// ABOUTME: Lightweight natural-language detection for article text and feed items.
+// ABOUTME: Wraps whatlang and reports ISO 639-1 codes; enabled by the `language-detection` feature.
+
+use whatlang::Lang;
+
+/// ISO 639-3 codes reported by whatlang mapped to the ISO 639-1 codes pages and feeds use.
+const ISO_639_1: &[(&str, &str)] = &[
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("aze", "az"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bul", "bg"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cmn", "zh"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("guj", "gu"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ind", "id"),
+    ("ita", "it"),
+    ("jav", "jv"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kat", "ka"),
+    ("khm", "km"),
+    ("kor", "ko"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lit", "lt"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("mkd", "mk"),
+    ("mya", "my"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nob", "nb"),
+    ("ori", "or"),
+    ("pan", "pa"),
+    ("pes", "fa"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slv", "sl"),
+    ("sna", "sn"),
+    ("spa", "es"),
+    ("srp", "sr"),
+    ("swe", "sv"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("vie", "vi"),
+    ("yid", "yi"),
+    ("zul", "zu"),
+];
+
+/// Detects the language of `text`, returning an ISO 639-1 code (e.g. "en").
+/// Returns None when the text is empty or detection isn't reliable, which is
+/// common for very short strings.
+pub fn detect_language(text: &str) -> Option<String> {
+    let info = whatlang::detect(text.trim())?;
+    if !info.is_reliable() {
+        return None;
+    }
+    Some(iso_639_1(info.lang()).to_string())
+}
+
+/// Like `detect_language`, but accepts any detection whose confidence
+/// (0.0 to 1.0) is at least `min_confidence`.
+pub fn detect_language_with_confidence(text: &str, min_confidence: f64) -> Option<String> {
+    let info = whatlang::detect(text.trim())?;
+    if info.confidence() < min_confidence {
+        return None;
+    }
+    Some(iso_639_1(info.lang()).to_string())
+}
+
+/// Falls back to the ISO 639-3 code for languages without a two-letter code.
+fn iso_639_1(lang: Lang) -> &'static str {
+    let code = lang.code();
+    ISO_639_1
+        .iter()
+        .find(|(three, _)| *three == code)
+        .map(|(_, two)| *two)
+        .unwrap_or(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_common_languages() {
+        assert_eq!(
+            detect_language(
+                "This week we talk about what happened at the conference and why it matters"
+            ),
+            Some("en".to_string())
+        );
+        assert_eq!(
+            detect_language(
+                "In dieser Folge sprechen wir darüber, warum die neue Version so lange gedauert hat"
+            ),
+            Some("de".to_string())
+        );
+        assert_eq!(
+            detect_language("Добро пожаловать в наш новый подкаст о технологиях и науке"),
+            Some("ru".to_string())
+        );
+    }
+
+    #[test]
+    fn test_empty_or_unreliable_returns_none() {
+        assert!(detect_language("").is_none());
+        assert!(detect_language("   ").is_none());
+        assert!(detect_language("ok").is_none());
+    }
+
+    #[test]
+    fn test_confidence_threshold() {
+        let text = "Le conseil municipal a voté hier soir le nouveau budget de la ville";
+        assert_eq!(
+            detect_language_with_confidence(text, 0.5),
+            Some("fr".to_string())
+        );
+        assert_eq!(detect_language_with_confidence(text, 1.01), None);
+        assert_eq!(detect_language_with_confidence("", 0.0), None);
+    }
+
+    #[test]
+    fn test_every_lang_maps_to_two_letters() {
+        for lang in Lang::all() {
+            assert_eq!(iso_639_1(*lang).len(), 2, "{:?}", lang);
+        }
+    }
+}
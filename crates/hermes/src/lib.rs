@@ -21,18 +21,25 @@
 //! ```
 
 pub mod client;
+pub mod diagnostics;
 pub mod dom;
 pub mod error;
 pub mod extractors;
 pub mod formats;
+pub mod keywords;
+#[cfg(feature = "language-detection")]
+pub mod language;
 pub mod metadata_adapter;
 pub mod options;
 pub mod reader_adapter;
 pub mod reader_result;
 pub mod resource;
 pub mod result;
+pub mod segments;
+pub mod summarize;
 
 pub use crate::client::Client;
+pub use crate::diagnostics::{set_log_hook, LogHook, LogLevel};
 pub use crate::error::{ErrorCode, ParseError};
 pub use crate::extractors::custom::{
     ContentExtractor, CustomExtractor, ExtractorRegistry, FieldExtractor, SelectorSpec,
@@ -43,4 +50,9 @@ pub use crate::metadata_adapter::{extract_metadata_only, Metadata};
 pub use crate::options::{ClientBuilder, ContentType, Options};
 pub use crate::reader_adapter::extract_reader_sync;
 pub use crate::reader_result::ReaderResult;
-pub use crate::result::{ParseResult, Result};
+pub use crate::result::{
+    Embed, EmbedPlatform, Event, ExtractionInfo, ExtractionMethod, ImageInfo, LinkInfo, OEmbed,
+    ParseResult, Product, Recipe, RedirectHop, Result, TableData, VideoInfo,
+};
+pub use crate::segments::{content_segments, Segment};
+pub use crate::summarize::summarize;
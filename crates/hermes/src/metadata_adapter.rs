@@ -21,11 +21,56 @@ pub struct Metadata {
     /// Primary image URL (resolved to absolute)
     pub image_url: String,
     pub image_alt: String,
+    /// `og:image:width` in pixels; 0 if unknown
+    #[serde(default)]
+    pub image_width: u32,
+    /// `og:image:height` in pixels; 0 if unknown
+    #[serde(default)]
+    pub image_height: u32,
     /// Favicon URL (resolved to absolute)
     pub icon_url: String,
     pub theme_color: String,
     /// Document language (e.g., "en", "fr")
     pub language: String,
+    /// `twitter:card` type (e.g., "summary_large_image")
+    #[serde(default)]
+    pub twitter_card: String,
+    /// `twitter:site` handle of the publishing site
+    #[serde(default)]
+    pub twitter_site: String,
+    /// `twitter:creator` handle of the author
+    #[serde(default)]
+    pub twitter_creator: String,
+    /// `article:published_time` as written (usually ISO 8601)
+    #[serde(default)]
+    pub published_time: String,
+    /// `article:modified_time` as written (usually ISO 8601)
+    #[serde(default)]
+    pub modified_time: String,
+    /// First `article:author` (a name or profile URL)
+    #[serde(default)]
+    pub article_author: String,
+    /// Every `article:tag`, in document order, without duplicates
+    #[serde(default)]
+    pub article_tags: Vec<String>,
+    /// App Links `al:ios:url` deep link
+    #[serde(default)]
+    pub ios_app_url: String,
+    /// App Links `al:ios:app_store_id`
+    #[serde(default)]
+    pub ios_app_store_id: String,
+    /// App Links `al:ios:app_name`
+    #[serde(default)]
+    pub ios_app_name: String,
+    /// App Links `al:android:url` deep link
+    #[serde(default)]
+    pub android_app_url: String,
+    /// App Links `al:android:package`
+    #[serde(default)]
+    pub android_package: String,
+    /// App Links `al:android:app_name`
+    #[serde(default)]
+    pub android_app_name: String,
 }
 
 /// Helper to extract meta content by property attribute.
@@ -60,6 +105,21 @@ fn get_meta_name(document: &Document, name: &str) -> Option<String> {
     }
 }
 
+/// Helper to collect every non-empty meta content for a property, deduplicated.
+fn get_meta_all(document: &Document, property: &str) -> Vec<String> {
+    let sel_str = format!("meta[property='{}'], meta[name='{}']", property, property);
+    let mut values: Vec<String> = Vec::new();
+    for elem in document.select(&sel_str).iter() {
+        if let Some(content) = elem.attr("content") {
+            let trimmed = content.trim();
+            if !trimmed.is_empty() && !values.iter().any(|v| v == trimmed) {
+                values.push(trimmed.to_string());
+            }
+        }
+    }
+    values
+}
+
 /// Helper to extract meta content by property first, then name as fallback.
 fn get_meta(document: &Document, property: &str, name: &str) -> Option<String> {
     if !property.is_empty() {
@@ -148,6 +208,38 @@ pub fn extract_metadata_only(html: &str, base_url: &str) -> Result<Metadata, Par
         .or_else(|| get_meta(&document, "twitter:image:alt", "twitter:image:alt"))
         .unwrap_or_default();
 
+    // Image dimensions: og:image:width/height (only meaningful alongside an image)
+    if !meta.image_url.is_empty() {
+        let dimension = |property: &str| {
+            get_meta(&document, property, "")
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(0)
+        };
+        meta.image_width = dimension("og:image:width");
+        meta.image_height = dimension("og:image:height");
+    }
+
+    // Twitter card fields (sites use either name= or property=)
+    let twitter = |key: &str| get_meta(&document, key, key).unwrap_or_default();
+    meta.twitter_card = twitter("twitter:card");
+    meta.twitter_site = twitter("twitter:site");
+    meta.twitter_creator = twitter("twitter:creator");
+
+    // Open Graph article fields
+    meta.published_time = get_meta(&document, "article:published_time", "").unwrap_or_default();
+    meta.modified_time = get_meta(&document, "article:modified_time", "").unwrap_or_default();
+    meta.article_author = get_meta(&document, "article:author", "").unwrap_or_default();
+    meta.article_tags = get_meta_all(&document, "article:tag");
+
+    // App Links deep links
+    let app_link = |key: &str| get_meta(&document, key, "").unwrap_or_default();
+    meta.ios_app_url = app_link("al:ios:url");
+    meta.ios_app_store_id = app_link("al:ios:app_store_id");
+    meta.ios_app_name = app_link("al:ios:app_name");
+    meta.android_app_url = app_link("al:android:url");
+    meta.android_package = app_link("al:android:package");
+    meta.android_app_name = app_link("al:android:app_name");
+
     // Icon: link[rel='icon'] > link[rel='shortcut icon'] > link[rel='apple-touch-icon']
     let icon_selectors = [
         "link[rel='icon']",
@@ -201,6 +293,44 @@ pub fn extract_metadata_only(html: &str, base_url: &str) -> Result<Metadata, Par
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_social_article_and_app_link_tags() {
+        let html = r##"
+            <html><head>
+                <meta name="twitter:card" content="summary_large_image">
+                <meta property="twitter:site" content="@example">
+                <meta name="twitter:creator" content="@writer">
+                <meta property="article:published_time" content="2024-03-01T09:30:00Z">
+                <meta property="article:modified_time" content="2024-03-02T10:00:00Z">
+                <meta property="article:author" content="https://example.com/authors/jo">
+                <meta property="article:tag" content="Rust">
+                <meta property="article:tag" content="Parsing">
+                <meta property="article:tag" content="Rust">
+                <meta property="al:ios:url" content="example://post/42">
+                <meta property="al:ios:app_store_id" content="123456">
+                <meta property="al:ios:app_name" content="Example">
+                <meta property="al:android:url" content="example://post/42">
+                <meta property="al:android:package" content="com.example.app">
+                <meta property="al:android:app_name" content="Example">
+            </head><body></body></html>
+        "##;
+
+        let meta = extract_metadata_only(html, "https://example.com/post").unwrap();
+        assert_eq!(meta.twitter_card, "summary_large_image");
+        assert_eq!(meta.twitter_site, "@example");
+        assert_eq!(meta.twitter_creator, "@writer");
+        assert_eq!(meta.published_time, "2024-03-01T09:30:00Z");
+        assert_eq!(meta.modified_time, "2024-03-02T10:00:00Z");
+        assert_eq!(meta.article_author, "https://example.com/authors/jo");
+        assert_eq!(meta.article_tags, vec!["Rust", "Parsing"]);
+        assert_eq!(meta.ios_app_url, "example://post/42");
+        assert_eq!(meta.ios_app_store_id, "123456");
+        assert_eq!(meta.ios_app_name, "Example");
+        assert_eq!(meta.android_app_url, "example://post/42");
+        assert_eq!(meta.android_package, "com.example.app");
+        assert_eq!(meta.android_app_name, "Example");
+    }
+
     #[test]
     fn test_extract_metadata_full() {
         let html = r##"
@@ -215,6 +345,8 @@ mod tests {
                 <meta property="og:url" content="https://example.com/article">
                 <meta property="og:image" content="/images/hero.jpg">
                 <meta property="og:image:alt" content="Hero image">
+                <meta property="og:image:width" content="1200">
+                <meta property="og:image:height" content="630">
                 <meta name="theme-color" content="#ff0000">
                 <link rel="icon" href="/favicon.ico">
             </head>
@@ -233,6 +365,7 @@ mod tests {
         assert_eq!(meta.url, "https://example.com/article");
         assert_eq!(meta.image_url, "https://example.com/images/hero.jpg");
         assert_eq!(meta.image_alt, "Hero image");
+        assert_eq!((meta.image_width, meta.image_height), (1200, 630));
         assert_eq!(meta.icon_url, "https://example.com/favicon.ico");
         assert_eq!(meta.theme_color, "#ff0000");
         assert_eq!(meta.language, "en");
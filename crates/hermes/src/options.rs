@@ -7,6 +7,8 @@ use std::time::Duration;
 
 use crate::client::Client;
 use crate::extractors::custom::ExtractorRegistry;
+use crate::formats::{DEFAULT_IMAGE_TARGET_WIDTH, DEFAULT_INLINE_IMAGES_MAX_BYTES};
+use crate::result::DEFAULT_READING_WPM;
 
 /// The content type format for parsed output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,6 +51,18 @@ pub struct Options {
     pub headers: HashMap<String, String>,
     pub registry: Option<ExtractorRegistry>,
     pub follow_next: bool,
+    pub extract_tables: bool,
+    pub track_provenance: bool,
+    pub collect_pull_quotes: bool,
+    pub amp_fallback: bool,
+    pub resolve_oembed: bool,
+    pub normalize_url: bool,
+    pub keep_raw_title: bool,
+    pub reading_wpm: u32,
+    pub summary_sentences: usize,
+    pub image_target_width: u32,
+    pub inline_images: bool,
+    pub inline_images_max_bytes: usize,
 }
 
 impl Default for Options {
@@ -62,6 +76,18 @@ impl Default for Options {
             headers: HashMap::new(),
             registry: None,
             follow_next: false,
+            extract_tables: false,
+            track_provenance: false,
+            collect_pull_quotes: false,
+            amp_fallback: false,
+            resolve_oembed: false,
+            normalize_url: false,
+            keep_raw_title: false,
+            reading_wpm: DEFAULT_READING_WPM,
+            summary_sentences: 0,
+            image_target_width: DEFAULT_IMAGE_TARGET_WIDTH,
+            inline_images: false,
+            inline_images_max_bytes: DEFAULT_INLINE_IMAGES_MAX_BYTES,
         }
     }
 }
@@ -131,6 +157,93 @@ impl ClientBuilder {
         self
     }
 
+    /// Enable extraction of content tables into `ParseResult.tables`.
+    pub fn extract_tables(mut self, extract: bool) -> Self {
+        self.opts.extract_tables = extract;
+        self
+    }
+
+    /// Record which selector or strategy produced the content in `ParseResult.content_selector`.
+    pub fn track_provenance(mut self, track: bool) -> Self {
+        self.opts.track_provenance = track;
+        self
+    }
+
+    /// Collect pull quotes into `ParseResult.pull_quotes` (they are removed from content either way).
+    pub fn collect_pull_quotes(mut self, collect: bool) -> Self {
+        self.opts.collect_pull_quotes = collect;
+        self
+    }
+
+    /// Fetch and extract from the page's `<link rel="amphtml">` version when the
+    /// page itself yields thin content, from `parse` and `parse_html` alike.
+    /// Costs at most one extra request.
+    pub fn amp_fallback(mut self, enabled: bool) -> Self {
+        self.opts.amp_fallback = enabled;
+        self
+    }
+
+    /// Fetch the page's advertised oEmbed document into `ParseResult.oembed`.
+    /// Costs at most one extra request, from `parse` and `parse_html` alike.
+    pub fn resolve_oembed(mut self, enabled: bool) -> Self {
+        self.opts.resolve_oembed = enabled;
+        self
+    }
+
+    /// Strip tracking parameters (`utm_*`, `fbclid`, `gclid`, ...) and the
+    /// fragment from the page URL and sort its query before it is stored in
+    /// `ParseResult.url` and used to resolve relative URLs.
+    pub fn normalize_url(mut self, normalize: bool) -> Self {
+        self.opts.normalize_url = normalize;
+        self
+    }
+
+    /// Keep the title as found (e.g. "Headline | Site - Section") in
+    /// `ParseResult.raw_title` alongside the cleaned `title`.
+    pub fn keep_raw_title(mut self, keep: bool) -> Self {
+        self.opts.keep_raw_title = keep;
+        self
+    }
+
+    /// Reading speed used for `ParseResult.reading_time_minutes`
+    /// (default `DEFAULT_READING_WPM`).
+    pub fn reading_wpm(mut self, wpm: u32) -> Self {
+        self.opts.reading_wpm = wpm;
+        self
+    }
+
+    /// Populate `ParseResult.summary` with up to `sentences` key sentences of
+    /// the content (see `summarize`); 0 disables summarization.
+    pub fn summary_sentences(mut self, sentences: usize) -> Self {
+        self.opts.summary_sentences = sentences;
+        self
+    }
+
+    /// Pixel width content images are chosen for from their `srcset`
+    /// (default `DEFAULT_IMAGE_TARGET_WIDTH`); 0 leaves `src` untouched.
+    pub fn image_target_width(mut self, width: u32) -> Self {
+        self.opts.image_target_width = width;
+        self
+    }
+
+    /// Fetch content images and embed them in HTML output as `data:` URIs, so
+    /// the content renders offline. Image requests get the same private
+    /// network checks as the page; Markdown and text output are unaffected.
+    /// Inlining runs last, so `lead_image_url`, `images`, and `keywords` are
+    /// computed from the original image URLs.
+    pub fn inline_images(mut self, enabled: bool) -> Self {
+        self.opts.inline_images = enabled;
+        self
+    }
+
+    /// Total bytes of image data [`inline_images`](Self::inline_images) may
+    /// embed (default `DEFAULT_INLINE_IMAGES_MAX_BYTES`). Images that do not
+    /// fit keep their URL.
+    pub fn inline_images_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.opts.inline_images_max_bytes = max_bytes;
+        self
+    }
+
     /// Build the Client with the configured options.
     pub fn build(self) -> Client {
         Client::new(self.opts)
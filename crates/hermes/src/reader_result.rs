@@ -25,6 +25,10 @@ pub struct ReaderResult {
     pub rendered_pages: u32,
     pub has_video_metadata: bool,
     pub video_url: String,
+    /// Canonical article URL, empty if unavailable.
+    pub canonical_url: String,
+    /// Estimated reading time in minutes, 0 for empty content.
+    pub reading_time_minutes: u32,
 }
 
 impl ReaderResult {
@@ -57,6 +61,8 @@ impl ReaderResult {
             rendered_pages: pr.rendered_pages.unwrap_or(1).max(0) as u32,
             has_video_metadata: pr.video_metadata.is_some(),
             video_url: pr.video_url.clone().unwrap_or_default(),
+            canonical_url: pr.canonical_url.clone().unwrap_or_default(),
+            reading_time_minutes: pr.reading_time_minutes,
         }
     }
 }
@@ -80,11 +86,13 @@ mod tests {
             domain: "example.com".to_string(),
             excerpt: Some("An excerpt.".to_string()),
             word_count: 100,
+            reading_time_minutes: 4,
             site_name: Some("Example Site".to_string()),
             language: Some("en".to_string()),
             theme_color: Some("#ffffff".to_string()),
             favicon: Some("https://example.com/favicon.ico".to_string()),
             video_url: Some("https://example.com/video.mp4".to_string()),
+            canonical_url: Some("https://example.com/article".to_string()),
             video_metadata: Some(serde_json::json!({"width": 1920})),
             total_pages: Some(3),
             rendered_pages: Some(2),
@@ -106,10 +114,12 @@ mod tests {
         assert_eq!(rr.theme_color, "#ffffff");
         assert_eq!(rr.published_ms, dt.timestamp_millis() as u64);
         assert_eq!(rr.word_count, 100);
+        assert_eq!(rr.reading_time_minutes, 4);
         assert_eq!(rr.total_pages, 3);
         assert_eq!(rr.rendered_pages, 2);
         assert!(rr.has_video_metadata);
         assert_eq!(rr.video_url, "https://example.com/video.mp4");
+        assert_eq!(rr.canonical_url, "https://example.com/article");
     }
 
     #[test]
@@ -122,7 +132,9 @@ mod tests {
         assert_eq!(rr.excerpt, "");
         assert_eq!(rr.published_ms, 0);
         assert_eq!(rr.word_count, 0);
+        assert_eq!(rr.reading_time_minutes, 0);
         assert_eq!(rr.total_pages, 1);
+        assert_eq!(rr.canonical_url, "");
         assert_eq!(rr.rendered_pages, 1);
         assert!(!rr.has_video_metadata);
     }
@@ -7,11 +7,25 @@ use std::net::IpAddr;
 use bytes::Bytes;
 use ipnet::{Ipv4Net, Ipv6Net};
 
+use crate::diagnostics;
 use crate::error::ParseError;
+use crate::result::RedirectHop;
 
 /// Maximum allowed content length (10 MB).
 pub const MAX_CONTENT_LENGTH: usize = 10 * 1024 * 1024;
 
+/// Maximum number of HTTP redirects followed by [`fetch`].
+pub const MAX_REDIRECTS: usize = 10;
+
+/// Request headers dropped when a redirect leaves the original host.
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
+
+/// Query parameters that only carry click/campaign tracking data.
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "msclkid", "yclid", "igshid", "mc_cid", "mc_eid", "_hsenc",
+    "_hsmi", "ref", "ref_src", "ref_url", "cmpid", "ncid", "sr_share", "spm",
+];
+
 /// Options for fetching a resource.
 #[derive(Debug, Clone)]
 pub struct FetchOptions {
@@ -30,6 +44,27 @@ impl Default for FetchOptions {
     }
 }
 
+impl FetchOptions {
+    /// These options without credential headers (`Authorization`, `Cookie`),
+    /// for requests to origins other than the page's (see [`same_origin`]).
+    pub(crate) fn without_credentials(&self) -> FetchOptions {
+        let mut opts = self.clone();
+        opts.headers
+            .retain(|key, _| !SENSITIVE_HEADERS.contains(&key.to_ascii_lowercase().as_str()));
+        opts
+    }
+
+    /// These options for a request to `target` made on behalf of `page`: kept
+    /// as they are when the two share an origin, else without credentials.
+    /// Unparseable URLs count as foreign.
+    pub(crate) fn for_target(&self, page: &str, target: &str) -> FetchOptions {
+        match (url::Url::parse(page), url::Url::parse(target)) {
+            (Ok(page), Ok(target)) if same_origin(&page, &target) => self.clone(),
+            _ => self.without_credentials(),
+        }
+    }
+}
+
 /// Result of a successful fetch operation.
 #[derive(Debug, Clone)]
 pub struct FetchResult {
@@ -38,6 +73,8 @@ pub struct FetchResult {
     pub final_url: String,
     pub content_type: Option<String>,
     pub body: Bytes,
+    /// Redirects followed on the way to `final_url`, in order.
+    pub redirects: Vec<RedirectHop>,
 }
 
 impl FetchResult {
@@ -82,8 +119,17 @@ pub(crate) fn is_private_ip(addr: &IpAddr) -> bool {
     }
 }
 
+/// Build an SSRF error for a blocked URL and report it to the diagnostic hook.
+fn ssrf_blocked(url: &str, reason: &str) -> ParseError {
+    diagnostics::warn(&format!("ssrf blocked {}: {}", url, reason));
+    ParseError::ssrf(url, "Fetch", Some(anyhow::anyhow!("{}", reason)))
+}
+
 /// Decode body bytes to a String using charset from content-type header or detection.
-fn decode_body(body: &[u8], content_type: Option<&str>) -> String {
+///
+/// `content_type` may be a full header value or a bare `charset=...` parameter;
+/// without a recognized charset the encoding is guessed with chardetng.
+pub fn decode_body(body: &[u8], content_type: Option<&str>) -> String {
     // Try to extract charset from content-type header
     if let Some(ct) = content_type {
         if let Some(charset) = extract_charset(ct) {
@@ -116,22 +162,60 @@ fn extract_charset(content_type: &str) -> Option<String> {
     None
 }
 
-/// Fetch a resource from the given URL.
-pub async fn fetch(
-    client: &reqwest::Client,
-    url: &str,
-    opts: &FetchOptions,
-) -> Result<FetchResult, ParseError> {
-    // Validate URL is non-empty
-    if url.is_empty() {
-        return Err(ParseError::invalid_url(url, "Fetch", None));
+/// True for query parameters that only carry tracking data (`utm_*`,
+/// `fbclid`, `gclid`, `ref`, ...), compared case-insensitively.
+pub fn is_tracking_param(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.starts_with("utm_") || TRACKING_PARAMS.contains(&key.as_str())
+}
+
+/// Normalizes a page URL: drops the fragment and tracking parameters (see
+/// [`is_tracking_param`]), and sorts the remaining query. Unparseable input
+/// is returned unchanged.
+pub fn normalize_url(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+    parsed.set_fragment(None);
+
+    let mut params: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !is_tracking_param(k))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    params.sort();
+    if params.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(params);
     }
+    parsed.to_string()
+}
 
-    // Parse and validate URL
-    let parsed_url = url::Url::parse(url).map_err(|e| {
-        ParseError::invalid_url(url, "Fetch", Some(anyhow::anyhow!("invalid URL: {}", e)))
-    })?;
+/// True when `a` and `b` share scheme, host, and port (default ports filled
+/// in), the boundary credential headers are not sent across.
+pub(crate) fn same_origin(a: &url::Url, b: &url::Url) -> bool {
+    a.scheme() == b.scheme()
+        && a.host() == b.host()
+        && a.port_or_known_default() == b.port_or_known_default()
+}
+
+/// Resolve `href` against `base`, keeping only absolute http(s) results.
+pub(crate) fn resolve_http_url(base: Option<&url::Url>, href: &str) -> Option<String> {
+    let resolved = match base {
+        Some(base) => base.join(href.trim()).ok()?,
+        None => url::Url::parse(href.trim()).ok()?,
+    };
+    matches!(resolved.scheme(), "http" | "https").then(|| resolved.to_string())
+}
 
+/// Validates the scheme of `parsed` and, unless private networks are allowed,
+/// rejects hosts that are or resolve to private IP addresses.
+async fn check_request_url(
+    url: &str,
+    parsed_url: &url::Url,
+    opts: &FetchOptions,
+) -> Result<(), ParseError> {
     // Check scheme
     let scheme = parsed_url.scheme();
     if scheme != "http" && scheme != "https" {
@@ -148,11 +232,7 @@ pub async fn fetch(
             // Try to parse as IP address
             if let Ok(ip) = host.parse::<IpAddr>() {
                 if is_private_ip(&ip) {
-                    return Err(ParseError::ssrf(
-                        url,
-                        "Fetch",
-                        Some(anyhow::anyhow!("private IP addresses are not allowed")),
-                    ));
+                    return Err(ssrf_blocked(url, "private IP addresses are not allowed"));
                 }
             } else {
                 // Host is a hostname, resolve it and check all addresses
@@ -169,27 +249,98 @@ pub async fn fetch(
 
                 for socket_addr in addrs {
                     if is_private_ip(&socket_addr.ip()) {
-                        return Err(ParseError::ssrf(
-                            url,
-                            "Fetch",
-                            Some(anyhow::anyhow!("private IP addresses are not allowed")),
-                        ));
+                        return Err(ssrf_blocked(url, "private IP addresses are not allowed"));
                     }
                 }
             }
         }
     }
+    Ok(())
+}
 
-    // Build request
-    let mut request = client.get(url);
-    for (key, value) in &opts.headers {
-        request = request.header(key, value);
+/// Fetch a resource from the given URL.
+///
+/// Redirects are followed here (up to [`MAX_REDIRECTS`]) so every hop gets the
+/// SSRF checks and is recorded in `FetchResult.redirects`. A caller-supplied
+/// client that follows redirects itself hides its hops.
+pub async fn fetch(
+    client: &reqwest::Client,
+    url: &str,
+    opts: &FetchOptions,
+) -> Result<FetchResult, ParseError> {
+    fetch_with_limit(client, url, opts, MAX_CONTENT_LENGTH).await
+}
+
+/// [`fetch`] with a body size limit below [`MAX_CONTENT_LENGTH`]. Larger
+/// responses fail on their Content-Length, or once the streamed body passes
+/// `max_len`, without buffering the rest.
+pub(crate) async fn fetch_with_limit(
+    client: &reqwest::Client,
+    url: &str,
+    opts: &FetchOptions,
+    max_len: usize,
+) -> Result<FetchResult, ParseError> {
+    let max_len = max_len.min(MAX_CONTENT_LENGTH);
+    // Validate URL is non-empty
+    if url.is_empty() {
+        return Err(ParseError::invalid_url(url, "Fetch", None));
     }
 
-    // Send request
-    let response = request.send().await.map_err(|e| {
-        ParseError::fetch(url, "Fetch", Some(anyhow::anyhow!("request failed: {}", e)))
+    // Parse and validate URL
+    let mut current_url = url::Url::parse(url).map_err(|e| {
+        ParseError::invalid_url(url, "Fetch", Some(anyhow::anyhow!("invalid URL: {}", e)))
     })?;
+    let mut redirects: Vec<RedirectHop> = Vec::new();
+
+    let mut response = loop {
+        check_request_url(url, &current_url, opts).await?;
+
+        // Build request; credentials only go to the originally requested origin
+        let first_origin = redirects.is_empty()
+            || url::Url::parse(url).is_ok_and(|u| same_origin(&u, &current_url));
+        let mut request = client.get(current_url.as_str());
+        for (key, value) in &opts.headers {
+            if first_origin || !SENSITIVE_HEADERS.contains(&key.to_ascii_lowercase().as_str()) {
+                request = request.header(key, value);
+            }
+        }
+
+        // Send request
+        let response = request.send().await.map_err(|e| {
+            ParseError::fetch(url, "Fetch", Some(anyhow::anyhow!("request failed: {}", e)))
+        })?;
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok());
+        let next_url = match location {
+            Some(location) if response.status().is_redirection() => {
+                current_url.join(location.trim()).map_err(|e| {
+                    ParseError::fetch(
+                        url,
+                        "Fetch",
+                        Some(anyhow::anyhow!("invalid redirect location: {}", e)),
+                    )
+                })?
+            }
+            _ => break response,
+        };
+
+        if redirects.len() >= MAX_REDIRECTS {
+            return Err(ParseError::fetch(
+                url,
+                "Fetch",
+                Some(anyhow::anyhow!("too many redirects")),
+            ));
+        }
+        diagnostics::info(&format!("redirect {} -> {}", current_url, next_url));
+        redirects.push(RedirectHop {
+            url: current_url.to_string(),
+            status: response.status().as_u16(),
+        });
+        current_url = next_url;
+    };
 
     // SSRF check after redirect: verify the final URL doesn't resolve to a private IP.
     // This re-resolution guards against DNS rebinding attacks where the DNS server
@@ -200,12 +351,9 @@ pub async fn fetch(
             // Try to parse as IP address first
             if let Ok(ip) = host.parse::<IpAddr>() {
                 if is_private_ip(&ip) {
-                    return Err(ParseError::ssrf(
+                    return Err(ssrf_blocked(
                         url,
-                        "Fetch",
-                        Some(anyhow::anyhow!(
-                            "redirect to private IP address is not allowed"
-                        )),
+                        "redirect to private IP address is not allowed",
                     ));
                 }
             } else {
@@ -232,12 +380,9 @@ pub async fn fetch(
 
                 for socket_addr in addrs {
                     if is_private_ip(&socket_addr.ip()) {
-                        return Err(ParseError::ssrf(
+                        return Err(ssrf_blocked(
                             url,
-                            "Fetch",
-                            Some(anyhow::anyhow!(
-                                "DNS rebinding detected: final URL resolves to private IP"
-                            )),
+                            "DNS rebinding detected: final URL resolves to private IP",
                         ));
                     }
                 }
@@ -256,7 +401,7 @@ pub async fn fetch(
     });
 
     if let Some(len) = content_length {
-        if len as usize > MAX_CONTENT_LENGTH {
+        if len > max_len as u64 {
             return Err(ParseError::fetch(
                 url,
                 "Fetch",
@@ -274,23 +419,25 @@ pub async fn fetch(
         .and_then(|v| v.to_str().ok())
         .map(|s| s.to_lowercase());
 
-    // Read body bytes
-    let body = response.bytes().await.map_err(|e| {
+    // Read body bytes, stopping once the size limit is passed
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| {
         ParseError::fetch(
             url,
             "Fetch",
             Some(anyhow::anyhow!("failed to read body: {}", e)),
         )
-    })?;
-
-    // Check body size
-    if body.len() > MAX_CONTENT_LENGTH {
-        return Err(ParseError::fetch(
-            url,
-            "Fetch",
-            Some(anyhow::anyhow!("content too large")),
-        ));
+    })? {
+        if body.len() + chunk.len() > max_len {
+            return Err(ParseError::fetch(
+                url,
+                "Fetch",
+                Some(anyhow::anyhow!("content too large")),
+            ));
+        }
+        body.extend_from_slice(&chunk);
     }
+    let body = Bytes::from(body);
 
     // Check status code
     if status != 200 && !opts.parse_non_200 {
@@ -307,6 +454,7 @@ pub async fn fetch(
         final_url,
         content_type,
         body,
+        redirects,
     })
 }
 
@@ -368,6 +516,137 @@ mod tests {
         assert!(err.is_fetch());
     }
 
+    #[tokio::test]
+    async fn test_fetch_records_redirect_hops() {
+        let server = MockServer::start();
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/old");
+            then.status(301).header("Location", "/moved");
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/moved");
+            then.status(302).header("Location", server.url("/final"));
+        });
+        let last = server.mock(|when, then| {
+            when.method(GET).path("/final");
+            then.status(200).body("done");
+        });
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let opts = FetchOptions {
+            allow_private_networks: true,
+            ..Default::default()
+        };
+
+        let result = fetch(&client, &server.url("/old"), &opts).await.unwrap();
+        first.assert();
+        second.assert();
+        last.assert();
+
+        assert_eq!(result.status, 200);
+        assert_eq!(result.url, server.url("/old"));
+        assert_eq!(result.final_url, server.url("/final"));
+        assert_eq!(
+            result.redirects,
+            vec![
+                RedirectHop {
+                    url: server.url("/old"),
+                    status: 301,
+                },
+                RedirectHop {
+                    url: server.url("/moved"),
+                    status: 302,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_redirect_drops_credentials_across_origins() {
+        let origin = MockServer::start();
+        let other_port = MockServer::start();
+        let start = origin.mock(|when, then| {
+            when.method(GET)
+                .path("/start")
+                .header("authorization", "Bearer secret");
+            then.status(302)
+                .header("Location", other_port.url("/final"));
+        });
+        let last = other_port.mock(|when, then| {
+            when.method(GET)
+                .path("/final")
+                .header_missing("authorization")
+                .header("x-client", "digests");
+            then.status(200).body("done");
+        });
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let opts = FetchOptions {
+            allow_private_networks: true,
+            headers: HashMap::from([
+                ("Authorization".to_string(), "Bearer secret".to_string()),
+                ("X-Client".to_string(), "digests".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        let result = fetch(&client, &origin.url("/start"), &opts).await;
+        start.assert();
+        last.assert();
+        assert_eq!(result.expect("fetch should succeed").status, 200);
+    }
+
+    #[test]
+    fn test_same_origin() {
+        let url = |s: &str| url::Url::parse(s).unwrap();
+        assert!(same_origin(
+            &url("https://example.com/a"),
+            &url("https://EXAMPLE.com:443/b?q=1")
+        ));
+        assert!(!same_origin(
+            &url("https://example.com/a"),
+            &url("http://example.com/a")
+        ));
+        assert!(!same_origin(
+            &url("https://example.com/a"),
+            &url("https://example.com:8443/a")
+        ));
+        assert!(!same_origin(
+            &url("https://example.com/a"),
+            &url("https://cdn.example.com/a")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_redirect_limit() {
+        let server = MockServer::start();
+        let looping = server.mock(|when, then| {
+            when.method(GET).path("/loop");
+            then.status(302).header("Location", "/loop");
+        });
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let opts = FetchOptions {
+            allow_private_networks: true,
+            ..Default::default()
+        };
+
+        let err = fetch(&client, &server.url("/loop"), &opts)
+            .await
+            .expect_err("should stop following");
+        looping.assert_calls(MAX_REDIRECTS + 1);
+        assert!(err.is_fetch());
+    }
+
     #[tokio::test]
     async fn test_fetch_non_200_allowed() {
         let server = MockServer::start();
@@ -422,6 +701,33 @@ mod tests {
         assert_eq!(result.body.len(), 20); // "normal sized content"
     }
 
+    #[tokio::test]
+    async fn test_fetch_with_limit_rejects_larger_bodies() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/image.png");
+            then.status(200)
+                .header("content-type", "image/png")
+                .body([0u8; 64]);
+        });
+
+        let client = create_test_client();
+        let opts = FetchOptions {
+            allow_private_networks: true,
+            ..Default::default()
+        };
+
+        let err = fetch_with_limit(&client, &server.url("/image.png"), &opts, 63)
+            .await
+            .expect_err("body over the limit should fail");
+        assert!(err.is_fetch());
+        let result = fetch_with_limit(&client, &server.url("/image.png"), &opts, 64)
+            .await
+            .expect("body at the limit should succeed");
+        assert_eq!(result.body.len(), 64);
+        mock.assert_calls(2);
+    }
+
     #[test]
     fn test_max_content_length_constant() {
         // Verify the constant is set correctly (10 MB)
@@ -498,6 +804,23 @@ mod tests {
         assert!(!is_private_ip(&"2001:4860:4860::8888".parse().unwrap()));
     }
 
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(
+            normalize_url("https://Example.com/a?utm_source=rss&b=2&fbclid=x&a=1&GCLID=y#top"),
+            "https://example.com/a?a=1&b=2"
+        );
+        assert_eq!(
+            normalize_url("https://example.com/a?utm_medium=feed#frag"),
+            "https://example.com/a"
+        );
+        assert_eq!(
+            normalize_url("https://example.com/a?ref=rss&id=7&SPM=a.b"),
+            "https://example.com/a?id=7"
+        );
+        assert_eq!(normalize_url("not a url"), "not a url");
+    }
+
     #[test]
     fn test_extract_charset() {
         assert_eq!(
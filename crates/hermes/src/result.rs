@@ -4,34 +4,313 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::formats::canonicalize_html;
+
+/// Cell text of a `<table>` found in the extracted content.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct TableData {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A schema.org Recipe found on the page (JSON-LD or microdata).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct Recipe {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    #[serde(default)]
+    pub ingredients: Vec<String>,
+    /// Instruction steps in order, with sections flattened.
+    #[serde(default)]
+    pub steps: Vec<String>,
+    pub prep_time_secs: Option<u64>,
+    pub cook_time_secs: Option<u64>,
+    /// `totalTime`, or prep + cook time when only those are given.
+    pub total_time_secs: Option<u64>,
+    /// Yield as written, e.g. "4 servings".
+    pub recipe_yield: Option<String>,
+}
+
+/// A product described by schema.org Product data or OpenGraph product tags.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct Product {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub brand: Option<String>,
+    /// Price as written by the page (e.g. "39.99"), kept as text to avoid rounding.
+    pub price: Option<String>,
+    /// ISO 4217 currency code, e.g. "EUR".
+    pub currency: Option<String>,
+    /// schema.org availability name, e.g. "InStock" or "OutOfStock".
+    pub availability: Option<String>,
+    #[serde(default)]
+    pub images: Vec<String>,
+}
+
+/// A schema.org Event (or subtype such as MusicEvent) found on the page.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct Event {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// `startDate` as written (ISO 8601; may be date-only or carry an offset).
+    pub start_date: Option<String>,
+    /// `endDate` as written.
+    pub end_date: Option<String>,
+    /// Name of the physical venue (`location`), e.g. "Riverside Hall".
+    pub venue: Option<String>,
+    /// Venue address on one line, e.g. "12 River Rd, Springfield, IL, US".
+    pub address: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+}
+
+/// Platform hosting an embedded post or video.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbedPlatform {
+    Twitter,
+    Instagram,
+    YouTube,
+    Vimeo,
+    TikTok,
+}
+
+/// A tweet, Instagram post, or video player embedded in the content.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Embed {
+    pub platform: EmbedPlatform,
+    /// Platform item id, e.g. a tweet id or YouTube video id.
+    pub id: String,
+    /// Canonical page URL of the item on the platform.
+    pub url: String,
+}
+
+/// The hosted player behind `video_url`, when it points to a known platform.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VideoInfo {
+    pub platform: EmbedPlatform,
+    /// Platform video id, e.g. "dQw4w9WgXcQ" for YouTube.
+    pub id: String,
+    /// Canonical watch page URL.
+    pub url: String,
+    /// Best-guess thumbnail: derived from the id where the platform allows it,
+    /// else the page's lead image.
+    pub thumbnail_url: Option<String>,
+}
+
+/// An image found in the extracted content.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// Absolute http(s) URL of the image.
+    pub url: String,
+    pub alt: Option<String>,
+    /// Text of the enclosing `<figure>`'s `<figcaption>`.
+    pub caption: Option<String>,
+    /// Pixel width from the `width` attribute.
+    pub width: Option<u32>,
+    /// Pixel height from the `height` attribute.
+    pub height: Option<u32>,
+}
+
+/// A link found in the extracted content.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct LinkInfo {
+    /// Absolute http(s) URL the link points to.
+    pub url: String,
+    /// Whitespace-normalized anchor text; empty for image-only links.
+    pub text: String,
+    /// Lowercased `rel` tokens, e.g. `["nofollow", "noopener"]`.
+    #[serde(default)]
+    pub rel: Vec<String>,
+    /// True when the link leaves the page's host (ignoring `www.`).
+    pub external: bool,
+}
+
+/// An oEmbed response describing the page, as served by its provider.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct OEmbed {
+    /// oEmbed `type`: "video", "photo", "rich" or "link".
+    pub kind: String,
+    pub title: Option<String>,
+    pub author_name: Option<String>,
+    pub provider_name: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub thumbnail_width: Option<u32>,
+    pub thumbnail_height: Option<u32>,
+    /// Provider embed markup, unsanitized; render it only in a sandbox.
+    pub html: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+/// One HTTP redirect followed while fetching a page.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct RedirectHop {
+    /// URL that answered with the redirect.
+    pub url: String,
+    /// Redirect status code (301, 302, 303, 307 or 308).
+    pub status: u16,
+}
+
+/// How the article content was located on the page.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionMethod {
+    /// A site-specific custom extractor's content selector matched.
+    CustomExtractor,
+    /// The readability scorer picked a candidate element.
+    Readability,
+    /// Nothing usable was extracted, so JSON-LD `articleBody` was used.
+    JsonLd,
+    /// No candidate was found, so the whole `<body>` was used.
+    Body,
+}
+
+/// Diagnostics describing how the content was extracted and how much to trust it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExtractionInfo {
+    pub method: ExtractionMethod,
+    /// True when the content came from the page's AMP version.
+    #[serde(default)]
+    pub from_amp: bool,
+    /// Readability score of the winning candidate; `None` for other methods.
+    pub candidate_score: Option<i32>,
+    /// Share of the content text that is link text, from 0.0 to 1.0.
+    pub link_density: f64,
+    /// Length of the content text in characters.
+    pub content_length: usize,
+    /// Rough 0.0-1.0 estimate that the content is a readable article, combining
+    /// the method, content length and link density. Reader mode is usually
+    /// worth offering above 0.5.
+    pub confidence: f64,
+}
+
 /// The result of parsing a page, containing extracted article data.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ParseResult {
     pub url: String,
     pub title: String,
+    /// Title before site-name/section cleaning; populated only with `Options.keep_raw_title`.
+    #[serde(default)]
+    pub raw_title: Option<String>,
     pub content: String,
     #[serde(skip_serializing, skip_deserializing)]
     pub raw_html: Option<String>,
+    /// All authors joined with ", " (see `authors`).
     pub author: Option<String>,
+    /// Every author found, in byline order.
+    #[serde(default)]
+    pub authors: Vec<String>,
     pub date_published: Option<DateTime<Utc>>,
+    pub date_modified: Option<DateTime<Utc>>,
+    /// True when `date_modified` is more than an hour after `date_published`.
+    #[serde(default)]
+    pub was_updated_after_publish: bool,
     pub lead_image_url: Option<String>,
     pub dek: Option<String>,
     pub domain: String,
     pub excerpt: Option<String>,
+    /// Extractive summary of the content; populated only with `Options.summary_sentences`.
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Ranked keywords: meta keywords and `article:tag` values first, then
+    /// recurring phrases from the content.
+    #[serde(default)]
+    pub keywords: Vec<String>,
     pub word_count: i32,
+    /// Estimated reading time of the extracted content in whole minutes
+    /// (see `Options.reading_wpm`); 0 for empty content.
+    #[serde(default)]
+    pub reading_time_minutes: u32,
     pub direction: Option<String>,
     pub total_pages: Option<i32>,
     pub rendered_pages: Option<i32>,
     pub site_name: Option<String>,
+    /// Publisher name from JSON-LD.
+    #[serde(default)]
+    pub publisher: Option<String>,
     pub site_title: Option<String>,
     pub site_image: Option<String>,
     pub description: Option<String>,
     pub language: Option<String>,
+    /// Normalized `og:locale` (e.g. "en-US").
+    pub locale: Option<String>,
+    /// Normalized, deduplicated `og:locale:alternate` values.
+    #[serde(default)]
+    pub alternate_locales: Vec<String>,
     pub theme_color: Option<String>,
     pub favicon: Option<String>,
     pub video_url: Option<String>,
     pub video_metadata: Option<serde_json::Value>,
+    /// Platform, id and thumbnail derived from `video_url` for YouTube, Vimeo and TikTok.
+    #[serde(default)]
+    pub video: Option<VideoInfo>,
     pub next_page_url: Option<String>,
+    /// Absolute `<link rel="canonical">` URL, falling back to `og:url`.
+    #[serde(default)]
+    pub canonical_url: Option<String>,
+    /// `(hreflang, url)` pairs from `<link rel="alternate" hreflang>`, with
+    /// normalized language tags (`x-default` kept as-is) and absolute URLs.
+    #[serde(default)]
+    pub alternates: Vec<(String, String)>,
+    /// Absolute RSS/Atom/JSON Feed URLs advertised via `<link rel="alternate">`.
+    #[serde(default)]
+    pub feeds: Vec<String>,
+    /// Breadcrumb trail as `(title, url)` pairs from the site root down to
+    /// this page, with absolute URLs.
+    #[serde(default)]
+    pub breadcrumbs: Vec<(String, String)>,
+    /// Absolute URL of the page's AMP version (`<link rel="amphtml">`).
+    #[serde(default)]
+    pub amp_url: Option<String>,
+    /// Absolute URL of the page's JSON oEmbed endpoint
+    /// (`<link rel="alternate" type="application/json+oembed">`).
+    #[serde(default)]
+    pub oembed_url: Option<String>,
+    /// The fetched oEmbed response; populated only with `Options.resolve_oembed`.
+    #[serde(default)]
+    pub oembed: Option<OEmbed>,
+    /// URLs that redirected (HTTP or `<meta http-equiv="refresh">`) on the way
+    /// to `url`, in order.
+    #[serde(default)]
+    pub redirect_chain: Vec<String>,
+    /// HTTP redirect hops with their status codes, in order.
+    #[serde(default)]
+    pub redirects: Vec<RedirectHop>,
+    /// Status code of the final response; `None` for `parse_html`.
+    #[serde(default)]
+    pub http_status: Option<u16>,
+    /// Structured tables from the content; populated only with `Options.extract_tables`.
+    #[serde(default)]
+    pub tables: Vec<TableData>,
+    /// Links in the extracted content, in order and deduplicated by URL.
+    #[serde(default)]
+    pub links: Vec<LinkInfo>,
+    /// Images in the extracted content, in order and deduplicated by URL.
+    #[serde(default)]
+    pub images: Vec<ImageInfo>,
+    /// Tweets, Instagram posts, TikToks and YouTube/Vimeo players embedded in the content.
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// Locator of the content region: the custom selector, `readability:<tag.class>`,
+    /// `json-ld:articleBody`, or `body`. Populated only with `Options.track_provenance`.
+    pub content_selector: Option<String>,
+    /// How the content was extracted, for deciding whether to offer reader mode.
+    #[serde(default)]
+    pub extraction: Option<ExtractionInfo>,
+    /// Structured recipe data, when the page describes a schema.org Recipe.
+    #[serde(default)]
+    pub recipe: Option<Recipe>,
+    /// Product details, when the page is a product page.
+    #[serde(default)]
+    pub product: Option<Product>,
+    /// Event details, when the page describes a schema.org Event.
+    #[serde(default)]
+    pub event: Option<Event>,
+    /// Pull-quote text removed from the content; populated only with `Options.collect_pull_quotes`.
+    #[serde(default)]
+    pub pull_quotes: Vec<String>,
 }
 
 impl ParseResult {
@@ -115,14 +394,76 @@ impl ParseResult {
             .as_ref()
             .map_or(false, |u| !u.is_empty())
     }
+
+    /// Returns the content in a deterministic, diff-friendly form.
+    ///
+    /// See [`canonicalize_html`] for the normalization rules. Two parses of the
+    /// same article produce byte-identical output, so the value can be stored and
+    /// compared across fetches to detect edits.
+    pub fn canonical_content(&self) -> String {
+        canonicalize_html(&self.content)
+    }
 }
 
 /// Type alias for Go-like naming convention.
 pub type Result = ParseResult;
 
-/// Count words in a text string using whitespace splitting.
+/// Count words in a text string.
+///
+/// Scripts written without spaces (Han, Hiragana, Katakana) count one word
+/// per character; everything else counts whitespace-separated runs. CJK
+/// punctuation (`。`, `、`, `「」`) separates but is not counted, so mixed
+/// text like "Rust 编程语言" counts as 5.
 pub fn word_count(text: &str) -> i32 {
-    text.split_whitespace().count() as i32
+    let mut count = 0;
+    let mut in_word = false;
+    for c in text.chars() {
+        if is_cjk_char(c) {
+            count += 1;
+            in_word = false;
+        } else if c.is_whitespace() || is_cjk_punctuation(c) {
+            in_word = false;
+        } else if !in_word {
+            count += 1;
+            in_word = true;
+        }
+    }
+    count
+}
+
+/// Han ideographs and Japanese kana.
+pub(crate) fn is_cjk_char(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{309F}'   // Hiragana
+        | '\u{30A0}'..='\u{30FF}' // Katakana
+        | '\u{31F0}'..='\u{31FF}' // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}' // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}' // Halfwidth katakana
+        | '\u{20000}'..='\u{2FA1F}' // CJK extensions B-F, compatibility supplement
+    )
+}
+
+/// CJK symbols and punctuation, plus fullwidth ASCII punctuation.
+fn is_cjk_punctuation(c: char) -> bool {
+    matches!(c, '\u{3000}'..='\u{303F}' | '\u{FF01}'..='\u{FF0F}' | '\u{FF1A}'..='\u{FF20}' | '\u{FF5B}'..='\u{FF65}')
+}
+
+/// Default reading speed in words per minute for `reading_time_minutes`.
+pub const DEFAULT_READING_WPM: u32 = 238;
+
+/// Estimated minutes to read `words` words and look at `images` images.
+///
+/// The first image adds 12 seconds, each later one a second less, down to 3
+/// seconds from the tenth image on. The result is rounded up, so any
+/// non-empty content takes at least one minute; a `wpm` of 0 uses
+/// `DEFAULT_READING_WPM`.
+pub fn reading_time_minutes(words: usize, images: usize, wpm: u32) -> u32 {
+    let wpm = if wpm == 0 { DEFAULT_READING_WPM } else { wpm };
+    let image_secs: usize = (0..images).map(|i| 12usize.saturating_sub(i).max(3)).sum();
+    let total_secs = words as f64 * 60.0 / f64::from(wpm) + image_secs as f64;
+    (total_secs / 60.0).ceil() as u32
 }
 
 #[cfg(test)]
@@ -213,6 +554,27 @@ mod tests {
         assert!(result.has_date());
     }
 
+    #[test]
+    fn test_canonical_content_is_stable_across_attribute_order() {
+        let a = ParseResult {
+            content: r#"<p id="lede" class="intro">Hello   <a title="t" href="/x">world</a></p>"#
+                .to_string(),
+            ..Default::default()
+        };
+        let b = ParseResult {
+            content: r#"<p class="intro" id="lede">Hello <a href="/x" title="t">world</a></p>"#
+                .to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(a.canonical_content(), a.canonical_content());
+        assert_eq!(a.canonical_content(), b.canonical_content());
+        assert_eq!(
+            a.canonical_content(),
+            r#"<p class="intro" id="lede">Hello <a href="/x" title="t">world</a></p>"#
+        );
+    }
+
     #[test]
     fn test_has_image() {
         let mut result = ParseResult::default();
@@ -224,4 +586,27 @@ mod tests {
         result.lead_image_url = Some("https://example.com/img.png".to_string());
         assert!(result.has_image());
     }
+
+    #[test]
+    fn test_reading_time_minutes() {
+        assert_eq!(reading_time_minutes(0, 0, 238), 0);
+        assert_eq!(reading_time_minutes(1, 0, 238), 1);
+        assert_eq!(reading_time_minutes(476, 0, 238), 2);
+        assert_eq!(reading_time_minutes(477, 0, 238), 3);
+        assert_eq!(reading_time_minutes(500, 0, 0), 3);
+        // 12 + 11 + ... + 3 = 75 seconds for ten images, then 3 each
+        assert_eq!(reading_time_minutes(0, 10, 238), 2);
+        assert_eq!(reading_time_minutes(200, 2, 200), 2);
+        assert_eq!(reading_time_minutes(200, 0, 200), 1);
+    }
+
+    #[test]
+    fn test_word_count_is_script_aware() {
+        assert_eq!(word_count("Hello,  world — again"), 4);
+        assert_eq!(word_count("今天天气很好。我们去公园吧！"), 12);
+        assert_eq!(word_count("東京タワーに行きました"), 11);
+        assert_eq!(word_count("Rust 编程语言"), 5);
+        assert_eq!(word_count("안녕하세요 세계"), 2);
+        assert_eq!(word_count("「」、。 "), 0);
+    }
 }
@@ -0,0 +1,220 @@
+// ABOUTME: Splits extracted article content into narratable segments for TTS chunking.
+// ABOUTME: Walks block structure so headings, paragraphs, and list items become separate units.
+
+use dom_query::{Document, NodeRef};
+use serde::{Deserialize, Serialize};
+
+use crate::formats::BLOCK_TAGS;
+use crate::result::ParseResult;
+
+/// A narratable unit of article text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segment {
+    /// One of `"heading"`, `"paragraph"`, or `"list_item"`.
+    pub kind: String,
+    pub text: String,
+}
+
+/// Blocks whose content is not narrated (code listings and tables).
+const SKIP_BLOCK_TAGS: &[&str] = &["pre", "table"];
+
+/// Elements never narrated wherever they appear; unlike [`SKIP_BLOCK_TAGS`]
+/// they do not split the surrounding paragraph.
+const SILENT_TAGS: &[&str] = &["script", "style", "noscript", "svg", "math"];
+
+const HEADING_TAGS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// Split a parse result's content into ordered narratable segments.
+///
+/// HTML content is walked block by block; code blocks and tables are skipped,
+/// while inline `<code>` is read as part of its sentence.
+/// Non-HTML content (markdown or text output) is split on blank lines, with
+/// markdown `#` lines reported as headings.
+pub fn content_segments(result: &ParseResult) -> Vec<Segment> {
+    if result.content.contains('<') {
+        html_segments(&result.content)
+    } else {
+        plain_segments(&result.content)
+    }
+}
+
+fn html_segments(html: &str) -> Vec<Segment> {
+    let doc = Document::from(html);
+    let mut out = Vec::new();
+    let body = doc.select("body");
+    if let Some(root) = body.nodes().first() {
+        walk_children(root, &mut out);
+    }
+    out
+}
+
+fn tag_name(node: &NodeRef) -> String {
+    node.node_name()
+        .map(|n| n.to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+fn is_block(tag: &str) -> bool {
+    BLOCK_TAGS.contains(&tag) || HEADING_TAGS.contains(&tag) || SKIP_BLOCK_TAGS.contains(&tag)
+}
+
+/// Text of `node`, leaving out the content of [`SILENT_TAGS`] elements.
+fn narrated_text(node: &NodeRef) -> String {
+    if node.is_text() {
+        return node.text().to_string();
+    }
+    if node.is_element() && SILENT_TAGS.contains(&tag_name(node).as_str()) {
+        return String::new();
+    }
+    node.children_it(false)
+        .map(|child| narrated_text(&child))
+        .collect()
+}
+
+fn has_block_descendant(node: &NodeRef) -> bool {
+    node.descendants_it()
+        .any(|d| d.is_element() && is_block(&tag_name(&d)))
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn push_segment(out: &mut Vec<Segment>, kind: &str, text: &str) {
+    let text = normalize(text);
+    if !text.is_empty() {
+        out.push(Segment {
+            kind: kind.to_string(),
+            text,
+        });
+    }
+}
+
+/// Walk a container's children, grouping loose inline content into paragraphs.
+fn walk_children(node: &NodeRef, out: &mut Vec<Segment>) {
+    let mut inline = String::new();
+    for child in node.children_it(false) {
+        if child.is_text() {
+            inline.push_str(&child.text());
+            continue;
+        }
+        if !child.is_element() {
+            continue;
+        }
+        let tag = tag_name(&child);
+        if SILENT_TAGS.contains(&tag.as_str()) {
+            continue;
+        }
+        if !is_block(&tag) && !has_block_descendant(&child) {
+            inline.push_str(&narrated_text(&child));
+            continue;
+        }
+        push_segment(out, "paragraph", &inline);
+        inline.clear();
+        walk_block(&child, &tag, out);
+    }
+    push_segment(out, "paragraph", &inline);
+}
+
+fn walk_block(node: &NodeRef, tag: &str, out: &mut Vec<Segment>) {
+    if SKIP_BLOCK_TAGS.contains(&tag) {
+        return;
+    }
+    if HEADING_TAGS.contains(&tag) {
+        push_segment(out, "heading", &narrated_text(node));
+        return;
+    }
+    if has_block_descendant(node) {
+        walk_children(node, out);
+        return;
+    }
+    let kind = if tag == "li" {
+        "list_item"
+    } else {
+        "paragraph"
+    };
+    push_segment(out, kind, &narrated_text(node));
+}
+
+fn plain_segments(text: &str) -> Vec<Segment> {
+    let mut out = Vec::new();
+    let mut para = String::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            push_segment(&mut out, "paragraph", &para);
+            para.clear();
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            push_segment(&mut out, "paragraph", &para);
+            para.clear();
+            push_segment(&mut out, "heading", heading.trim_start_matches('#'));
+        } else {
+            para.push(' ');
+            para.push_str(trimmed);
+        }
+    }
+    push_segment(&mut out, "paragraph", &para);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_with(content: &str) -> ParseResult {
+        ParseResult {
+            content: content.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_heading_and_paragraphs_yield_ordered_segments() {
+        let result = result_with(
+            "<div><h2>Intro</h2><p>First <em>para</em>.</p><pre>let x = 1;</pre><p>Second para.</p></div>",
+        );
+        let segments = content_segments(&result);
+        let kinds: Vec<&str> = segments.iter().map(|s| s.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["heading", "paragraph", "paragraph"]);
+        assert_eq!(segments[0].text, "Intro");
+        assert_eq!(segments[1].text, "First para.");
+        assert_eq!(segments[2].text, "Second para.");
+    }
+
+    #[test]
+    fn test_inline_code_stays_in_its_paragraph() {
+        let result = result_with(
+            "<p>Use <code>x</code> here<script>track()</script>.</p><pre><code>let x = 1;</code></pre>",
+        );
+        assert_eq!(
+            content_segments(&result),
+            vec![Segment {
+                kind: "paragraph".into(),
+                text: "Use x here.".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_markdown_content_splits_on_blank_lines() {
+        let result = result_with("## Title\n\nOne line\ncontinues.\n\nTwo.");
+        let segments = content_segments(&result);
+        assert_eq!(
+            segments,
+            vec![
+                Segment {
+                    kind: "heading".into(),
+                    text: "Title".into()
+                },
+                Segment {
+                    kind: "paragraph".into(),
+                    text: "One line continues.".into()
+                },
+                Segment {
+                    kind: "paragraph".into(),
+                    text: "Two.".into()
+                },
+            ]
+        );
+    }
+}
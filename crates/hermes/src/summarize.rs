@@ -0,0 +1,285 @@
+// ABOUTME: Extractive summarization of article text using TextRank over sentence similarity.
+// ABOUTME: Runs offline with no language resources; picks the most central sentences in document order.
+
+//! Extractive summarization.
+//!
+//! Key behaviors:
+//! - Text is split into sentences at `.`, `!`, `?` (and CJK `。！？`) and at
+//!   line breaks; common abbreviations ("Dr.", "e.g.") do not end a sentence.
+//! - Sentences are compared by cosine similarity of TF-ISF word weights, so
+//!   words that appear in most sentences ("the", "de") carry little weight
+//!   without a stopword list.
+//! - TextRank (PageRank over the similarity graph) scores each sentence; the
+//!   top scorers are returned in their original order.
+
+use std::collections::HashMap;
+
+use crate::result::is_cjk_char;
+
+/// Sentences shorter than this many words are never picked.
+const MIN_SENTENCE_WORDS: usize = 4;
+
+/// Unpunctuated lines (headings, captions) need at least this many words to be picked.
+const MIN_UNPUNCTUATED_WORDS: usize = 12;
+
+/// Only the first sentences are ranked, keeping the O(n²) graph bounded.
+const MAX_SENTENCES: usize = 300;
+
+const DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 50;
+const CONVERGENCE: f64 = 1e-6;
+
+/// Words that end with a period without ending the sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "st", "jr", "sr", "vs", "etc", "inc", "ltd", "co", "no",
+    "fig", "e.g", "i.e", "u.s", "u.k", "a.m", "p.m",
+];
+
+/// Pick the `sentences` most representative sentences of `text`, returned in
+/// document order.
+///
+/// Returns every candidate sentence when there are no more than `sentences`,
+/// and an empty list for empty text or `sentences == 0`.
+pub fn summarize(text: &str, sentences: usize) -> Vec<String> {
+    if sentences == 0 {
+        return Vec::new();
+    }
+    let candidates: Vec<(String, Vec<String>)> = split_sentences(text)
+        .into_iter()
+        .filter_map(|s| {
+            let words = tokenize(&s);
+            let punctuated = s.ends_with(['.', '!', '?', '。', '！', '？', '"', '”', '»']);
+            let min = if punctuated {
+                MIN_SENTENCE_WORDS
+            } else {
+                MIN_UNPUNCTUATED_WORDS
+            };
+            (words.len() >= min).then_some((s, words))
+        })
+        .take(MAX_SENTENCES)
+        .collect();
+    if candidates.len() <= sentences {
+        return candidates.into_iter().map(|(s, _)| s).collect();
+    }
+
+    let scores = text_rank(&candidates.iter().map(|(_, w)| w).collect::<Vec<_>>());
+    let mut ranked: Vec<usize> = (0..candidates.len()).collect();
+    // Highest score first; earlier sentences win ties
+    ranked.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]).then(a.cmp(&b)));
+    ranked.truncate(sentences);
+    ranked.sort_unstable();
+    ranked
+        .into_iter()
+        .map(|i| candidates[i].0.clone())
+        .collect()
+}
+
+/// Split text into trimmed sentences at terminal punctuation and line breaks.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for line in text.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut start = 0;
+        for i in 0..chars.len() {
+            let c = chars[i];
+            let ends = match c {
+                '。' | '！' | '？' => true,
+                '.' | '!' | '?' => {
+                    let next = chars.get(i + 1).copied();
+                    let after_space = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                    next.is_none_or(char::is_whitespace)
+                        && !after_space.is_some_and(|c| c.is_lowercase())
+                        && !(c == '.' && is_abbreviation(&chars[start..i]))
+                }
+                _ => false,
+            };
+            if ends {
+                // Keep closing quotes and brackets with the sentence
+                let mut end = i + 1;
+                while chars
+                    .get(end)
+                    .is_some_and(|c| matches!(c, '"' | '\'' | '”' | '’' | ')' | '»' | '」'))
+                {
+                    end += 1;
+                }
+                push_sentence(&mut out, &chars[start..end]);
+                start = end;
+            }
+        }
+        push_sentence(&mut out, &chars[start..]);
+    }
+    out
+}
+
+fn push_sentence(out: &mut Vec<String>, chars: &[char]) {
+    let sentence: String = chars.iter().collect();
+    let sentence = sentence.trim();
+    if !sentence.is_empty() {
+        out.push(sentence.to_string());
+    }
+}
+
+/// True when the word before a period is an abbreviation or a single letter (an initial).
+fn is_abbreviation(before: &[char]) -> bool {
+    let word: String = before
+        .iter()
+        .rev()
+        .take_while(|c| !c.is_whitespace())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect::<String>()
+        .trim_start_matches(['(', '"', '“'])
+        .to_lowercase();
+    word.chars().count() == 1 || ABBREVIATIONS.contains(&word.as_str())
+}
+
+/// Lowercased words; CJK characters count as one word each.
+fn tokenize(sentence: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for c in sentence.chars() {
+        if is_cjk_char(c) {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            words.push(c.to_string());
+        } else if c.is_alphanumeric() {
+            current.extend(c.to_lowercase());
+        } else if !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// TextRank scores for sentences given as word lists.
+fn text_rank(sentences: &[&Vec<String>]) -> Vec<f64> {
+    let n = sentences.len();
+
+    // Inverse sentence frequency: words in every sentence weigh nothing
+    let mut sentence_freq: HashMap<&str, usize> = HashMap::new();
+    for words in sentences {
+        let mut seen: Vec<&str> = words.iter().map(String::as_str).collect();
+        seen.sort_unstable();
+        seen.dedup();
+        for word in seen {
+            *sentence_freq.entry(word).or_default() += 1;
+        }
+    }
+    let vectors: Vec<HashMap<&str, f64>> = sentences
+        .iter()
+        .map(|words| {
+            let mut tf: HashMap<&str, f64> = HashMap::new();
+            for word in words.iter() {
+                *tf.entry(word.as_str()).or_default() += 1.0;
+            }
+            for (word, weight) in tf.iter_mut() {
+                *weight *= (n as f64 / sentence_freq[word] as f64).ln();
+            }
+            tf
+        })
+        .collect();
+    let norms: Vec<f64> = vectors
+        .iter()
+        .map(|v| v.values().map(|w| w * w).sum::<f64>().sqrt())
+        .collect();
+
+    let mut weights = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if norms[i] == 0.0 || norms[j] == 0.0 {
+                continue;
+            }
+            let dot: f64 = vectors[i]
+                .iter()
+                .filter_map(|(word, w)| vectors[j].get(word).map(|v| w * v))
+                .sum();
+            let similarity = dot / (norms[i] * norms[j]);
+            weights[i][j] = similarity;
+            weights[j][i] = similarity;
+        }
+    }
+    let out_sums: Vec<f64> = weights.iter().map(|row| row.iter().sum()).collect();
+
+    let mut scores = vec![1.0; n];
+    for _ in 0..MAX_ITERATIONS {
+        let next: Vec<f64> = (0..n)
+            .map(|i| {
+                let incoming: f64 = (0..n)
+                    .filter(|&j| out_sums[j] > 0.0)
+                    .map(|j| weights[j][i] / out_sums[j] * scores[j])
+                    .sum();
+                (1.0 - DAMPING) + DAMPING * incoming
+            })
+            .collect();
+        let delta: f64 = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+        scores = next;
+        if delta < CONVERGENCE {
+            break;
+        }
+    }
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sentences() {
+        let text = "Dr. Smith arrived at 9 a.m. on Monday. Was it late? \
+            \"Not at all,\" she said. The U.S. team agreed!\nHeading line\n3.5 percent rose.";
+        assert_eq!(
+            split_sentences(text),
+            vec![
+                "Dr. Smith arrived at 9 a.m. on Monday.",
+                "Was it late?",
+                "\"Not at all,\" she said.",
+                "The U.S. team agreed!",
+                "Heading line",
+                "3.5 percent rose.",
+            ]
+        );
+        assert_eq!(
+            split_sentences("今天下雨了。我们在家。"),
+            vec!["今天下雨了。", "我们在家。"]
+        );
+    }
+
+    #[test]
+    fn test_summarize_picks_central_sentences_in_order() {
+        let text = "\
+The city council approved a new budget for public transit on Tuesday.
+Local Weather
+Rain is expected over the weekend with cooler temperatures.
+The transit budget adds new bus routes and extends train service hours.
+Council members said the transit budget was the largest in a decade.
+A bakery on Main Street celebrated its fiftieth anniversary.
+Riders have asked the council for better bus and train service for years.";
+
+        let summary = summarize(text, 2);
+        assert_eq!(summary.len(), 2);
+        assert!(
+            summary.iter().all(|s| s.contains("transit")),
+            "{:?}",
+            summary
+        );
+        let first = text.find(summary[0].as_str()).unwrap();
+        let second = text.find(summary[1].as_str()).unwrap();
+        assert!(first < second);
+    }
+
+    #[test]
+    fn test_summarize_short_and_empty_text() {
+        assert_eq!(
+            summarize("Only one sentence is here. Tiny.", 3),
+            vec!["Only one sentence is here."]
+        );
+        assert!(summarize("", 3).is_empty());
+        assert!(summarize("Some words that form a sentence.", 0).is_empty());
+    }
+}